@@ -64,6 +64,7 @@ async fn main() -> Result<(), Error> {
             check_constraints: true,
             fire_triggers: false,
             keep_nulls: true,
+            keep_identity: false,
             table_lock: false,
             order_hint: None,
         });
@@ -209,6 +209,7 @@ fn demonstrate_error_categorization() {
                 server: None,
                 procedure: None,
                 line: 0,
+                additional: Vec::new(),
             },
         ),
         (
@@ -221,6 +222,7 @@ fn demonstrate_error_categorization() {
                 server: None,
                 procedure: None,
                 line: 1,
+                additional: Vec::new(),
             },
         ),
     ];
@@ -39,11 +39,10 @@
 
 use bytes::{Buf, Bytes, BytesMut};
 use tds_protocol::ProtocolError;
-use tds_protocol::token::{ColMetaData, ColumnData, NbcRow, RawRow, Token, TokenParser};
+use tds_protocol::token::{ColMetaData, ColumnData, NbcRow, RawRow, Token};
 use tds_protocol::types::TypeId;
 
 use crate::Client;
-use crate::client::response::server_token_to_error;
 use crate::error::{Error, Result};
 use crate::plp::{PlpDecoder, PlpEvent};
 use crate::row::{Column, Row};
@@ -71,7 +70,6 @@ pub struct BlobStream<'a, S: ConnectionState = Ready> {
     buf: Bytes,
     /// END_OF_MESSAGE seen — no more packets will arrive.
     eom: bool,
-    encryption_enabled: bool,
     /// Full result-set metadata (all columns, including the trailing MAX ones).
     meta: ColMetaData,
     /// Metadata for just the leading scalar columns (for row decoding).
@@ -111,7 +109,6 @@ impl<'a, S: ConnectionState> BlobStream<'a, S> {
         client: &'a mut Client<S>,
         buf: Bytes,
         eom: bool,
-        encryption_enabled: bool,
         meta: ColMetaData,
         first_blob: usize,
         blob_count: usize,
@@ -137,7 +134,6 @@ impl<'a, S: ConnectionState> BlobStream<'a, S> {
             client,
             buf,
             eom,
-            encryption_enabled,
             meta,
             prefix_meta,
             scalar_row_meta: std::sync::Arc::new(crate::row::ColMetaData::new(scalar_columns)),
@@ -338,6 +334,7 @@ impl<'a, S: ConnectionState> BlobStream<'a, S> {
                         &raw,
                         &self.prefix_meta,
                         &self.scalar_row_meta,
+                        self.client.lenient_utf16(),
                     )?;
                     self.begin_row(None);
                     return Ok(row);
@@ -363,6 +360,7 @@ impl<'a, S: ConnectionState> BlobStream<'a, S> {
                         &nbc,
                         &self.prefix_meta,
                         &self.scalar_row_meta,
+                        self.client.lenient_utf16(),
                     )?;
                     self.begin_row(Some(nbc));
                     return Ok(row);
@@ -379,12 +377,14 @@ impl<'a, S: ConnectionState> BlobStream<'a, S> {
     /// the stream continues or has finished.
     async fn parse_control_token(&mut self) -> Result<Control> {
         loop {
-            let mut parser =
-                TokenParser::new(self.buf.clone()).with_encryption(self.encryption_enabled);
+            let mut parser = self.client.create_parser(self.buf.clone());
             match parser.next_token_with_metadata(Some(&self.meta)) {
                 Ok(Some(token)) => {
                     let consumed = self.buf.len() - parser.remaining();
                     self.buf.advance(consumed);
+                    if let Token::Error(err) = token {
+                        return Err(self.fail(&err).await);
+                    }
                     return self.classify(token);
                 }
                 Ok(None) => {
@@ -403,6 +403,18 @@ impl<'a, S: ConnectionState> BlobStream<'a, S> {
         }
     }
 
+    /// Read the rest of a response that failed with `err`, returning the
+    /// error with the server's later messages attached (see
+    /// `Client::finish_streamed_error`).
+    async fn fail(&mut self, err: &tds_protocol::token::ServerError) -> Error {
+        self.finished = true;
+        let mut source = self.client.row_source().with_metadata(self.meta.clone());
+        source.push_packet(std::mem::take(&mut self.buf), self.eom);
+        self.client
+            .finish_streamed_error(err, Vec::new(), &mut source)
+            .await
+    }
+
     fn classify(&mut self, token: Token) -> Result<Control> {
         match token {
             Token::Done(d) => {
@@ -417,15 +429,14 @@ impl<'a, S: ConnectionState> BlobStream<'a, S> {
                     Control::Finished
                 })
             }
-            Token::Error(e) => Err(server_token_to_error(&e)),
             Token::ColMetaData(_) => Err(Error::Protocol(
                 "blob streaming does not support multiple result sets".to_string(),
             )),
             Token::EnvChange(ref e) => {
-                // Keep the transaction descriptor in sync with raw
-                // BEGIN/COMMIT/ROLLBACK seen mid-stream, as the buffered
-                // readers do.
-                self.client.apply_transaction_env_change(e);
+                // Keep the transaction descriptor and current database in
+                // sync with raw BEGIN/COMMIT/ROLLBACK or USE seen mid-stream,
+                // as the buffered readers do.
+                self.client.apply_env_change(e);
                 Ok(Control::Continue)
             }
             // DoneProc / DoneInProc / Info / Order / etc.
@@ -516,3 +527,96 @@ enum Control {
     Continue,
     Finished,
 }
+
+/// An [`AsyncRead`](tokio::io::AsyncRead) over one MAX value streamed from the
+/// socket. Returned by [`Client::read_blob`](crate::Client::read_blob).
+///
+/// Bytes are handed out as each PLP chunk arrives, never after collecting the
+/// whole value. A NULL value reads as empty. Once the value is read to the end,
+/// the rest of the response is drained, so the connection is clean for the
+/// next request. Dropping the reader early is safe: the client cancels the
+/// abandoned response before its next request.
+#[must_use = "readers must be consumed; dropping one discards the rest of the value"]
+pub struct BlobStreamReader<'a, S: ConnectionState = Ready> {
+    state: ReadState<'a, S>,
+    /// Unread remainder of the last chunk.
+    chunk: Bytes,
+}
+
+/// A pending [`BlobStream::read_chunk`], owning the stream until it completes.
+type ChunkFuture<'a, S> = std::pin::Pin<
+    Box<dyn Future<Output = (Box<BlobStream<'a, S>>, Result<Option<Bytes>>)> + Send + 'a>,
+>;
+
+enum ReadState<'a, S: ConnectionState> {
+    Idle(Box<BlobStream<'a, S>>),
+    Reading(ChunkFuture<'a, S>),
+    Done,
+}
+
+impl<'a, S: ConnectionState> BlobStreamReader<'a, S> {
+    /// Wrap a stream positioned on the blob to read.
+    pub(crate) fn new(stream: BlobStream<'a, S>) -> Self {
+        Self {
+            state: ReadState::Idle(Box::new(stream)),
+            chunk: Bytes::new(),
+        }
+    }
+}
+
+/// Read the next chunk; at the end of the value, drain the rest of the
+/// response instead.
+async fn next_chunk<S: ConnectionState>(
+    mut stream: Box<BlobStream<'_, S>>,
+) -> (Box<BlobStream<'_, S>>, Result<Option<Bytes>>) {
+    let result = match stream.read_chunk().await {
+        Ok(None) => {
+            async {
+                while stream.next().await?.is_some() {}
+                Ok(None)
+            }
+            .await
+        }
+        other => other,
+    };
+    (stream, result)
+}
+
+impl<'a, S: ConnectionState + Send + 'a> tokio::io::AsyncRead for BlobStreamReader<'a, S> {
+    fn poll_read(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        use std::task::Poll;
+
+        let this = self.get_mut();
+        loop {
+            if !this.chunk.is_empty() {
+                let n = this.chunk.len().min(buf.remaining());
+                buf.put_slice(&this.chunk.split_to(n));
+                return Poll::Ready(Ok(()));
+            }
+            match std::mem::replace(&mut this.state, ReadState::Done) {
+                ReadState::Done => return Poll::Ready(Ok(())),
+                ReadState::Idle(stream) => {
+                    this.state = ReadState::Reading(Box::pin(next_chunk(stream)));
+                }
+                ReadState::Reading(mut future) => match future.as_mut().poll(cx) {
+                    Poll::Pending => {
+                        this.state = ReadState::Reading(future);
+                        return Poll::Pending;
+                    }
+                    Poll::Ready((stream, Ok(Some(chunk)))) => {
+                        this.chunk = chunk;
+                        this.state = ReadState::Idle(stream);
+                    }
+                    Poll::Ready((_, Ok(None))) => return Poll::Ready(Ok(())),
+                    Poll::Ready((_, Err(e))) => {
+                        return Poll::Ready(Err(std::io::Error::other(e)));
+                    }
+                },
+            }
+        }
+    }
+}
@@ -33,9 +33,9 @@
 //!
 //! let mut writer = client.bulk_insert(&builder).await?;
 //!
-//! // Send rows — buffered in memory, sent on finish()
+//! // Send rows — written to the connection every batch_size rows
 //! for user in users {
-//!     writer.send_row(&[&user.id, &user.name, &user.email])?;
+//!     writer.write_row(&[&user.id, &user.name, &user.email]).await?;
 //! }
 //!
 //! let result = writer.finish().await?;
@@ -70,15 +70,15 @@ use crate::error::Error;
 /// affect performance, logging, and constraint checking.
 #[derive(Debug, Clone)]
 pub struct BulkOptions {
-    /// Rows-per-batch hint sent to the server.
+    /// Rows-per-batch hint sent to the server, and the client's flush size.
     ///
     /// When non-zero this is emitted as the `ROWS_PER_BATCH` hint on the
-    /// `INSERT BULK` statement, which helps the server pick a query plan.
-    /// It does **not** change client-side behavior: [`BulkWriter`] buffers
-    /// all rows in memory and sends them as a single batch on
-    /// [`finish()`](BulkWriter::finish). Incremental flushing is planned
-    /// alongside the response-streaming work.
-    /// Default: 0 (no hint).
+    /// `INSERT BULK` statement, which helps the server pick a query plan,
+    /// and [`BulkWriter::write_row`] writes the buffered rows to the
+    /// connection every `batch_size` rows. The rows still form one BulkLoad
+    /// message, which the server commits as a single batch on
+    /// [`finish()`](BulkWriter::finish).
+    /// Default: 0 (no hint; rows are buffered until `finish()`).
     pub batch_size: usize,
 
     /// Check constraints during insert.
@@ -96,6 +96,15 @@ pub struct BulkOptions {
     /// Default: true
     pub keep_nulls: bool,
 
+    /// Load the given values into IDENTITY columns (the `KEEP_IDENTITY`
+    /// hint, the bulk-load counterpart of `SET IDENTITY_INSERT ON`).
+    ///
+    /// When false, leave identity columns off the builder and the server
+    /// generates their values; [`Client::bulk_insert`](crate::Client::bulk_insert)
+    /// rejects a builder that lists one.
+    /// Default: false
+    pub keep_identity: bool,
+
     /// Acquire a table-level lock for the duration of the bulk operation.
     ///
     /// This can significantly improve performance by reducing lock
@@ -118,6 +127,7 @@ impl Default for BulkOptions {
             check_constraints: true,
             fire_triggers: false,
             keep_nulls: true,
+            keep_identity: false,
             table_lock: false,
             order_hint: None,
         }
@@ -376,6 +386,32 @@ pub struct BulkInsertResult {
     pub has_errors: bool,
 }
 
+/// Result of a [`Client::bulk_upsert`](crate::Client::bulk_upsert) operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BulkUpsertResult {
+    /// Number of rows bulk-loaded into the staging table.
+    pub rows_staged: u64,
+    /// Number of target rows inserted or updated by the `MERGE`.
+    pub rows_merged: u64,
+}
+
+/// COLMETADATA `fIdentity` flag.
+const COLUMN_FLAG_IDENTITY: u16 = 0x0010;
+/// COLMETADATA `fComputed` flag.
+const COLUMN_FLAG_COMPUTED: u16 = 0x0020;
+
+/// Session temp table that [`Client::bulk_upsert`](crate::Client::bulk_upsert)
+/// stages rows in before merging them into the target.
+pub(crate) const UPSERT_STAGING_TABLE: &str = "#bulk_upsert";
+
+/// Drop the upsert staging table if this session has one.
+pub(crate) fn drop_upsert_staging_statement() -> String {
+    format!(
+        "IF OBJECT_ID('tempdb..{UPSERT_STAGING_TABLE}') IS NOT NULL DROP TABLE {UPSERT_STAGING_TABLE}"
+    )
+}
+
 /// Builder for configuring a bulk insert operation.
 #[derive(Debug)]
 pub struct BulkInsertBuilder {
@@ -447,6 +483,14 @@ impl BulkInsertBuilder {
         self
     }
 
+    /// Load the given values into IDENTITY columns instead of having the
+    /// server generate them. See [`BulkOptions::keep_identity`].
+    #[must_use]
+    pub fn keep_identity(mut self, enabled: bool) -> Self {
+        self.options.keep_identity = enabled;
+        self
+    }
+
     /// Get the table name.
     pub fn table_name(&self) -> &str {
         &self.table_name
@@ -511,6 +555,9 @@ impl BulkInsertBuilder {
         if self.options.keep_nulls {
             hints.push("KEEP_NULLS".to_string());
         }
+        if self.options.keep_identity {
+            hints.push("KEEP_IDENTITY".to_string());
+        }
         if self.options.table_lock {
             hints.push("TABLOCK".to_string());
         }
@@ -534,6 +581,172 @@ impl BulkInsertBuilder {
 
         Ok(sql)
     }
+
+    /// Build the `SELECT TOP 0` that fetches the server's metadata for the
+    /// builder's columns, in the builder's order (every column when none
+    /// are specified).
+    ///
+    /// Selecting only the builder's columns keeps the metadata aligned with
+    /// the `INSERT BULK` column list when identity, computed or defaulted
+    /// columns are left out.
+    pub(crate) fn build_schema_discovery_statement(&self) -> Result<String, Error> {
+        crate::validation::validate_qualified_identifier(&self.table_name)?;
+        let columns = if self.columns.is_empty() {
+            "*".to_string()
+        } else {
+            let mut names = Vec::with_capacity(self.columns.len());
+            for col in &self.columns {
+                crate::validation::validate_identifier(&col.name)?;
+                names.push(col.name.as_str());
+            }
+            names.join(", ")
+        };
+        Ok(format!("SELECT TOP 0 {columns} FROM {}", self.table_name))
+    }
+
+    /// Reject builder columns the server will not load: computed columns,
+    /// and identity columns unless [`BulkOptions::keep_identity`] is set.
+    ///
+    /// Only the builder's listed columns are checked. With no column list the
+    /// discovery statement selects every column, and the server generates
+    /// identity values itself, so there is nothing to reject.
+    ///
+    /// `server_columns` is the result of
+    /// [`build_schema_discovery_statement`](Self::build_schema_discovery_statement).
+    pub(crate) fn check_insertable(
+        &self,
+        server_columns: &[tds_protocol::token::ColumnData],
+    ) -> Result<(), Error> {
+        if self.columns.is_empty() {
+            return Ok(());
+        }
+        let listed = server_columns.iter().filter(|col| {
+            self.columns
+                .iter()
+                .any(|c| c.name.eq_ignore_ascii_case(&col.name))
+        });
+        for col in listed {
+            if col.flags & COLUMN_FLAG_COMPUTED != 0 {
+                return Err(Error::Config(format!(
+                    "column `{}` of `{}` is computed and cannot be bulk-loaded; \
+                     leave it out of the builder's columns",
+                    col.name, self.table_name
+                )));
+            }
+            if col.flags & COLUMN_FLAG_IDENTITY != 0 && !self.options.keep_identity {
+                return Err(Error::Config(format!(
+                    "column `{}` of `{}` is an IDENTITY column; leave it out of \
+                     the builder's columns to have the server generate it, or \
+                     set keep_identity to load the given values",
+                    col.name, self.table_name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// A builder for loading this builder's columns into the upsert staging
+    /// table, with the same options.
+    pub(crate) fn upsert_staging(&self) -> Self {
+        Self {
+            table_name: UPSERT_STAGING_TABLE.to_string(),
+            columns: self.columns.clone(),
+            options: self.options.clone(),
+        }
+    }
+
+    /// Build the batch that (re)creates the upsert staging table as an empty
+    /// copy of the target's columns.
+    ///
+    /// The `UNION ALL` keeps `SELECT INTO` from copying an `IDENTITY`
+    /// property, which would make the bulk load silently discard the staged
+    /// values of that column.
+    pub(crate) fn build_upsert_staging_statement(&self) -> Result<String, Error> {
+        let columns = self.upsert_column_list()?;
+        Ok(format!(
+            "{drop}; SELECT TOP 0 {columns} INTO {staging} FROM {target} \
+             UNION ALL SELECT TOP 0 {columns} FROM {target}",
+            drop = drop_upsert_staging_statement(),
+            staging = UPSERT_STAGING_TABLE,
+            target = self.table_name,
+        ))
+    }
+
+    /// Build the `MERGE` that applies the staged rows to the target, matching
+    /// on `key_columns`.
+    ///
+    /// Matched rows have every non-key column updated; unmatched rows are
+    /// inserted. `HOLDLOCK` keeps a concurrent upsert of the same keys from
+    /// inserting between the match and the insert.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no key columns are given or a key column is not
+    /// one of the builder's columns.
+    pub(crate) fn build_merge_statement(&self, key_columns: &[&str]) -> Result<String, Error> {
+        let columns = self.upsert_column_list()?;
+        if key_columns.is_empty() {
+            return Err(Error::Config(
+                "bulk upsert requires at least one key column".into(),
+            ));
+        }
+        for key in key_columns {
+            if !self.columns.iter().any(|c| c.name == *key) {
+                return Err(Error::Config(format!(
+                    "bulk upsert key column '{key}' is not one of the bulk insert columns"
+                )));
+            }
+        }
+
+        let on = key_columns
+            .iter()
+            .map(|k| format!("t.{k} = s.{k}"))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let updates = self
+            .columns
+            .iter()
+            .filter(|c| !key_columns.contains(&c.name.as_str()))
+            .map(|c| format!("t.{name} = s.{name}", name = c.name))
+            .collect::<Vec<_>>();
+        let values = self
+            .columns
+            .iter()
+            .map(|c| format!("s.{}", c.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut sql = format!(
+            "MERGE {} WITH (HOLDLOCK) AS t USING {} AS s ON {}",
+            self.table_name, UPSERT_STAGING_TABLE, on
+        );
+        // With only key columns there is nothing to update.
+        if !updates.is_empty() {
+            sql.push_str(" WHEN MATCHED THEN UPDATE SET ");
+            sql.push_str(&updates.join(", "));
+        }
+        sql.push_str(&format!(
+            " WHEN NOT MATCHED BY TARGET THEN INSERT ({columns}) VALUES ({values});"
+        ));
+        Ok(sql)
+    }
+
+    /// Validate the target and columns of an upsert and join the column
+    /// names into a select list.
+    fn upsert_column_list(&self) -> Result<String, Error> {
+        crate::validation::validate_qualified_identifier(&self.table_name)?;
+        if self.columns.is_empty() {
+            return Err(Error::Config(
+                "bulk upsert requires the columns to be specified".into(),
+            ));
+        }
+        let mut names = Vec::with_capacity(self.columns.len());
+        for col in &self.columns {
+            crate::validation::validate_identifier(&col.name)?;
+            names.push(col.name.as_str());
+        }
+        Ok(names.join(", "))
+    }
 }
 
 /// Validate a SQL type specifier to prevent SQL injection.
@@ -1012,14 +1225,17 @@ impl BulkInsert {
                     // MONEY / SMALLMONEY — fixed-point scaled by 10_000, not DECIMAL format.
                     encode_money_value(*d, col, buf, is_fixed)?;
                 } else {
+                    // Same defaults as the column metadata written for it.
                     let precision = col.precision.unwrap_or(18);
+                    let scale = col.scale.unwrap_or(0);
+                    let d = mssql_types::__private::fit_decimal(*d, precision, scale)?;
                     let len = decimal_byte_length(precision);
                     buf.put_u8(len);
 
                     // Sign: 0 = negative, 1 = positive
                     buf.put_u8(if d.is_sign_negative() { 0 } else { 1 });
 
-                    // Mantissa as unsigned 128-bit integer
+                    // Mantissa as unsigned 128-bit integer, at the column's scale
                     let mantissa = d.mantissa().unsigned_abs();
                     let mantissa_bytes = mantissa.to_le_bytes();
                     buf.put_slice(&mantissa_bytes[..((len - 1) as usize)]);
@@ -1042,10 +1258,8 @@ impl BulkInsert {
             #[cfg(feature = "chrono")]
             SqlValue::Time(t) => {
                 let scale = col.scale.unwrap_or(7);
-                let len = time_byte_length(scale);
-                buf.put_u8(len);
-                // Encode time with proper scale handling
-                encode_time_with_scale(*t, scale, buf);
+                buf.put_u8(mssql_types::__private::time_byte_length(scale));
+                mssql_types::__private::encode_time_with_scale(*t, scale, buf);
             }
 
             #[cfg(feature = "chrono")]
@@ -1070,12 +1284,9 @@ impl BulkInsert {
                     }
                 } else {
                     let scale = col.scale.unwrap_or(7);
-                    let time_len = time_byte_length(scale);
-                    let total_len = time_len + 3;
+                    let total_len = mssql_types::__private::time_byte_length(scale) + 3;
                     buf.put_u8(total_len);
-                    // Encode time then date
-                    encode_time_with_scale(dt.time(), scale, buf);
-                    mssql_types::__private::encode_date(dt.date(), buf)?;
+                    mssql_types::__private::encode_datetime2_with_scale(*dt, scale, buf)?;
                 }
             }
             #[cfg(feature = "chrono")]
@@ -1106,18 +1317,9 @@ impl BulkInsert {
             #[cfg(feature = "chrono")]
             SqlValue::DateTimeOffset(dto) => {
                 let scale = col.scale.unwrap_or(7);
-                let time_len = time_byte_length(scale);
-                let total_len = time_len + 3 + 2;
+                let total_len = mssql_types::__private::time_byte_length(scale) + 3 + 2;
                 buf.put_u8(total_len);
-                // The wire date/time portion is UTC per MS-TDS §2.2.5.5.1.9,
-                // not the local wall-clock.
-                let utc = dto.naive_utc();
-                encode_time_with_scale(utc.time(), scale, buf);
-                mssql_types::__private::encode_date(utc.date(), buf)?;
-                // Timezone offset in minutes
-                use chrono::Offset;
-                let offset_minutes = (dto.offset().fix().local_minus_utc() / 60) as i16;
-                buf.put_i16_le(offset_minutes);
+                mssql_types::__private::encode_datetimeoffset_with_scale(*dto, scale, buf)?;
             }
 
             #[cfg(feature = "json")]
@@ -1248,20 +1450,6 @@ fn encode_varchar_for_collation(value: &str, collation: Option<&Collation>) -> V
     tds_protocol::__private::encode_str_for_collation(value, collation)
 }
 
-/// Encode time with specific scale (for bulk copy).
-#[cfg(feature = "chrono")]
-fn encode_time_with_scale(time: chrono::NaiveTime, scale: u8, buf: &mut BytesMut) {
-    use chrono::Timelike;
-
-    let nanos = time.num_seconds_from_midnight() as u64 * 1_000_000_000 + time.nanosecond() as u64;
-    let intervals = nanos / time_scale_divisor(scale);
-    let len = time_byte_length(scale);
-
-    for i in 0..len {
-        buf.put_u8(((intervals >> (i * 8)) & 0xFF) as u8);
-    }
-}
-
 impl BulkInsert {
     /// Write the DONE token signaling completion.
     fn write_done(&mut self) {
@@ -1320,7 +1508,8 @@ impl BulkInsert {
 
             packets.push(packet);
             offset += payload_size;
-            self.packet_id = self.packet_id.wrapping_add(1);
+            // Packet IDs wrap from 255 back to 1; 0 is never used.
+            self.packet_id = self.packet_id.wrapping_add(1).max(1);
         }
 
         packets
@@ -1338,13 +1527,17 @@ impl BulkInsert {
 
     /// Check if a batch flush is needed.
     ///
-    /// Note: [`BulkWriter`] does not consult this — it buffers every row and
-    /// sends one batch on finish. This is a helper for callers driving the
-    /// lower-level [`BulkInsert`] packet API manually.
+    /// [`BulkWriter::write_row`] flushes when this returns true.
     pub fn should_flush(&self) -> bool {
         self.batch_size > 0 && self.rows_in_batch >= self.batch_size
     }
 
+    /// Take the buffered bytes for sending and start a new batch.
+    pub(crate) fn take_buffered(&mut self) -> bytes::Bytes {
+        self.rows_in_batch = 0;
+        self.buffer.split().freeze()
+    }
+
     /// Prepare for finishing the bulk operation.
     /// Writes the DONE token and returns final packets.
     pub fn finish_packets(&mut self) -> Vec<BytesMut> {
@@ -1367,6 +1560,9 @@ impl BulkInsert {
 /// Created via [`crate::client::Client::bulk_insert()`]. Rows are buffered in
 /// memory as they are added with [`send_row()`](BulkWriter::send_row), then
 /// transmitted to the server when [`finish()`](BulkWriter::finish) is called.
+/// [`write_row()`](BulkWriter::write_row) instead writes them to the
+/// connection every [`batch_size`](BulkOptions::batch_size) rows, so large
+/// loads are not held in memory.
 ///
 /// The writer holds a mutable reference to the [`crate::Client`], preventing
 /// other operations on the connection while the bulk insert is in progress.
@@ -1393,19 +1589,26 @@ impl BulkInsert {
 pub struct BulkWriter<'a, S: crate::state::ConnectionState> {
     client: &'a mut crate::client::Client<S>,
     bulk: BulkInsert,
+    /// The BulkLoad message, once the first rows have been flushed.
+    message: Option<crate::client::StreamedMessage>,
 }
 
 impl<'a, S: crate::state::ConnectionState> BulkWriter<'a, S> {
     /// Create a new bulk writer.
     pub(crate) fn new(client: &'a mut crate::client::Client<S>, bulk: BulkInsert) -> Self {
-        Self { client, bulk }
+        Self {
+            client,
+            bulk,
+            message: None,
+        }
     }
 
     /// Add a row to the bulk insert buffer.
     ///
     /// Values are encoded immediately but not sent to the server until
-    /// [`finish()`](BulkWriter::finish) is called. The number of values must
-    /// match the number of columns defined for this bulk insert.
+    /// [`flush()`](BulkWriter::flush) or [`finish()`](BulkWriter::finish) is
+    /// called. The number of values must match the number of columns defined
+    /// for this bulk insert.
     pub fn send_row<T: ToSql>(&mut self, values: &[T]) -> Result<(), Error> {
         self.bulk.send_row(values)
     }
@@ -1415,15 +1618,74 @@ impl<'a, S: crate::state::ConnectionState> BulkWriter<'a, S> {
         self.bulk.send_row_values(values)
     }
 
+    /// Add a row, flushing the buffer once it holds
+    /// [`batch_size`](BulkOptions::batch_size) rows.
+    ///
+    /// With a non-zero batch size this bounds the writer's memory to one
+    /// batch of encoded rows however many rows are inserted.
+    pub async fn write_row<T: ToSql>(&mut self, values: &[T]) -> Result<(), Error> {
+        self.bulk.send_row(values)?;
+        self.flush_full_batch().await
+    }
+
+    /// Add a row of pre-converted SQL values, flushing the buffer once it
+    /// holds [`batch_size`](BulkOptions::batch_size) rows.
+    pub async fn write_row_values(&mut self, values: &[SqlValue]) -> Result<(), Error> {
+        self.bulk.send_row_values(values)?;
+        self.flush_full_batch().await
+    }
+
+    /// Write the buffered rows to the connection.
+    ///
+    /// The rows go out as further packets of the single BulkLoad message, so
+    /// the server still applies the whole bulk insert as one batch when
+    /// [`finish()`](BulkWriter::finish) ends the message. Each flush runs
+    /// under [`command_timeout`](crate::Config::command_timeout) with the
+    /// same abandon-on-expiry semantics as `finish()`.
+    ///
+    /// Once anything has been flushed, the connection is mid-request until
+    /// `finish()` completes; dropping the writer instead leaves the client
+    /// in flight, so the pool discards it.
+    pub async fn flush(&mut self) -> Result<(), Error> {
+        if self.bulk.rows_in_batch() == 0 {
+            return Ok(());
+        }
+        let deadline = self.client.command_deadline();
+        let data = self.bulk.take_buffered();
+        let message = self
+            .message
+            .get_or_insert_with(|| self.client.start_bulk_load());
+        let send = message.extend(self.client, &data);
+        match deadline {
+            Some(d) => tokio::time::timeout(d, send)
+                .await
+                .map_err(|_| Error::CommandTimeout)?,
+            None => send.await,
+        }
+    }
+
+    /// Flush if a full batch of rows is buffered.
+    pub(crate) async fn flush_full_batch(&mut self) -> Result<(), Error> {
+        if self.bulk.should_flush() {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
     /// Get the number of rows buffered so far.
     pub fn total_rows(&self) -> u64 {
         self.bulk.total_rows()
     }
 
+    /// Get the number of rows buffered since the last flush.
+    pub fn buffered_rows(&self) -> usize {
+        self.bulk.rows_in_batch()
+    }
+
     /// Finish the bulk insert operation and send all buffered data to the server.
     ///
-    /// Writes the DONE token, sends the accumulated row data as a BulkLoad
-    /// (0x07) message, and reads the server's response.
+    /// Writes the DONE token, sends the rows not yet flushed as the end of
+    /// the BulkLoad (0x07) message, and reads the server's response.
     ///
     /// The transfer runs under
     /// [`command_timeout`](crate::Config::command_timeout). On expiry it
@@ -1436,9 +1698,13 @@ impl<'a, S: crate::state::ConnectionState> BulkWriter<'a, S> {
         let total_rows = self.bulk.total_rows();
         tracing::debug!(total_rows = total_rows, "finishing bulk insert");
 
-        // Write DONE token and freeze the payload
+        // Write DONE token and take what is left to send
         self.bulk.write_done();
-        let payload = self.bulk.buffer.split().freeze();
+        let data = self.bulk.take_buffered();
+        let mut message = self
+            .message
+            .take()
+            .unwrap_or_else(|| self.client.start_bulk_load());
 
         // Send BulkLoad data and read server response.
         //
@@ -1448,7 +1714,10 @@ impl<'a, S: crate::state::ConnectionState> BulkWriter<'a, S> {
         // On expiry the connection is left mid-request (in_flight stays
         // set), so the pool discards it instead of reusing it —
         // SqlBulkCopy's BulkCopyTimeout behaves the same way.
-        let send_and_read = self.client.send_and_read_bulk_load(payload);
+        let send_and_read = async {
+            message.extend(self.client, &data).await?;
+            self.client.finish_bulk_load(message).await
+        };
         let rows_affected = match deadline {
             Some(d) => tokio::time::timeout(d, send_and_read)
                 .await
@@ -1501,33 +1770,6 @@ fn decimal_byte_length(precision: u8) -> u8 {
     }
 }
 
-/// Calculate byte length for time based on scale.
-#[cfg(feature = "chrono")]
-fn time_byte_length(scale: u8) -> u8 {
-    match scale {
-        0..=2 => 3,
-        3..=4 => 4,
-        5..=7 => 5,
-        _ => 5,
-    }
-}
-
-/// Get the divisor for time scale.
-#[cfg(feature = "chrono")]
-fn time_scale_divisor(scale: u8) -> u64 {
-    match scale {
-        0 => 1_000_000_000,
-        1 => 100_000_000,
-        2 => 10_000_000,
-        3 => 1_000_000,
-        4 => 100_000,
-        5 => 10_000,
-        6 => 1_000,
-        7 => 100,
-        _ => 100,
-    }
-}
-
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
@@ -1540,6 +1782,7 @@ mod tests {
         assert!(opts.check_constraints);
         assert!(!opts.fire_triggers);
         assert!(opts.keep_nulls);
+        assert!(!opts.keep_identity);
         assert!(!opts.table_lock);
     }
 
@@ -1711,6 +1954,80 @@ mod tests {
         assert!(sql.contains("TABLOCK"));
     }
 
+    #[test]
+    fn test_insert_bulk_statement_keep_identity() {
+        let builder = BulkInsertBuilder::new("dbo.Users")
+            .with_typed_columns(vec![BulkColumn::new("id", "INT", 0).unwrap()])
+            .keep_identity(true);
+
+        let sql = builder.build_insert_bulk_statement().unwrap();
+        assert_eq!(
+            sql,
+            "INSERT BULK dbo.Users (id INT) WITH (CHECK_CONSTRAINTS, KEEP_NULLS, KEEP_IDENTITY)"
+        );
+    }
+
+    #[test]
+    fn test_schema_discovery_statement_selects_builder_columns() {
+        let builder = BulkInsertBuilder::new("dbo.Users").with_columns(&["name", "email"]);
+        assert_eq!(
+            builder.build_schema_discovery_statement().unwrap(),
+            "SELECT TOP 0 name, email FROM dbo.Users"
+        );
+        assert_eq!(
+            BulkInsertBuilder::new("dbo.Users")
+                .build_schema_discovery_statement()
+                .unwrap(),
+            "SELECT TOP 0 * FROM dbo.Users"
+        );
+        assert!(
+            BulkInsertBuilder::new("t;DROP TABLE users")
+                .build_schema_discovery_statement()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_insertable_identity_and_computed() {
+        fn server_column(name: &str, flags: u16) -> tds_protocol::token::ColumnData {
+            tds_protocol::token::ColumnData {
+                name: name.to_string(),
+                type_id: tds_protocol::types::TypeId::IntN,
+                col_type: 0x26,
+                flags,
+                user_type: 0,
+                type_info: tds_protocol::token::TypeInfo::default(),
+                crypto_metadata: None,
+            }
+        }
+        let builder = BulkInsertBuilder::new("dbo.Users").with_columns(&["name", "id", "total"]);
+        let plain = server_column("name", 0x0009);
+        let identity = server_column("id", 0x0010);
+        let computed = server_column("total", 0x0020);
+
+        assert!(builder.check_insertable(&[plain.clone()]).is_ok());
+        let err = builder
+            .check_insertable(&[identity.clone()])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`id`") && err.contains("IDENTITY"), "{err}");
+        let keep = BulkInsertBuilder::new("dbo.Users")
+            .with_columns(&["id", "total"])
+            .keep_identity(true);
+        assert!(keep.check_insertable(&[identity.clone()]).is_ok());
+        assert!(keep.check_insertable(&[computed.clone()]).is_err());
+
+        // Unlisted columns, or no column list at all, are left to the server.
+        let unlisted = BulkInsertBuilder::new("dbo.Users").with_columns(&["name"]);
+        assert!(
+            unlisted
+                .check_insertable(&[plain, identity.clone(), computed.clone()])
+                .is_ok()
+        );
+        let all = BulkInsertBuilder::new("dbo.Users");
+        assert!(all.check_insertable(&[identity, computed]).is_ok());
+    }
+
     #[test]
     fn test_bulk_insert_rejects_injection() {
         let builder = BulkInsertBuilder::new("table;DROP TABLE users")
@@ -1756,14 +2073,6 @@ mod tests {
         assert_eq!(decimal_byte_length(35), 17);
     }
 
-    #[test]
-    #[cfg(feature = "chrono")]
-    fn test_time_byte_length() {
-        assert_eq!(time_byte_length(0), 3);
-        assert_eq!(time_byte_length(3), 4);
-        assert_eq!(time_byte_length(7), 5);
-    }
-
     #[test]
     fn test_plp_string_encoding() {
         let mut buf = BytesMut::new();
@@ -1996,6 +2305,53 @@ mod tests {
         }
     }
 
+    /// A nullable BIT column is sent as BITN: every cell carries a length
+    /// byte, so `Some(true)`, `Some(false)` and `None` are 1/1/0 bytes long.
+    #[test]
+    fn test_nullable_bit_rows_use_bitn_encoding() {
+        let columns = vec![BulkColumn::new("flag", "BIT", 0).unwrap()];
+        let mut bulk = BulkInsert::new(columns, 0);
+        assert_eq!(&*bulk.fixed_len, &[false]);
+        let header_len = bulk.buffer.len();
+
+        for value in [Some(true), Some(false), None] {
+            bulk.send_row(&[value]).unwrap();
+        }
+
+        let row = TokenType::Row as u8;
+        assert_eq!(
+            &bulk.buffer[header_len..],
+            &[row, 1, 1, row, 1, 0, row, 0][..]
+        );
+    }
+
+    /// A DECIMAL cell carries its mantissa at the column's declared scale:
+    /// `12.5` into DECIMAL(5,2) is sent as 1250, not 125. A value with more
+    /// digits than the column's precision is rejected, not truncated.
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal_rows_use_column_scale_and_precision() {
+        use rust_decimal::Decimal;
+
+        let columns = vec![BulkColumn::new("price", "DECIMAL(5,2)", 0).unwrap()];
+        let mut bulk = BulkInsert::new(columns, 0);
+        let header_len = bulk.buffer.len();
+
+        bulk.send_row(&[Decimal::new(125, 1)]).unwrap();
+        let row = TokenType::Row as u8;
+        assert_eq!(
+            &bulk.buffer[header_len..],
+            &[row, 5, 1, 0xE2, 0x04, 0x00, 0x00][..]
+        );
+
+        let err = bulk.send_row(&[Decimal::new(1_000, 0)]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("decimal 1000.00 does not fit DECIMAL precision 5"),
+            "{err}"
+        );
+    }
+
     /// Verify that NOT NULL columns use fixed-width type IDs (0x38 Int4,
     /// 0x32 Bit, etc.) rather than nullable type IDs (0x26 INTN, 0x68 BITN).
     /// SQL Server's BulkLoad rejects nullable IDs for NOT NULL columns.
@@ -2160,4 +2516,60 @@ mod tests {
         assert_eq!(type_id, 0xE7);
         assert_eq!(len, Some(200)); // 100 * 2 for UTF-16
     }
+
+    fn upsert_builder() -> BulkInsertBuilder {
+        BulkInsertBuilder::new("dbo.Users").with_columns(&["id", "name", "email"])
+    }
+
+    #[test]
+    fn test_upsert_staging_statement() {
+        let sql = upsert_builder().build_upsert_staging_statement().unwrap();
+        assert_eq!(
+            sql,
+            "IF OBJECT_ID('tempdb..#bulk_upsert') IS NOT NULL DROP TABLE #bulk_upsert; \
+             SELECT TOP 0 id, name, email INTO #bulk_upsert FROM dbo.Users \
+             UNION ALL SELECT TOP 0 id, name, email FROM dbo.Users"
+        );
+    }
+
+    #[test]
+    fn test_upsert_merge_statement() {
+        let sql = upsert_builder().build_merge_statement(&["id"]).unwrap();
+        assert_eq!(
+            sql,
+            "MERGE dbo.Users WITH (HOLDLOCK) AS t USING #bulk_upsert AS s ON t.id = s.id \
+             WHEN MATCHED THEN UPDATE SET t.name = s.name, t.email = s.email \
+             WHEN NOT MATCHED BY TARGET THEN INSERT (id, name, email) \
+             VALUES (s.id, s.name, s.email);"
+        );
+    }
+
+    #[test]
+    fn test_upsert_merge_with_only_key_columns_skips_update() {
+        let builder = BulkInsertBuilder::new("dbo.Tags").with_columns(&["post_id", "tag"]);
+        let sql = builder.build_merge_statement(&["post_id", "tag"]).unwrap();
+        assert!(sql.contains("ON t.post_id = s.post_id AND t.tag = s.tag"));
+        assert!(!sql.contains("WHEN MATCHED"));
+    }
+
+    #[test]
+    fn test_upsert_rejects_bad_keys_and_columns() {
+        assert!(upsert_builder().build_merge_statement(&[]).is_err());
+        assert!(
+            upsert_builder()
+                .build_merge_statement(&["missing"])
+                .is_err()
+        );
+        assert!(
+            BulkInsertBuilder::new("dbo.Users")
+                .build_upsert_staging_statement()
+                .is_err()
+        );
+        assert!(
+            BulkInsertBuilder::new("dbo.Users; DROP TABLE x")
+                .with_columns(&["id"])
+                .build_merge_statement(&["id"])
+                .is_err()
+        );
+    }
 }
@@ -56,6 +56,14 @@
 //! rather than handed back dirty (the pool's `test_on_checkin` /
 //! `test_on_checkout` options), but you pay for a replacement connection.
 //!
+//! When the caller that may give up holds a
+//! [`CancellationToken`](crate::CancellationToken) (for example a request
+//! handler), pass it to
+//! [`Client::query_with_cancellation`](crate::Client::query_with_cancellation)
+//! or [`Client::execute_with_cancellation`](crate::Client::execute_with_cancellation):
+//! cancelling the token stops the statement with an Attention and returns
+//! [`Error::Cancelled`], keeping the connection.
+//!
 //! For a timeout, race the query against a timer and cancel explicitly rather
 //! than letting the future drop:
 //!
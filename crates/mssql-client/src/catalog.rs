@@ -0,0 +1,44 @@
+//! Catalog discovery types.
+//!
+//! [`TableInfo`] is the row type returned by
+//! [`Client::list_tables`](crate::Client::list_tables); database names come
+//! back from [`Client::list_databases`](crate::Client::list_databases) as
+//! plain strings.
+
+use std::fmt;
+
+/// A user table in the current database, from `sys.tables` joined to
+/// `sys.schemas`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableInfo {
+    /// Schema the table belongs to (e.g. `dbo`).
+    pub schema: String,
+    /// Table name.
+    pub name: String,
+}
+
+impl fmt::Display for TableInfo {
+    /// Formats as the bracket-quoted two-part name, e.g. `[dbo].[orders]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}].[{}]",
+            self.schema.replace(']', "]]"),
+            self.name.replace(']', "]]")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_info_display_quotes_names() {
+        let table = TableInfo {
+            schema: "dbo".into(),
+            name: "odd]name".into(),
+        };
+        assert_eq!(table.to_string(), "[dbo].[odd]]name]");
+    }
+}
@@ -22,9 +22,11 @@
 // and for regex patterns that are compile-time constants
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::needless_range_loop)]
 
+mod blob;
 mod connect;
 mod params;
 pub(crate) mod response;
+mod streamed;
 
 use std::marker::PhantomData;
 
@@ -38,48 +40,68 @@ use tokio::net::TcpStream;
 
 use crate::config::Config;
 use crate::error::{Error, Result};
+use crate::features::EnabledFeatures;
 #[cfg(feature = "otel")]
 use crate::instrumentation::InstrumentationContext;
 use crate::state::{ConnectionState, InTransaction, Ready};
 use crate::statement_cache::StatementCache;
-use crate::stream::{MultiResultStream, QueryStream};
+use crate::stream::{ExecuteResult, MultiResultStream, QueryStream};
 use crate::transaction::SavePoint;
 
+pub(crate) use streamed::StreamedMessage;
+
 /// How long to wait for the server to acknowledge an Attention packet after
 /// a command timeout. SqlClient waits 5 seconds before dooming the
 /// connection; we match it.
 const ATTENTION_ACK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
 
-/// Run a network future under an optional command deadline.
+/// Session option applied after login and after every reset when
+/// [`Config::xact_abort`] is set.
+const XACT_ABORT_ON: &str = "SET XACT_ABORT ON";
+
+/// `sys.databases.snapshot_isolation_state` of the current database.
+const SNAPSHOT_ISOLATION_STATE_QUERY: &str = "SELECT CAST(snapshot_isolation_state AS INT) \
+     FROM sys.databases WHERE database_id = DB_ID()";
+
+/// `snapshot_isolation_state` once `ALLOW_SNAPSHOT_ISOLATION ON` has taken
+/// effect.
+const SNAPSHOT_ISOLATION_ON: i32 = 1;
+
+/// Server error raised when a snapshot transaction reads from a database
+/// without `ALLOW_SNAPSHOT_ISOLATION ON`.
+const SNAPSHOT_ISOLATION_NOT_ALLOWED: i32 = 3952;
+
+/// Rolls back a transaction left open on the session, if any.
+const ROLLBACK_OPEN_TRANSACTION: &str = "IF @@TRANCOUNT > 0 ROLLBACK TRANSACTION";
+
+/// Drive `fut` to completion unless `trigger` fires first.
 ///
-/// On timeout this sends an Attention packet via `canceller` and then awaits
-/// the future so its own read loop drains the server's DONE_ATTN
-/// acknowledgement, leaving the connection clean before returning
-/// [`Error::CommandTimeout`]. This is the cancel-safe alternative to dropping
-/// the future (e.g. via `tokio::time::timeout`), which would leave unconsumed
-/// TDS data in the connection buffer and desync the next request.
+/// When `trigger` fires this sends an Attention packet via `canceller` and
+/// then awaits the future so its own read loop drains the server's DONE_ATTN
+/// acknowledgement, leaving the connection clean before returning `err`. This
+/// is the cancel-safe alternative to dropping the future (e.g. via
+/// `tokio::time::timeout`), which would leave unconsumed TDS data in the
+/// connection buffer and desync the next request.
 ///
 /// The drain itself is bounded by [`ATTENTION_ACK_TIMEOUT`] — a hung server
-/// that never acknowledges the attention must not turn the timeout into an
-/// infinite wait. When the bound expires the connection is abandoned
+/// that never acknowledges the attention must not turn the cancellation into
+/// an infinite wait. When the bound expires the connection is abandoned
 /// mid-response: `in_flight` stays set, so the pool discards the connection
 /// at check-in instead of reusing it.
-pub(crate) async fn run_with_deadline<F, T>(
+async fn run_until<F, T>(
     fut: F,
-    deadline: Option<std::time::Duration>,
+    trigger: impl std::future::Future<Output = ()>,
     canceller: crate::cancel::CancelHandle,
+    err: Error,
 ) -> Result<T>
 where
     F: std::future::Future<Output = Result<T>>,
 {
-    let Some(d) = deadline else {
-        return fut.await;
-    };
     tokio::pin!(fut);
     tokio::select! {
         biased;
         res = &mut fut => res,
-        () = tokio::time::sleep(d) => {
+        () = trigger => {
             // Signal cancellation, then let the in-flight read consume the
             // server's attention acknowledgement so the connection stays usable.
             let drain = async {
@@ -92,11 +114,45 @@ where
                     "server did not acknowledge attention; abandoning the connection as dirty"
                 );
             }
-            Err(Error::CommandTimeout)
+            Err(err)
         }
     }
 }
 
+/// Run a network future under an optional command deadline.
+///
+/// On timeout the request is cancelled and drained as described in
+/// [`run_until`], and [`Error::CommandTimeout`] is returned.
+pub(crate) async fn run_with_deadline<F, T>(
+    fut: F,
+    deadline: Option<std::time::Duration>,
+    canceller: crate::cancel::CancelHandle,
+) -> Result<T>
+where
+    F: std::future::Future<Output = Result<T>>,
+{
+    let Some(d) = deadline else {
+        return fut.await;
+    };
+    run_until(fut, tokio::time::sleep(d), canceller, Error::CommandTimeout).await
+}
+
+/// Drive `fut` to completion unless `token` is cancelled first.
+///
+/// On cancellation the request is cancelled and drained as described in
+/// [`run_until`], and [`Error::Cancelled`] is returned with the connection
+/// left usable.
+pub(crate) async fn run_until_cancelled<F, T>(
+    fut: F,
+    token: &tokio_util::sync::CancellationToken,
+    canceller: crate::cancel::CancelHandle,
+) -> Result<T>
+where
+    F: std::future::Future<Output = Result<T>>,
+{
+    run_until(fut, token.cancelled(), canceller, Error::Cancelled).await
+}
+
 /// SQL Server client with type-state connection management.
 ///
 /// The generic parameter `S` represents the current connection state,
@@ -109,12 +165,19 @@ pub struct Client<S: ConnectionState> {
     connection: Option<ConnectionHandle>,
     /// Server version from LoginAck (raw u32 TDS version)
     server_version: Option<u32>,
+    /// Server program name and version from LoginAck, formatted for display.
+    server_program: Option<String>,
+    /// Server process ID from the login response packet headers.
+    spid: Option<u16>,
     /// Current database from EnvChange
     current_database: Option<String>,
     /// Server's default collation from SqlCollation EnvChange during login.
     /// Used when `SendStringParametersAsUnicode=false` to encode VARCHAR
     /// parameters with the correct character encoding and collation bytes.
     server_collation: Option<tds_protocol::token::Collation>,
+    /// Packet size in effect: the value the server confirmed in its PacketSize
+    /// EnvChange during login, or `config.packet_size` if it sent none.
+    packet_size: u16,
     /// Prepared statement cache for query optimization
     statement_cache: StatementCache,
     /// Transaction descriptor from BeginTransaction EnvChange.
@@ -128,6 +191,17 @@ pub struct Client<S: ConnectionState> {
     /// Set by connection pool on checkin, cleared after first query/execute.
     /// When true, the RESETCONNECTION flag is set on the first TDS packet.
     needs_reset: bool,
+    /// Whether this connection is owned by a connection pool, which discards
+    /// connections by dropping them. Suppresses the un-closed drop warning.
+    pooled: bool,
+    /// Whether the first query may be retried once on a fresh connection
+    /// after a transient failure. Armed at connect from
+    /// [`Config::retry_first_query`]; cleared as soon as any request is sent.
+    first_query_retry: bool,
+    /// Feature extensions the server acknowledged in its login response.
+    enabled_features: EnabledFeatures,
+    /// Product version, queried on first use by [`Client::product_version`].
+    product_version: Option<crate::ProductVersion>,
     /// OpenTelemetry instrumentation context (when otel feature is enabled)
     #[cfg(feature = "otel")]
     instrumentation: InstrumentationContext,
@@ -141,7 +215,7 @@ pub struct Client<S: ConnectionState> {
 /// This is an enum to support different connection types:
 /// - TLS (TDS 8.0 strict mode) - requires `tls` feature
 /// - TLS with PreLogin wrapping (TDS 7.x style) - requires `tls` feature
-/// - Plain TCP (for internal networks or when `tls` feature is disabled)
+/// - Plain TCP (`Encrypt=no_tls` / [`Config::no_tls`](crate::Config::no_tls))
 enum ConnectionHandle {
     /// TLS connection (TDS 8.0 strict mode - TLS before any TDS traffic)
     #[cfg(feature = "tls")]
@@ -149,14 +223,13 @@ enum ConnectionHandle {
     /// TLS connection with PreLogin wrapping (TDS 7.x style)
     #[cfg(feature = "tls")]
     TlsPrelogin(Connection<TlsStream<mssql_tls::TlsPreloginWrapper<TcpStream>>>),
-    /// Plain TCP connection (for internal networks or when `tls` feature is disabled)
+    /// Plain TCP connection (`no_tls`, with or without the `tls` feature)
     Plain(Connection<TcpStream>),
 }
 
 /// The parameter `TypeInfo` to declare a typed NULL ([`crate::null`]) with, from
 /// its [`crate::ToSql::sql_type`] name. Returns `None` for an untyped NULL
 /// (`Option::None`, type `"NULL"`), which falls back to the default param type.
-#[cfg(feature = "always-encrypted")]
 fn null_param_type_info(sql_type: &str) -> Option<tds_protocol::rpc::TypeInfo> {
     use tds_protocol::rpc::TypeInfo;
     Some(match sql_type {
@@ -204,6 +277,37 @@ fn encrypted_param_type_info(
 
 // Private helper methods available to all connection states
 impl<S: ConnectionState> Client<S> {
+    /// Move the connection and session state into a client in state `T`.
+    ///
+    /// `Client` implements `Drop`, so its fields cannot be moved out by a
+    /// struct literal; they are taken instead, leaving `self` without a
+    /// connection so its drop is a no-op.
+    fn into_state<T: ConnectionState>(mut self, transaction_descriptor: u64) -> Client<T> {
+        Client {
+            config: std::mem::take(&mut self.config),
+            _state: PhantomData,
+            connection: self.connection.take(),
+            server_version: self.server_version,
+            server_program: self.server_program.take(),
+            spid: self.spid,
+            current_database: self.current_database.take(),
+            server_collation: self.server_collation.take(),
+            packet_size: self.packet_size,
+            statement_cache: std::mem::take(&mut self.statement_cache),
+            transaction_descriptor,
+            needs_reset: self.needs_reset,
+            pooled: self.pooled,
+            enabled_features: std::mem::take(&mut self.enabled_features),
+            product_version: self.product_version.take(),
+            first_query_retry: self.first_query_retry,
+            in_flight: self.in_flight,
+            #[cfg(feature = "otel")]
+            instrumentation: self.instrumentation.clone(),
+            #[cfg(feature = "always-encrypted")]
+            encryption_context: self.encryption_context.take(),
+        }
+    }
+
     /// The default per-command deadline from `command_timeout`.
     ///
     /// Returns `None` when `command_timeout` is zero, which means "no limit"
@@ -311,13 +415,19 @@ impl<S: ConnectionState> Client<S> {
         }
     }
 
-    /// Apply a transaction-related `ENVCHANGE` to this client's descriptor.
+    /// Apply an `ENVCHANGE` seen in a response to this client's session
+    /// state: the transaction descriptor and the current database.
     ///
-    /// Lets the streaming readers (which live in sibling modules) keep the
-    /// transaction descriptor in sync with raw `BEGIN`/`COMMIT`/`ROLLBACK`
-    /// batches seen mid-stream, exactly as the buffered readers do.
-    pub(crate) fn apply_transaction_env_change(&mut self, env: &EnvChange) {
+    /// Every response reader calls this, so raw `BEGIN`/`COMMIT`/`ROLLBACK`
+    /// and `USE` batches are tracked however their results are read.
+    pub(crate) fn apply_env_change(&mut self, env: &EnvChange) {
         Self::process_transaction_env_change(env, &mut self.transaction_descriptor);
+        if env.env_type == EnvChangeType::Database {
+            if let tds_protocol::token::EnvChangeValue::String(ref database) = env.new_value {
+                tracing::debug!(database = %database, "database changed");
+                self.current_database = Some(database.clone());
+            }
+        }
     }
 
     /// Send a SQL batch to the server.
@@ -334,11 +444,14 @@ impl<S: ConnectionState> Client<S> {
         // does not pick up the old response's bytes.
         self.cancel_in_flight_response().await?;
 
+        if self.needs_reset && self.config.xact_abort {
+            self.reset_with_session_options().await?;
+        }
+
         let payload = tds_protocol::__private::encode_sql_batch_with_transaction(
             sql,
             self.transaction_descriptor,
         );
-        let max_packet = self.config.packet_size as usize;
 
         // Check if we need to reset the connection on this request
         let reset = self.needs_reset;
@@ -351,26 +464,9 @@ impl<S: ConnectionState> Client<S> {
         }
 
         self.in_flight = true;
-        let connection = self.connection.as_mut().ok_or(Error::ConnectionClosed)?;
-
-        match connection {
-            #[cfg(feature = "tls")]
-            ConnectionHandle::Tls(conn) => {
-                conn.send_message_with_reset(PacketType::SqlBatch, payload, max_packet, reset)
-                    .await?;
-            }
-            #[cfg(feature = "tls")]
-            ConnectionHandle::TlsPrelogin(conn) => {
-                conn.send_message_with_reset(PacketType::SqlBatch, payload, max_packet, reset)
-                    .await?;
-            }
-            ConnectionHandle::Plain(conn) => {
-                conn.send_message_with_reset(PacketType::SqlBatch, payload, max_packet, reset)
-                    .await?;
-            }
-        }
-
-        Ok(())
+        self.first_query_retry = false;
+        self.write_request(PacketType::SqlBatch, payload, reset)
+            .await
     }
 
     /// Send an RPC request to the server.
@@ -384,8 +480,11 @@ impl<S: ConnectionState> Client<S> {
         // issuing this request.
         self.cancel_in_flight_response().await?;
 
+        if self.needs_reset && self.config.xact_abort {
+            self.reset_with_session_options().await?;
+        }
+
         let payload = rpc.encode_with_transaction(self.transaction_descriptor);
-        let max_packet = self.config.packet_size as usize;
 
         // Check if we need to reset the connection on this request
         let reset = self.needs_reset;
@@ -398,21 +497,34 @@ impl<S: ConnectionState> Client<S> {
         }
 
         self.in_flight = true;
+        self.first_query_retry = false;
+        self.write_request(PacketType::Rpc, payload, reset).await
+    }
+
+    /// Write one request message, setting RESETCONNECTION on its first packet
+    /// when `reset` is true.
+    async fn write_request(
+        &mut self,
+        packet_type: PacketType,
+        payload: bytes::Bytes,
+        reset: bool,
+    ) -> Result<()> {
+        let max_packet = self.packet_size as usize;
         let connection = self.connection.as_mut().ok_or(Error::ConnectionClosed)?;
 
         match connection {
             #[cfg(feature = "tls")]
             ConnectionHandle::Tls(conn) => {
-                conn.send_message_with_reset(PacketType::Rpc, payload, max_packet, reset)
+                conn.send_message_with_reset(packet_type, payload, max_packet, reset)
                     .await?;
             }
             #[cfg(feature = "tls")]
             ConnectionHandle::TlsPrelogin(conn) => {
-                conn.send_message_with_reset(PacketType::Rpc, payload, max_packet, reset)
+                conn.send_message_with_reset(packet_type, payload, max_packet, reset)
                     .await?;
             }
             ConnectionHandle::Plain(conn) => {
-                conn.send_message_with_reset(PacketType::Rpc, payload, max_packet, reset)
+                conn.send_message_with_reset(packet_type, payload, max_packet, reset)
                     .await?;
             }
         }
@@ -420,6 +532,56 @@ impl<S: ConnectionState> Client<S> {
         Ok(())
     }
 
+    /// Reject `sql` if [`Config::read_only_guard`] is on and it would write.
+    fn check_read_only(&self, sql: &str) -> Result<()> {
+        if !self.config.read_only_guard {
+            return Ok(());
+        }
+        match crate::read_only::find_write_keyword(sql) {
+            Some(keyword) => Err(Error::WriteAttemptOnReadOnly { keyword }),
+            None => Ok(()),
+        }
+    }
+
+    /// Apply session options from the config after login.
+    ///
+    /// Currently only [`Config::xact_abort`]. Resets re-apply it through
+    /// [`reset_with_session_options`](Self::reset_with_session_options).
+    pub(crate) async fn apply_session_options(&mut self) -> Result<()> {
+        if self.config.xact_abort {
+            tracing::debug!("enabling XACT_ABORT for the session");
+            // A driver-internal batch: the caller's first query is still the
+            // one `Retry First Query` applies to.
+            let first_query_retry = self.first_query_retry;
+            self.send_sql_batch(XACT_ABORT_ON).await?;
+            self.read_execute_result().await?;
+            self.first_query_retry = first_query_retry;
+        }
+        Ok(())
+    }
+
+    /// Consume a pending reset with a `SET XACT_ABORT ON` batch.
+    ///
+    /// RESETCONNECTION restores the login's SET options, which would silently
+    /// turn `XACT_ABORT` back off. Carrying the flag on this batch instead of
+    /// the caller's request costs one round trip per reset and keeps the
+    /// option in force for everything that follows.
+    async fn reset_with_session_options(&mut self) -> Result<()> {
+        self.needs_reset = false;
+        let _ = self.statement_cache.clear();
+        tracing::debug!("sending session options with RESETCONNECTION flag");
+
+        let payload = tds_protocol::__private::encode_sql_batch_with_transaction(
+            XACT_ABORT_ON,
+            self.transaction_descriptor,
+        );
+        self.in_flight = true;
+        self.write_request(PacketType::SqlBatch, payload, true)
+            .await?;
+        self.read_execute_result().await?;
+        Ok(())
+    }
+
     /// Start building a stored procedure call with full control over parameters.
     ///
     /// Returns a [`crate::procedure::ProcedureBuilder`] that allows adding named input and output
@@ -522,6 +684,123 @@ impl<S: ConnectionState> Client<S> {
         result
     }
 
+    /// Shared implementation of [`Client<Ready>::query_typed_stream`] and its
+    /// transaction counterpart; `query_typed` collects the returned iterator.
+    async fn query_typed_stream_inner<R: crate::FromRow>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<crate::MapRows<QueryStream<'static>, R>> {
+        let stream = self.query_buffered_inner(sql, params).await?;
+        Ok(crate::RowIteratorExt::map_rows::<R>(stream))
+    }
+
+    /// Shared implementation of [`Client<Ready>::query_tvf`] and its transaction counterpart.
+    async fn query_tvf_inner<R: crate::FromRow>(
+        &mut self,
+        function: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<Vec<R>> {
+        crate::validation::validate_qualified_identifier(function)?;
+
+        let args: Vec<String> = (1..=params.len()).map(|i| format!("@p{i}")).collect();
+        // Note: function is validated above; arguments are bound parameters
+        let sql = format!("SELECT * FROM {function}({})", args.join(", "));
+
+        tracing::debug!(
+            function = function,
+            params_count = params.len(),
+            "querying table-valued function"
+        );
+
+        let stream = self.query_buffered_inner(&sql, params).await?;
+        crate::RowIteratorExt::map_rows::<R>(stream).collect()
+    }
+
+    /// Shared implementation behind `query_one_or` / `query_one_or_else` for
+    /// both `Ready` and `InTransaction`: map the first row to `R`, or fall back
+    /// to `default()` when the query returns no rows.
+    async fn query_one_or_else_inner<R, F>(
+        &mut self,
+        default: F,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<R>
+    where
+        R: crate::FromRow,
+        F: FnOnce() -> R,
+    {
+        let stream = self.query_buffered_inner(sql, params).await?;
+        match crate::RowIteratorExt::map_rows::<R>(stream).next() {
+            Some(row) => row,
+            None => Ok(default()),
+        }
+    }
+
+    /// Shared implementation of [`Client<Ready>::execute_returning`] and its
+    /// transaction counterpart.
+    async fn execute_returning_inner<R: crate::FromRow>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<(Vec<R>, u64)> {
+        let resp = self.query_buffered_raw(sql, params).await?;
+        let rows_affected = resp.rows_affected.unwrap_or(0);
+        let rows =
+            crate::RowIteratorExt::map_rows::<R>(resp.into_stream()).collect::<Result<Vec<R>>>()?;
+        Ok((rows, rows_affected))
+    }
+
+    /// Run a query under the command timeout and buffer its response into a
+    /// [`QueryStream`], recording the otel span and operation metric.
+    async fn query_buffered_inner(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<QueryStream<'static>> {
+        Ok(self.query_buffered_raw(sql, params).await?.into_stream())
+    }
+
+    /// Run a query under the command timeout and return its raw buffered
+    /// response, recording the otel span and operation metric.
+    async fn query_buffered_raw(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<response::RawQueryResponse> {
+        self.check_read_only(sql)?;
+        #[cfg(feature = "otel")]
+        let instrumentation = self.instrumentation.clone();
+        #[cfg(feature = "otel")]
+        let mut span = instrumentation.query_span(sql);
+        #[cfg(feature = "otel")]
+        let timer = crate::instrumentation::OperationTimer::start("SELECT");
+
+        let deadline = self.command_deadline();
+        let canceller = self.connection_cancel_handle();
+        let result = run_with_deadline(
+            async {
+                self.send_query_request(sql, params).await?;
+                self.read_query_response().await
+            },
+            deadline,
+            canceller,
+        )
+        .await;
+
+        #[cfg(feature = "otel")]
+        match &result {
+            Ok(_) => InstrumentationContext::record_success(&mut span, None),
+            Err(e) => InstrumentationContext::record_error(&mut span, e),
+        }
+        #[cfg(feature = "otel")]
+        timer.finish(instrumentation.metrics(), result.is_ok());
+        #[cfg(feature = "otel")]
+        drop(span);
+
+        result
+    }
+
     /// Ask the server how each parameter of a statement must be encrypted.
     ///
     /// Issues the `sp_describe_parameter_encryption` system RPC for the
@@ -568,9 +847,8 @@ impl<S: ConnectionState> Client<S> {
         if self.encryption_context.is_some() {
             return self.build_encrypted_sql_rpc(sql, params).await;
         }
-        let rpc_params =
-            Self::convert_params(params, self.send_unicode(), self.server_collation())?;
-        Ok(RpcRequest::execute_sql(sql, rpc_params))
+        let (sql, rpc_params) = self.convert_query_params(sql, params)?;
+        Ok(RpcRequest::execute_sql(&sql, rpc_params))
     }
 
     /// Send a parameterized `query` request, consulting the prepared-statement
@@ -612,8 +890,7 @@ impl<S: ConnectionState> Client<S> {
             let _ = self.statement_cache.clear();
         }
 
-        let rpc_params =
-            Self::convert_params(params, self.send_unicode(), self.server_collation())?;
+        let (sql, rpc_params) = self.convert_query_params(sql, params)?;
         // Key on the parameter declaration + SQL: a cached handle is only valid
         // for the exact prepared parameter types, so two calls with the same
         // SQL but different param types must not share a handle.
@@ -635,7 +912,7 @@ impl<S: ConnectionState> Client<S> {
             // the `@handle` RETURNVALUE, then stores it under `key` (see
             // `store_pending_prepared_handle`).
             self.statement_cache.set_pending(Some(key));
-            let rpc = RpcRequest::prepexec(sql, rpc_params);
+            let rpc = RpcRequest::prepexec(&sql, rpc_params);
             self.send_rpc(&rpc).await?;
         }
         Ok(())
@@ -798,6 +1075,12 @@ impl<S: ConnectionState> Client<S> {
     /// a mutable borrow on the client, preventing other operations while
     /// the bulk insert is in progress.
     ///
+    /// The server's metadata for the builder's columns is fetched first, so
+    /// columns with defaults can simply be left out. Identity columns must be
+    /// left out too, unless [`BulkOptions::keep_identity`](crate::BulkOptions::keep_identity)
+    /// is set to load the given values; computed columns always must. Listing
+    /// one is an [`Error::Config`] before the server enters bulk-load mode.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -822,6 +1105,11 @@ impl<S: ConnectionState> Client<S> {
         &mut self,
         builder: &crate::bulk::BulkInsertBuilder,
     ) -> Result<crate::bulk::BulkWriter<'_, S>> {
+        if self.config.read_only_guard {
+            return Err(Error::WriteAttemptOnReadOnly {
+                keyword: "INSERT BULK",
+            });
+        }
         use tds_protocol::token::{ColMetaData, Token};
 
         tracing::debug!(
@@ -830,10 +1118,10 @@ impl<S: ConnectionState> Client<S> {
             "starting bulk insert"
         );
 
-        // Step 1: Query the server for column metadata.
+        // Step 1: Query the server for the builder's columns' metadata.
         // This gives us the exact type encoding the server expects for BulkLoad,
         // following the pattern established by Tiberius.
-        let meta_query = format!("SELECT TOP 0 * FROM {}", builder.table_name());
+        let meta_query = builder.build_schema_discovery_statement()?;
         let deadline = self.command_deadline();
         let canceller = self.connection_cancel_handle();
         let message = run_with_deadline(
@@ -871,6 +1159,12 @@ impl<S: ConnectionState> Client<S> {
             }
         }
 
+        // Identity and computed columns cannot be loaded (identity only with
+        // KEEP_IDENTITY); fail before the server is put in bulk-load mode.
+        if let Some(ref meta) = server_metadata {
+            builder.check_insertable(&meta.columns)?;
+        }
+
         // Reject deprecated TEXT/NTEXT/IMAGE columns reported by the server.
         // These types require a legacy TEXTPTR wire format that this driver
         // does not support — users should migrate the column to VARCHAR(MAX) /
@@ -937,7 +1231,7 @@ impl<S: ConnectionState> Client<S> {
     /// Start a bulk insert without querying the server for column metadata.
     ///
     /// Unlike [`bulk_insert()`](Self::bulk_insert), this method does not send
-    /// `SELECT TOP 0 * FROM table` to discover column types. Instead, the
+    /// `SELECT TOP 0` of the builder's columns to discover their types. Instead, the
     /// column metadata is constructed from the `BulkColumn` types provided
     /// on the builder. This saves a round-trip when the schema is known.
     ///
@@ -954,6 +1248,11 @@ impl<S: ConnectionState> Client<S> {
         &mut self,
         builder: &crate::bulk::BulkInsertBuilder,
     ) -> Result<crate::bulk::BulkWriter<'_, S>> {
+        if self.config.read_only_guard {
+            return Err(Error::WriteAttemptOnReadOnly {
+                keyword: "INSERT BULK",
+            });
+        }
         tracing::debug!(
             table = builder.table_name(),
             columns = builder.columns().len(),
@@ -981,34 +1280,166 @@ impl<S: ConnectionState> Client<S> {
         Ok(crate::bulk::BulkWriter::new(self, bulk))
     }
 
-    /// Send bulk load data as a BulkLoad (0x07) message and read the server response.
+    /// Bulk-load rows into a staging temp table, then `MERGE` them into the
+    /// builder's table on `key_columns`.
+    ///
+    /// The idempotent-load pattern in one call: an empty session temp table
+    /// with the builder's columns is created from the target, `rows` are
+    /// bulk-loaded into it with the builder's options, and a generated `MERGE`
+    /// updates every non-key column of the target rows whose keys match and
+    /// inserts the rest. The staging table is dropped afterwards.
+    ///
+    /// The target is only changed by the single `MERGE` statement, so either
+    /// every staged row is applied or none is. Call this on a
+    /// [`Client<InTransaction>`] to make the upsert part of a larger
+    /// transaction.
+    ///
+    /// The builder's columns must be named (see
+    /// [`BulkInsertBuilder::with_columns`](crate::BulkInsertBuilder::with_columns))
+    /// and must be insertable, so leave out identity and computed columns.
+    /// Each row holds one value per column, in the builder's column order.
+    ///
+    /// # Errors
+    ///
+    /// Fails without touching the server if no key columns are given, a key
+    /// column is not one of the builder's columns, or an identifier is
+    /// invalid. Rows that repeat a key make the `MERGE` fail with server
+    /// error 8672, leaving the target unchanged.
     ///
-    /// Used internally by [`crate::bulk::BulkWriter::finish()`] to transmit accumulated
-    /// row data after the `INSERT BULK` statement has been acknowledged.
-    pub(crate) async fn send_and_read_bulk_load(&mut self, payload: bytes::Bytes) -> Result<u64> {
-        let max_packet = self.config.packet_size as usize;
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// use mssql_client::{BulkInsertBuilder, SqlValue};
+    ///
+    /// let builder = BulkInsertBuilder::new("dbo.Users").with_columns(&["id", "name"]);
+    /// let rows = vec![
+    ///     vec![SqlValue::Int(1), SqlValue::String("Alice".into())],
+    ///     vec![SqlValue::Int(2), SqlValue::String("Bob".into())],
+    /// ];
+    ///
+    /// let result = client.bulk_upsert(&builder, &["id"], &rows).await?;
+    /// println!("Merged {} of {} rows", result.rows_merged, result.rows_staged);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bulk_upsert<I, R>(
+        &mut self,
+        builder: &crate::bulk::BulkInsertBuilder,
+        key_columns: &[&str],
+        rows: I,
+    ) -> Result<crate::bulk::BulkUpsertResult>
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[mssql_types::SqlValue]>,
+    {
+        if self.config.read_only_guard {
+            return Err(Error::WriteAttemptOnReadOnly { keyword: "MERGE" });
+        }
+        let create_staging = builder.build_upsert_staging_statement()?;
+        let merge = builder.build_merge_statement(key_columns)?;
 
-        self.in_flight = true;
-        let connection = self.connection.as_mut().ok_or(Error::ConnectionClosed)?;
+        tracing::debug!(
+            table = builder.table_name(),
+            columns = builder.columns().len(),
+            keys = key_columns.len(),
+            "starting bulk upsert"
+        );
 
-        match connection {
-            #[cfg(feature = "tls")]
-            ConnectionHandle::Tls(conn) => {
-                conn.send_message(PacketType::BulkLoad, payload, max_packet)
-                    .await?;
+        let result = async {
+            self.execute_bulk_upsert_step(&create_staging).await?;
+
+            let staging = builder.upsert_staging();
+            let mut writer = self.bulk_insert(&staging).await?;
+            let mut row_error = None;
+            for row in rows {
+                if let Err(e) = writer.send_row_values(row.as_ref()) {
+                    row_error = Some(e);
+                    break;
+                }
+                writer.flush_full_batch().await?;
             }
-            #[cfg(feature = "tls")]
-            ConnectionHandle::TlsPrelogin(conn) => {
-                conn.send_message(PacketType::BulkLoad, payload, max_packet)
-                    .await?;
+            // The server is in bulk load mode until it gets the data, so send
+            // what was buffered even when a row was rejected; the partial
+            // load only reaches the staging table.
+            let staged = writer.finish().await;
+            if let Some(e) = row_error {
+                return Err(e);
             }
-            ConnectionHandle::Plain(conn) => {
-                conn.send_message(PacketType::BulkLoad, payload, max_packet)
-                    .await?;
+            let rows_staged = staged?.rows_affected;
+
+            let rows_merged = self.execute_bulk_upsert_step(&merge).await?;
+            Ok(crate::bulk::BulkUpsertResult {
+                rows_staged,
+                rows_merged,
+            })
+        }
+        .await;
+
+        // Best effort: a leftover staging table is dropped by the next upsert
+        // or by the pool's session reset.
+        if !self.in_flight {
+            let drop_staging = crate::bulk::drop_upsert_staging_statement();
+            if let Err(e) = self.execute_bulk_upsert_step(&drop_staging).await {
+                tracing::warn!(error = %e, "failed to drop bulk upsert staging table");
+            }
+        }
+
+        result
+    }
+
+    /// Run `statements` in order through `execute`, stopping where `policy`
+    /// says to. Shared by the `execute_batch` of each state, which differ only
+    /// in how a single statement is executed.
+    async fn execute_batch_inner(
+        &mut self,
+        statements: &[&str],
+        policy: crate::BatchErrorPolicy,
+        mut execute: impl AsyncFnMut(&mut Self, &str) -> Result<u64>,
+    ) -> Vec<Result<u64>> {
+        let mut results = Vec::with_capacity(statements.len());
+        for sql in statements {
+            let result = execute(self, sql).await;
+            let stop = result.as_ref().is_err_and(|e| !policy.continues_after(e));
+            results.push(result);
+            if stop {
+                break;
             }
         }
+        results
+    }
+
+    /// Run one statement of [`bulk_upsert`](Self::bulk_upsert) under the
+    /// command timeout and return its row count.
+    async fn execute_bulk_upsert_step(&mut self, sql: &str) -> Result<u64> {
+        let deadline = self.command_deadline();
+        let canceller = self.connection_cancel_handle();
+        run_with_deadline(
+            async {
+                self.send_sql_batch(sql).await?;
+                self.read_execute_result().await
+            },
+            deadline,
+            canceller,
+        )
+        .await
+    }
+
+    /// Begin the BulkLoad (0x07) message that carries the rows of a bulk
+    /// insert.
+    ///
+    /// Used by [`crate::bulk::BulkWriter`] once the `INSERT BULK` statement
+    /// has been acknowledged. The client is in flight from here until the
+    /// response to the finished message has been read.
+    pub(crate) fn start_bulk_load(&mut self) -> StreamedMessage {
+        self.in_flight = true;
+        self.first_query_retry = false;
+        StreamedMessage::new(PacketType::BulkLoad, self.packet_size as usize, false)
+    }
 
-        // Read the server's Done response with row count
+    /// End a BulkLoad message and read the server's row count.
+    pub(crate) async fn finish_bulk_load(&mut self, message: StreamedMessage) -> Result<u64> {
+        message.finish(self).await?;
         self.read_execute_result().await
     }
 
@@ -1049,6 +1480,7 @@ impl<S: ConnectionState> Client<S> {
         sql: &str,
         params: &[crate::to_params::NamedParam],
     ) -> Result<QueryStream<'a>> {
+        self.check_read_only(sql)?;
         tracing::debug!(
             sql = sql,
             params_count = params.len(),
@@ -1091,24 +1523,7 @@ impl<S: ConnectionState> Client<S> {
         #[cfg(feature = "otel")]
         drop(span);
 
-        let resp = result?;
-        #[cfg(feature = "always-encrypted")]
-        {
-            Ok(QueryStream::from_raw(
-                resp.columns,
-                resp.pending_rows,
-                resp.meta,
-                resp.decryptor,
-            ))
-        }
-        #[cfg(not(feature = "always-encrypted"))]
-        {
-            Ok(QueryStream::from_raw(
-                resp.columns,
-                resp.pending_rows,
-                resp.meta,
-            ))
-        }
+        Ok(result?.into_stream())
     }
 
     /// Execute a statement with named parameters.
@@ -1140,6 +1555,7 @@ impl<S: ConnectionState> Client<S> {
         sql: &str,
         params: &[crate::to_params::NamedParam],
     ) -> Result<u64> {
+        self.check_read_only(sql)?;
         tracing::debug!(
             sql = sql,
             params_count = params.len(),
@@ -1208,27 +1624,236 @@ impl<S: ConnectionState> Client<S> {
         self.statement_cache.stats()
     }
 
-    /// Whether string parameters are sent as NVARCHAR (Unicode).
-    pub(crate) fn send_unicode(&self) -> bool {
-        self.config.send_string_parameters_as_unicode
+    /// Describe each statement in this connection's prepared-statement cache,
+    /// most recently used first.
+    ///
+    /// Each entry reports how often its handle was reused and when it was last
+    /// used. A statement that keeps falling out of the list and coming back
+    /// with zero hits is being evicted before it is reused; raise
+    /// [`Config::statement_cache_size`](crate::Config::statement_cache_size).
+    /// Empty unless [`Config::statement_cache`](crate::Config::statement_cache)
+    /// is enabled.
+    #[must_use]
+    pub fn statement_cache_entries(&self) -> Vec<crate::CachedStatementInfo> {
+        self.statement_cache.entries()
     }
 
-    /// Server's default collation, captured from ENVCHANGE during login.
-    pub(crate) fn server_collation(&self) -> Option<&tds_protocol::token::Collation> {
-        self.server_collation.as_ref()
+    /// Feature extensions the server acknowledged when this connection
+    /// logged in (federated authentication, Always Encrypted, UTF-8, ...).
+    ///
+    /// Login fails if the server does not acknowledge a feature the driver
+    /// depends on, so this is mainly useful for optional features the server
+    /// enables on its own, such as UTF-8 support.
+    #[must_use]
+    pub fn enabled_features(&self) -> &EnabledFeatures {
+        &self.enabled_features
     }
 
-    /// Shared implementation behind `query_stream` for both `Ready` and
-    /// `InTransaction`. Sends the request, then pulls packets until the first
-    /// result set's `ColMetaData` (resolving columns and any Always Encrypted
-    /// decryptor up front) before handing back a [`RowStream`].
-    pub(crate) async fn query_stream_inner<'a>(
+    /// Whether traffic on this connection is encrypted with TLS.
+    ///
+    /// `true` once the connection has been upgraded to TLS for the whole
+    /// session, whether TDS 8.0 strict (TLS first) or TDS 7.x (TLS negotiated
+    /// in PreLogin). `false` for `Encrypt=no_tls` and for login-only
+    /// encryption, where only the LOGIN7 packet is protected and everything
+    /// after it is sent in plaintext. Applications can assert on this at
+    /// startup to catch a production connection that silently fell back.
+    #[must_use]
+    pub fn is_encrypted(&self) -> bool {
+        match &self.connection {
+            #[cfg(feature = "tls")]
+            Some(ConnectionHandle::Tls(_) | ConnectionHandle::TlsPrelogin(_)) => true,
+            Some(ConnectionHandle::Plain(_)) | None => false,
+        }
+    }
+
+    /// TDS packet size used for requests on this connection.
+    ///
+    /// This is the size the server agreed to during login, which may differ
+    /// from the requested [`Config::packet_size`](crate::Config::packet_size):
+    /// the server can cap it or override it with its own configured value.
+    #[must_use]
+    pub fn packet_size(&self) -> u16 {
+        self.packet_size
+    }
+
+    /// Server process ID (SPID) of this connection's session.
+    ///
+    /// Taken from the headers of the server's login response, so it is
+    /// available without a round trip. This is the `session_id` to look up
+    /// in `sys.dm_exec_sessions` or `sys.dm_exec_requests` when diagnosing
+    /// blocking, and the SPID to pass to `KILL`. `None` if the server left the
+    /// header field zero.
+    #[must_use]
+    pub fn spid(&self) -> Option<u16> {
+        self.spid
+    }
+
+    /// The server's program name and version from its login acknowledgement,
+    /// e.g. `Microsoft SQL Server 16.0.4225`.
+    ///
+    /// Available without a round trip, unlike
+    /// [`product_version`](Self::product_version), but the build number is
+    /// all the server reports here; use `product_version` for a comparable
+    /// value.
+    #[must_use]
+    pub fn server_version_string(&self) -> Option<&str> {
+        self.server_program.as_deref()
+    }
+
+    /// The TDS version the server acknowledged at login.
+    ///
+    /// May be lower than the requested [`Config::tds_version`](Config::tds_version()): SQL Server
+    /// 2000 and 2005 acknowledge 7.1 and 7.2 and the session still works for
+    /// queries. Those servers have no DATE, TIME, DATETIME2 or DATETIMEOFFSET
+    /// types, so check
+    /// [`supports_date_time_types`](tds_protocol::TdsVersion::supports_date_time_types)
+    /// before binding chrono dates and times, or bind them as strings.
+    #[must_use]
+    pub fn tds_version(&self) -> Option<tds_protocol::TdsVersion> {
+        self.server_version.map(tds_protocol::TdsVersion::new)
+    }
+
+    /// The session's current database.
+    ///
+    /// Starts as the database the server reported at login and follows every
+    /// database change it reports afterwards, such as a `USE` statement. This
+    /// can differ from [`Config::database`](crate::Config::database), which
+    /// is only what was requested.
+    #[must_use]
+    pub fn current_database(&self) -> Option<&str> {
+        self.current_database.as_deref()
+    }
+
+    /// The server's product version, from `SERVERPROPERTY('ProductVersion')`.
+    ///
+    /// The first call runs the query; the result is cached for the life of
+    /// the connection, so later calls do not touch the network. Useful for
+    /// version-gating features, e.g. via
+    /// [`ProductVersion::supports_json`](crate::ProductVersion::supports_json).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// use mssql_client::ProductVersion;
+    ///
+    /// let version = client.product_version().await?;
+    /// if version >= ProductVersion::new(15, 0, 0, 0) {
+    ///     println!("SQL Server 2019 or later ({version})");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn product_version(&mut self) -> Result<crate::ProductVersion> {
+        if let Some(version) = self.product_version {
+            return Ok(version);
+        }
+        let stream = self
+            .query_buffered_inner(
+                "SELECT CAST(SERVERPROPERTY('ProductVersion') AS NVARCHAR(128))",
+                &[],
+            )
+            .await?;
+        let text: String = match stream.into_iter().next() {
+            Some(row) => row?.get(0)?,
+            None => {
+                return Err(Error::Protocol(
+                    "SERVERPROPERTY('ProductVersion') returned no rows".into(),
+                ));
+            }
+        };
+        let version = crate::ProductVersion::parse(&text).ok_or_else(|| {
+            Error::Protocol(format!("unrecognized server product version: {text}"))
+        })?;
+        self.product_version = Some(version);
+        Ok(version)
+    }
+
+    /// Names of the databases on the server, from `sys.databases`, sorted.
+    ///
+    /// Only databases the login can see are listed; without `VIEW ANY
+    /// DATABASE` that is `master`, `tempdb` and the databases it owns.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// for name in client.list_databases().await? {
+    ///     println!("{name}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_databases(&mut self) -> Result<Vec<String>> {
+        let stream = self
+            .query_buffered_inner("SELECT name FROM sys.databases ORDER BY name", &[])
+            .await?;
+        stream.into_iter().map(|row| Ok(row?.get(0)?)).collect()
+    }
+
+    /// User tables in the current database, from `sys.tables` and
+    /// `sys.schemas`, sorted by schema then name.
+    ///
+    /// Pass `Some(schema)` to list only that schema's tables. Views and
+    /// system tables are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// for table in client.list_tables(Some("dbo")).await? {
+    ///     println!("{table}"); // [dbo].[orders]
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_tables(&mut self, schema: Option<&str>) -> Result<Vec<crate::TableInfo>> {
+        const SQL: &str = "SELECT s.name, t.name FROM sys.tables t \
+             JOIN sys.schemas s ON s.schema_id = t.schema_id";
+        let stream = match schema {
+            Some(schema) => {
+                let sql = format!("{SQL} WHERE s.name = @p1 ORDER BY s.name, t.name");
+                self.query_buffered_inner(&sql, &[&schema]).await?
+            }
+            None => {
+                let sql = format!("{SQL} ORDER BY s.name, t.name");
+                self.query_buffered_inner(&sql, &[]).await?
+            }
+        };
+        stream
+            .into_iter()
+            .map(|row| {
+                let row = row?;
+                Ok(crate::TableInfo {
+                    schema: row.get(0)?,
+                    name: row.get(1)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether string parameters are sent as NVARCHAR (Unicode).
+    pub(crate) fn send_unicode(&self) -> bool {
+        self.config.send_string_parameters_as_unicode
+    }
+
+    /// Server's default collation, captured from ENVCHANGE during login.
+    pub(crate) fn server_collation(&self) -> Option<&tds_protocol::token::Collation> {
+        self.server_collation.as_ref()
+    }
+
+    /// Shared implementation behind `query_stream` for both `Ready` and
+    /// `InTransaction`. Sends the request, then pulls packets until the first
+    /// result set's `ColMetaData` (resolving columns and any Always Encrypted
+    /// decryptor up front) before handing back a [`RowStream`].
+    pub(crate) async fn query_stream_inner<'a>(
         &'a mut self,
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
     ) -> Result<crate::row_stream::RowStream<'a, S>> {
-        use crate::client::response::server_token_to_error;
-        use crate::row_source::{Pull, RowSource};
+        self.check_read_only(sql)?;
+        use crate::client::response::server_info_message;
+        use crate::row_source::Pull;
         use tds_protocol::token::Token;
 
         tracing::debug!(sql = sql, params_count = params.len(), "streaming query");
@@ -1242,12 +1867,9 @@ impl<S: ConnectionState> Client<S> {
         }
         self.in_flight = true;
 
-        #[cfg(feature = "always-encrypted")]
-        let encryption_enabled = self.encryption_context.is_some();
-        #[cfg(not(feature = "always-encrypted"))]
-        let encryption_enabled = false;
-
-        let mut source = RowSource::new(encryption_enabled);
+        let mut source = self.row_source();
+        // INFO messages seen before an error, reported with it.
+        let mut messages = Vec::new();
 
         // Prelude: pull packets until the first result set's ColMetaData (so the
         // columns and any Always Encrypted decryptor are resolved up front), or
@@ -1271,9 +1893,11 @@ impl<S: ConnectionState> Client<S> {
                     ));
                 }
                 Pull::Token(Token::Error(err)) => {
-                    self.in_flight = false;
-                    return Err(server_token_to_error(&err));
+                    return Err(self
+                        .finish_streamed_error(&err, messages, &mut source)
+                        .await);
                 }
+                Pull::Token(Token::Info(info)) => messages.push(server_info_message(&info)),
                 Pull::Token(Token::Done(done)) => {
                     if done.status.error {
                         self.in_flight = false;
@@ -1289,10 +1913,10 @@ impl<S: ConnectionState> Client<S> {
                     // More results may follow; keep looking for ColMetaData.
                 }
                 Pull::Token(Token::EnvChange(env)) => {
-                    Self::process_transaction_env_change(&env, &mut self.transaction_descriptor);
+                    self.apply_env_change(&env);
                 }
                 Pull::Token(_) => {
-                    // Info / Order / DoneProc / DoneInProc, etc. — keep pulling.
+                    // Order / DoneProc / DoneInProc, etc. — keep pulling.
                 }
                 Pull::NeedMore => match self.read_response_packet().await? {
                     Some((payload, is_eom)) => source.push_packet(payload, is_eom),
@@ -1309,6 +1933,33 @@ impl<S: ConnectionState> Client<S> {
         }
     }
 
+    /// Shared implementation behind `query_events` for both `Ready` and
+    /// `InTransaction`: send the request and hand the undecoded response to
+    /// an [`EventStream`](crate::EventStream).
+    pub(crate) async fn query_events_inner<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<crate::event_stream::EventStream<'a, S>> {
+        self.check_read_only(sql)?;
+        tracing::debug!(
+            sql = sql,
+            params_count = params.len(),
+            "streaming query events"
+        );
+
+        if params.is_empty() {
+            self.send_sql_batch(sql).await?;
+        } else {
+            let rpc = self.build_parameterized_rpc(sql, params).await?;
+            self.send_rpc(&rpc).await?;
+        }
+        self.in_flight = true;
+
+        let source = self.row_source();
+        Ok(crate::event_stream::EventStream::new(self, source))
+    }
+
     /// Shared implementation behind `query_stream_blob` for both `Ready` and
     /// `InTransaction`.
     pub(crate) async fn query_stream_blob_inner<'a>(
@@ -1316,22 +1967,50 @@ impl<S: ConnectionState> Client<S> {
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
     ) -> Result<crate::blob_stream::BlobStream<'a, S>> {
-        let (meta, buf, eom, encryption_enabled) = self.open_blob_stream(sql, params).await?;
+        self.check_read_only(sql)?;
+        let (meta, buf, eom) = self.open_blob_stream(sql, params).await?;
         let first_blob = Self::validate_blob_result_set(&meta)?;
         Ok(crate::blob_stream::BlobStream::new(
-            self,
-            buf,
-            eom,
-            encryption_enabled,
-            meta,
-            first_blob,
+            self, buf, eom, meta, first_blob,
             // Single trailing MAX column; auto-position it so the existing
             // `next` → `copy_blob_to` flow works without an explicit `next_blob`.
-            1,
-            true,
+            1, true,
         ))
     }
 
+    /// Shared implementation behind `read_blob` for both `Ready` and
+    /// `InTransaction`.
+    pub(crate) async fn read_blob_inner<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<crate::blob_stream::BlobStreamReader<'a, S>> {
+        let mut stream = self.query_stream_blob_inner(sql, params).await?;
+        if stream.next().await?.is_none() {
+            return Err(Error::Query(
+                "read_blob: query returned no rows".to_string(),
+            ));
+        }
+        Ok(crate::blob_stream::BlobStreamReader::new(stream))
+    }
+
+    /// Shared implementation behind `query_to_channel` for both `Ready` and
+    /// `InTransaction`.
+    pub(crate) async fn query_to_channel_inner(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        tx: tokio::sync::mpsc::Sender<Result<crate::Row>>,
+    ) -> Result<u64> {
+        match self.query_stream_inner(sql, params).await {
+            Ok(stream) => stream.send_to(tx).await,
+            Err(e) => match tx.send(Err(e)).await {
+                Ok(()) => Ok(0),
+                Err(tokio::sync::mpsc::error::SendError(item)) => item.map(|_| 0),
+            },
+        }
+    }
+
     /// Shared implementation behind `query_stream_rows` for both `Ready` and
     /// `InTransaction`.
     pub(crate) async fn query_stream_rows_inner<'a>(
@@ -1339,16 +2018,11 @@ impl<S: ConnectionState> Client<S> {
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
     ) -> Result<crate::blob_stream::BlobStream<'a, S>> {
-        let (meta, buf, eom, encryption_enabled) = self.open_blob_stream(sql, params).await?;
+        self.check_read_only(sql)?;
+        let (meta, buf, eom) = self.open_blob_stream(sql, params).await?;
         let (first_blob, blob_count) = Self::validate_blob_rows_result_set(&meta)?;
         Ok(crate::blob_stream::BlobStream::new(
-            self,
-            buf,
-            eom,
-            encryption_enabled,
-            meta,
-            first_blob,
-            blob_count,
+            self, buf, eom, meta, first_blob, blob_count,
             // Caller drives blobs explicitly via `next_blob`.
             false,
         ))
@@ -1361,9 +2035,9 @@ impl<S: ConnectionState> Client<S> {
         &mut self,
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
-    ) -> Result<(tds_protocol::token::ColMetaData, bytes::Bytes, bool, bool)> {
-        use crate::client::response::server_token_to_error;
-        use crate::row_source::{Pull, RowSource};
+    ) -> Result<(tds_protocol::token::ColMetaData, bytes::Bytes, bool)> {
+        use crate::client::response::server_info_message;
+        use crate::row_source::Pull;
         use tds_protocol::token::Token;
 
         if params.is_empty() {
@@ -1374,23 +2048,21 @@ impl<S: ConnectionState> Client<S> {
         }
         self.in_flight = true;
 
-        #[cfg(feature = "always-encrypted")]
-        let encryption_enabled = self.encryption_context.is_some();
-        #[cfg(not(feature = "always-encrypted"))]
-        let encryption_enabled = false;
-
-        let mut source = RowSource::new(encryption_enabled);
+        let mut source = self.row_source();
+        let mut messages = Vec::new();
 
         loop {
             match source.pull()? {
                 Pull::Token(Token::ColMetaData(meta)) => {
                     let (buf, eom) = source.into_parts();
-                    return Ok((meta, buf, eom, encryption_enabled));
+                    return Ok((meta, buf, eom));
                 }
                 Pull::Token(Token::Error(err)) => {
-                    self.in_flight = false;
-                    return Err(server_token_to_error(&err));
+                    return Err(self
+                        .finish_streamed_error(&err, messages, &mut source)
+                        .await);
                 }
+                Pull::Token(Token::Info(info)) => messages.push(server_info_message(&info)),
                 Pull::Token(Token::Done(_)) => {
                     self.in_flight = false;
                     return Err(Error::Protocol(
@@ -1488,6 +2160,16 @@ impl<S: ConnectionState> Client<S> {
 }
 
 impl Client<Ready> {
+    /// Set whether this connection is owned by a connection pool.
+    ///
+    /// Called by the connection pool when it creates a connection, and
+    /// cleared when a connection is detached from it. A pool discards
+    /// connections by dropping them, so a pooled client dropped without
+    /// [`close()`](Self::close) does not log the un-closed connection warning.
+    pub fn set_pooled(&mut self, pooled: bool) {
+        self.pooled = pooled;
+    }
+
     /// Mark this connection as needing a reset on next use.
     ///
     /// Called by the connection pool when a connection is returned.
@@ -1511,13 +2193,157 @@ impl Client<Ready> {
         self.needs_reset
     }
 
+    /// Bring a client in an unknown state back to a clean, usable one
+    /// without reconnecting.
+    ///
+    /// For a client abandoned part-way through an operation, e.g. a stream
+    /// dropped by a panic in user code:
+    ///
+    /// 1. a response still in flight is cancelled with an Attention and
+    ///    drained to the acknowledgement, so the socket is at a clean message
+    ///    boundary;
+    /// 2. a transaction left open on the session (by raw `BEGIN TRANSACTION`
+    ///    or otherwise) is rolled back;
+    /// 3. the session is reset with [`reset_connection`](Self::reset_connection),
+    ///    the protocol-level `sp_reset_connection`, which drops temp tables
+    ///    and restores SET options, as on pooled connections.
+    ///
+    /// The rollback and the reset each run under the configured command
+    /// timeout, so a server that stops answering fails the recovery instead
+    /// of hanging it. Safe to call on a healthy client; the rollback is
+    /// skipped on the server when no transaction is open.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection cannot be brought back, e.g. the
+    /// server never acknowledges the Attention or the socket is gone. The
+    /// client is then left marked in flight ([`is_in_flight`](Self::is_in_flight)),
+    /// so a pool discards it at check-in; reconnect instead.
+    pub async fn recover(&mut self) -> Result<()> {
+        tracing::debug!(
+            in_flight = self.in_flight,
+            in_transaction = self.transaction_descriptor != 0,
+            "recovering connection"
+        );
+        let result = async {
+            self.cancel_in_flight_response().await?;
+            // Roll back before resetting, without the reset flag, so the
+            // request is sent in the transaction it ends.
+            self.needs_reset = false;
+            let deadline = self.command_deadline();
+            let canceller = self.cancel_handle();
+            run_with_deadline(
+                async {
+                    self.send_sql_batch(ROLLBACK_OPEN_TRANSACTION).await?;
+                    self.read_execute_result().await
+                },
+                deadline,
+                canceller,
+            )
+            .await?;
+            self.reset_connection().await.map(drop)
+        }
+        .await;
+        if let Err(e) = &result {
+            tracing::warn!(error = %e, "connection could not be recovered");
+            self.in_flight = true;
+        }
+        result
+    }
+
+    /// Release every prepared statement cached on this connection.
+    ///
+    /// Each server-side handle is freed with `sp_unprepare`; later queries
+    /// simply re-prepare. Returns the number of statements dropped. When a
+    /// reset is already pending ([`mark_needs_reset`](Self::mark_needs_reset)),
+    /// the handles are only forgotten locally, since RESETCONNECTION releases
+    /// them on the server anyway.
+    ///
+    /// Used by the connection pool to stop long-lived connections from
+    /// accumulating prepared handles.
+    pub async fn clear_statement_cache(&mut self) -> Result<usize> {
+        let statements: Vec<_> = self.statement_cache.clear().collect();
+        if !self.needs_reset {
+            for stmt in &statements {
+                let unprepare = RpcRequest::unprepare(stmt.handle());
+                self.send_rpc(&unprepare).await?;
+                let _ = self.read_procedure_result().await?;
+            }
+        }
+        Ok(statements.len())
+    }
+
+    /// Reset the session to a fresh state now, without reconnecting.
+    ///
+    /// Runs `sp_reset_connection` on the server: temp tables are dropped, any
+    /// open transaction is rolled back, SET options and the isolation level
+    /// return to their login defaults, and open cursors and prepared handles
+    /// are released. Useful for callers doing their own pooling.
+    ///
+    /// SQL Server exposes `sp_reset_connection` to clients only through the
+    /// RESETCONNECTION status bit of the TDS packet header (MS-TDS
+    /// §2.2.3.1.2) — there is no client-callable RPC ProcID for it. This sends
+    /// a trivial batch with that bit set and waits for it to complete, unlike
+    /// [`mark_needs_reset`](Self::mark_needs_reset), which defers the reset to
+    /// the next request.
+    ///
+    /// Returns `true` if a reset was already pending from
+    /// [`mark_needs_reset`](Self::mark_needs_reset). That reset is the one
+    /// performed here, so callers that count resets (a pool marking returned
+    /// connections) should not count it again.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// client.execute("CREATE TABLE #scratch (id INT)", &[]).await?;
+    /// client.reset_connection().await?;
+    /// // #scratch is gone; the session is back to its login defaults.
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reset_connection(&mut self) -> Result<bool> {
+        tracing::debug!("resetting connection state");
+
+        // `send_sql_batch` consumes the flag, sets RESETCONNECTION on the
+        // packet, and clears the statement cache (the server releases every
+        // prepared handle on reset).
+        let was_pending = std::mem::replace(&mut self.needs_reset, true);
+
+        let deadline = self.command_deadline();
+        let canceller = self.cancel_handle();
+        run_with_deadline(
+            async {
+                self.send_sql_batch("SELECT 1").await?;
+                self.read_execute_result().await
+            },
+            deadline,
+            canceller,
+        )
+        .await?;
+
+        // The reset rolls back any transaction opened with raw T-SQL; the
+        // server reports it via EnvChange, but clear the descriptor
+        // regardless so no stale value is sent in ALL_HEADERS.
+        self.transaction_descriptor = 0;
+        Ok(was_pending)
+    }
+
     /// Execute a query and return a result set with lazy per-row decoding.
     ///
     /// Per ADR-007 the full response is buffered in memory and each row is
     /// *decoded* on demand as you iterate — this is not incremental network
     /// streaming, so peak memory tracks the response size. Use
     /// `.collect_all()` if you want all rows materialized into a `Vec` up
-    /// front.
+    /// front. For result sets too large to hold in memory, use
+    /// [`query_stream`](Self::query_stream), which reads rows from the socket
+    /// as they are pulled (peak memory ≈ one packet plus one row).
+    ///
+    /// The response is always read through to its final DONE token, so a batch
+    /// that produces extra result sets (a second `SELECT`, or a trigger that
+    /// selects) never leaves tokens behind for the next request. The rows
+    /// returned are those of the batch's last result set; use
+    /// [`query_multiple`](Self::query_multiple) to read each one.
     ///
     /// # Example
     ///
@@ -1525,7 +2351,7 @@ impl Client<Ready> {
     /// # use mssql_client::Row;
     /// # fn process(_: &Row) {}
     /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
-    /// // Streaming (synchronous iteration over the result set)
+    /// // Synchronous iteration over the buffered result set
     /// let stream = client.query("SELECT * FROM users WHERE id = @p1", &[&1]).await?;
     /// for row in stream {
     ///     let row = row?;
@@ -1558,6 +2384,7 @@ impl Client<Ready> {
         params: &[&(dyn crate::ToSql + Sync)],
         deadline: Option<std::time::Duration>,
     ) -> Result<QueryStream<'a>> {
+        self.check_read_only(sql)?;
         tracing::debug!(sql = sql, params_count = params.len(), "executing query");
 
         #[cfg(feature = "otel")]
@@ -1569,20 +2396,32 @@ impl Client<Ready> {
             crate::instrumentation::extract_operation(sql),
         );
 
-        let canceller = self.cancel_handle();
-        let result = run_with_deadline(
-            async {
-                // Sends via the prepared-statement cache when enabled, else the
-                // SQL batch / sp_executesql default.
-                self.send_query_request(sql, params).await?;
-
-                // Read complete response including columns and rows
-                self.read_query_response().await
-            },
-            deadline,
-            canceller,
-        )
-        .await;
+        let mut retry_armed = std::mem::take(&mut self.first_query_retry);
+        let result = loop {
+            let canceller = self.cancel_handle();
+            let attempt = run_with_deadline(
+                async {
+                    // Sends via the prepared-statement cache when enabled, else the
+                    // SQL batch / sp_executesql default.
+                    self.send_query_request(sql, params).await?;
+
+                    // Read complete response including columns and rows
+                    self.read_query_response().await
+                },
+                deadline,
+                canceller,
+            )
+            .await;
+            match attempt {
+                Err(e) if retry_armed && e.is_transient() => {
+                    retry_armed = false;
+                    if let Err(reconnect_err) = self.reconnect_for_first_query(&e).await {
+                        break Err(reconnect_err);
+                    }
+                }
+                other => break other,
+            }
+        };
 
         #[cfg(feature = "otel")]
         match &result {
@@ -1596,24 +2435,27 @@ impl Client<Ready> {
         #[cfg(feature = "otel")]
         drop(span);
 
-        let resp = result?;
-        #[cfg(feature = "always-encrypted")]
-        {
-            Ok(QueryStream::from_raw(
-                resp.columns,
-                resp.pending_rows,
-                resp.meta,
-                resp.decryptor,
-            ))
-        }
-        #[cfg(not(feature = "always-encrypted"))]
-        {
-            Ok(QueryStream::from_raw(
-                resp.columns,
-                resp.pending_rows,
-                resp.meta,
-            ))
-        }
+        Ok(result?.into_stream())
+    }
+
+    /// Replace this connection with a fresh one after the first query failed
+    /// transiently (see [`Config::retry_first_query`]).
+    ///
+    /// The stale connection is dropped without a goodbye; the server side has
+    /// either already gone away or will clean up the session on its own.
+    async fn reconnect_for_first_query(&mut self, error: &Error) -> Result<()> {
+        tracing::warn!(
+            error = %error,
+            "first query failed with a transient error; reconnecting once to retry"
+        );
+        let mut fresh = Client::<crate::state::Disconnected>::connect(self.config.clone()).await?;
+        // The replaced client is discarded on purpose: close its socket here
+        // so its Drop does not warn, and keep the pool's ownership mark.
+        drop(self.connection.take());
+        fresh.pooled = self.pooled;
+        *self = fresh;
+        self.first_query_retry = false;
+        Ok(())
     }
 
     /// Execute a query and stream rows incrementally from the network.
@@ -1650,6 +2492,127 @@ impl Client<Ready> {
         self.query_stream_inner(sql, params).await
     }
 
+    /// Execute a query and stream every result-set boundary, row, completion
+    /// and informational message as a [`QueryEvent`](crate::QueryEvent), in
+    /// protocol order.
+    ///
+    /// The most faithful reading API: where [`query_stream`](Self::query_stream)
+    /// flattens a response into rows, this preserves where each result set
+    /// starts, each statement's row count, and `PRINT` output in between —
+    /// what a proxy or passthrough needs to relay the response unchanged.
+    /// Packets are read on demand as with `query_stream`, and the returned
+    /// [`EventStream`](crate::EventStream) borrows the client until it is
+    /// consumed or dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use mssql_client::QueryEvent;
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// let mut events = client
+    ///     .query_events("SELECT name FROM sys.databases; SELECT @@VERSION", &[])
+    ///     .await?;
+    /// while let Some(event) = events.try_next().await? {
+    ///     if let QueryEvent::NewResultSet(columns) = event {
+    ///         println!("result set with {} column(s)", columns.len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_events<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<crate::event_stream::EventStream<'a, Ready>> {
+        self.query_events_inner(sql, params).await
+    }
+
+    /// Execute a query and write each row, serialized by `serialize`, to an
+    /// async writer — returning the number of rows written.
+    ///
+    /// Built on [`query_stream`](Self::query_stream): rows are pulled from the
+    /// network one at a time and written before the next is read, so large
+    /// exports to a file or socket run in bounded memory with backpressure
+    /// from the writer. See [`RowStream::write_into`](crate::RowStream::write_into)
+    /// for how write errors are handled.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(
+    /// #     client: &mut mssql_client::Client<mssql_client::Ready>,
+    /// #     out: &mut (impl tokio::io::AsyncWrite + Unpin),
+    /// # ) -> Result<(), mssql_client::Error> {
+    /// use bytes::Bytes;
+    ///
+    /// let rows = client
+    ///     .query_stream_into("SELECT id, name FROM users", &[], out, |row| {
+    ///         let id: i32 = row.get(0).unwrap_or_default();
+    ///         let name: String = row.get(1).unwrap_or_default();
+    ///         Bytes::from(format!("{id},{name}\n"))
+    ///     })
+    ///     .await?;
+    /// # let _ = rows;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_stream_into<W, F>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        writer: &mut W,
+        serialize: F,
+    ) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+        F: FnMut(&crate::Row) -> bytes::Bytes,
+    {
+        self.query_stream_inner(sql, params)
+            .await?
+            .write_into(writer, serialize)
+            .await
+    }
+
+    /// Execute a query and send its rows into a bounded channel, returning
+    /// the number of rows sent.
+    ///
+    /// Built on [`query_stream`](Self::query_stream): rows are pulled from the
+    /// network one at a time, and a full channel pauses the read until
+    /// consumers catch up, so a producer task can fan a large result out to
+    /// several workers in bounded memory. Errors, including one that fails
+    /// the query before any row, are sent to the channel as its last item;
+    /// see [`RowStream::send_to`](crate::RowStream::send_to) for the details
+    /// and for what happens when every receiver is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(mut client: mssql_client::Client<mssql_client::Ready>) {
+    /// let (tx, mut rx) = tokio::sync::mpsc::channel(256);
+    /// let producer = tokio::spawn(async move {
+    ///     client
+    ///         .query_to_channel("SELECT id, name FROM users", &[], tx)
+    ///         .await
+    /// });
+    /// while let Some(row) = rx.recv().await {
+    ///     match row {
+    ///         Ok(row) => { /* hand the row to a worker */ let _ = row; }
+    ///         Err(e) => eprintln!("query failed: {e}"),
+    ///     }
+    /// }
+    /// # let _ = producer.await;
+    /// # }
+    /// ```
+    pub async fn query_to_channel(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        tx: tokio::sync::mpsc::Sender<Result<crate::Row>>,
+    ) -> Result<u64> {
+        self.query_to_channel_inner(sql, params, tx).await
+    }
+
     /// Execute a query and stream a row's trailing MAX column from the network.
     ///
     /// For result sets whose last column is a single MAX type
@@ -1709,6 +2672,296 @@ impl Client<Ready> {
         self.query_stream_rows_inner(sql, params).await
     }
 
+    /// Execute a query and read the MAX value in the last column of its
+    /// first row as an [`AsyncRead`](tokio::io::AsyncRead).
+    ///
+    /// The value is streamed from the socket chunk by chunk as packets
+    /// arrive, so a value larger than memory can be copied to a file or
+    /// socket with [`tokio::io::copy`]. The column rules are those of
+    /// [`query_stream_blob`](Self::query_stream_blob); any leading columns are
+    /// skipped. A NULL value reads as empty, and rows after the first are
+    /// discarded. Also available on `Client<InTransaction>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(
+    /// #     client: &mut mssql_client::Client<mssql_client::Ready>,
+    /// #     file: &mut (impl tokio::io::AsyncWrite + Unpin),
+    /// # ) -> Result<(), mssql_client::Error> {
+    /// let mut blob = client
+    ///     .read_blob("SELECT data FROM files WHERE id = @p1", &[&42i32])
+    ///     .await?;
+    /// let bytes = tokio::io::copy(&mut blob, file).await?;
+    /// # let _ = bytes;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query returns no rows, or in the cases listed
+    /// on [`query_stream_blob`](Self::query_stream_blob). Errors while reading
+    /// surface as [`std::io::Error`]s wrapping an [`Error`].
+    pub async fn read_blob<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<crate::blob_stream::BlobStreamReader<'a, Ready>> {
+        self.read_blob_inner(sql, params).await
+    }
+
+    /// Execute a statement, streaming a `VARBINARY(MAX)` parameter from an
+    /// [`AsyncRead`](tokio::io::AsyncRead).
+    ///
+    /// The blob is bound as the parameter after `params`: `@p1` with no other
+    /// parameters, `@p2` after one, and so on. It is read and sent in PLP
+    /// chunks of `chunk_size` bytes, packet by packet, so only one chunk is
+    /// held in memory however large the source is. Returns the number of
+    /// affected rows, like [`execute`](Self::execute).
+    ///
+    /// If reading the source fails, the partly sent request is abandoned
+    /// (the server discards it) and the read error is returned; the
+    /// connection stays usable. The command timeout does not apply, since an
+    /// upload's duration depends on the source. Also available on
+    /// `Client<InTransaction>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(
+    /// #     client: &mut mssql_client::Client<mssql_client::Ready>,
+    /// #     file: &mut (impl tokio::io::AsyncRead + Unpin),
+    /// # ) -> Result<(), mssql_client::Error> {
+    /// client
+    ///     .write_blob(
+    ///         "UPDATE files SET data = @p2 WHERE id = @p1",
+    ///         &[&42i32],
+    ///         file,
+    ///         64 * 1024,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `chunk_size` is zero or exceeds
+    /// `u32::MAX`, or if Always Encrypted is enabled (not supported on this
+    /// path).
+    pub async fn write_blob<R>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        blob: &mut R,
+        chunk_size: usize,
+    ) -> Result<u64>
+    where
+        R: tokio::io::AsyncRead + Unpin + ?Sized,
+    {
+        self.write_blob_inner(sql, params, blob, chunk_size).await
+    }
+
+    /// Execute a query and map every row to `R`.
+    ///
+    /// Shorthand for [`query`](Self::query) followed by
+    /// [`map_rows`](crate::RowIteratorExt::map_rows) and `collect`: the first
+    /// row that fails to map aborts the call with that error. Use
+    /// [`query_typed_stream`](Self::query_typed_stream) to map rows one at a
+    /// time instead of materializing the whole `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// #[derive(mssql_derive::FromRow)]
+    /// struct User {
+    ///     id: i32,
+    ///     #[mssql(rename = "user_name")]
+    ///     name: String,
+    ///     email: Option<String>,
+    /// }
+    ///
+    /// let users: Vec<User> = client
+    ///     .query_typed("SELECT id, user_name, email FROM users WHERE active = @p1", &[&true])
+    ///     .await?;
+    /// # let _ = users;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_typed<R: crate::FromRow>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<Vec<R>> {
+        self.query_typed_stream_inner(sql, params).await?.collect()
+    }
+
+    /// Execute a query and return an iterator that maps each row to `R` as it
+    /// is consumed.
+    ///
+    /// Like [`query`](Self::query), the response is buffered per ADR-007;
+    /// rows are decoded and mapped lazily, so a mapping error is reported for
+    /// the row that caused it without aborting the rows before it. For
+    /// incremental network streaming use
+    /// [`query_stream`](Self::query_stream) with
+    /// [`FromRow::from_row`](crate::FromRow::from_row).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// #[derive(mssql_derive::FromRow)]
+    /// struct Event {
+    ///     id: i64,
+    ///     kind: String,
+    /// }
+    ///
+    /// for event in client
+    ///     .query_typed_stream::<Event>("SELECT id, kind FROM events", &[])
+    ///     .await?
+    /// {
+    ///     let event = event?;
+    ///     println!("{} {}", event.id, event.kind);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_typed_stream<R: crate::FromRow>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<crate::MapRows<QueryStream<'static>, R>> {
+        self.query_typed_stream_inner(sql, params).await
+    }
+
+    /// Call a table-valued function and map each returned row to `R`.
+    ///
+    /// Builds `SELECT * FROM function(@p1, @p2, ...)` with one positional
+    /// parameter per entry in `params`. The function name is validated to
+    /// prevent SQL injection and may be schema-qualified (e.g.,
+    /// `"dbo.fn_GetOrders"`); parameter values are always sent as RPC
+    /// parameters, never spliced into the SQL text.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// #[derive(mssql_derive::FromRow)]
+    /// struct Order {
+    ///     id: i32,
+    ///     total: f64,
+    /// }
+    ///
+    /// let orders: Vec<Order> = client
+    ///     .query_tvf("dbo.fn_GetOrders", &[&42i32])
+    ///     .await?;
+    /// # let _ = orders;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_tvf<R: crate::FromRow>(
+        &mut self,
+        function: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<Vec<R>> {
+        self.query_tvf_inner(function, params).await
+    }
+
+    /// Execute a query and map its first row to `R`, or return `default` if
+    /// the query returns no rows.
+    ///
+    /// Rows after the first are ignored. Errors from the query or from
+    /// mapping the row are returned as-is; only the empty result falls back.
+    /// Use [`query_one_or_else`](Self::query_one_or_else) when the default is
+    /// expensive to build.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// #[derive(mssql_derive::FromRow)]
+    /// struct Settings {
+    ///     theme: String,
+    /// }
+    ///
+    /// let settings = client
+    ///     .query_one_or(
+    ///         Settings { theme: "light".into() },
+    ///         "SELECT theme FROM user_settings WHERE user_id = @p1",
+    ///         &[&42i32],
+    ///     )
+    ///     .await?;
+    /// # let _ = settings.theme;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_one_or<R: crate::FromRow>(
+        &mut self,
+        default: R,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<R> {
+        self.query_one_or_else_inner(|| default, sql, params).await
+    }
+
+    /// Execute a query and map its first row to `R`, or call `default` to
+    /// build the result if the query returns no rows.
+    ///
+    /// The lazily evaluated form of [`query_one_or`](Self::query_one_or):
+    /// `default` runs only when no row is found.
+    pub async fn query_one_or_else<R, F>(
+        &mut self,
+        default: F,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<R>
+    where
+        R: crate::FromRow,
+        F: FnOnce() -> R,
+    {
+        self.query_one_or_else_inner(default, sql, params).await
+    }
+
+    /// Execute a statement with an `OUTPUT` clause, returning both the
+    /// `OUTPUT` rows mapped to `R` and the number of rows affected.
+    ///
+    /// [`execute`](Self::execute) reports the count but discards the rows,
+    /// while [`query`](Self::query) keeps the rows but not the count; the two
+    /// can differ, e.g. when an `INSTEAD OF` trigger or `OUTPUT ... INTO` is
+    /// involved. The count is summed over every DONE token as in
+    /// [`execute_detailed`](Self::execute_detailed) and is `0` when the server
+    /// reported none (`SET NOCOUNT ON`).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// #[derive(mssql_derive::FromRow)]
+    /// struct Deleted {
+    ///     id: i32,
+    /// }
+    ///
+    /// let (deleted, count): (Vec<Deleted>, u64) = client
+    ///     .execute_returning(
+    ///         "DELETE FROM jobs OUTPUT DELETED.id WHERE done = 1 AND owner = @p1",
+    ///         &[&42i32],
+    ///     )
+    ///     .await?;
+    /// println!("removed {count} job(s)");
+    /// # let _ = deleted.first().map(|d| d.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_returning<R: crate::FromRow>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<(Vec<R>, u64)> {
+        self.execute_returning_inner(sql, params).await
+    }
+
     /// Execute a query with a specific timeout.
     ///
     /// This overrides the default `command_timeout` from the connection configuration
@@ -1784,6 +3037,7 @@ impl Client<Ready> {
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
     ) -> Result<MultiResultStream<'a>> {
+        self.check_read_only(sql)?;
         tracing::debug!(
             sql = sql,
             params_count = params.len(),
@@ -1830,29 +3084,154 @@ impl Client<Ready> {
         #[cfg(feature = "otel")]
         drop(span);
 
-        let result_sets = result?;
-        Ok(MultiResultStream::new(result_sets))
+        let (result_sets, messages) = result?;
+        Ok(MultiResultStream::new(result_sets).with_messages(messages))
     }
 
     /// Execute a query that doesn't return rows.
     ///
-    /// Returns the number of affected rows.
+    /// Returns the number of affected rows (`0` when the server reported no
+    /// count; use [`execute_detailed`](Self::execute_detailed) to tell that
+    /// apart from a genuine zero-row match).
     pub async fn execute(
         &mut self,
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
     ) -> Result<u64> {
+        let deadline = self.command_deadline();
+        self.execute_inner(sql, params, deadline)
+            .await
+            .map(|r| r.rows_affected.unwrap_or(0))
+    }
+
+    /// Execute a statement, retrying it under the configured
+    /// [`RetryPolicy`](crate::RetryPolicy) when the server fails it with a
+    /// transient error.
+    ///
+    /// Only transient server errors are retried, such as a deadlock victim
+    /// (1205) or Azure throttling (see
+    /// [`Error::is_transient_server_error`](crate::Error::is_transient_server_error)):
+    /// the connection stays usable after them. A connection failure or timeout
+    /// is returned at once; use [`RetryPolicy::run`](crate::RetryPolicy::run)
+    /// around a fresh connection for those. Each retry reruns all of `sql`, so
+    /// use this for idempotent statements.
+    pub async fn execute_with_retry(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<u64> {
+        let policy = self.config.retry.clone();
+        let mut attempt = 0;
+        loop {
+            let err = match self.execute(sql, params).await {
+                Err(err @ Error::Server { .. }) => err,
+                result => return result,
+            };
+            let Some(backoff) = policy.retry_after(attempt, &err) else {
+                return Err(err);
+            };
+            attempt += 1;
+            tracing::debug!(
+                attempt,
+                backoff_ms = backoff.as_millis() as u64,
+                error = %err,
+                "retrying statement after transient server error"
+            );
+            tokio::time::sleep(backoff).await;
+        }
+    }
+
+    /// Execute a statement and return the detailed [`ExecuteResult`].
+    ///
+    /// Unlike [`execute`](Self::execute), the row count distinguishes a
+    /// statement that matched no rows (`Some(0)`) from one for which the
+    /// server never reported a count (`None`), e.g. DDL, a statement skipped
+    /// by control flow, or a batch run under `SET NOCOUNT ON`. Useful for
+    /// idempotency checks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// let result = client
+    ///     .execute_detailed("UPDATE jobs SET done = 1 WHERE id = @p1", &[&7i32])
+    ///     .await?;
+    /// match result.rows_affected {
+    ///     Some(0) => println!("no job matched"),
+    ///     Some(n) => println!("{n} job(s) updated"),
+    ///     None => println!("no row count reported"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_detailed(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<ExecuteResult> {
         let deadline = self.command_deadline();
         self.execute_inner(sql, params, deadline).await
     }
 
+    /// Execute several statements in order, one request each.
+    ///
+    /// Returns one entry per statement that was run: its row count (`0` when
+    /// the server reported none, as with [`execute`](Self::execute)) or its
+    /// error. Under
+    /// [`BatchErrorPolicy::StopOnError`](crate::BatchErrorPolicy::StopOnError)
+    /// the batch stops at the first failure, which is the last entry; later
+    /// statements are not sent. Under
+    /// [`BatchErrorPolicy::ContinueOnError`](crate::BatchErrorPolicy::ContinueOnError)
+    /// a statement that the server rejects is recorded and the batch moves on,
+    /// so the result has an entry for every statement. Errors other than
+    /// server errors (a lost connection, a timeout) stop the batch under either
+    /// policy.
+    ///
+    /// Statements are not wrapped in a transaction; begin one first if the
+    /// batch must apply atomically.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// use mssql_client::BatchErrorPolicy;
+    ///
+    /// let results = client
+    ///     .execute_batch(
+    ///         &[
+    ///             "CREATE INDEX ix_orders_customer ON orders (customer_id)",
+    ///             "UPDATE STATISTICS orders",
+    ///         ],
+    ///         BatchErrorPolicy::ContinueOnError,
+    ///     )
+    ///     .await;
+    /// for (i, result) in results.iter().enumerate() {
+    ///     if let Err(e) = result {
+    ///         eprintln!("statement {i} failed: {e}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_batch(
+        &mut self,
+        statements: &[&str],
+        policy: crate::BatchErrorPolicy,
+    ) -> Vec<Result<u64>> {
+        self.execute_batch_inner(statements, policy, async |client, sql| {
+            client.execute(sql, &[]).await
+        })
+        .await
+    }
+
     /// Shared execute implementation with an explicit command deadline.
     async fn execute_inner(
         &mut self,
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
         deadline: Option<std::time::Duration>,
-    ) -> Result<u64> {
+    ) -> Result<ExecuteResult> {
+        self.check_read_only(sql)?;
         tracing::debug!(
             sql = sql,
             params_count = params.len(),
@@ -1868,29 +3247,41 @@ impl Client<Ready> {
             crate::instrumentation::extract_operation(sql),
         );
 
-        let canceller = self.cancel_handle();
-        let result = run_with_deadline(
-            async {
-                if params.is_empty() {
-                    // Simple statement without parameters - use SQL batch
-                    self.send_sql_batch(sql).await?;
-                } else {
-                    // Parameterized statement - sp_executesql (encrypts Always Encrypted params).
-                    let rpc = self.build_parameterized_rpc(sql, params).await?;
-                    self.send_rpc(&rpc).await?;
-                }
+        let mut retry_armed = std::mem::take(&mut self.first_query_retry);
+        let result = loop {
+            let canceller = self.cancel_handle();
+            let attempt = run_with_deadline(
+                async {
+                    if params.is_empty() {
+                        // Simple statement without parameters - use SQL batch
+                        self.send_sql_batch(sql).await?;
+                    } else {
+                        // Parameterized statement - sp_executesql (encrypts Always Encrypted params).
+                        let rpc = self.build_parameterized_rpc(sql, params).await?;
+                        self.send_rpc(&rpc).await?;
+                    }
 
-                // Read response and get row count
-                self.read_execute_result().await
-            },
-            deadline,
-            canceller,
-        )
-        .await;
+                    // Read response and get row count
+                    self.read_execute_detailed().await
+                },
+                deadline,
+                canceller,
+            )
+            .await;
+            match attempt {
+                Err(e) if retry_armed && e.is_transient() => {
+                    retry_armed = false;
+                    if let Err(reconnect_err) = self.reconnect_for_first_query(&e).await {
+                        break Err(reconnect_err);
+                    }
+                }
+                other => break other,
+            }
+        };
 
         #[cfg(feature = "otel")]
         match &result {
-            Ok(rows) => InstrumentationContext::record_success(&mut span, Some(*rows)),
+            Ok(r) => InstrumentationContext::record_success(&mut span, r.rows_affected),
             Err(e) => InstrumentationContext::record_error(&mut span, e),
         }
         #[cfg(feature = "otel")]
@@ -1939,10 +3330,67 @@ impl Client<Ready> {
         &mut self,
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
-        timeout_duration: std::time::Duration,
+        timeout_duration: std::time::Duration,
+    ) -> Result<u64> {
+        self.execute_inner(sql, params, Some(timeout_duration))
+            .await
+            .map(|r| r.rows_affected.unwrap_or(0))
+    }
+
+    /// Execute a query that is abandoned when `token` is cancelled.
+    ///
+    /// Use this where the caller may give up on the query, such as a request
+    /// handler whose client disconnected. Cancelling the token sends an
+    /// Attention packet to stop the statement server-side, drains the
+    /// acknowledgement, and returns [`Error::Cancelled`] with the connection
+    /// left usable. Simply dropping a query future instead leaves the
+    /// connection [in flight](Self::is_in_flight): the next request on it
+    /// sends the Attention first, and the pool discards it at check-in.
+    ///
+    /// The configured `command_timeout` still applies.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// use mssql_client::CancellationToken;
+    ///
+    /// let token = CancellationToken::new();
+    /// let guard = token.clone().drop_guard(); // cancels if this scope unwinds
+    /// let rows = client
+    ///     .query_with_cancellation("SELECT * FROM large_table", &[], &token)
+    ///     .await?;
+    /// guard.disarm();
+    /// # let _ = rows;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_with_cancellation<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        token: &tokio_util::sync::CancellationToken,
+    ) -> Result<QueryStream<'a>> {
+        let canceller = self.connection_cancel_handle();
+        let deadline = self.command_deadline();
+        run_until_cancelled(self.query_inner(sql, params, deadline), token, canceller).await
+    }
+
+    /// Execute a statement that is abandoned when `token` is cancelled.
+    ///
+    /// See [`query_with_cancellation`](Self::query_with_cancellation) for how
+    /// cancellation behaves.
+    pub async fn execute_with_cancellation(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        token: &tokio_util::sync::CancellationToken,
     ) -> Result<u64> {
-        self.execute_inner(sql, params, Some(timeout_duration))
+        let canceller = self.connection_cancel_handle();
+        let deadline = self.command_deadline();
+        run_until_cancelled(self.execute_inner(sql, params, deadline), token, canceller)
             .await
+            .map(|r| r.rows_affected.unwrap_or(0))
     }
 
     /// Begin a transaction.
@@ -1978,22 +3426,7 @@ impl Client<Ready> {
 
         let transaction_descriptor = result?;
 
-        Ok(Client {
-            config: self.config,
-            _state: PhantomData,
-            connection: self.connection,
-            server_version: self.server_version,
-            current_database: self.current_database,
-            server_collation: self.server_collation,
-            statement_cache: self.statement_cache,
-            transaction_descriptor, // Store the descriptor from server
-            needs_reset: self.needs_reset,
-            in_flight: self.in_flight,
-            #[cfg(feature = "otel")]
-            instrumentation: self.instrumentation,
-            #[cfg(feature = "always-encrypted")]
-            encryption_context: self.encryption_context,
-        })
+        Ok(self.into_state(transaction_descriptor))
     }
 
     /// Begin a transaction with a specific isolation level.
@@ -2001,6 +3434,13 @@ impl Client<Ready> {
     /// This transitions the client from `Ready` to `InTransaction` state
     /// with the specified isolation level.
     ///
+    /// For [`IsolationLevel::Snapshot`](crate::IsolationLevel::Snapshot) the
+    /// current database is checked first: SQL Server accepts the `SET` and
+    /// `BEGIN TRANSACTION` either way and only fails the first statement that
+    /// reads data (error 3952), so a database without
+    /// `ALLOW_SNAPSHOT_ISOLATION ON` is reported here instead, as the same
+    /// [`Error::Server`] with number 3952.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -2029,6 +3469,9 @@ impl Client<Ready> {
 
         // First set the isolation level
         let result = async {
+            if isolation_level == crate::transaction::IsolationLevel::Snapshot {
+                self.check_snapshot_isolation_allowed().await?;
+            }
             self.send_sql_batch(isolation_level.as_sql()).await?;
             self.read_execute_result().await?;
 
@@ -2049,21 +3492,37 @@ impl Client<Ready> {
 
         let transaction_descriptor = result?;
 
-        Ok(Client {
-            config: self.config,
-            _state: PhantomData,
-            connection: self.connection,
-            server_version: self.server_version,
-            current_database: self.current_database,
-            server_collation: self.server_collation,
-            statement_cache: self.statement_cache,
-            transaction_descriptor,
-            needs_reset: self.needs_reset,
-            in_flight: self.in_flight,
-            #[cfg(feature = "otel")]
-            instrumentation: self.instrumentation,
-            #[cfg(feature = "always-encrypted")]
-            encryption_context: self.encryption_context,
+        Ok(self.into_state(transaction_descriptor))
+    }
+
+    /// Fail unless the current database has `ALLOW_SNAPSHOT_ISOLATION ON`.
+    async fn check_snapshot_isolation_allowed(&mut self) -> Result<()> {
+        let stream = self
+            .query_buffered_inner(SNAPSHOT_ISOLATION_STATE_QUERY, &[])
+            .await?;
+        // No row means the state is not visible to this login; leave the
+        // decision to the server.
+        let state: i32 = match stream.into_iter().next() {
+            Some(row) => row?.get(0)?,
+            None => return Ok(()),
+        };
+        if state == SNAPSHOT_ISOLATION_ON {
+            return Ok(());
+        }
+        let database = self.current_database.as_deref().unwrap_or("current");
+        Err(Error::Server {
+            number: SNAPSHOT_ISOLATION_NOT_ALLOWED,
+            class: 16,
+            state: 1,
+            message: format!(
+                "Snapshot isolation transaction failed accessing database '{database}' \
+                 because snapshot isolation is not allowed in this database. \
+                 Use ALTER DATABASE to allow snapshot isolation."
+            ),
+            server: None,
+            procedure: None,
+            line: 0,
+            additional: Vec::new(),
         })
     }
 
@@ -2072,6 +3531,7 @@ impl Client<Ready> {
     /// This is useful for DDL statements and simple queries where you
     /// don't need to retrieve the affected row count.
     pub async fn simple_query(&mut self, sql: &str) -> Result<()> {
+        self.check_read_only(sql)?;
         tracing::debug!(sql = sql, "executing simple query");
 
         // Send SQL batch
@@ -2083,13 +3543,174 @@ impl Client<Ready> {
         Ok(())
     }
 
+    /// Check that a SQL batch compiles, without executing it.
+    ///
+    /// The batch is sent under `SET NOEXEC ON`: the server parses it and
+    /// compiles each statement, so syntax errors and references to missing
+    /// objects come back as the usual [`Error::Server`], but nothing runs and
+    /// nothing is changed. Errors that only happen at run time (a constraint
+    /// violation, a divide by zero) are not detected. `NOEXEC` is turned back
+    /// off before this returns, whether or not the batch compiled.
+    ///
+    /// `SET NOEXEC OFF` takes effect inside the batch too, so a batch that
+    /// contains it really executes every statement after it. Only pass SQL
+    /// that leaves `NOEXEC` alone.
+    ///
+    /// The batch is sent on its own, so it may start with a statement that
+    /// must be first in a batch, such as `CREATE PROCEDURE`. `GO` is a
+    /// client-side separator, not T-SQL, and must not appear in `sql`.
+    ///
+    /// Each of the three round trips is bounded by the command timeout. If
+    /// the batch times out it is cancelled, and `NOEXEC` is still turned off.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// // Fails with "Invalid object name" if the table does not exist, but
+    /// // deletes nothing either way.
+    /// client.validate_sql("DELETE FROM dbo.orders WHERE id = 1").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate_sql(&mut self, sql: &str) -> Result<()> {
+        tracing::debug!(sql = sql, "validating SQL batch");
+
+        self.validate_round_trip("SET NOEXEC ON").await?;
+        let result = self.validate_round_trip(sql).await;
+        let restore = self.validate_round_trip("SET NOEXEC OFF").await;
+
+        result?;
+        restore?;
+        Ok(())
+    }
+
+    /// Send one batch of [`validate_sql`](Self::validate_sql) and read its
+    /// response under the command timeout.
+    async fn validate_round_trip(&mut self, sql: &str) -> Result<()> {
+        let deadline = self.command_deadline();
+        let canceller = self.cancel_handle();
+        run_with_deadline(
+            async {
+                self.send_sql_batch(sql).await?;
+                self.read_execute_result().await
+            },
+            deadline,
+            canceller,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Execute a SQL batch without parameters and return all of its result
+    /// sets.
+    ///
+    /// The batch is sent as-is in a SQL batch packet, with no `sp_executesql`
+    /// wrapper, so it behaves like a script run from `sqlcmd`: every SELECT in
+    /// it produces a result set, and PRINT output and other informational
+    /// messages are available from [`MultiResultStream::messages`]. `GO` is a
+    /// client-side separator, not T-SQL, and must not appear in `sql`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// let mut results = client
+    ///     .simple_query_multiple("PRINT 'start'; SELECT 1 AS a; SELECT 2 AS b;")
+    ///     .await?;
+    ///
+    /// for message in results.messages() {
+    ///     println!("{}", message.message);
+    /// }
+    /// loop {
+    ///     while let Some(row) = results.next_row().await? {
+    ///         println!("{:?}", row);
+    ///     }
+    ///     if !results.next_result().await? {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn simple_query_multiple<'a>(
+        &'a mut self,
+        sql: &str,
+    ) -> Result<MultiResultStream<'a>> {
+        self.query_multiple(sql, &[]).await
+    }
+
+    /// Switch the session to another database (`USE [name]`).
+    ///
+    /// [`current_database`](Self::current_database) follows the database
+    /// change the server reports. Resetting the connection (as the pool does
+    /// on check-in) returns the session to its login database.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidIdentifier`] without contacting the server if
+    /// `name` is not a plain identifier, and the server's error (911) if the
+    /// database does not exist or cannot be opened.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+    /// client.use_database("sales").await?;
+    /// assert_eq!(client.current_database(), Some("sales"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn use_database(&mut self, name: &str) -> Result<()> {
+        crate::validation::validate_identifier(name)?;
+        tracing::debug!(database = name, "switching database");
+
+        // Note: name is validated by validate_identifier() to prevent SQL injection
+        let sql = format!("USE [{name}]");
+        self.send_sql_batch(&sql).await?;
+        self.read_execute_result().await?;
+
+        Ok(())
+    }
+
     /// Close the connection gracefully.
-    pub async fn close(self) -> Result<()> {
+    ///
+    /// If a response is still in flight (e.g. a [`RowStream`](crate::RowStream)
+    /// dropped part-way), it is cancelled with an Attention first. The stream
+    /// is then flushed and its write half shut down, so SQL Server sees the
+    /// disconnect and reclaims the session right away instead of waiting for
+    /// the socket to time out. TDS 7.x has no logout message; the
+    /// end-of-stream is the logout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the shutdown cannot be written. A failed Attention
+    /// is only logged, since the connection is being discarded anyway.
+    pub async fn close(mut self) -> Result<()> {
         tracing::debug!("closing connection");
+
+        if let Err(e) = self.cancel_in_flight_response().await {
+            tracing::warn!(error = %e, "failed to cancel in-flight response on close");
+        }
+
+        let Some(mut connection) = self.connection.take() else {
+            return Ok(());
+        };
+        match &mut connection {
+            #[cfg(feature = "tls")]
+            ConnectionHandle::Tls(conn) => conn.shutdown().await?,
+            #[cfg(feature = "tls")]
+            ConnectionHandle::TlsPrelogin(conn) => conn.shutdown().await?,
+            ConnectionHandle::Plain(conn) => conn.shutdown().await?,
+        }
         Ok(())
     }
 
-    /// Get the current database name.
+    /// Get the database named in the configuration.
+    ///
+    /// This is what was requested at connect; see
+    /// [`current_database`](Self::current_database) for the database the
+    /// session is actually using.
     #[must_use]
     pub fn database(&self) -> Option<&str> {
         self.config.database.as_deref()
@@ -2162,6 +3783,11 @@ impl Client<Ready> {
     ///
     /// The cancel handle can be cloned and sent to other tasks, enabling
     /// cancellation of long-running queries from a separate async context.
+    /// Cancelling writes an Attention packet on the connection's write half,
+    /// so it works while a read is blocked. The pending `query` — or the next
+    /// read of a [`RowStream`](crate::RowStream) — then fails with
+    /// [`Error::Cancelled`] once the server acknowledges, and the connection
+    /// is ready for the next request.
     ///
     /// # Example
     ///
@@ -2201,8 +3827,9 @@ impl Client<Ready> {
 ///
 /// ## Consequences of dropping without commit/rollback
 ///
-/// - **Direct connections:** The transaction leaks until the OS TCP timeout
-///   (potentially 30+ minutes), holding locks on any modified rows.
+/// - **Direct connections:** Dropping the client closes its socket, so the
+///   server rolls the transaction back once it notices the disconnect. Until
+///   then it keeps holding locks on any modified rows.
 /// - **Pooled connections:** The pool detects the active transaction descriptor
 ///   and discards the connection rather than returning it to the idle pool
 ///   (see `PooledConnection::drop` in `mssql-driver-pool`).
@@ -2224,9 +3851,11 @@ impl Client<Ready> {
 /// # }
 /// ```
 impl Client<InTransaction> {
-    /// Execute a query within the transaction and return a streaming result set.
+    /// Execute a query within the transaction and return a buffered result set.
     ///
-    /// See [`Client<Ready>::query`] for usage examples.
+    /// See [`Client<Ready>::query`] for usage examples, and
+    /// [`query_stream`](Self::query_stream) for result sets too large to
+    /// buffer.
     pub async fn query<'a>(
         &'a mut self,
         sql: &str,
@@ -2243,6 +3872,7 @@ impl Client<InTransaction> {
         params: &[&(dyn crate::ToSql + Sync)],
         deadline: Option<std::time::Duration>,
     ) -> Result<QueryStream<'a>> {
+        self.check_read_only(sql)?;
         tracing::debug!(
             sql = sql,
             params_count = params.len(),
@@ -2285,24 +3915,7 @@ impl Client<InTransaction> {
         #[cfg(feature = "otel")]
         drop(span);
 
-        let resp = result?;
-        #[cfg(feature = "always-encrypted")]
-        {
-            Ok(QueryStream::from_raw(
-                resp.columns,
-                resp.pending_rows,
-                resp.meta,
-                resp.decryptor,
-            ))
-        }
-        #[cfg(not(feature = "always-encrypted"))]
-        {
-            Ok(QueryStream::from_raw(
-                resp.columns,
-                resp.pending_rows,
-                resp.meta,
-            ))
-        }
+        Ok(result?.into_stream())
     }
 
     /// Stream rows incrementally from the network within the transaction.
@@ -2320,6 +3933,53 @@ impl Client<InTransaction> {
         self.query_stream_inner(sql, params).await
     }
 
+    /// Stream the query's events within the transaction.
+    ///
+    /// See [`Client<Ready>::query_events`] for semantics.
+    pub async fn query_events<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<crate::event_stream::EventStream<'a, InTransaction>> {
+        self.query_events_inner(sql, params).await
+    }
+
+    /// Execute a query within the transaction and send its rows into a
+    /// bounded channel.
+    ///
+    /// See [`Client<Ready>::query_to_channel`] for semantics; the only
+    /// difference is that the query runs inside the open transaction.
+    pub async fn query_to_channel(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        tx: tokio::sync::mpsc::Sender<Result<crate::Row>>,
+    ) -> Result<u64> {
+        self.query_to_channel_inner(sql, params, tx).await
+    }
+
+    /// Execute a query within the transaction and write each serialized row
+    /// to an async writer.
+    ///
+    /// See [`Client<Ready>::query_stream_into`] for semantics; the only
+    /// difference is that the query runs inside the open transaction.
+    pub async fn query_stream_into<W, F>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        writer: &mut W,
+        serialize: F,
+    ) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+        F: FnMut(&crate::Row) -> bytes::Bytes,
+    {
+        self.query_stream_inner(sql, params)
+            .await?
+            .write_into(writer, serialize)
+            .await
+    }
+
     /// Stream a row's trailing MAX column from the network within the
     /// transaction.
     ///
@@ -2346,25 +4006,108 @@ impl Client<InTransaction> {
         self.query_stream_rows_inner(sql, params).await
     }
 
+    /// Read a streamed MAX value within the transaction.
+    ///
+    /// See [`Client<Ready>::read_blob`] for semantics and constraints; the
+    /// only difference is that the query runs inside the open transaction.
+    pub async fn read_blob<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<crate::blob_stream::BlobStreamReader<'a, InTransaction>> {
+        self.read_blob_inner(sql, params).await
+    }
+
+    /// Execute a statement within the transaction, streaming a
+    /// `VARBINARY(MAX)` parameter from an async reader.
+    ///
+    /// See [`Client<Ready>::write_blob`] for semantics; the only difference
+    /// is that the statement runs inside the open transaction.
+    pub async fn write_blob<R>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        blob: &mut R,
+        chunk_size: usize,
+    ) -> Result<u64>
+    where
+        R: tokio::io::AsyncRead + Unpin + ?Sized,
+    {
+        self.write_blob_inner(sql, params, blob, chunk_size).await
+    }
+
     /// Execute a statement within the transaction.
     ///
-    /// Returns the number of affected rows.
+    /// Returns the number of affected rows (`0` when the server reported no
+    /// count; use [`execute_detailed`](Self::execute_detailed) to tell that
+    /// apart from a genuine zero-row match).
     pub async fn execute(
         &mut self,
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
     ) -> Result<u64> {
+        let deadline = self.command_deadline();
+        self.execute_inner(sql, params, deadline)
+            .await
+            .map(|r| r.rows_affected.unwrap_or(0))
+    }
+
+    /// Execute a statement and return the detailed [`ExecuteResult`].
+    ///
+    /// Unlike [`execute`](Self::execute), the row count distinguishes a
+    /// statement that matched no rows (`Some(0)`) from one for which the
+    /// server never reported a count (`None`), e.g. DDL, a statement skipped
+    /// by control flow, or a batch run under `SET NOCOUNT ON`. Useful for
+    /// idempotency checks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(client: &mut mssql_client::Client<mssql_client::InTransaction>) -> Result<(), mssql_client::Error> {
+    /// let result = client
+    ///     .execute_detailed("UPDATE jobs SET done = 1 WHERE id = @p1", &[&7i32])
+    ///     .await?;
+    /// match result.rows_affected {
+    ///     Some(0) => println!("no job matched"),
+    ///     Some(n) => println!("{n} job(s) updated"),
+    ///     None => println!("no row count reported"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_detailed(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<ExecuteResult> {
         let deadline = self.command_deadline();
         self.execute_inner(sql, params, deadline).await
     }
 
+    /// Execute several statements in order within the transaction.
+    ///
+    /// See [`Client<Ready>::execute_batch`] for semantics. A failed statement
+    /// may doom the transaction (e.g. under `SET XACT_ABORT ON`), in which
+    /// case the statements after it fail too.
+    pub async fn execute_batch(
+        &mut self,
+        statements: &[&str],
+        policy: crate::BatchErrorPolicy,
+    ) -> Vec<Result<u64>> {
+        self.execute_batch_inner(statements, policy, async |client, sql| {
+            client.execute(sql, &[]).await
+        })
+        .await
+    }
+
     /// Shared execute implementation with an explicit command deadline.
     async fn execute_inner(
         &mut self,
         sql: &str,
         params: &[&(dyn crate::ToSql + Sync)],
         deadline: Option<std::time::Duration>,
-    ) -> Result<u64> {
+    ) -> Result<ExecuteResult> {
+        self.check_read_only(sql)?;
         tracing::debug!(
             sql = sql,
             params_count = params.len(),
@@ -2393,7 +4136,7 @@ impl Client<InTransaction> {
                 }
 
                 // Read response and get row count
-                self.read_execute_result().await
+                self.read_execute_detailed().await
             },
             deadline,
             canceller,
@@ -2402,7 +4145,7 @@ impl Client<InTransaction> {
 
         #[cfg(feature = "otel")]
         match &result {
-            Ok(rows) => InstrumentationContext::record_success(&mut span, Some(*rows)),
+            Ok(r) => InstrumentationContext::record_success(&mut span, r.rows_affected),
             Err(e) => InstrumentationContext::record_error(&mut span, e),
         }
         #[cfg(feature = "otel")]
@@ -2415,6 +4158,82 @@ impl Client<InTransaction> {
         result
     }
 
+    /// Execute a query within the transaction and map every row to `R`.
+    ///
+    /// See [`Client<Ready>::query_typed`] for semantics.
+    pub async fn query_typed<R: crate::FromRow>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<Vec<R>> {
+        self.query_typed_stream_inner(sql, params).await?.collect()
+    }
+
+    /// Execute a query within the transaction and return an iterator that
+    /// maps each row to `R`.
+    ///
+    /// See [`Client<Ready>::query_typed_stream`] for semantics.
+    pub async fn query_typed_stream<R: crate::FromRow>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<crate::MapRows<QueryStream<'static>, R>> {
+        self.query_typed_stream_inner(sql, params).await
+    }
+
+    /// Call a table-valued function within the transaction.
+    ///
+    /// See [`Client<Ready>::query_tvf`] for details.
+    pub async fn query_tvf<R: crate::FromRow>(
+        &mut self,
+        function: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<Vec<R>> {
+        self.query_tvf_inner(function, params).await
+    }
+
+    /// Execute a query within the transaction and map its first row to `R`,
+    /// or return `default` if it returns no rows.
+    ///
+    /// See [`Client<Ready>::query_one_or`] for semantics.
+    pub async fn query_one_or<R: crate::FromRow>(
+        &mut self,
+        default: R,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<R> {
+        self.query_one_or_else_inner(|| default, sql, params).await
+    }
+
+    /// Execute a query within the transaction and map its first row to `R`,
+    /// or call `default` if it returns no rows.
+    ///
+    /// See [`Client<Ready>::query_one_or_else`] for semantics.
+    pub async fn query_one_or_else<R, F>(
+        &mut self,
+        default: F,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<R>
+    where
+        R: crate::FromRow,
+        F: FnOnce() -> R,
+    {
+        self.query_one_or_else_inner(default, sql, params).await
+    }
+
+    /// Execute a statement with an `OUTPUT` clause within the transaction,
+    /// returning the `OUTPUT` rows and the number of rows affected.
+    ///
+    /// See [`Client<Ready>::execute_returning`] for semantics.
+    pub async fn execute_returning<R: crate::FromRow>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<(Vec<R>, u64)> {
+        self.execute_returning_inner(sql, params).await
+    }
+
     /// Execute a query within the transaction with a specific timeout.
     ///
     /// See [`Client<Ready>::query_with_timeout`] for details.
@@ -2438,6 +4257,39 @@ impl Client<InTransaction> {
     ) -> Result<u64> {
         self.execute_inner(sql, params, Some(timeout_duration))
             .await
+            .map(|r| r.rows_affected.unwrap_or(0))
+    }
+
+    /// Execute a query within the transaction that is abandoned when `token`
+    /// is cancelled.
+    ///
+    /// See [`Client<Ready>::query_with_cancellation`] for details.
+    pub async fn query_with_cancellation<'a>(
+        &'a mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        token: &tokio_util::sync::CancellationToken,
+    ) -> Result<QueryStream<'a>> {
+        let canceller = self.connection_cancel_handle();
+        let deadline = self.command_deadline();
+        run_until_cancelled(self.query_inner(sql, params, deadline), token, canceller).await
+    }
+
+    /// Execute a statement within the transaction that is abandoned when
+    /// `token` is cancelled.
+    ///
+    /// See [`Client<Ready>::query_with_cancellation`] for details.
+    pub async fn execute_with_cancellation(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        token: &tokio_util::sync::CancellationToken,
+    ) -> Result<u64> {
+        let canceller = self.connection_cancel_handle();
+        let deadline = self.command_deadline();
+        run_until_cancelled(self.execute_inner(sql, params, deadline), token, canceller)
+            .await
+            .map(|r| r.rows_affected.unwrap_or(0))
     }
 
     /// Open a FILESTREAM BLOB for async reading and/or writing.
@@ -2540,22 +4392,7 @@ impl Client<InTransaction> {
 
         result?;
 
-        Ok(Client {
-            config: self.config,
-            _state: PhantomData,
-            connection: self.connection,
-            server_version: self.server_version,
-            current_database: self.current_database,
-            server_collation: self.server_collation,
-            statement_cache: self.statement_cache,
-            transaction_descriptor: 0, // Reset to auto-commit mode
-            needs_reset: self.needs_reset,
-            in_flight: self.in_flight,
-            #[cfg(feature = "otel")]
-            instrumentation: self.instrumentation,
-            #[cfg(feature = "always-encrypted")]
-            encryption_context: self.encryption_context,
-        })
+        Ok(self.into_state(0)) // Reset to auto-commit mode
     }
 
     /// Rollback the transaction.
@@ -2588,22 +4425,7 @@ impl Client<InTransaction> {
 
         result?;
 
-        Ok(Client {
-            config: self.config,
-            _state: PhantomData,
-            connection: self.connection,
-            server_version: self.server_version,
-            current_database: self.current_database,
-            server_collation: self.server_collation,
-            statement_cache: self.statement_cache,
-            transaction_descriptor: 0, // Reset to auto-commit mode
-            needs_reset: self.needs_reset,
-            in_flight: self.in_flight,
-            #[cfg(feature = "otel")]
-            instrumentation: self.instrumentation,
-            #[cfg(feature = "always-encrypted")]
-            encryption_context: self.encryption_context,
-        })
+        Ok(self.into_state(0)) // Reset to auto-commit mode
     }
 
     /// Create a savepoint and return a handle for later rollback.
@@ -2697,10 +4519,36 @@ impl<S: ConnectionState> std::fmt::Debug for Client<S> {
             .field("host", &self.config.host)
             .field("port", &self.config.port)
             .field("database", &self.config.database)
+            .field("spid", &self.spid)
             .finish()
     }
 }
 
+/// Dropping a client that still holds a connection closes its socket, so the
+/// server sees the disconnect and ends the session (rolling back any open
+/// transaction). Nothing is flushed and no in-flight request is cancelled:
+/// `Drop` cannot await. Debug builds log a warning for clients not owned by a
+/// pool; call [`close()`](Client::close) to disconnect gracefully.
+impl<S: ConnectionState> Drop for Client<S> {
+    fn drop(&mut self) {
+        let Some(connection) = self.connection.take() else {
+            return;
+        };
+        #[cfg(debug_assertions)]
+        if !self.pooled {
+            tracing::warn!(
+                host = %self.config.host,
+                port = self.config.port,
+                spid = ?self.spid,
+                in_transaction = self.transaction_descriptor != 0,
+                "Client dropped without close(); call close() or use a connection pool"
+            );
+        }
+        // Dropping the stream closes the socket synchronously.
+        drop(connection);
+    }
+}
+
 #[cfg(test)]
 mod blob_result_set_validation_tests {
     use tds_protocol::token::{ColMetaData, ColumnData, TypeInfo};
@@ -0,0 +1,138 @@
+//! Streaming a `VARBINARY(MAX)` parameter from an [`AsyncRead`] source.
+//!
+//! The request is an ordinary `sp_executesql` RPC whose last parameter is a
+//! PLP value of unknown length. Everything up to that value is encoded as
+//! usual; the value itself is read from the source one chunk at a time and
+//! framed into packets as it goes, so only one packet and one chunk are held
+//! in memory however large the value is.
+
+use bytes::{BufMut, BytesMut};
+use tds_protocol::packet::PacketType;
+use tds_protocol::rpc::{RpcParam, RpcRequest, TypeInfo as RpcTypeInfo};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::error::{Error, Result};
+use crate::state::ConnectionState;
+
+use super::{Client, StreamedMessage};
+
+/// PLP length marker for a value whose total length is not sent up front.
+const PLP_UNKNOWN_LEN: u64 = 0xFFFF_FFFF_FFFF_FFFE;
+
+/// Encoding of a NULL PLP value, which ends the placeholder parameter.
+const PLP_NULL: [u8; 8] = [0xFF; 8];
+
+impl<S: ConnectionState> Client<S> {
+    /// Shared implementation behind `write_blob` for both `Ready` and
+    /// `InTransaction`.
+    pub(crate) async fn write_blob_inner<R>(
+        &mut self,
+        sql: &str,
+        params: &[&(dyn crate::ToSql + Sync)],
+        blob: &mut R,
+        chunk_size: usize,
+    ) -> Result<u64>
+    where
+        R: AsyncRead + Unpin + ?Sized,
+    {
+        self.check_read_only(sql)?;
+        #[cfg(feature = "always-encrypted")]
+        if self.encryption_context.is_some() {
+            return Err(Error::Config(
+                "write_blob does not support Always Encrypted connections".to_string(),
+            ));
+        }
+        if chunk_size == 0 || chunk_size > u32::MAX as usize {
+            return Err(Error::Config(format!(
+                "invalid blob chunk size: {chunk_size}"
+            )));
+        }
+
+        // Drain an abandoned streamed response before issuing this request.
+        self.cancel_in_flight_response().await?;
+        if self.needs_reset && self.config.xact_abort {
+            self.reset_with_session_options().await?;
+        }
+
+        // Encode the RPC with a NULL VARBINARY(MAX) placeholder as its last
+        // parameter, then swap the placeholder's PLP NULL for the header of
+        // an unknown-length value whose chunks follow.
+        let (sql, mut rpc_params) = self.convert_query_params(sql, params)?;
+        rpc_params.push(RpcParam::null(
+            format!("@p{}", params.len() + 1),
+            RpcTypeInfo::varbinary_max(),
+        ));
+        let rpc = RpcRequest::execute_sql(&sql, rpc_params);
+        let mut head =
+            BytesMut::from(&rpc.encode_with_transaction(self.transaction_descriptor)[..]);
+        if !head.ends_with(&PLP_NULL) {
+            return Err(Error::Protocol(
+                "blob placeholder is not the last RPC parameter".to_string(),
+            ));
+        }
+        head.truncate(head.len() - PLP_NULL.len());
+        head.put_u64_le(PLP_UNKNOWN_LEN);
+
+        let reset = self.needs_reset;
+        if reset {
+            self.needs_reset = false;
+            let _ = self.statement_cache.clear();
+            tracing::debug!("sending blob RPC with RESETCONNECTION flag");
+        }
+        self.in_flight = true;
+        self.first_query_retry = false;
+
+        let mut message = StreamedMessage::new(PacketType::Rpc, self.packet_size as usize, reset);
+        message.extend(self, &head).await?;
+
+        let mut chunk = vec![0u8; chunk_size];
+        let mut total = 0u64;
+        loop {
+            let filled = match read_full(blob, &mut chunk).await {
+                Ok(filled) => filled,
+                Err(e) => {
+                    // The server has only seen part of the request. Tell it to
+                    // discard the message; it sends no response to an ignored
+                    // message, so the connection is immediately reusable. The
+                    // discarded message takes its RESETCONNECTION with it, so
+                    // the reset is still owed to the next request.
+                    self.needs_reset = reset;
+                    message.abandon(self).await?;
+                    self.in_flight = false;
+                    return Err(Error::from(e));
+                }
+            };
+            if filled == 0 {
+                break;
+            }
+            message.extend(self, &(filled as u32).to_le_bytes()).await?;
+            message.extend(self, &chunk[..filled]).await?;
+            total += filled as u64;
+        }
+        // PLP terminator.
+        message.extend(self, &0u32.to_le_bytes()).await?;
+        message.finish(self).await?;
+        tracing::debug!(bytes = total, "blob parameter sent");
+
+        self.read_execute_detailed()
+            .await
+            .map(|r| r.rows_affected.unwrap_or(0))
+    }
+}
+
+/// Read until `buf` is full or the source is exhausted, returning the number
+/// of bytes read.
+async fn read_full<R>(reader: &mut R, buf: &mut [u8]) -> std::io::Result<usize>
+where
+    R: AsyncRead + Unpin + ?Sized,
+{
+    let mut filled = 0;
+    while filled < buf.len() {
+        let n = reader.read(&mut buf[filled..]).await?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    Ok(filled)
+}
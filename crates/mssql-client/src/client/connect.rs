@@ -10,7 +10,7 @@ use bytes::BytesMut;
 use mssql_codec::connection::Connection;
 #[cfg(feature = "tls")]
 use mssql_tls::{TlsConfig, TlsConnector, TlsNegotiationMode};
-use tds_protocol::login7::Login7;
+use tds_protocol::login7::{FeatureExtension, FeatureId, Login7};
 use tds_protocol::packet::DEFAULT_PACKET_SIZE;
 use tds_protocol::packet::PacketType;
 use tds_protocol::prelogin::{EncryptionLevel, PreLogin};
@@ -20,11 +20,14 @@ use tokio::time::timeout;
 
 use crate::config::Config;
 use crate::error::{Error, Result};
+use crate::features::EnabledFeatures;
 #[cfg(feature = "otel")]
 use crate::instrumentation::InstrumentationContext;
+use crate::resolver::{CONNECTION_ATTEMPT_DELAY, Resolver, SystemResolver, connect_happy_eyeballs};
 use crate::state::{Disconnected, Ready};
 use crate::statement_cache::StatementCache;
 
+use super::response::{collect_server_error, server_info_message};
 use super::{Client, ConnectionHandle};
 
 /// Federated authentication parameters for a single LOGIN7 attempt.
@@ -37,6 +40,26 @@ struct FedAuthLogin<'a> {
     echo: bool,
 }
 
+/// What the server reported in its response to LOGIN7.
+struct LoginResponse {
+    /// TDS version from LoginAck.
+    server_version: Option<u32>,
+    /// Database from the Database EnvChange.
+    database: Option<String>,
+    /// Redirect target from a Routing EnvChange.
+    routing: Option<(String, u16)>,
+    /// Server default collation from the SqlCollation EnvChange.
+    collation: Option<tds_protocol::token::Collation>,
+    /// Packet size from the PacketSize EnvChange.
+    packet_size: Option<u16>,
+    /// Feature extensions the server acknowledged.
+    enabled_features: EnabledFeatures,
+    /// Server process ID from the response packet headers.
+    spid: Option<u16>,
+    /// Program name and version from LoginAck, formatted for display.
+    server_program: Option<String>,
+}
+
 impl Client<Disconnected> {
     /// Connect to SQL Server.
     ///
@@ -55,6 +78,8 @@ impl Client<Disconnected> {
     /// ```
     pub async fn connect(config: Config) -> Result<Client<Ready>> {
         Self::validate_credential_support(&config)?;
+        #[cfg(feature = "always-encrypted")]
+        Self::validate_column_encryption_support(&config)?;
 
         // Azure AD / Entra credentials use the FEDAUTH SecurityToken workflow
         // (MS-TDS §2.2.6.4): the access token is acquired client-side before
@@ -98,7 +123,21 @@ impl Client<Disconnected> {
                         break Err(Error::TooManyRedirects { max: max_redirects });
                     }
 
-                    match Self::try_connect(&current_config, fed_auth_token.as_deref()).await {
+                    // `login_timeout` bounds the whole attempt. A server that
+                    // accepts the socket but never answers PreLogin or Login7
+                    // would otherwise hang until the overall deadline.
+                    let attempt = timeout(
+                        current_config.timeouts.login_timeout,
+                        Self::try_connect(&current_config, fed_auth_token.as_deref()),
+                    )
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(Error::LoginTimeout {
+                            host: current_config.host.clone(),
+                            port: current_config.port,
+                        })
+                    });
+                    match attempt {
                         Ok(client) => break Ok(client),
                         Err(Error::Routing { host, port }) => {
                             if !follow_redirects {
@@ -119,7 +158,10 @@ impl Client<Disconnected> {
                 };
 
                 match attempt_result {
-                    Ok(client) => return Ok(client),
+                    Ok(mut client) => {
+                        client.apply_session_options().await?;
+                        return Ok(client);
+                    }
                     Err(ref e) if e.is_transient() && retry.should_retry(retry_attempt) => {
                         tracing::warn!(
                             retry_attempt,
@@ -147,6 +189,22 @@ impl Client<Disconnected> {
         }
     }
 
+    /// Always Encrypted is negotiated in the LOGIN7 FeatureExt block, which
+    /// TDS versions before 7.4 do not have.
+    #[cfg(feature = "always-encrypted")]
+    fn validate_column_encryption_support(config: &Config) -> Result<()> {
+        if config.column_encryption.is_some()
+            && !config.strict_mode
+            && !config.tds_version.supports_column_encryption()
+        {
+            return Err(Error::Config(format!(
+                "Always Encrypted requires TDS 7.4 or later (configured: {})",
+                config.tds_version
+            )));
+        }
+        Ok(())
+    }
+
     /// Validate that the configured credentials can complete a login.
     ///
     /// Fails fast with an actionable error instead of sending a login the
@@ -364,21 +422,7 @@ impl Client<Disconnected> {
         };
 
         // Step 1: Establish TCP connection
-        let tcp_stream = if config.multi_subnet_failover {
-            Self::connect_parallel(host, port, config.timeouts.connect_timeout).await?
-        } else {
-            let addr = format!("{host}:{port}");
-            tracing::debug!("establishing TCP connection to {}", addr);
-            let stream = timeout(config.timeouts.connect_timeout, TcpStream::connect(&addr))
-                .await
-                .map_err(|_| Error::ConnectTimeout {
-                    host: config.host.clone(),
-                    port: config.port,
-                })?
-                .map_err(Error::from)?;
-            stream.set_nodelay(true).map_err(Error::from)?;
-            stream
-        };
+        let tcp_stream = Self::connect_tcp(config, host, port).await?;
 
         #[cfg(feature = "tls")]
         {
@@ -420,42 +464,58 @@ impl Client<Disconnected> {
         }
     }
 
-    /// Resolve hostname to all IPs and race parallel TCP connections.
+    /// Resolve the host and establish the TCP connection.
     ///
-    /// Used when `MultiSubnetFailover=True` for AlwaysOn AG listeners that
-    /// span multiple subnets. First successful TCP connection wins.
-    async fn connect_parallel(
-        host: &str,
-        port: u16,
-        connect_timeout: std::time::Duration,
-    ) -> Result<TcpStream> {
-        let addr_str = format!("{host}:{port}");
-        let addrs: Vec<SocketAddr> = tokio::net::lookup_host(&addr_str)
-            .await
-            .map_err(Error::from)?
-            .collect();
+    /// Resolution goes through [`Config::resolver`] (the system resolver by
+    /// default). With `MultiSubnetFailover=True` every resolved address is
+    /// raced at once; otherwise addresses are tried Happy Eyeballs style
+    /// (IPv6 first, staggered attempts). Resolution and connection together
+    /// are bounded by the connect timeout.
+    async fn connect_tcp(config: &Config, host: &str, port: u16) -> Result<TcpStream> {
+        let connect = async {
+            let addrs = match config.resolver {
+                Some(ref resolver) => resolver.resolve(host, port).await,
+                None => SystemResolver.resolve(host, port).await,
+            }
+            .map_err(Error::from)?;
 
-        if addrs.is_empty() {
-            return Err(Error::from(std::io::Error::new(
-                std::io::ErrorKind::AddrNotAvailable,
-                format!("no addresses resolved for {host}:{port}"),
-            )));
-        }
+            if addrs.is_empty() {
+                return Err(Error::from(std::io::Error::new(
+                    std::io::ErrorKind::AddrNotAvailable,
+                    format!("no addresses resolved for {host}:{port}"),
+                )));
+            }
 
-        // Single address — no need to spawn tasks
-        if addrs.len() == 1 {
-            tracing::debug!(addr = %addrs[0], "MultiSubnetFailover: single address resolved");
-            let stream = timeout(connect_timeout, TcpStream::connect(addrs[0]))
-                .await
-                .map_err(|_| Error::ConnectTimeout {
-                    host: host.to_string(),
-                    port,
-                })?
-                .map_err(Error::from)?;
-            stream.set_nodelay(true).map_err(Error::from)?;
-            return Ok(stream);
-        }
+            tracing::debug!(
+                host = host,
+                port = port,
+                resolved_count = addrs.len(),
+                multi_subnet_failover = config.multi_subnet_failover,
+                "establishing TCP connection",
+            );
+
+            if config.multi_subnet_failover {
+                Self::connect_parallel(addrs, host, port).await
+            } else {
+                connect_happy_eyeballs(addrs, CONNECTION_ATTEMPT_DELAY)
+                    .await
+                    .map_err(Error::from)
+            }
+        };
 
+        timeout(config.timeouts.connect_timeout, connect)
+            .await
+            .map_err(|_| Error::ConnectTimeout {
+                host: config.host.clone(),
+                port: config.port,
+            })?
+    }
+
+    /// Race parallel TCP connections to all resolved IPs.
+    ///
+    /// Used when `MultiSubnetFailover=True` for AlwaysOn AG listeners that
+    /// span multiple subnets. First successful TCP connection wins.
+    async fn connect_parallel(addrs: Vec<SocketAddr>, host: &str, port: u16) -> Result<TcpStream> {
         let addr_count = addrs.len();
         tracing::debug!(
             host = host,
@@ -467,14 +527,8 @@ impl Client<Disconnected> {
         let mut join_set = tokio::task::JoinSet::new();
 
         for addr in addrs {
-            let dur = connect_timeout;
             join_set.spawn(async move {
-                let tcp = timeout(dur, TcpStream::connect(addr)).await.map_err(|_| {
-                    std::io::Error::new(
-                        std::io::ErrorKind::TimedOut,
-                        format!("connection to {addr} timed out"),
-                    )
-                })??;
+                let tcp = TcpStream::connect(addr).await?;
                 tcp.set_nodelay(true)?;
                 Ok::<(TcpStream, SocketAddr), std::io::Error>((tcp, addr))
             });
@@ -568,10 +622,20 @@ impl Client<Disconnected> {
         Self::send_login7(&mut connection, &login).await?;
 
         // Process login response (with timeout to prevent hangs during redirect)
-        let (server_version, current_database, routing, server_collation) = timeout(
+        let LoginResponse {
+            server_version,
+            database: current_database,
+            routing,
+            collation: server_collation,
+            packet_size,
+            enabled_features,
+            spid,
+            server_program,
+        } = timeout(
             config.timeouts.login_timeout,
             Self::process_login_response(
                 &mut connection,
+                &login.features,
                 #[cfg(any(feature = "integrated-auth", feature = "sspi-auth"))]
                 negotiator.as_deref(),
             ),
@@ -592,15 +656,23 @@ impl Client<Disconnected> {
             _state: PhantomData,
             connection: Some(ConnectionHandle::Tls(connection)),
             server_version,
+            server_program,
+            spid,
             current_database: current_database.clone(),
             server_collation,
-            statement_cache: StatementCache::with_default_size(),
+            packet_size: packet_size.unwrap_or(config.packet_size),
+            enabled_features,
+            product_version: None,
+            statement_cache: StatementCache::new(config.statement_cache_size.max(1)),
             transaction_descriptor: 0, // Auto-commit mode initially
             needs_reset: false,        // Fresh connection, no reset needed
-            in_flight: false,          // No request pending
+            pooled: false,
+            first_query_retry: config.retry_first_query,
+            in_flight: false, // No request pending
             #[cfg(feature = "otel")]
             instrumentation: InstrumentationContext::new(config.host.clone(), config.port)
-                .with_database(current_database.clone().unwrap_or_default()),
+                .with_database(current_database.clone().unwrap_or_default())
+                .with_session_id(spid),
             #[cfg(feature = "always-encrypted")]
             encryption_context: config.column_encryption.clone().map(|cfg| {
                 std::sync::Arc::new(crate::encryption::EncryptionContext::from_arc(cfg))
@@ -858,10 +930,20 @@ impl Client<Disconnected> {
                 connection.set_max_message_size(config.max_response_size);
 
                 // Process login response (comes in plaintext, with timeout)
-                let (server_version, current_database, routing, server_collation) = timeout(
+                let LoginResponse {
+                    server_version,
+                    database: current_database,
+                    routing,
+                    collation: server_collation,
+                    packet_size,
+                    enabled_features,
+                    spid,
+                    server_program,
+                } = timeout(
                     config.timeouts.login_timeout,
                     Self::process_login_response(
                         &mut connection,
+                        &login.features,
                         #[cfg(any(feature = "integrated-auth", feature = "sspi-auth"))]
                         negotiator.as_deref(),
                     ),
@@ -883,15 +965,23 @@ impl Client<Disconnected> {
                     _state: PhantomData,
                     connection: Some(ConnectionHandle::Plain(connection)),
                     server_version,
+                    server_program,
+                    spid,
                     current_database: current_database.clone(),
                     server_collation,
-                    statement_cache: StatementCache::with_default_size(),
+                    packet_size: packet_size.unwrap_or(config.packet_size),
+                    enabled_features,
+                    product_version: None,
+                    statement_cache: StatementCache::new(config.statement_cache_size.max(1)),
                     transaction_descriptor: 0, // Auto-commit mode initially
                     needs_reset: false,        // Fresh connection, no reset needed
-                    in_flight: false,          // No request pending
+                    pooled: false,
+                    first_query_retry: config.retry_first_query,
+                    in_flight: false, // No request pending
                     #[cfg(feature = "otel")]
                     instrumentation: InstrumentationContext::new(config.host.clone(), config.port)
-                        .with_database(current_database.clone().unwrap_or_default()),
+                        .with_database(current_database.clone().unwrap_or_default())
+                        .with_session_id(spid),
                     #[cfg(feature = "always-encrypted")]
                     encryption_context: config.column_encryption.clone().map(|cfg| {
                         std::sync::Arc::new(crate::encryption::EncryptionContext::from_arc(cfg))
@@ -919,10 +1009,20 @@ impl Client<Disconnected> {
                 Self::send_login7(&mut connection, &login).await?;
 
                 // Process login response (with timeout)
-                let (server_version, current_database, routing, server_collation) = timeout(
+                let LoginResponse {
+                    server_version,
+                    database: current_database,
+                    routing,
+                    collation: server_collation,
+                    packet_size,
+                    enabled_features,
+                    spid,
+                    server_program,
+                } = timeout(
                     config.timeouts.login_timeout,
                     Self::process_login_response(
                         &mut connection,
+                        &login.features,
                         #[cfg(any(feature = "integrated-auth", feature = "sspi-auth"))]
                         negotiator.as_deref(),
                     ),
@@ -943,15 +1043,23 @@ impl Client<Disconnected> {
                     _state: PhantomData,
                     connection: Some(ConnectionHandle::TlsPrelogin(connection)),
                     server_version,
+                    server_program,
+                    spid,
                     current_database: current_database.clone(),
                     server_collation,
-                    statement_cache: StatementCache::with_default_size(),
+                    packet_size: packet_size.unwrap_or(config.packet_size),
+                    enabled_features,
+                    product_version: None,
+                    statement_cache: StatementCache::new(config.statement_cache_size.max(1)),
                     transaction_descriptor: 0, // Auto-commit mode initially
                     needs_reset: false,        // Fresh connection, no reset needed
-                    in_flight: false,          // No request pending
+                    pooled: false,
+                    first_query_retry: config.retry_first_query,
+                    in_flight: false, // No request pending
                     #[cfg(feature = "otel")]
                     instrumentation: InstrumentationContext::new(config.host.clone(), config.port)
-                        .with_database(current_database.clone().unwrap_or_default()),
+                        .with_database(current_database.clone().unwrap_or_default())
+                        .with_session_id(spid),
                     #[cfg(feature = "always-encrypted")]
                     encryption_context: config.column_encryption.clone().map(|cfg| {
                         std::sync::Arc::new(crate::encryption::EncryptionContext::from_arc(cfg))
@@ -987,10 +1095,20 @@ impl Client<Disconnected> {
             Self::send_login7(&mut connection, &login).await?;
 
             // Process login response (with timeout)
-            let (server_version, current_database, routing, server_collation) = timeout(
+            let LoginResponse {
+                server_version,
+                database: current_database,
+                routing,
+                collation: server_collation,
+                packet_size,
+                enabled_features,
+                spid,
+                server_program,
+            } = timeout(
                 config.timeouts.login_timeout,
                 Self::process_login_response(
                     &mut connection,
+                    &login.features,
                     #[cfg(any(feature = "integrated-auth", feature = "sspi-auth"))]
                     negotiator.as_deref(),
                 ),
@@ -1011,15 +1129,23 @@ impl Client<Disconnected> {
                 _state: PhantomData,
                 connection: Some(ConnectionHandle::Plain(connection)),
                 server_version,
+                server_program,
+                spid,
                 current_database: current_database.clone(),
                 server_collation,
-                statement_cache: StatementCache::with_default_size(),
+                packet_size: packet_size.unwrap_or(config.packet_size),
+                enabled_features,
+                product_version: None,
+                statement_cache: StatementCache::new(config.statement_cache_size.max(1)),
                 transaction_descriptor: 0, // Auto-commit mode initially
                 needs_reset: false,        // Fresh connection, no reset needed
-                in_flight: false,          // No request pending
+                pooled: false,
+                first_query_retry: config.retry_first_query,
+                in_flight: false, // No request pending
                 #[cfg(feature = "otel")]
                 instrumentation: InstrumentationContext::new(config.host.clone(), config.port)
-                    .with_database(current_database.clone().unwrap_or_default()),
+                    .with_database(current_database.clone().unwrap_or_default())
+                    .with_session_id(spid),
                 #[cfg(feature = "always-encrypted")]
                 encryption_context: config.column_encryption.clone().map(|cfg| {
                     std::sync::Arc::new(crate::encryption::EncryptionContext::from_arc(cfg))
@@ -1119,10 +1245,20 @@ impl Client<Disconnected> {
         Self::send_login7(&mut connection, &login).await?;
 
         // Process login response (with timeout)
-        let (server_version, current_database, routing, server_collation) = timeout(
+        let LoginResponse {
+            server_version,
+            database: current_database,
+            routing,
+            collation: server_collation,
+            packet_size,
+            enabled_features,
+            spid,
+            server_program,
+        } = timeout(
             config.timeouts.login_timeout,
             Self::process_login_response(
                 &mut connection,
+                &login.features,
                 #[cfg(any(feature = "integrated-auth", feature = "sspi-auth"))]
                 negotiator.as_deref(),
             ),
@@ -1143,15 +1279,23 @@ impl Client<Disconnected> {
             _state: PhantomData,
             connection: Some(ConnectionHandle::Plain(connection)),
             server_version,
+            server_program,
+            spid,
             current_database: current_database.clone(),
             server_collation,
-            statement_cache: StatementCache::with_default_size(),
+            packet_size: packet_size.unwrap_or(config.packet_size),
+            enabled_features,
+            product_version: None,
+            statement_cache: StatementCache::new(config.statement_cache_size.max(1)),
             transaction_descriptor: 0,
             needs_reset: false,
+            pooled: false,
+            first_query_retry: config.retry_first_query,
             in_flight: false,
             #[cfg(feature = "otel")]
             instrumentation: InstrumentationContext::new(config.host.clone(), config.port)
-                .with_database(current_database.clone().unwrap_or_default()),
+                .with_database(current_database.clone().unwrap_or_default())
+                .with_session_id(spid),
             #[cfg(feature = "always-encrypted")]
             encryption_context: config.column_encryption.clone().map(|cfg| {
                 std::sync::Arc::new(crate::encryption::EncryptionContext::from_arc(cfg))
@@ -1172,8 +1316,14 @@ impl Client<Disconnected> {
             .with_version(version)
             .with_encryption(encryption);
 
+        // MARS is never advertised: once the server agrees to it, every later
+        // packet must carry an SMP header, and this driver has no SMP layer
+        // (ARCHITECTURE.md ADR-006).
         if config.mars {
-            prelogin = prelogin.with_mars(true);
+            tracing::warn!(
+                "MultipleActiveResultSets is not supported; connecting without MARS. \
+                 Use the connection pool for concurrent queries."
+            );
         }
 
         if let Some(ref instance) = config.instance {
@@ -1215,7 +1365,10 @@ impl Client<Disconnected> {
     /// When `fed_auth` is provided (Azure AD / Entra), the packet carries the
     /// FEDAUTH feature extension (SecurityToken workflow) and no username or
     /// password — per MS-TDS §2.2.6.4, `fIntSecurity` must be 0 and the
-    /// credential fields stay empty.
+    /// credential fields stay empty. The token is already in hand, so there is
+    /// no FEDAUTHINFO round trip or FEDAUTHTOKEN message: those belong to the
+    /// ADAL workflow, where the server names the STS and SPN for the client
+    /// to acquire a token from.
     fn build_login7(
         config: &Config,
         sspi_token: Option<Vec<u8>>,
@@ -1270,6 +1423,15 @@ impl Client<Disconnected> {
             login = login.with_sql_auth(username.as_ref(), password.as_ref());
         }
 
+        // Advertise UTF-8 support (empty payload) so SQL Server 2019+ sends
+        // `_UTF8` collation data as-is instead of transcoding it to a code page.
+        if version.supports_utf8() {
+            login = login.with_feature(tds_protocol::login7::FeatureExtension {
+                feature_id: tds_protocol::login7::FeatureId::Utf8Support,
+                data: bytes::Bytes::new(),
+            });
+        }
+
         // When Always Encrypted is configured, add the ColumnEncryption feature extension.
         // Version 1 = client supports column encryption without enclave computations.
         #[cfg(feature = "always-encrypted")]
@@ -1281,6 +1443,11 @@ impl Client<Disconnected> {
             tracing::debug!("Login7: adding ColumnEncryption feature extension (version 1)");
         }
 
+        if let Some(ref customizer) = config.login7_customizer {
+            customizer.apply(&mut login);
+            tracing::debug!("Login7: applied user customizer");
+        }
+
         login
     }
 
@@ -1383,32 +1550,37 @@ impl Client<Disconnected> {
     /// 2. Sending the response via an SSPI packet
     /// 3. Reading the next server message and continuing
     ///
-    /// Returns: (server_version, database, routing_info)
     #[allow(clippy::never_loop)] // Loop is used when integrated-auth/sspi-auth features are enabled
     async fn process_login_response<T>(
         connection: &mut Connection<T>,
+        requested_features: &[FeatureExtension],
         #[cfg(any(feature = "integrated-auth", feature = "sspi-auth"))] negotiator: Option<
             &dyn mssql_auth::SspiNegotiator,
         >,
-    ) -> Result<(
-        Option<u32>,
-        Option<String>,
-        Option<(String, u16)>,
-        Option<tds_protocol::token::Collation>,
-    )>
+    ) -> Result<LoginResponse>
     where
         T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
     {
         let mut server_version = None;
+        let mut server_program = None;
+        let mut spid = None;
         let mut database = None;
         let mut routing = None;
         let mut collation = None;
+        let mut packet_size = None;
+        let mut enabled_features = EnabledFeatures::default();
+        let mut server_messages = Vec::new();
 
         'outer: loop {
             let message = connection
                 .read_message()
                 .await?
                 .ok_or(Error::ConnectionClosed)?;
+            // The server stamps its responses with the session's SPID; zero
+            // means it did not report one.
+            if message.spid != 0 {
+                spid = Some(message.spid);
+            }
 
             let response_bytes = message.payload;
             let mut parser = TokenParser::new(response_bytes);
@@ -1423,9 +1595,16 @@ impl Client<Disconnected> {
                             "login acknowledged"
                         );
                         server_version = Some(ack.tds_version);
+                        server_program = Some(format_server_program(&ack));
                     }
                     Token::EnvChange(env) => {
-                        Self::process_env_change(&env, &mut database, &mut routing, &mut collation);
+                        Self::process_env_change(
+                            &env,
+                            &mut database,
+                            &mut routing,
+                            &mut collation,
+                            &mut packet_size,
+                        );
                     }
                     #[cfg(any(feature = "integrated-auth", feature = "sspi-auth"))]
                     Token::Sspi(sspi_token) => {
@@ -1456,25 +1635,15 @@ impl Client<Disconnected> {
                         continue 'outer;
                     }
                     Token::Error(err) => {
-                        return Err(Error::Server {
-                            number: err.number,
-                            state: err.state,
-                            class: err.class,
-                            message: err.message.clone(),
-                            server: if err.server.is_empty() {
-                                None
-                            } else {
-                                Some(err.server.clone())
-                            },
-                            procedure: if err.procedure.is_empty() {
-                                None
-                            } else {
-                                Some(err.procedure.clone())
-                            },
-                            line: err.line as u32,
-                        });
+                        return Err(classify_login_error(collect_server_error(
+                            &err,
+                            server_messages,
+                            &mut parser,
+                            None,
+                        )));
                     }
                     Token::Info(info) => {
+                        server_messages.push(server_info_message(&info));
                         tracing::info!(
                             number = info.number,
                             message = %info.message,
@@ -1489,10 +1658,14 @@ impl Client<Disconnected> {
                                 "server acknowledged feature extension"
                             );
                         }
+                        enabled_features.extend_from_ack(&ack);
                     }
                     Token::Done(done) => {
                         if done.status.error {
-                            return Err(Error::Protocol("login failed".to_string()));
+                            return Err(Error::Protocol(match server_messages.last() {
+                                Some(last) => format!("login failed: {last}"),
+                                None => "login failed".to_string(),
+                            }));
                         }
                         break 'outer;
                     }
@@ -1504,7 +1677,22 @@ impl Client<Disconnected> {
             break;
         }
 
-        Ok((server_version, database, routing, collation))
+        // A redirected login is completed against the routed server, which
+        // sends its own acknowledgement.
+        if routing.is_none() {
+            check_feature_acks(requested_features, &enabled_features)?;
+        }
+
+        Ok(LoginResponse {
+            server_version,
+            database,
+            routing,
+            collation,
+            packet_size,
+            enabled_features,
+            spid,
+            server_program,
+        })
     }
 
     /// Process an EnvChange token.
@@ -1513,6 +1701,7 @@ impl Client<Disconnected> {
         database: &mut Option<String>,
         routing: &mut Option<(String, u16)>,
         collation: &mut Option<tds_protocol::token::Collation>,
+        packet_size: &mut Option<u16>,
     ) {
         use tds_protocol::token::EnvChangeValue;
 
@@ -1544,6 +1733,22 @@ impl Client<Disconnected> {
                     }
                 }
             }
+            EnvChangeType::PacketSize => {
+                if let EnvChangeValue::String(ref new_value) = env.new_value {
+                    match new_value.parse::<u16>() {
+                        Ok(size) if size as usize > tds_protocol::packet::PACKET_HEADER_SIZE => {
+                            tracing::debug!(packet_size = size, "packet size negotiated");
+                            *packet_size = Some(size);
+                        }
+                        _ => {
+                            tracing::warn!(
+                                value = %new_value,
+                                "ignoring invalid packet size from server"
+                            );
+                        }
+                    }
+                }
+            }
             _ => {
                 if let EnvChangeValue::String(ref new_value) = env.new_value {
                     tracing::debug!(
@@ -1557,6 +1762,91 @@ impl Client<Disconnected> {
     }
 }
 
+/// Format LoginAck's program name and version, e.g.
+/// `Microsoft SQL Server 16.0.4225`.
+///
+/// ProgVersion is four bytes on the wire: major, minor, then the build number
+/// high byte first (MS-TDS §2.2.7.14).
+fn format_server_program(ack: &tds_protocol::token::LoginAck) -> String {
+    let [major, minor, build_hi, build_lo] = ack.prog_version.to_le_bytes();
+    let build = u16::from_be_bytes([build_hi, build_lo]);
+    format!("{} {major}.{minor}.{build}", ack.prog_name)
+}
+
+/// Map a server error received during login to the variant callers act on.
+///
+/// Rejected credentials become [`Error::Authentication`] and an unopenable
+/// database becomes [`Error::DatabaseUnavailable`], so reconnection logic can
+/// tell a bad password from a database that is briefly offline. Other errors
+/// are returned unchanged.
+fn classify_login_error(error: Error) -> Error {
+    let Error::Server {
+        number,
+        class,
+        state,
+        message,
+        server,
+        procedure,
+        line,
+        additional,
+    } = error
+    else {
+        return error;
+    };
+    match number {
+        // Login failed / untrusted domain / account locked out / password
+        // expired / password must be changed. The state tells a DBA why the
+        // login was rejected, so keep it in the message.
+        18456 | 18452 | 18486 | 18487 | 18488 => {
+            Error::Authentication(mssql_auth::AuthError::AuthenticationFailed(format!(
+                "{message} (error {number}, severity {class}, state {state})"
+            )))
+        }
+        // Cannot open database requested by the login / database not
+        // currently available (Azure).
+        4060 | 40613 => Error::DatabaseUnavailable {
+            number,
+            class,
+            state,
+            message,
+            additional,
+        },
+        _ => Error::Server {
+            number,
+            class,
+            state,
+            message,
+            server,
+            procedure,
+            line,
+            additional,
+        },
+    }
+}
+
+/// Fail the login if the server did not acknowledge a feature extension the
+/// connection cannot work without.
+///
+/// Federated authentication and Always Encrypted change how the rest of the
+/// session is encoded, so silently continuing without them would surface
+/// later as confusing authentication or decryption errors. Other requested
+/// features are advisory and may be declined by the server.
+fn check_feature_acks(requested: &[FeatureExtension], acked: &EnabledFeatures) -> Result<()> {
+    for feature in requested {
+        let mandatory = matches!(
+            feature.feature_id,
+            FeatureId::FedAuth | FeatureId::ColumnEncryption
+        );
+        if mandatory && !acked.contains(feature.feature_id) {
+            return Err(Error::Protocol(format!(
+                "server did not acknowledge the requested {:?} feature extension",
+                feature.feature_id
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Build the TLS configuration for an outbound connection.
 ///
 /// Starts from the user's [`Config::tls`] so custom root certificates, client
@@ -1651,6 +1941,116 @@ mod tls_config_tests {
     }
 }
 
+#[cfg(test)]
+mod login_error_tests {
+    use super::*;
+
+    fn server_error(number: i32, message: &str) -> Error {
+        Error::Server {
+            number,
+            class: 14,
+            state: 1,
+            message: message.to_string(),
+            server: None,
+            procedure: None,
+            line: 1,
+            additional: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn login_failed_is_an_authentication_error() {
+        let err = classify_login_error(server_error(18456, "Login failed for user 'sa'."));
+        assert!(err.is_authentication_error(), "got: {err:?}");
+        assert!(err.is_terminal() && !err.is_transient());
+        assert!(err.to_string().contains("Login failed for user 'sa'."));
+        assert!(err.to_string().contains("18456"));
+    }
+
+    #[test]
+    fn cannot_open_database_is_database_unavailable() {
+        let err = classify_login_error(server_error(
+            4060,
+            "Cannot open database \"sales\" requested by the login.",
+        ));
+        assert!(
+            matches!(err, Error::DatabaseUnavailable { number: 4060, .. }),
+            "got: {err:?}"
+        );
+        assert!(err.is_transient());
+        // The server details survive the reclassification.
+        assert!(err.is_server_error(4060));
+        assert_eq!(err.class(), Some(14));
+    }
+
+    #[test]
+    fn other_login_errors_stay_server_errors() {
+        let err = classify_login_error(server_error(17809, "Maximum connections reached."));
+        assert!(err.is_server_error(17809), "got: {err:?}");
+        assert!(matches!(
+            classify_login_error(Error::ConnectionClosed),
+            Error::ConnectionClosed
+        ));
+    }
+}
+
+#[cfg(test)]
+mod feature_ack_tests {
+    use super::*;
+    use bytes::Bytes;
+    use tds_protocol::token::{FeatureAck, FeatureExtAck};
+
+    fn requested(id: FeatureId) -> FeatureExtension {
+        FeatureExtension {
+            feature_id: id,
+            data: Bytes::new(),
+        }
+    }
+
+    fn acked(ids: &[FeatureId]) -> EnabledFeatures {
+        let mut features = EnabledFeatures::default();
+        features.extend_from_ack(&FeatureExtAck {
+            features: ids
+                .iter()
+                .map(|&id| FeatureAck {
+                    feature_id: id as u8,
+                    data: Bytes::new(),
+                })
+                .collect(),
+        });
+        features
+    }
+
+    #[test]
+    fn missing_mandatory_ack_fails_login() {
+        let err = check_feature_acks(&[requested(FeatureId::FedAuth)], &acked(&[]))
+            .expect_err("an unacknowledged FEDAUTH request must fail");
+        assert!(err.to_string().contains("FedAuth"), "got: {err}");
+
+        assert!(
+            check_feature_acks(
+                &[requested(FeatureId::ColumnEncryption)],
+                &acked(&[FeatureId::Utf8Support])
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn acknowledged_or_optional_features_pass() {
+        assert!(
+            check_feature_acks(
+                &[requested(FeatureId::FedAuth)],
+                &acked(&[FeatureId::FedAuth, FeatureId::Utf8Support])
+            )
+            .is_ok()
+        );
+        // Optional features may be declined.
+        assert!(check_feature_acks(&[requested(FeatureId::Utf8Support)], &acked(&[])).is_ok());
+        assert!(check_feature_acks(&[], &acked(&[])).is_ok());
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod login7_config_wiring_tests {
@@ -1674,6 +2074,42 @@ mod login7_config_wiring_tests {
         );
     }
 
+    #[test]
+    fn build_login7_advertises_utf8_support_on_tds_7_4() {
+        let has_utf8 = |login: &Login7| {
+            login
+                .features
+                .iter()
+                .any(|f| f.feature_id == FeatureId::Utf8Support && f.data.is_empty())
+        };
+
+        let login = Client::<Disconnected>::build_login7(&Config::new(), None, None);
+        assert!(
+            has_utf8(&login),
+            "TDS 7.4 logins must request UTF-8 support"
+        );
+
+        let legacy = Config::new().tds_version(tds_protocol::version::TdsVersion::V7_3B);
+        let login = Client::<Disconnected>::build_login7(&legacy, None, None);
+        assert!(
+            login.features.is_empty(),
+            "TDS 7.3 has no FeatureExt block; nothing may be requested"
+        );
+    }
+
+    #[test]
+    fn build_login7_applies_customizer_last() {
+        let config = Config::new()
+            .language("us_english")
+            .login7_customizer(std::sync::Arc::new(|login: &mut Login7| {
+                login.language = "Deutsch".into();
+                login.option_flags2.odbc = true;
+            }));
+        let login = Client::<Disconnected>::build_login7(&config, None, None);
+        assert_eq!(login.language, "Deutsch", "customizer overrides config");
+        assert!(login.option_flags2.odbc);
+    }
+
     #[test]
     fn build_login7_wires_language_field() {
         let login =
@@ -1785,11 +2221,15 @@ mod fed_auth_login_tests {
             "options must be (SecurityToken << 1) | echo, then DWORD-LE \
              token byte length, then UTF-16LE token"
         );
+        // The UTF-8 support request (empty payload) follows, then the
+        // terminator.
+        let next = feature_off + 5 + data_len;
         assert_eq!(
-            encoded[feature_off + 5 + data_len],
-            0xFF,
-            "FeatureExt terminator must follow"
+            &encoded[next..next + 5],
+            &[0x0A, 0x00, 0x00, 0x00, 0x00],
+            "UTF8_SUPPORT feature with zero-length data must follow FEDAUTH"
         );
+        assert_eq!(encoded[next + 5], 0xFF, "FeatureExt terminator must follow");
     }
 
     /// The echo bit mirrors the server's PRELOGIN FEDAUTHREQUIRED response;
@@ -1834,6 +2274,16 @@ mod fed_auth_login_tests {
         assert!(!prelogin.fed_auth_required);
     }
 
+    /// Requesting MARS must not put it in PRELOGIN: a server that accepts it
+    /// then expects SMP-framed packets the client cannot send.
+    #[test]
+    fn prelogin_never_advertises_mars() {
+        let mut config = Config::new();
+        config.mars = true;
+        let prelogin = Client::<Disconnected>::build_prelogin(&config, EncryptionLevel::On);
+        assert!(!prelogin.mars);
+    }
+
     /// Regression: a LOGIN7 carrying a large FEDAUTH token exceeds the 4096-byte
     /// TDS default packet size and MUST be split across multiple packets, each
     /// within 4096 bytes. Before the fix, `send_login7` passed MAX_PACKET_SIZE
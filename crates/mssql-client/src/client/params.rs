@@ -3,7 +3,10 @@
 //! This module converts Rust types (via `ToSql`) into TDS wire-format
 //! RPC parameters, including Table-Valued Parameter (TVP) encoding.
 
-use bytes::BytesMut;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use bytes::{Bytes, BytesMut};
 #[cfg(feature = "decimal")]
 use tds_protocol::__private::encode_tvp_decimal;
 use tds_protocol::__private::{
@@ -106,6 +109,12 @@ impl<S: ConnectionState> Client<S> {
             }
             #[cfg(feature = "decimal")]
             SqlValue::Decimal(d) => {
+                // Declared as DECIMAL(38, s): the precision is fixed, but the
+                // scale is the value's own, since rust_decimal carries up to 28
+                // fractional digits and no single scale fits every value.
+                // Values of different scales therefore still get different
+                // declarations, and so separate statement-cache and plan-cache
+                // entries; callers who want one plan `rescale` to a fixed scale.
                 let mut buf = BytesMut::with_capacity(17);
                 mssql_types::__private::encode_decimal(*d, &mut buf);
                 let scale = d.scale() as u8;
@@ -177,6 +186,13 @@ impl<S: ConnectionState> Client<S> {
         collation: Option<&tds_protocol::token::Collation>,
     ) -> Result<RpcParam> {
         let sql_value = value.to_sql()?;
+        // A typed NULL (e.g. `null::<bool>()`) keeps its declared type, so a
+        // NULL bit goes out as BITN rather than the untyped NVARCHAR default.
+        if sql_value.is_null() {
+            if let Some(type_info) = super::null_param_type_info(value.sql_type()) {
+                return Ok(RpcParam::null(name, type_info));
+            }
+        }
         Self::sql_value_to_rpc_param(name, &sql_value, send_unicode, collation)
     }
 
@@ -196,6 +212,24 @@ impl<S: ConnectionState> Client<S> {
             .collect()
     }
 
+    /// Convert positional query parameters for `sp_executesql` / `sp_prepexec`,
+    /// collapsing identical values when
+    /// [`Config::deduplicate_params`](crate::Config::deduplicate_params) is
+    /// enabled. Returns the SQL text to send alongside the parameters.
+    pub(crate) fn convert_query_params<'s>(
+        &self,
+        sql: &'s str,
+        params: &[&(dyn crate::ToSql + Sync)],
+    ) -> Result<(Cow<'s, str>, Vec<RpcParam>)> {
+        let rpc_params =
+            Self::convert_params(params, self.send_unicode(), self.server_collation())?;
+        if self.config.deduplicate_params {
+            Ok(deduplicate_params(sql, rpc_params))
+        } else {
+            Ok((Cow::Borrowed(sql), rpc_params))
+        }
+    }
+
     /// Convert ToSql parameters to RPC parameters with empty names for positional binding.
     ///
     /// Used by [`Client::call_procedure`](super::Client::call_procedure) because
@@ -279,6 +313,21 @@ impl<S: ConnectionState> Client<S> {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        // Every row must fill every column: the encoder writes cells
+        // positionally, so a short row would shift the rest of the stream.
+        if let Some((index, row)) = tvp_data
+            .rows
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != wire_columns.len())
+        {
+            return Err(Error::Config(format!(
+                "TVP {full_type_name} row {index} has {} values but the type has {} columns",
+                row.len(),
+                wire_columns.len()
+            )));
+        }
+
         // Create encoder
         let encoder = TvpEncoder::new(&tvp_data.schema, &tvp_data.type_name, &wire_columns);
 
@@ -452,6 +501,14 @@ impl<S: ConnectionState> Client<S> {
                         let scaled = mssql_types::__private::decimal_to_smallmoney_cents_i32(*d)?;
                         encode_tvp_smallmoney(scaled, buf);
                     }
+                    TvpWireType::Decimal { precision, scale } => {
+                        // The column metadata declares `scale`, so the
+                        // mantissa must be written at that scale.
+                        let d = mssql_types::__private::fit_decimal(*d, *precision, *scale)?;
+                        let sign = if d.is_sign_negative() { 0u8 } else { 1u8 };
+                        let mantissa = d.mantissa().unsigned_abs();
+                        encode_tvp_decimal(sign, mantissa, buf);
+                    }
                     _ => {
                         let sign = if d.is_sign_negative() { 0u8 } else { 1u8 };
                         let mantissa = d.mantissa().unsigned_abs();
@@ -576,6 +633,126 @@ impl<S: ConnectionState> Client<S> {
     }
 }
 
+/// Collapse parameters with identical wire encodings onto the first
+/// occurrence's name and rewrite references to the dropped names in `sql`.
+///
+/// Names are not renumbered: if `@p1` and `@p3` are identical, `@p1` and `@p2`
+/// are sent and every `@p3` in `sql` becomes `@p1`, so the output is
+/// deterministic for a given SQL text and argument list. Output and encrypted
+/// parameters are never collapsed.
+pub(crate) fn deduplicate_params(
+    sql: &str,
+    params: Vec<RpcParam>,
+) -> (Cow<'_, str>, Vec<RpcParam>) {
+    let mut first_by_value: HashMap<(u8, Bytes, Option<Bytes>), String> = HashMap::new();
+    let mut renames: HashMap<String, String> = HashMap::new();
+    let mut kept = Vec::with_capacity(params.len());
+
+    for param in params {
+        if param.flags.by_ref || param.crypto_metadata.is_some() || param.name.is_empty() {
+            kept.push(param);
+            continue;
+        }
+        let mut type_info = BytesMut::new();
+        param.type_info.encode(&mut type_info);
+        let key = (
+            param.flags.encode(),
+            type_info.freeze(),
+            param.value.clone(),
+        );
+        match first_by_value.get(&key) {
+            Some(first) => {
+                renames.insert(param.name.to_ascii_lowercase(), first.clone());
+            }
+            None => {
+                first_by_value.insert(key, param.name.clone());
+                kept.push(param);
+            }
+        }
+    }
+
+    if renames.is_empty() {
+        return (Cow::Borrowed(sql), kept);
+    }
+    (Cow::Owned(rewrite_param_refs(sql, &renames)), kept)
+}
+
+/// Replace whole-word variable references (matched case-insensitively via the
+/// lowercase keys of `renames`) outside string literals, quoted and bracketed
+/// identifiers, and comments.
+fn rewrite_param_refs(sql: &str, renames: &HashMap<String, String>) -> String {
+    // Identifier characters; bytes >= 0x80 belong to non-ASCII identifier
+    // characters. Every delimiter below is ASCII, so byte offsets always land
+    // on char boundaries.
+    fn is_word_byte(b: u8) -> bool {
+        b.is_ascii_alphanumeric() || matches!(b, b'_' | b'@' | b'#' | b'$') || b >= 0x80
+    }
+
+    let b = sql.as_bytes();
+    let mut out = String::with_capacity(sql.len());
+    let mut copied = 0;
+    let mut i = 0;
+
+    while i < b.len() {
+        match b[i] {
+            open @ (b'\'' | b'"' | b'[') => {
+                let close = if open == b'[' { b']' } else { open };
+                i += 1;
+                while i < b.len() {
+                    if b[i] == close {
+                        // A doubled delimiter is an escaped one.
+                        if b.get(i + 1) == Some(&close) {
+                            i += 2;
+                            continue;
+                        }
+                        i += 1;
+                        break;
+                    }
+                    i += 1;
+                }
+            }
+            b'-' if b.get(i + 1) == Some(&b'-') => {
+                while i < b.len() && b[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if b.get(i + 1) == Some(&b'*') => {
+                // T-SQL block comments nest.
+                let mut depth = 0usize;
+                while i < b.len() {
+                    if b[i..].starts_with(b"/*") {
+                        depth += 1;
+                        i += 2;
+                    } else if b[i..].starts_with(b"*/") {
+                        depth -= 1;
+                        i += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        i += 1;
+                    }
+                }
+            }
+            c if is_word_byte(c) => {
+                let start = i;
+                while i < b.len() && is_word_byte(b[i]) {
+                    i += 1;
+                }
+                if let Some(new_name) = renames.get(&sql[start..i].to_ascii_lowercase()) {
+                    out.push_str(&sql[copied..start]);
+                    out.push_str(new_name);
+                    copied = i;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+
+    out.push_str(&sql[copied..]);
+    out
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -584,6 +761,184 @@ mod tests {
     use mssql_types::SqlValue;
     use tds_protocol::tvp::TvpWireType;
 
+    /// A `Uuid` bound as a parameter and read back from a UNIQUEIDENTIFIER
+    /// column must be equal: the parameter encoder's mixed-endian swap and
+    /// the row decoder's inverse swap have to match. A UUID with all-distinct
+    /// bytes catches an asymmetric swap.
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_param_round_trips_through_row_decode() {
+        use tds_protocol::token::{ColumnData, TypeInfo};
+        use tds_protocol::types::TypeId;
+
+        let original = uuid::Uuid::parse_str("00112233-4455-6677-8899-aabbccddeeff").unwrap();
+        let param =
+            Client::<Ready>::sql_value_to_rpc_param("@p1", &SqlValue::Uuid(original), true, None)
+                .unwrap();
+        let stored = param.value.expect("non-NULL parameter value");
+        assert_eq!(stored.len(), 16);
+
+        // SELECT returns the stored bytes as a length-prefixed GUIDN cell.
+        let mut row = vec![16u8];
+        row.extend_from_slice(&stored);
+        let col = ColumnData {
+            name: "id".to_string(),
+            type_id: TypeId::Guid,
+            col_type: TypeId::Guid as u8,
+            flags: 0x01,
+            user_type: 0,
+            type_info: TypeInfo {
+                max_length: Some(16),
+                precision: None,
+                scale: None,
+                collation: None,
+            },
+            crypto_metadata: None,
+        };
+        let mut buf: &[u8] = &row;
+        let decoded = crate::column_parser::parse_column_value(&mut buf, &col, None).unwrap();
+        assert_eq!(decoded, SqlValue::Uuid(original));
+        assert!(buf.is_empty());
+    }
+
+    /// `Some(true)`, `Some(false)` and `None` bound to a nullable BIT column
+    /// must all be declared BITN and read back unchanged. A plain `None` has
+    /// no type to declare, so the typed `null::<bool>()` is the BIT NULL.
+    #[test]
+    fn bit_params_are_bitn_and_round_trip() {
+        use tds_protocol::token::{ColumnData, TypeInfo};
+        use tds_protocol::types::TypeId;
+
+        let col = ColumnData {
+            name: "flag".to_string(),
+            type_id: TypeId::BitN,
+            col_type: TypeId::BitN as u8,
+            flags: 0x01,
+            user_type: 0,
+            type_info: TypeInfo {
+                max_length: Some(1),
+                precision: None,
+                scale: None,
+                collation: None,
+            },
+            crypto_metadata: None,
+        };
+        let null_bit = crate::null::<bool>();
+        let cases: [(&(dyn crate::ToSql + Sync), SqlValue); 3] = [
+            (&Some(true), SqlValue::Bool(true)),
+            (&Some(false), SqlValue::Bool(false)),
+            (&null_bit, SqlValue::Null),
+        ];
+
+        for (value, expected) in cases {
+            let param = Client::<Ready>::convert_single_param("@p1", value, true, None).unwrap();
+            assert_eq!(param.type_info.type_id, 0x68, "{expected:?} must be BITN");
+            assert_eq!(param.type_info.max_length, Some(1));
+
+            // SELECT returns the stored value as a length-prefixed BITN cell.
+            let row = match &param.value {
+                Some(stored) => {
+                    assert_eq!(stored.len(), 1);
+                    vec![1, stored[0]]
+                }
+                None => vec![0],
+            };
+            let mut buf: &[u8] = &row;
+            let decoded = crate::column_parser::parse_column_value(&mut buf, &col, None).unwrap();
+            assert_eq!(decoded, expected);
+            assert!(buf.is_empty());
+        }
+    }
+
+    /// Decimal parameters go out as DECIMALN(38, s) whatever the value, with
+    /// the sign byte and the full 16-byte mantissa.
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal_param_encodes_native_decimaln() {
+        use std::str::FromStr;
+
+        let cases: [(&str, u8, u8, u128); 3] = [
+            ("0.00", 2, 1, 0),
+            ("-12345.6789", 4, 0, 123_456_789),
+            ("1234567890.12", 2, 1, 123_456_789_012),
+        ];
+
+        for (text, scale, sign, mantissa) in cases {
+            let d = rust_decimal::Decimal::from_str(text).unwrap();
+            let param =
+                Client::<Ready>::sql_value_to_rpc_param("@p1", &SqlValue::Decimal(d), true, None)
+                    .unwrap();
+            let mut expected = vec![sign];
+            expected.extend_from_slice(&mantissa.to_le_bytes());
+            assert_eq!(param.type_info.type_id, 0x6C, "{text} must be DECIMALN");
+            assert_eq!(param.type_info.precision, Some(38), "{text}");
+            assert_eq!(param.type_info.scale, Some(scale), "{text}");
+            assert_eq!(param.value.as_deref(), Some(&expected[..]), "{text}");
+        }
+    }
+
+    /// `Money` and `SmallMoney` parameters go out as MONEYN in the 8- and
+    /// 4-byte fixed-point forms, and read back from a MONEY column to the
+    /// exact same value, so no cents are lost to FLOAT rounding.
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn money_params_encode_fixed_point_moneyn() {
+        use std::str::FromStr;
+        use tds_protocol::token::{ColumnData, TypeInfo};
+        use tds_protocol::types::TypeId;
+
+        let money_col = |len: u32| ColumnData {
+            name: "amount".to_string(),
+            type_id: TypeId::MoneyN,
+            col_type: TypeId::MoneyN as u8,
+            flags: 0x01,
+            user_type: 0,
+            type_info: TypeInfo {
+                max_length: Some(len),
+                precision: None,
+                scale: None,
+                collation: None,
+            },
+            crypto_metadata: None,
+        };
+
+        for text in ["0.01", "-0.0001", "19.99", "922337203685477.5807"] {
+            let d = rust_decimal::Decimal::from_str(text).unwrap();
+            let param =
+                Client::<Ready>::convert_single_param("@p1", &crate::Money(d), true, None).unwrap();
+            assert_eq!(param.type_info.type_id, 0x6E, "{text} must be MONEYN");
+            assert_eq!(param.type_info.max_length, Some(8), "{text}");
+
+            // MONEY is the value in ten-thousandths: high 32 bits, then low.
+            let stored = param.value.expect("non-NULL parameter value");
+            let units = d.mantissa() as i64 * 10_i64.pow(4 - d.scale());
+            let mut expected = ((units >> 32) as i32).to_le_bytes().to_vec();
+            expected.extend_from_slice(&(units as u32).to_le_bytes());
+            assert_eq!(&stored[..], &expected[..], "{text}");
+
+            let mut row = vec![8u8];
+            row.extend_from_slice(&stored);
+            let mut buf: &[u8] = &row;
+            let decoded =
+                crate::column_parser::parse_column_value(&mut buf, &money_col(8), None).unwrap();
+            assert_eq!(decoded, SqlValue::Decimal(d), "{text}");
+        }
+
+        let d = rust_decimal::Decimal::from_str("-214748.3648").unwrap();
+        let param = Client::<Ready>::convert_single_param("@p1", &crate::SmallMoney(d), true, None)
+            .unwrap();
+        assert_eq!(param.type_info.type_id, 0x6E);
+        assert_eq!(param.type_info.max_length, Some(4));
+        let stored = param.value.expect("non-NULL parameter value");
+        assert_eq!(&stored[..], &i32::MIN.to_le_bytes()[..]);
+        let mut row = vec![4u8];
+        row.extend_from_slice(&stored);
+        let mut buf: &[u8] = &row;
+        let decoded =
+            crate::column_parser::parse_column_value(&mut buf, &money_col(4), None).unwrap();
+        assert_eq!(decoded, SqlValue::Decimal(d));
+    }
+
     /// Issue #157 regression: TVP cells whose value the wire type cannot
     /// represent must error — previously they were silently written as NULL,
     /// inserting wrong data the server cannot distinguish from intentional
@@ -614,6 +969,47 @@ mod tests {
         .expect_err("out-of-range SMALLMONEY must error");
     }
 
+    /// A TVP DECIMAL cell is written at the scale its column declares, and a
+    /// value too wide for the column's precision is rejected client-side.
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn tvp_decimal_cell_uses_column_scale_and_precision() {
+        let column = TvpWireType::Decimal {
+            precision: 18,
+            scale: 4,
+        };
+        let mut buf = BytesMut::new();
+        Client::<Ready>::encode_tvp_value(
+            &SqlValue::Decimal(rust_decimal::Decimal::new(125, 1)),
+            &column,
+            None,
+            &mut buf,
+        )
+        .unwrap();
+        let mut expected = vec![17, 1];
+        expected.extend_from_slice(&125_000u128.to_le_bytes());
+        assert_eq!(&buf[..], &expected[..]);
+
+        let mut buf = BytesMut::new();
+        let err = Client::<Ready>::encode_tvp_value(
+            &SqlValue::Decimal(rust_decimal::Decimal::from(10u64.pow(15))),
+            &column,
+            None,
+            &mut buf,
+        )
+        .expect_err("15 integer digits do not fit DECIMAL(18,4)");
+        assert!(
+            matches!(
+                err,
+                Error::Type(mssql_types::TypeError::DecimalOverflow {
+                    max_precision: 18,
+                    ..
+                })
+            ),
+            "{err:?}"
+        );
+    }
+
     /// Issue #157 regression: unsupported variants (nested TVPs) must error,
     /// not silently encode NULL.
     #[test]
@@ -659,4 +1055,78 @@ mod tests {
         Client::<Ready>::encode_tvp_param("@tvp", &good, None)
             .expect("a schema-qualified TVP type name must convert");
     }
+
+    /// A row whose value count differs from the column count is rejected
+    /// when the parameter is encoded, naming the row.
+    #[test]
+    fn tvp_row_with_wrong_value_count_is_rejected() {
+        let mut data = mssql_types::TvpData::new("dbo", "Pairs")
+            .with_column(mssql_types::TvpColumnDef::new(
+                mssql_types::TvpColumnType::Int,
+            ))
+            .with_column(mssql_types::TvpColumnDef::new(
+                mssql_types::TvpColumnType::Int,
+            ));
+        data.rows = vec![
+            vec![SqlValue::Int(1), SqlValue::Int(2)],
+            vec![SqlValue::Int(3)],
+        ];
+        let err = Client::<Ready>::encode_tvp_param("@tvp", &data, None)
+            .expect_err("a short row must be rejected");
+        assert!(
+            err.to_string()
+                .contains("TVP dbo.Pairs row 1 has 1 values but the type has 2 columns"),
+            "{err}"
+        );
+
+        data.rows[1].push(SqlValue::Int(4));
+        data.rows[1].push(SqlValue::Int(5));
+        Client::<Ready>::encode_tvp_param("@tvp", &data, None)
+            .expect_err("a long row must be rejected");
+
+        data.rows[1].pop();
+        Client::<Ready>::encode_tvp_param("@tvp", &data, None).expect("matching rows encode");
+    }
+
+    fn positional(values: &[&(dyn crate::ToSql + Sync)]) -> Vec<RpcParam> {
+        Client::<Ready>::convert_params(values, true, None).unwrap()
+    }
+
+    #[test]
+    fn deduplicate_params_reuses_first_name_for_identical_values() {
+        let blob = vec![0xABu8; 64];
+        let params = positional(&[&blob, &1i32, &blob, &"x"]);
+        let (sql, kept) = deduplicate_params(
+            "SELECT * FROM t WHERE a = @p1 AND b = @p2 AND c = @P3 AND d = @p4",
+            params,
+        );
+        assert_eq!(
+            sql,
+            "SELECT * FROM t WHERE a = @p1 AND b = @p2 AND c = @p1 AND d = @p4"
+        );
+        let names: Vec<_> = kept.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["@p1", "@p2", "@p4"]);
+    }
+
+    /// Equal values of different types (INT 1 vs BIGINT 1) are declared
+    /// differently, so they must stay separate parameters.
+    #[test]
+    fn deduplicate_params_keeps_distinct_types() {
+        let (sql, kept) = deduplicate_params("SELECT @p1, @p2", positional(&[&1i32, &1i64]));
+        assert_eq!(sql, "SELECT @p1, @p2");
+        assert_eq!(kept.len(), 2);
+        assert!(matches!(sql, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn rewrite_param_refs_skips_literals_comments_and_longer_names() {
+        let renames = HashMap::from([("@p2".to_string(), "@p1".to_string())]);
+        let sql = "SELECT @p2, @p20, @@p2, N'@p2', [@p2], \"@p2\" -- @p2\n\
+                   /* @p2 /* @p2 */ @p2 */ FROM t WHERE x = @P2 AND y = 'it''s @p2'";
+        assert_eq!(
+            rewrite_param_refs(sql, &renames),
+            "SELECT @p1, @p20, @@p2, N'@p2', [@p2], \"@p2\" -- @p2\n\
+             /* @p2 /* @p2 */ @p2 */ FROM t WHERE x = @p1 AND y = 'it''s @p2'"
+        );
+    }
 }
@@ -5,8 +5,10 @@
 
 use tds_protocol::token::{ColMetaData, EnvChangeType, Token, TokenParser};
 
-use crate::error::{Error, Result};
+use crate::error::{Error, Result, ServerMessage};
+use crate::row_source::{Pull, RowSource};
 use crate::state::ConnectionState;
+use crate::stream::QueryStream;
 
 use super::{Client, ConnectionHandle};
 
@@ -23,9 +25,65 @@ pub(crate) fn server_token_to_error(err: &tds_protocol::token::ServerError) -> E
         server: (!err.server.is_empty()).then(|| err.server.clone()),
         procedure: (!err.procedure.is_empty()).then(|| err.procedure.clone()),
         line: err.line as u32,
+        additional: Vec::new(),
     }
 }
 
+/// Convert a server `ERROR` token into a [`ServerMessage`].
+pub(crate) fn server_error_message(err: &tds_protocol::token::ServerError) -> ServerMessage {
+    ServerMessage {
+        number: err.number,
+        class: err.class,
+        state: err.state,
+        message: err.message.clone(),
+        server: (!err.server.is_empty()).then(|| err.server.clone()),
+        procedure: (!err.procedure.is_empty()).then(|| err.procedure.clone()),
+        line: err.line as u32,
+    }
+}
+
+/// Convert a server `INFO` token into a [`ServerMessage`].
+pub(crate) fn server_info_message(info: &tds_protocol::token::ServerInfo) -> ServerMessage {
+    ServerMessage {
+        number: info.number,
+        class: info.class,
+        state: info.state,
+        message: info.message.clone(),
+        server: (!info.server.is_empty()).then(|| info.server.clone()),
+        procedure: (!info.procedure.is_empty()).then(|| info.procedure.clone()),
+        line: info.line as u32,
+    }
+}
+
+/// Build the [`Error::Server`] for a failed buffered response.
+///
+/// `messages` holds the INFO messages seen before the error. The rest of the
+/// (already fully read) response is scanned for further ERROR/INFO tokens so
+/// the returned error carries the server's complete output. A parse failure
+/// while scanning just ends the scan — the primary error is what matters.
+pub(crate) fn collect_server_error(
+    err: &tds_protocol::token::ServerError,
+    mut messages: Vec<ServerMessage>,
+    parser: &mut TokenParser,
+    metadata: Option<ColMetaData>,
+) -> Error {
+    let mut metadata = metadata;
+    while let Ok(Some(token)) = parser.next_token_with_metadata(metadata.as_ref()) {
+        match token {
+            Token::ColMetaData(meta) => metadata = Some(meta),
+            Token::Error(e) => messages.push(server_error_message(&e)),
+            Token::Info(i) => messages.push(server_info_message(&i)),
+            _ => {}
+        }
+    }
+
+    let mut error = server_token_to_error(err);
+    if let Error::Server { additional, .. } = &mut error {
+        *additional = messages;
+    }
+    error
+}
+
 impl<S: ConnectionState> Client<S> {
     /// Read the next response message from the connection.
     ///
@@ -60,7 +118,9 @@ impl<S: ConnectionState> Client<S> {
     /// it does **not** reassemble the whole multi-packet response, so it is the
     /// primitive the incremental streaming path uses to pull one packet at a
     /// time. Returns the packet payload and whether it carried END_OF_MESSAGE,
-    /// or `None` at end of stream.
+    /// or `None` at end of stream. A cancelled response ends in
+    /// [`Error::Cancelled`] once the server's acknowledgement is drained, as in
+    /// [`read_response_message`](Self::read_response_message).
     pub(crate) async fn read_response_packet(&mut self) -> Result<Option<(bytes::Bytes, bool)>> {
         let connection = self.connection.as_mut().ok_or(Error::ConnectionClosed)?;
         let result = match connection {
@@ -76,6 +136,10 @@ impl<S: ConnectionState> Client<S> {
                 Ok(Some((packet.payload.freeze(), is_eom)))
             }
             Ok(None) => Ok(None),
+            Err(mssql_codec::CodecError::Cancelled) => {
+                self.in_flight = false;
+                Err(Error::Cancelled)
+            }
             Err(e) => Err(e.into()),
         }
     }
@@ -87,9 +151,65 @@ impl<S: ConnectionState> Client<S> {
         self.in_flight = false;
     }
 
+    /// Create an empty [`RowSource`] for this connection's responses, with
+    /// Always Encrypted and strict parsing set as the buffered parser has them.
+    pub(crate) fn row_source(&self) -> RowSource {
+        #[cfg(feature = "always-encrypted")]
+        let encryption_enabled = self.encryption_context.is_some();
+        #[cfg(not(feature = "always-encrypted"))]
+        let encryption_enabled = false;
+
+        RowSource::new(encryption_enabled).with_strict(self.config.strict_protocol)
+    }
+
+    /// Build the [`Error::Server`] for a failed streamed response.
+    ///
+    /// The streaming readers see the ERROR token before the rest of the
+    /// response has arrived, so this reads the remainder through `source`,
+    /// gathering further ERROR/INFO messages as [`collect_server_error`] does
+    /// for a buffered response, and marks the connection drained. `messages`
+    /// holds the INFO messages seen before the error. If the remainder cannot
+    /// be read, the connection stays in flight — the next request or the pool
+    /// cleans it up — and the messages gathered so far are kept.
+    pub(crate) async fn finish_streamed_error(
+        &mut self,
+        err: &tds_protocol::token::ServerError,
+        mut messages: Vec<ServerMessage>,
+        source: &mut RowSource,
+    ) -> Error {
+        loop {
+            match source.pull() {
+                Ok(Pull::Token(Token::Error(e))) => messages.push(server_error_message(&e)),
+                Ok(Pull::Token(Token::Info(i))) => messages.push(server_info_message(&i)),
+                Ok(Pull::Token(Token::EnvChange(env))) => self.apply_env_change(&env),
+                Ok(Pull::Token(_)) => {}
+                Ok(Pull::NeedMore) => match self.read_response_packet().await {
+                    Ok(Some((payload, is_eom))) => source.push_packet(payload, is_eom),
+                    Ok(None) | Err(_) => break,
+                },
+                Ok(Pull::End) => {
+                    self.note_response_drained();
+                    break;
+                }
+                Err(_) => break,
+            }
+        }
+
+        let mut error = server_token_to_error(err);
+        if let Error::Server { additional, .. } = &mut error {
+            *additional = messages;
+        }
+        error
+    }
+
+    /// Whether row decoding replaces invalid UTF-16 (`Config::lenient_utf16`).
+    pub(crate) fn lenient_utf16(&self) -> bool {
+        self.config.lenient_utf16
+    }
+
     /// Create a TokenParser with encryption awareness when configured.
-    pub(super) fn create_parser(&self, payload: bytes::Bytes) -> TokenParser {
-        let parser = TokenParser::new(payload);
+    pub(crate) fn create_parser(&self, payload: bytes::Bytes) -> TokenParser {
+        let parser = TokenParser::new(payload).with_strict(self.config.strict_protocol);
         #[cfg(feature = "always-encrypted")]
         let parser = if self.encryption_context.is_some() {
             parser.with_encryption(true)
@@ -162,10 +282,31 @@ pub(super) struct RawQueryResponse {
     pub columns: Vec<crate::row::Column>,
     pub pending_rows: Vec<crate::stream::PendingRow>,
     pub meta: ColMetaData,
+    /// Sum of the DONE-token row counts, as in [`Client::read_execute_detailed`].
+    pub rows_affected: Option<u64>,
+    /// Zero-based sort-key columns from the result set's ORDER token.
+    pub order_columns: Option<Vec<usize>>,
+    /// `Config::lenient_utf16` of the connection that read the response.
+    pub lenient_utf16: bool,
     #[cfg(feature = "always-encrypted")]
     pub decryptor: Option<std::sync::Arc<crate::column_decryptor::ColumnDecryptor>>,
 }
 
+impl RawQueryResponse {
+    /// Wrap the response in a [`QueryStream`] that decodes rows lazily.
+    pub(super) fn into_stream<'a>(self) -> QueryStream<'a> {
+        #[cfg(feature = "always-encrypted")]
+        let stream =
+            QueryStream::from_raw(self.columns, self.pending_rows, self.meta, self.decryptor);
+        #[cfg(not(feature = "always-encrypted"))]
+        let stream = QueryStream::from_raw(self.columns, self.pending_rows, self.meta);
+        stream
+            .with_rows_affected(self.rows_affected)
+            .with_order_columns(self.order_columns)
+            .with_lenient_utf16(self.lenient_utf16)
+    }
+}
+
 impl<S: ConnectionState> Client<S> {
     /// Read complete query response, deferring row decoding to the stream.
     ///
@@ -182,6 +323,7 @@ impl<S: ConnectionState> Client<S> {
         self.in_flight = false;
 
         let mut parser = self.create_parser(message.payload);
+        let mut server_messages: Vec<ServerMessage> = Vec::new();
         let mut columns: Vec<crate::row::Column> = Vec::new();
         let mut pending_rows: Vec<crate::stream::PendingRow> = Vec::new();
         let mut protocol_metadata: Option<ColMetaData> = None;
@@ -190,6 +332,8 @@ impl<S: ConnectionState> Client<S> {
         // RETURNVALUE token after the rows. Capture it for the post-read cache
         // store; `None` for every other query.
         let mut prepared_handle: Option<i32> = None;
+        let mut rows_affected: Option<u64> = None;
+        let mut order_columns: Option<Vec<usize>> = None;
         #[cfg(feature = "always-encrypted")]
         let mut current_decryptor: Option<
             std::sync::Arc<crate::column_decryptor::ColumnDecryptor>,
@@ -208,6 +352,7 @@ impl<S: ConnectionState> Client<S> {
                     // New result set starting - clear previous rows
                     // This enables multi-statement batches to return the last result set
                     pending_rows.clear();
+                    order_columns = None;
 
                     columns = Self::build_columns(&meta);
 
@@ -232,8 +377,23 @@ impl<S: ConnectionState> Client<S> {
                         pending_rows.push(crate::stream::PendingRow::Nbc(nbc_row));
                     }
                 }
+                Token::Order(order) => {
+                    // ORDER lists 1-based column numbers of the sort key.
+                    order_columns = Some(
+                        order
+                            .columns
+                            .iter()
+                            .filter_map(|&col| usize::from(col).checked_sub(1))
+                            .collect(),
+                    );
+                }
                 Token::Error(err) => {
-                    return Err(server_token_to_error(&err));
+                    return Err(collect_server_error(
+                        &err,
+                        server_messages,
+                        &mut parser,
+                        protocol_metadata,
+                    ));
                 }
                 Token::Done(done) => {
                     if done.status.error {
@@ -246,6 +406,9 @@ impl<S: ConnectionState> Client<S> {
                         has_more = done.status.more,
                         "query complete"
                     );
+                    if done.status.count {
+                        *rows_affected.get_or_insert(0) += done.row_count;
+                    }
                     // Only break if there are no more result sets
                     // This enables multi-statement batches to process all results
                     if !done.status.more {
@@ -259,6 +422,9 @@ impl<S: ConnectionState> Client<S> {
                                 .to_string(),
                         ));
                     }
+                    if done.status.count {
+                        *rows_affected.get_or_insert(0) += done.row_count;
+                    }
                 }
                 Token::DoneInProc(done) => {
                     if done.status.error {
@@ -267,8 +433,12 @@ impl<S: ConnectionState> Client<S> {
                                 .to_string(),
                         ));
                     }
+                    if done.status.count {
+                        *rows_affected.get_or_insert(0) += done.row_count;
+                    }
                 }
                 Token::Info(info) => {
+                    server_messages.push(server_info_message(&info));
                     tracing::debug!(
                         number = info.number,
                         message = %info.message,
@@ -276,10 +446,10 @@ impl<S: ConnectionState> Client<S> {
                     );
                 }
                 Token::EnvChange(env) => {
-                    // Process transaction-related EnvChange tokens.
-                    // This allows BEGIN TRANSACTION, COMMIT, ROLLBACK via raw SQL
-                    // to properly update the transaction descriptor.
-                    Self::process_transaction_env_change(&env, &mut self.transaction_descriptor);
+                    // Track the transaction descriptor and current database,
+                    // so BEGIN TRANSACTION, COMMIT, ROLLBACK and USE via raw
+                    // SQL update the session state.
+                    self.apply_env_change(&env);
                 }
                 Token::ReturnValue(ret_val) if prepared_handle.is_none() => {
                     // sp_prepexec's `@handle` OUTPUT parameter. Decode it with the
@@ -323,13 +493,32 @@ impl<S: ConnectionState> Client<S> {
             columns,
             pending_rows,
             meta: protocol_metadata.unwrap_or_default(),
+            rows_affected,
+            order_columns,
+            lenient_utf16: self.config.lenient_utf16,
             #[cfg(feature = "always-encrypted")]
             decryptor: current_decryptor,
         })
     }
 
     /// Read execute result (row count) from the response.
+    ///
+    /// `0` when the server reported no count; see
+    /// [`read_execute_detailed`](Self::read_execute_detailed).
     pub(super) async fn read_execute_result(&mut self) -> Result<u64> {
+        Ok(self
+            .read_execute_detailed()
+            .await?
+            .rows_affected
+            .unwrap_or(0))
+    }
+
+    /// Read an execute response, keeping whether any row count was reported.
+    ///
+    /// `rows_affected` is `None` unless at least one DONE/DONEPROC/DONEINPROC
+    /// token had its count bit (`DONE_COUNT`) set, so "matched zero rows" is
+    /// distinguishable from "no count" (DDL, skipped statement, NOCOUNT).
+    pub(super) async fn read_execute_detailed(&mut self) -> Result<crate::stream::ExecuteResult> {
         // Note: execute() doesn't read row values, so no decryption needed.
         // But we still need the encryption-aware parser for ColMetaData/Row token parsing.
         let message = self.read_response_message().await?;
@@ -338,7 +527,8 @@ impl<S: ConnectionState> Client<S> {
         self.in_flight = false;
 
         let mut parser = self.create_parser(message.payload);
-        let mut rows_affected = 0u64;
+        let mut server_messages: Vec<ServerMessage> = Vec::new();
+        let mut rows_affected: Option<u64> = None;
         let mut current_metadata: Option<ColMetaData> = None;
 
         loop {
@@ -366,7 +556,7 @@ impl<S: ConnectionState> Client<S> {
                     }
                     if done.status.count {
                         // Accumulate row counts from all statements in a batch
-                        rows_affected += done.row_count;
+                        *rows_affected.get_or_insert(0) += done.row_count;
                     }
                     // Only break if there are no more result sets
                     // This enables multi-statement batches to report total affected rows
@@ -376,34 +566,24 @@ impl<S: ConnectionState> Client<S> {
                 }
                 Token::DoneProc(done) => {
                     if done.status.count {
-                        rows_affected += done.row_count;
+                        *rows_affected.get_or_insert(0) += done.row_count;
                     }
                 }
                 Token::DoneInProc(done) => {
                     if done.status.count {
-                        rows_affected += done.row_count;
+                        *rows_affected.get_or_insert(0) += done.row_count;
                     }
                 }
                 Token::Error(err) => {
-                    return Err(Error::Server {
-                        number: err.number,
-                        state: err.state,
-                        class: err.class,
-                        message: err.message.clone(),
-                        server: if err.server.is_empty() {
-                            None
-                        } else {
-                            Some(err.server.clone())
-                        },
-                        procedure: if err.procedure.is_empty() {
-                            None
-                        } else {
-                            Some(err.procedure.clone())
-                        },
-                        line: err.line as u32,
-                    });
+                    return Err(collect_server_error(
+                        &err,
+                        server_messages,
+                        &mut parser,
+                        current_metadata,
+                    ));
                 }
                 Token::Info(info) => {
+                    server_messages.push(server_info_message(&info));
                     tracing::info!(
                         number = info.number,
                         message = %info.message,
@@ -411,16 +591,19 @@ impl<S: ConnectionState> Client<S> {
                     );
                 }
                 Token::EnvChange(env) => {
-                    // Process transaction-related EnvChange tokens.
-                    // This allows BEGIN TRANSACTION, COMMIT, ROLLBACK via raw SQL
-                    // to properly update the transaction descriptor.
-                    Self::process_transaction_env_change(&env, &mut self.transaction_descriptor);
+                    // Track the transaction descriptor and current database,
+                    // so BEGIN TRANSACTION, COMMIT, ROLLBACK and USE via raw
+                    // SQL update the session state.
+                    self.apply_env_change(&env);
                 }
                 _ => {}
             }
         }
 
-        Ok(rows_affected)
+        Ok(crate::stream::ExecuteResult {
+            rows_affected,
+            output_params: Vec::new(),
+        })
     }
 
     /// Read the response from BEGIN TRANSACTION and extract the transaction descriptor.
@@ -435,6 +618,7 @@ impl<S: ConnectionState> Client<S> {
         self.in_flight = false;
 
         let mut parser = self.create_parser(message.payload);
+        let mut server_messages: Vec<ServerMessage> = Vec::new();
         let mut transaction_descriptor: u64 = 0;
 
         loop {
@@ -472,25 +656,15 @@ impl<S: ConnectionState> Client<S> {
                     break;
                 }
                 Token::Error(err) => {
-                    return Err(Error::Server {
-                        number: err.number,
-                        state: err.state,
-                        class: err.class,
-                        message: err.message.clone(),
-                        server: if err.server.is_empty() {
-                            None
-                        } else {
-                            Some(err.server.clone())
-                        },
-                        procedure: if err.procedure.is_empty() {
-                            None
-                        } else {
-                            Some(err.procedure.clone())
-                        },
-                        line: err.line as u32,
-                    });
+                    return Err(collect_server_error(
+                        &err,
+                        server_messages,
+                        &mut parser,
+                        None,
+                    ));
                 }
                 Token::Info(info) => {
+                    server_messages.push(server_info_message(&info));
                     tracing::info!(
                         number = info.number,
                         message = %info.message,
@@ -512,6 +686,7 @@ impl<S: ConnectionState> Client<S> {
     ///
     /// Token handling is order-tolerant and accumulative:
     /// - `ColMetaData` / `Row` / `NbcRow` / `DoneInProc`: collect result sets
+    ///   and per-statement row counts
     /// - `ReturnValue`: decode value via `parse_column_value()`, push as `OutputParam`
     /// - `ReturnStatus`: store as `return_value`
     /// - `DoneProc`: final token, break when `!more`
@@ -523,6 +698,7 @@ impl<S: ConnectionState> Client<S> {
         self.in_flight = false;
 
         let mut parser = self.create_parser(message.payload);
+        let mut server_messages: Vec<ServerMessage> = Vec::new();
         let mut result = crate::stream::ProcedureResult::new();
 
         // State for accumulating the current result set. Rows are staged as
@@ -609,6 +785,7 @@ impl<S: ConnectionState> Client<S> {
 
                     if done.status.count {
                         result.rows_affected += done.row_count;
+                        result.statement_row_counts.push(done.row_count);
                     }
                     if done.status.error {
                         return Err(Error::Query(
@@ -634,8 +811,12 @@ impl<S: ConnectionState> Client<S> {
                         crypto_metadata: None,
                     };
                     let mut buf = ret_val.value.as_ref();
-                    let sql_value =
-                        crate::column_parser::parse_column_value(&mut buf, &col_data, None)?;
+                    let sql_value = crate::column_parser::parse_column_value_with(
+                        &mut buf,
+                        &col_data,
+                        None,
+                        self.config.lenient_utf16,
+                    )?;
 
                     result.output_params.push(crate::stream::OutputParam {
                         name: ret_val.param_name,
@@ -683,25 +864,15 @@ impl<S: ConnectionState> Client<S> {
                     }
                 }
                 Token::Error(err) => {
-                    return Err(Error::Server {
-                        number: err.number,
-                        state: err.state,
-                        class: err.class,
-                        message: err.message.clone(),
-                        server: if err.server.is_empty() {
-                            None
-                        } else {
-                            Some(err.server.clone())
-                        },
-                        procedure: if err.procedure.is_empty() {
-                            None
-                        } else {
-                            Some(err.procedure.clone())
-                        },
-                        line: err.line as u32,
-                    });
+                    return Err(collect_server_error(
+                        &err,
+                        server_messages,
+                        &mut parser,
+                        protocol_metadata,
+                    ));
                 }
                 Token::Info(info) => {
+                    server_messages.push(server_info_message(&info));
                     tracing::debug!(
                         number = info.number,
                         message = %info.message,
@@ -709,7 +880,7 @@ impl<S: ConnectionState> Client<S> {
                     );
                 }
                 Token::EnvChange(env) => {
-                    Self::process_transaction_env_change(&env, &mut self.transaction_descriptor);
+                    self.apply_env_change(&env);
                 }
                 other => {
                     tracing::trace!(token = ?std::mem::discriminant(&other), "procedure: unhandled token");
@@ -725,6 +896,12 @@ impl<S: ConnectionState> Client<S> {
             "procedure response parsed"
         );
 
+        let lenient_utf16 = self.config.lenient_utf16;
+        result.result_sets = std::mem::take(&mut result.result_sets)
+            .into_iter()
+            .map(|rs| rs.with_lenient_utf16(lenient_utf16))
+            .collect();
+
         Ok(result)
     }
 }
@@ -733,16 +910,18 @@ impl<S: ConnectionState> Client<S> {
 use crate::state::Ready;
 
 impl Client<Ready> {
-    /// Read multiple result sets from a query response.
+    /// Read multiple result sets from a query response, along with the INFO
+    /// messages (PRINT output and the like) the server sent.
     pub(super) async fn read_multi_result_response(
         &mut self,
-    ) -> Result<Vec<crate::stream::ResultSet>> {
+    ) -> Result<(Vec<crate::stream::ResultSet>, Vec<ServerMessage>)> {
         let message = self.read_response_message().await?;
 
         // Full response received from wire — connection is clean for next request
         self.in_flight = false;
 
         let mut parser = self.create_parser(message.payload);
+        let mut server_messages: Vec<ServerMessage> = Vec::new();
         let mut result_sets: Vec<crate::stream::ResultSet> = Vec::new();
         let mut current_columns: Vec<crate::row::Column> = Vec::new();
         // Rows are staged as `PendingRow` slices so typed `Row` decode is
@@ -812,23 +991,12 @@ impl Client<Ready> {
                     }
                 }
                 Token::Error(err) => {
-                    return Err(Error::Server {
-                        number: err.number,
-                        state: err.state,
-                        class: err.class,
-                        message: err.message.clone(),
-                        server: if err.server.is_empty() {
-                            None
-                        } else {
-                            Some(err.server.clone())
-                        },
-                        procedure: if err.procedure.is_empty() {
-                            None
-                        } else {
-                            Some(err.procedure.clone())
-                        },
-                        line: err.line as u32,
-                    });
+                    return Err(collect_server_error(
+                        &err,
+                        server_messages,
+                        &mut parser,
+                        protocol_metadata,
+                    ));
                 }
                 Token::Done(done) => {
                     if done.status.error {
@@ -901,6 +1069,7 @@ impl Client<Ready> {
                     // DoneProc marks end of stored procedure, not necessarily end of results
                 }
                 Token::Info(info) => {
+                    server_messages.push(server_info_message(&info));
                     tracing::debug!(
                         number = info.number,
                         message = %info.message,
@@ -931,6 +1100,86 @@ impl Client<Ready> {
             ));
         }
 
-        Ok(result_sets)
+        let lenient_utf16 = self.config.lenient_utf16;
+        let result_sets = result_sets
+            .into_iter()
+            .map(|rs| rs.with_lenient_utf16(lenient_utf16))
+            .collect();
+        Ok((result_sets, server_messages))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use bytes::{BufMut, BytesMut};
+    use tds_protocol::token::TokenType;
+
+    /// Encode an ERROR or INFO token (identical layouts).
+    fn put_message(dst: &mut BytesMut, token: TokenType, number: i32, class: u8, message: &str) {
+        let msg: Vec<u16> = message.encode_utf16().collect();
+        dst.put_u8(token as u8);
+        dst.put_u16_le((4 + 1 + 1 + 2 + msg.len() * 2 + 1 + 1 + 4) as u16);
+        dst.put_i32_le(number);
+        dst.put_u8(1); // state
+        dst.put_u8(class);
+        dst.put_u16_le(msg.len() as u16);
+        for c in msg {
+            dst.put_u16_le(c);
+        }
+        dst.put_u8(0); // server
+        dst.put_u8(0); // procedure
+        dst.put_i32_le(1); // line
+    }
+
+    fn first_error(parser: &mut TokenParser) -> tds_protocol::token::ServerError {
+        match parser.next_token().unwrap() {
+            Some(Token::Error(err)) => err,
+            other => panic!("expected ERROR token, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_collect_server_error_gathers_trailing_messages() {
+        let mut buf = BytesMut::new();
+        put_message(&mut buf, TokenType::Error, 547, 16, "constraint violated");
+        put_message(&mut buf, TokenType::Info, 3621, 0, "statement terminated");
+        put_message(&mut buf, TokenType::Error, 2627, 14, "duplicate key");
+
+        let mut parser = TokenParser::new(buf.freeze());
+        let err = first_error(&mut parser);
+        let preceding = vec![ServerMessage {
+            number: 5701,
+            class: 0,
+            state: 1,
+            message: "changed database context".into(),
+            server: None,
+            procedure: None,
+            line: 1,
+        }];
+
+        let error = collect_server_error(&err, preceding, &mut parser, None);
+        assert!(error.is_server_error(547));
+        let numbers: Vec<i32> = error
+            .additional_messages()
+            .iter()
+            .map(|m| m.number)
+            .collect();
+        assert_eq!(numbers, vec![5701, 3621, 2627]);
+        assert!(!error.additional_messages()[1].is_error());
+        assert!(error.additional_messages()[2].is_error());
+    }
+
+    #[test]
+    fn test_collect_server_error_single_error_has_no_additional() {
+        let mut buf = BytesMut::new();
+        put_message(&mut buf, TokenType::Error, 208, 16, "invalid object");
+
+        let mut parser = TokenParser::new(buf.freeze());
+        let err = first_error(&mut parser);
+        let error = collect_server_error(&err, Vec::new(), &mut parser, None);
+        assert!(error.is_server_error(208));
+        assert!(error.additional_messages().is_empty());
     }
 }
@@ -0,0 +1,130 @@
+//! Sending a request message whose bytes are produced incrementally.
+//!
+//! Blob parameters and bulk loads can be far larger than what should be held
+//! in memory at once. [`StreamedMessage`] frames the bytes into full packets
+//! as they are appended and writes each one as soon as it fills, so only one
+//! packet is ever pending.
+
+use bytes::{Bytes, BytesMut};
+use tds_protocol::packet::{PACKET_HEADER_SIZE, PacketStatus, PacketType};
+
+use crate::error::{Error, Result};
+use crate::state::ConnectionState;
+
+use super::{Client, ConnectionHandle};
+
+impl<S: ConnectionState> Client<S> {
+    /// Send one packet of a request that is being streamed.
+    async fn write_request_packet(
+        &mut self,
+        packet_type: PacketType,
+        status: PacketStatus,
+        payload: Bytes,
+        first: bool,
+    ) -> Result<()> {
+        let connection = self.connection.as_mut().ok_or(Error::ConnectionClosed)?;
+        match connection {
+            #[cfg(feature = "tls")]
+            ConnectionHandle::Tls(conn) => {
+                conn.send_message_packet(packet_type, status, payload, first)
+                    .await?;
+            }
+            #[cfg(feature = "tls")]
+            ConnectionHandle::TlsPrelogin(conn) => {
+                conn.send_message_packet(packet_type, status, payload, first)
+                    .await?;
+            }
+            ConnectionHandle::Plain(conn) => {
+                conn.send_message_packet(packet_type, status, payload, first)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Frames a request into full packets as its bytes are produced.
+pub(crate) struct StreamedMessage {
+    packet_type: PacketType,
+    /// Bytes not yet sent; always less than one packet's payload after
+    /// [`extend`](Self::extend) returns.
+    pending: BytesMut,
+    max_payload: usize,
+    /// Set RESETCONNECTION on the first packet.
+    reset: bool,
+    /// No packet has been sent yet.
+    first: bool,
+}
+
+impl StreamedMessage {
+    pub(crate) fn new(packet_type: PacketType, max_packet_size: usize, reset: bool) -> Self {
+        let max_payload = max_packet_size.saturating_sub(PACKET_HEADER_SIZE).max(1);
+        Self {
+            packet_type,
+            pending: BytesMut::with_capacity(max_payload),
+            max_payload,
+            reset,
+            first: true,
+        }
+    }
+
+    /// Append bytes, sending every packet that fills up.
+    pub(crate) async fn extend<S: ConnectionState>(
+        &mut self,
+        client: &mut Client<S>,
+        data: &[u8],
+    ) -> Result<()> {
+        let mut data = data;
+        while !data.is_empty() {
+            // Keep a full packet pending rather than sending it, so the last
+            // packet of the message is never empty.
+            if self.pending.len() == self.max_payload {
+                let payload = self.pending.split().freeze();
+                self.send(client, PacketStatus::NORMAL, payload).await?;
+            }
+            let take = (self.max_payload - self.pending.len()).min(data.len());
+            self.pending.extend_from_slice(&data[..take]);
+            data = &data[take..];
+        }
+        Ok(())
+    }
+
+    /// Send the remaining bytes as the last packet.
+    pub(crate) async fn finish<S: ConnectionState>(mut self, client: &mut Client<S>) -> Result<()> {
+        let payload = self.pending.split().freeze();
+        self.send(client, PacketStatus::END_OF_MESSAGE, payload)
+            .await
+    }
+
+    /// End the message with IGNORE_EVENT so the server discards it.
+    pub(crate) async fn abandon<S: ConnectionState>(
+        mut self,
+        client: &mut Client<S>,
+    ) -> Result<()> {
+        if self.first {
+            // Nothing reached the server; there is no message to cancel.
+            return Ok(());
+        }
+        self.send(
+            client,
+            PacketStatus::END_OF_MESSAGE | PacketStatus::IGNORE_EVENT,
+            Bytes::new(),
+        )
+        .await
+    }
+
+    async fn send<S: ConnectionState>(
+        &mut self,
+        client: &mut Client<S>,
+        mut status: PacketStatus,
+        payload: Bytes,
+    ) -> Result<()> {
+        if self.first && self.reset {
+            status |= PacketStatus::RESET_CONNECTION;
+        }
+        let first = std::mem::replace(&mut self.first, false);
+        client
+            .write_request_packet(self.packet_type, status, payload, first)
+            .await
+    }
+}
@@ -86,12 +86,13 @@ pub(crate) fn convert_raw_row(
     raw: &RawRow,
     meta: &ColMetaData,
     row_meta: &Arc<crate::row::ColMetaData>,
+    lenient_utf16: bool,
 ) -> Result<crate::row::Row> {
     let mut values = Vec::with_capacity(meta.columns.len());
     let mut buf = raw.data.as_ref();
 
     for col in &meta.columns {
-        let value = parse_column_value(&mut buf, col, Some(&raw.data))?;
+        let value = parse_column_value_with(&mut buf, col, Some(&raw.data), lenient_utf16)?;
         values.push(value);
     }
 
@@ -108,6 +109,7 @@ pub(crate) fn convert_nbc_row(
     nbc: &NbcRow,
     meta: &ColMetaData,
     row_meta: &Arc<crate::row::ColMetaData>,
+    lenient_utf16: bool,
 ) -> Result<crate::row::Row> {
     let mut values = Vec::with_capacity(meta.columns.len());
     let mut buf = nbc.data.as_ref();
@@ -116,7 +118,7 @@ pub(crate) fn convert_nbc_row(
         if nbc.is_null(i) {
             values.push(mssql_types::SqlValue::Null);
         } else {
-            let value = parse_column_value(&mut buf, col, Some(&nbc.data))?;
+            let value = parse_column_value_with(&mut buf, col, Some(&nbc.data), lenient_utf16)?;
             values.push(value);
         }
     }
@@ -141,6 +143,7 @@ pub(crate) fn convert_raw_row_decrypted(
     meta: &ColMetaData,
     row_meta: &Arc<crate::row::ColMetaData>,
     decryptor: &crate::column_decryptor::ColumnDecryptor,
+    lenient_utf16: bool,
 ) -> Result<crate::row::Row> {
     let mut values = Vec::with_capacity(meta.columns.len());
     let mut buf = raw.data.as_ref();
@@ -149,7 +152,7 @@ pub(crate) fn convert_raw_row_decrypted(
         let value = if decryptor.is_encrypted(i) {
             decrypt_column(&mut buf, col, decryptor, i)?
         } else {
-            parse_column_value(&mut buf, col, Some(&raw.data))?
+            parse_column_value_with(&mut buf, col, Some(&raw.data), lenient_utf16)?
         };
         values.push(value);
     }
@@ -169,6 +172,7 @@ pub(crate) fn convert_nbc_row_decrypted(
     meta: &ColMetaData,
     row_meta: &Arc<crate::row::ColMetaData>,
     decryptor: &crate::column_decryptor::ColumnDecryptor,
+    lenient_utf16: bool,
 ) -> Result<crate::row::Row> {
     let mut values = Vec::with_capacity(meta.columns.len());
     let mut buf = nbc.data.as_ref();
@@ -180,7 +184,7 @@ pub(crate) fn convert_nbc_row_decrypted(
             let value = if decryptor.is_encrypted(i) {
                 decrypt_column(&mut buf, col, decryptor, i)?
             } else {
-                parse_column_value(&mut buf, col, Some(&nbc.data))?
+                parse_column_value_with(&mut buf, col, Some(&nbc.data), lenient_utf16)?
             };
             values.push(value);
         }
@@ -577,10 +581,24 @@ fn take_binary(buf: &mut &[u8], len: usize, src: Option<&bytes::Bytes>) -> bytes
 
 /// Parse one column value from `buf`. `src` is the row's backing `Bytes` (when
 /// available) so binary cells can slice it zero-copy; pass `None` to force copies.
+///
+/// Invalid UTF-16 in Unicode text is an error; see [`parse_column_value_with`].
 pub fn parse_column_value(
     buf: &mut &[u8],
     col: &ColumnData,
     src: Option<&bytes::Bytes>,
+) -> Result<SqlValue> {
+    parse_column_value_with(buf, col, src, false)
+}
+
+/// [`parse_column_value`] with the `Config::lenient_utf16` setting: when
+/// `lenient_utf16` is true, unpaired surrogates in NVARCHAR / NCHAR / NTEXT /
+/// XML / SQL_VARIANT text decode to U+FFFD instead of failing the row.
+pub(crate) fn parse_column_value_with(
+    buf: &mut &[u8],
+    col: &ColumnData,
+    src: Option<&bytes::Bytes>,
+    lenient_utf16: bool,
 ) -> Result<SqlValue> {
     let value = match col.type_id {
         // Fixed-length null type
@@ -671,9 +689,9 @@ pub fn parse_column_value(
         // Variable-length string types (BigVarChar, BigChar)
         TypeId::BigVarChar | TypeId::BigChar => parse_bigvarchar(buf, col)?,
         // NTEXT type - always uses PLP encoding (deprecated LOB type)
-        TypeId::NText => parse_plp_nvarchar(buf)?,
+        TypeId::NText => parse_plp_nvarchar(buf, &col.name, lenient_utf16)?,
         // Variable-length Unicode string types (NVarChar, NChar)
-        TypeId::NVarChar | TypeId::NChar => parse_nvarchar(buf, col)?,
+        TypeId::NVarChar | TypeId::NChar => parse_nvarchar(buf, col, lenient_utf16)?,
 
         // IMAGE type - always uses PLP encoding (deprecated LOB type)
         TypeId::Image => parse_plp_varbinary(buf)?,
@@ -683,11 +701,11 @@ pub fn parse_column_value(
         TypeId::BigVarBinary | TypeId::BigBinary => parse_bigvarbinary(buf, col, src)?,
 
         // XML type - always uses PLP encoding
-        TypeId::Xml => parse_xml(buf)?,
+        TypeId::Xml => parse_xml(buf, &col.name, lenient_utf16)?,
         // GUID/UniqueIdentifier
         TypeId::Guid => parse_guid(buf)?,
         // SQL_VARIANT - contains embedded type info
-        TypeId::Variant => parse_sql_variant(buf)?,
+        TypeId::Variant => parse_sql_variant(buf, &col.name, lenient_utf16)?,
         // UDT (User-Defined Type) - uses PLP encoding, return as binary
         TypeId::Udt => parse_plp_varbinary(buf)?,
 
@@ -1117,11 +1135,11 @@ fn parse_bigvarchar(buf: &mut &[u8], col: &ColumnData) -> Result<SqlValue> {
 }
 
 /// NVARCHAR / NCHAR — 2-byte length prefix (bytes), or PLP for the MAX variant.
-fn parse_nvarchar(buf: &mut &[u8], col: &ColumnData) -> Result<SqlValue> {
+fn parse_nvarchar(buf: &mut &[u8], col: &ColumnData, lenient_utf16: bool) -> Result<SqlValue> {
     // Check if this is a MAX type (uses PLP encoding)
     if col.type_info.max_length == Some(0xFFFF) {
         // PLP format: 8-byte total length, then chunks
-        return parse_plp_nvarchar(buf);
+        return parse_plp_nvarchar(buf, &col.name, lenient_utf16);
     }
     // 2-byte length prefix (in bytes, not chars) for non-MAX types
     if buf.remaining() < 2 {
@@ -1137,22 +1155,51 @@ fn parse_nvarchar(buf: &mut &[u8], col: &ColumnData) -> Result<SqlValue> {
             "unexpected EOF reading nvarchar data".into(),
         ));
     } else {
-        let data = &buf[..len as usize];
-        // UTF-16LE to String, decoded directly into a capacity-reserved String:
-        // no Vec<u16> intermediate and no realloc-growth churn (each is a heap
-        // allocation per cell). Char count <= code-unit count = len/2.
-        let mut s = String::with_capacity(len as usize / 2);
-        for unit in char::decode_utf16(
-            data.chunks_exact(2)
-                .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]])),
-        ) {
-            s.push(unit.map_err(|_| Error::Protocol("invalid UTF-16 in nvarchar".into()))?);
-        }
+        let s = decode_utf16_text(&buf[..len as usize], &col.name, lenient_utf16)?;
         buf.advance(len as usize);
         SqlValue::String(s)
     })
 }
 
+/// Decode UTF-16LE text from column `col_name`.
+///
+/// Decodes directly into a capacity-reserved String: no Vec<u16> intermediate
+/// and no realloc-growth churn (each is a heap allocation per cell). Char
+/// count <= code-unit count = len/2.
+///
+/// SQL Server stores unpaired surrogates without complaint. In strict mode
+/// one fails the value with an error naming the column and the byte offset of
+/// the bad code unit; with `lenient` it becomes U+FFFD, as
+/// `String::from_utf16_lossy` would produce.
+fn decode_utf16_text(data: &[u8], col_name: &str, lenient: bool) -> Result<String> {
+    let mut s = String::with_capacity(data.len() / 2);
+    let mut offset = 0usize;
+    for unit in char::decode_utf16(
+        data.chunks_exact(2)
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]])),
+    ) {
+        match unit {
+            Ok(c) => {
+                offset += c.len_utf16() * 2;
+                s.push(c);
+            }
+            Err(_) if lenient => {
+                offset += 2;
+                s.push(char::REPLACEMENT_CHARACTER);
+            }
+            Err(e) => {
+                return Err(Error::Protocol(format!(
+                    "invalid UTF-16 in column '{col_name}' at byte offset {offset}: \
+                     unpaired surrogate {:#06X} (enable Config::lenient_utf16 to \
+                     replace it with U+FFFD)",
+                    e.unpaired_surrogate()
+                )));
+            }
+        }
+    }
+    Ok(s)
+}
+
 /// BINARY / VARBINARY — legacy byte-length binary with a 1-byte length prefix.
 fn parse_legacy_varbinary(buf: &mut &[u8], src: Option<&bytes::Bytes>) -> Result<SqlValue> {
     if buf.remaining() < 1 {
@@ -1203,9 +1250,9 @@ fn parse_bigvarbinary(
 }
 
 /// XML — PLP-encoded UTF-16, surfaced as [`SqlValue::Xml`].
-fn parse_xml(buf: &mut &[u8]) -> Result<SqlValue> {
+fn parse_xml(buf: &mut &[u8], col_name: &str, lenient_utf16: bool) -> Result<SqlValue> {
     // Parse as PLP NVARCHAR (XML is UTF-16 encoded in TDS)
-    match parse_plp_nvarchar(buf)? {
+    match parse_plp_nvarchar(buf, col_name, lenient_utf16)? {
         SqlValue::Null => Ok(SqlValue::Null),
         SqlValue::String(s) => Ok(SqlValue::Xml(s)),
         _ => Err(Error::Protocol(
@@ -1265,7 +1312,11 @@ fn parse_default_binary(
 /// PLP format stored by decode_plp_type:
 /// - 8-byte total length (0xFFFFFFFFFFFFFFFF = NULL)
 /// - Chunks: 4-byte chunk length + chunk data, terminated by 0 length
-pub(crate) fn parse_plp_nvarchar(buf: &mut &[u8]) -> Result<SqlValue> {
+pub(crate) fn parse_plp_nvarchar(
+    buf: &mut &[u8],
+    col_name: &str,
+    lenient_utf16: bool,
+) -> Result<SqlValue> {
     if buf.remaining() < 8 {
         return Err(Error::Protocol(
             "unexpected EOF reading PLP total length".into(),
@@ -1298,13 +1349,7 @@ pub(crate) fn parse_plp_nvarchar(buf: &mut &[u8]) -> Result<SqlValue> {
         buf.advance(chunk_len);
     }
 
-    // Convert UTF-16LE to String
-    let utf16: Vec<u16> = all_data
-        .chunks_exact(2)
-        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
-        .collect();
-    let s = String::from_utf16(&utf16)
-        .map_err(|_| Error::Protocol("invalid UTF-16 in PLP nvarchar".into()))?;
+    let s = decode_utf16_text(&all_data, col_name, lenient_utf16)?;
     Ok(SqlValue::String(s))
 }
 
@@ -1318,6 +1363,12 @@ fn decode_varchar_string(data: &[u8], collation: Option<&Collation>) -> String {
     // Try collation-aware decoding first (handles GBK, Shift-JIS, etc.)
     #[cfg(feature = "encoding")]
     if let Some(coll) = collation {
+        // `_UTF8` collations carry UTF-8 on the wire (the server only sends
+        // them untranscoded after acknowledging the UTF-8 support feature).
+        // Skip encoding_rs, whose BOM sniffing would drop a leading U+FEFF.
+        if coll.is_utf8() {
+            return String::from_utf8_lossy(data).into_owned();
+        }
         if let Some(encoding) = coll.encoding() {
             let (decoded, _, had_errors) = encoding.decode(data);
             if !had_errors {
@@ -1414,7 +1465,7 @@ pub(crate) fn parse_plp_varbinary(buf: &mut &[u8]) -> Result<SqlValue> {
 /// - 1 byte: property byte count
 /// - N bytes: type-specific properties
 /// - Remaining bytes: actual data
-fn parse_sql_variant(buf: &mut &[u8]) -> Result<SqlValue> {
+fn parse_sql_variant(buf: &mut &[u8], col_name: &str, lenient_utf16: bool) -> Result<SqlValue> {
     // Read 4-byte length
     if buf.remaining() < 4 {
         return Err(Error::Protocol(
@@ -1519,7 +1570,7 @@ fn parse_sql_variant(buf: &mut &[u8]) -> Result<SqlValue> {
         0x2A => variant_datetime2(buf, prop_count, data_len),
         0x2B => variant_datetimeoffset(buf, prop_count, data_len),
         0xA7 | 0x2F | 0x27 => variant_varchar(buf, prop_count, data_len),
-        0xE7 | 0xEF => variant_nvarchar(buf, prop_count, data_len),
+        0xE7 | 0xEF => variant_nvarchar(buf, prop_count, data_len, col_name, lenient_utf16),
         0xA5 | 0x2D | 0x25 => {
             // BigVarBinary/BigBinary/Binary/VarBinary - 2 prop bytes (maxlen)
             buf.advance(prop_count);
@@ -1814,19 +1865,20 @@ fn variant_varchar(buf: &mut &[u8], prop_count: usize, data_len: usize) -> Resul
 }
 
 /// SQL_VARIANT NVarChar/NChar — 7 property bytes (collation 5 + max length 2).
-fn variant_nvarchar(buf: &mut &[u8], prop_count: usize, data_len: usize) -> Result<SqlValue> {
+fn variant_nvarchar(
+    buf: &mut &[u8],
+    prop_count: usize,
+    data_len: usize,
+    col_name: &str,
+    lenient_utf16: bool,
+) -> Result<SqlValue> {
     buf.advance(prop_count);
     if data_len == 0 {
         return Ok(SqlValue::String(String::new()));
     }
     // UTF-16LE encoded
-    let utf16: Vec<u16> = buf[..data_len]
-        .chunks_exact(2)
-        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
-        .collect();
+    let s = decode_utf16_text(&buf[..data_len], col_name, lenient_utf16)?;
     buf.advance(data_len);
-    let s = String::from_utf16(&utf16)
-        .map_err(|_| Error::Protocol("invalid UTF-16 in SQL_VARIANT nvarchar".into()))?;
     Ok(SqlValue::String(s))
 }
 
@@ -1903,7 +1955,7 @@ mod tests {
         let plp = make_plp_data(10, &[&utf16_data]);
         let mut buf: &[u8] = &plp;
 
-        let result = parse_plp_nvarchar(&mut buf).unwrap();
+        let result = parse_plp_nvarchar(&mut buf, "c", false).unwrap();
         match result {
             SqlValue::String(s) => assert_eq!(s, "Hello"),
             _ => panic!("expected String, got {result:?}"),
@@ -1916,7 +1968,7 @@ mod tests {
         let plp = 0xFFFFFFFFFFFFFFFFu64.to_le_bytes();
         let mut buf: &[u8] = &plp;
 
-        let result = parse_plp_nvarchar(&mut buf).unwrap();
+        let result = parse_plp_nvarchar(&mut buf, "c", false).unwrap();
         assert!(matches!(result, SqlValue::Null));
     }
 
@@ -1926,7 +1978,7 @@ mod tests {
         let plp = make_plp_data(0, &[]);
         let mut buf: &[u8] = &plp;
 
-        let result = parse_plp_nvarchar(&mut buf).unwrap();
+        let result = parse_plp_nvarchar(&mut buf, "c", false).unwrap();
         match result {
             SqlValue::String(s) => assert_eq!(s, ""),
             _ => panic!("expected empty String"),
@@ -1941,13 +1993,61 @@ mod tests {
         let plp = make_plp_data(10, &[&chunk1, &chunk2]);
         let mut buf: &[u8] = &plp;
 
-        let result = parse_plp_nvarchar(&mut buf).unwrap();
+        let result = parse_plp_nvarchar(&mut buf, "c", false).unwrap();
         match result {
             SqlValue::String(s) => assert_eq!(s, "Hello"),
             _ => panic!("expected String"),
         }
     }
 
+    #[test]
+    fn nvarchar_lone_surrogate_strict_names_column_and_offset() {
+        let col = ColumnData {
+            name: "comment".to_string(),
+            type_id: TypeId::NVarChar,
+            col_type: 0xE7,
+            flags: 0x01,
+            user_type: 0,
+            type_info: TypeInfo {
+                max_length: Some(100),
+                precision: None,
+                scale: None,
+                collation: None,
+            },
+            crypto_metadata: None,
+        };
+        // "a" + lone high surrogate + "b", 2-byte length prefix.
+        let data = [6u8, 0, 0x61, 0x00, 0x3D, 0xD8, 0x62, 0x00];
+
+        let mut buf: &[u8] = &data;
+        let err = parse_column_value(&mut buf, &col, None).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("'comment'"), "{msg}");
+        assert!(msg.contains("byte offset 2"), "{msg}");
+        assert!(msg.contains("0xD83D"), "{msg}");
+
+        let mut buf: &[u8] = &data;
+        let value = parse_column_value_with(&mut buf, &col, None, true).unwrap();
+        assert_eq!(value, SqlValue::String("a\u{FFFD}b".to_string()));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn plp_nvarchar_lone_surrogate_lenient_matches_from_utf16_lossy() {
+        // Valid pair (U+1F600) then a lone low surrogate, split across chunks.
+        let units = [0xD83Du16, 0xDE00, 0xDE00, 0x0063];
+        let bytes: Vec<u8> = units.iter().flat_map(|u| u.to_le_bytes()).collect();
+        let plp = make_plp_data(8, &[&bytes[..4], &bytes[4..]]);
+
+        let mut buf: &[u8] = &plp;
+        let err = parse_plp_nvarchar(&mut buf, "notes", false).unwrap_err();
+        assert!(err.to_string().contains("byte offset 4"), "{err}");
+
+        let mut buf: &[u8] = &plp;
+        let value = parse_plp_nvarchar(&mut buf, "notes", true).unwrap();
+        assert_eq!(value, SqlValue::String(String::from_utf16_lossy(&units)));
+    }
+
     #[test]
     fn test_parse_plp_varchar_simple() {
         let data = b"Hello World";
@@ -1961,6 +2061,17 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn test_decode_varchar_utf8_collation_is_passthrough() {
+        let utf8 = Collation {
+            lcid: 0x0400_0409, // Latin1_General_100_CI_AS_SC_UTF8
+            sort_id: 0,
+        };
+        let text = "\u{feff}h\u{e9}llo \u{4e16}\u{754c}";
+        assert_eq!(decode_varchar_string(text.as_bytes(), Some(&utf8)), text);
+    }
+
     #[test]
     fn test_parse_plp_varchar_null() {
         let plp = 0xFFFFFFFFFFFFFFFFu64.to_le_bytes();
@@ -2015,6 +2126,82 @@ mod tests {
         }
     }
 
+    /// Column metadata for a MAX type (`max_length == 0xFFFF`).
+    fn max_column(type_id: TypeId, col_type: u8) -> ColumnData {
+        ColumnData {
+            name: "doc".to_string(),
+            type_id,
+            col_type,
+            flags: 0x01,
+            user_type: 0,
+            type_info: TypeInfo {
+                max_length: Some(0xFFFF),
+                precision: None,
+                scale: None,
+                collation: None,
+            },
+            crypto_metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_column_value_max_types_reassemble_plp_chunks() {
+        // The server sends the UNKNOWN total length marker when it streams a
+        // value without knowing its size up front; chunks may split a UTF-16
+        // code unit. A trailing byte checks the cursor stops at the terminator.
+        const PLP_UNKNOWN_LEN: u64 = 0xFFFF_FFFF_FFFF_FFFE;
+
+        let text: Vec<u8> = "héllo wörld"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        let mut plp = make_plp_data(PLP_UNKNOWN_LEN, &[&text[..3], &text[3..10], &text[10..]]);
+        plp.push(0x2A);
+        let mut buf: &[u8] = &plp;
+        let value =
+            parse_column_value(&mut buf, &max_column(TypeId::NVarChar, 0xE7), None).unwrap();
+        assert_eq!(value, SqlValue::String("héllo wörld".into()));
+        assert_eq!(buf, [0x2A]);
+
+        let mut plp = make_plp_data(PLP_UNKNOWN_LEN, &[b"multi", b"-chunk"]);
+        plp.push(0x2A);
+        let mut buf: &[u8] = &plp;
+        let value =
+            parse_column_value(&mut buf, &max_column(TypeId::BigVarChar, 0xA7), None).unwrap();
+        assert_eq!(value, SqlValue::String("multi-chunk".into()));
+        assert_eq!(buf, [0x2A]);
+
+        let mut plp = make_plp_data(4, &[&[1, 2], &[3], &[4]]);
+        plp.push(0x2A);
+        let mut buf: &[u8] = &plp;
+        let value =
+            parse_column_value(&mut buf, &max_column(TypeId::BigVarBinary, 0xA5), None).unwrap();
+        assert_eq!(
+            value,
+            SqlValue::Binary(bytes::Bytes::from_static(&[1, 2, 3, 4]))
+        );
+        assert_eq!(buf, [0x2A]);
+    }
+
+    #[test]
+    fn test_parse_column_value_max_type_plp_null_and_truncation() {
+        let col = max_column(TypeId::NVarChar, 0xE7);
+
+        let null = 0xFFFF_FFFF_FFFF_FFFFu64.to_le_bytes();
+        let mut buf: &[u8] = &null;
+        assert_eq!(
+            parse_column_value(&mut buf, &col, None).unwrap(),
+            SqlValue::Null
+        );
+        assert!(buf.is_empty());
+
+        // Missing zero-length terminator.
+        let mut plp = make_plp_data(4, &[&[0x41, 0x00, 0x42, 0x00]]);
+        plp.truncate(plp.len() - 4);
+        let mut buf: &[u8] = &plp;
+        assert!(parse_column_value(&mut buf, &col, None).is_err());
+    }
+
     // ========================================================================
     // Multi-Column Row Parsing Tests
     // ========================================================================
@@ -2042,11 +2229,6 @@ mod tests {
         data
     }
 
-    // ========================================================================
-    // Hostile wire data: out-of-range date/time values from a malicious or
-    // buggy server must produce protocol errors, never panics.
-    // ========================================================================
-
     #[cfg(feature = "chrono")]
     fn datetime_col(type_id: TypeId, col_type: u8, max_length: Option<u32>) -> ColumnData {
         ColumnData {
@@ -2065,6 +2247,124 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // Temporal decoding from known wire bytes.
+    // ========================================================================
+
+    /// Decode one temporal cell at `scale`, checking the whole buffer is
+    /// consumed.
+    #[cfg(feature = "chrono")]
+    fn decode_temporal(
+        type_id: TypeId,
+        col_type: u8,
+        max_length: Option<u32>,
+        scale: Option<u8>,
+        data: &[u8],
+    ) -> SqlValue {
+        let mut col = datetime_col(type_id, col_type, max_length);
+        col.type_info.scale = scale;
+        let mut buf: &[u8] = data;
+        let value = parse_column_value(&mut buf, &col, None).unwrap();
+        assert!(buf.is_empty(), "{type_id:?} left {} bytes", buf.len());
+        value
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn temporal_types_decode_known_wire_bytes() {
+        use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeZone};
+
+        let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+
+        // DATE: 3-byte day count since 0001-01-01 (738_959).
+        assert_eq!(
+            decode_temporal(TypeId::Date, 0x28, None, None, &[3, 0x8F, 0x46, 0x0B]),
+            SqlValue::Date(date)
+        );
+
+        // TIME(3): milliseconds since midnight in 4 bytes.
+        assert_eq!(
+            decode_temporal(
+                TypeId::Time,
+                0x29,
+                None,
+                Some(3),
+                &[4, 0x95, 0x2C, 0xB3, 0x02]
+            ),
+            SqlValue::Time(NaiveTime::from_hms_milli_opt(12, 34, 56, 789).unwrap())
+        );
+
+        // DATETIME2(7): 100ns intervals (5 bytes) then the date (3 bytes).
+        assert_eq!(
+            decode_temporal(
+                TypeId::DateTime2,
+                0x2A,
+                None,
+                Some(7),
+                &[8, 0x22, 0x81, 0xFD, 0x76, 0x69, 0x8F, 0x46, 0x0B]
+            ),
+            SqlValue::DateTime(
+                date.and_time(NaiveTime::from_hms_nano_opt(12, 34, 56, 789_123_400).unwrap())
+            )
+        );
+
+        // DATETIMEOFFSET(7): the UTC instant (06:30), then +330 minutes.
+        let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+        assert_eq!(
+            decode_temporal(
+                TypeId::DateTimeOffset,
+                0x2B,
+                None,
+                Some(7),
+                &[
+                    10, 0x00, 0xA4, 0x7C, 0x7B, 0x36, 0x8F, 0x46, 0x0B, 0x4A, 0x01
+                ]
+            ),
+            SqlValue::DateTimeOffset(offset.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap())
+        );
+
+        // DATETIME: days since 1900-01-01 (45_364) then 1/300 s ticks.
+        assert_eq!(
+            decode_temporal(
+                TypeId::DateTimeN,
+                0x6F,
+                Some(8),
+                None,
+                &[8, 0x34, 0xB1, 0x00, 0x00, 0x00, 0xC1, 0xC5, 0x00]
+            ),
+            SqlValue::DateTime(date.and_hms_opt(12, 0, 0).unwrap())
+        );
+
+        // SMALLDATETIME: days since 1900-01-01 then minutes since midnight.
+        assert_eq!(
+            decode_temporal(
+                TypeId::DateTimeN,
+                0x6F,
+                Some(4),
+                None,
+                &[4, 0x34, 0xB1, 0xF2, 0x02]
+            ),
+            SqlValue::DateTime(date.and_hms_opt(12, 34, 0).unwrap())
+        );
+
+        // Fixed-width DATETIME carries no length prefix.
+        assert_eq!(
+            decode_temporal(
+                TypeId::DateTime,
+                0x3D,
+                None,
+                None,
+                &[0x34, 0xB1, 0x00, 0x00, 0x00, 0xC1, 0xC5, 0x00]
+            ),
+            SqlValue::DateTime(date.and_hms_opt(12, 0, 0).unwrap())
+        );
+    }
+
+    // ========================================================================
+    // Hostile wire data: out-of-range date/time values from a malicious or
+    // buggy server must produce protocol errors, never panics.
+    // ========================================================================
+
     #[cfg(feature = "chrono")]
     #[test]
     fn hostile_smalldatetime_minutes_is_error_not_panic() {
@@ -2166,7 +2466,7 @@ mod tests {
         data.extend_from_slice(&i32::MAX.to_le_bytes());
         data.extend_from_slice(&0u32.to_le_bytes());
         let mut buf: &[u8] = &data;
-        assert!(parse_sql_variant(&mut buf).is_err());
+        assert!(parse_sql_variant(&mut buf, "c", false).is_err());
     }
 
     #[cfg(feature = "decimal")]
@@ -2189,7 +2489,8 @@ mod tests {
         data.push(0x01); // sign (positive)
         data.extend_from_slice(&mantissa.to_le_bytes());
         let mut buf: &[u8] = &data;
-        let err = parse_sql_variant(&mut buf).expect_err("oversized NUMERIC must error");
+        let err =
+            parse_sql_variant(&mut buf, "c", false).expect_err("oversized NUMERIC must error");
         assert!(
             err.to_string().contains("rust_decimal"),
             "error should explain the range limitation: {err}"
@@ -2211,10 +2512,54 @@ mod tests {
         data.push(0x01); // sign (positive)
         data.extend_from_slice(&12345u16.to_le_bytes()); // mantissa LE
         let mut buf: &[u8] = &data;
-        let value = parse_sql_variant(&mut buf).expect("valid NUMERIC must decode");
+        let value = parse_sql_variant(&mut buf, "c", false).expect("valid NUMERIC must decode");
         assert_eq!(value, SqlValue::Decimal("123.45".parse().unwrap()));
     }
 
+    /// MONEY is an i64 scaled by 10^4, sent as the high then the low 32 bits.
+    fn money_wire(scaled: i64) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&((scaled >> 32) as i32).to_le_bytes());
+        data.extend_from_slice(&(scaled as u32).to_le_bytes());
+        data
+    }
+
+    /// MONEY and SMALLMONEY decode exactly at scale 4, including the extremes
+    /// an f64 cannot represent.
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn money_decodes_to_exact_decimal() {
+        for (scaled, expected) in [
+            (i64::MAX, "922337203685477.5807"),
+            (i64::MIN, "-922337203685477.5808"),
+            (12_345, "1.2345"),
+        ] {
+            let data = money_wire(scaled);
+            let value = parse_money(&mut data.as_slice(), TypeId::Money).unwrap();
+            assert_eq!(value, SqlValue::Decimal(expected.parse().unwrap()));
+
+            // MONEYN carries a length byte in front of the same encoding.
+            let data = [vec![8u8], money_wire(scaled)].concat();
+            let value = parse_money(&mut data.as_slice(), TypeId::MoneyN).unwrap();
+            assert_eq!(value, SqlValue::Decimal(expected.parse().unwrap()));
+        }
+
+        let data = i32::MAX.to_le_bytes();
+        let value = parse_money(&mut data.as_slice(), TypeId::Money4).unwrap();
+        assert_eq!(value, SqlValue::Decimal("214748.3647".parse().unwrap()));
+
+        let value = parse_money(&mut [0u8].as_slice(), TypeId::MoneyN).unwrap();
+        assert_eq!(value, SqlValue::Null);
+    }
+
+    #[cfg(not(feature = "decimal"))]
+    #[test]
+    fn money_decodes_to_double_without_decimal_feature() {
+        let data = money_wire(12_345);
+        let value = parse_money(&mut data.as_slice(), TypeId::Money).unwrap();
+        assert_eq!(value, SqlValue::Double(1.2345));
+    }
+
     /// A SQL_VARIANT DECIMALN whose payload exceeds the 17-byte NUMERIC maximum
     /// (sign + 16 mantissa) is malformed: it decodes to Null, not through the
     /// shared decoder. data_len = 18 (sign + 17 mantissa); total_len = 22.
@@ -2229,7 +2574,8 @@ mod tests {
         data.push(0x01); // sign
         data.extend_from_slice(&[0u8; 17]); // 17 mantissa bytes => data_len 18
         let mut buf: &[u8] = &data;
-        let value = parse_sql_variant(&mut buf).expect("oversized payload must not error");
+        let value =
+            parse_sql_variant(&mut buf, "c", false).expect("oversized payload must not error");
         assert_eq!(value, SqlValue::Null);
         assert!(buf.is_empty(), "the whole payload must be consumed");
     }
@@ -2437,4 +2783,44 @@ mod tests {
 
         assert_eq!(buf.len(), 0, "buffer should be fully consumed");
     }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn guid_decodes_mixed_endian_to_uuid() {
+        let col = ColumnData {
+            name: "id".to_string(),
+            type_id: TypeId::Guid,
+            col_type: 0x24,
+            flags: 0x01,
+            user_type: 0,
+            type_info: TypeInfo {
+                max_length: Some(16),
+                precision: None,
+                scale: None,
+                collation: None,
+            },
+            crypto_metadata: None,
+        };
+        let expected = uuid::Uuid::parse_str("6f9619ff-8b86-d011-b42d-00c04fc964ff").unwrap();
+
+        // First three groups little-endian on the wire, last eight bytes as-is.
+        let data = [
+            16u8, 0xFF, 0x19, 0x96, 0x6F, 0x86, 0x8B, 0x11, 0xD0, 0xB4, 0x2D, 0x00, 0xC0, 0x4F,
+            0xC9, 0x64, 0xFF,
+        ];
+        let mut buf: &[u8] = &data;
+        let value = parse_column_value(&mut buf, &col, None).unwrap();
+        assert_eq!(value, SqlValue::Uuid(expected));
+        assert!(buf.is_empty());
+        assert_eq!(
+            <uuid::Uuid as mssql_types::FromSql>::from_sql(&value).unwrap(),
+            expected
+        );
+
+        let mut buf: &[u8] = &[0u8];
+        assert_eq!(
+            parse_column_value(&mut buf, &col, None).unwrap(),
+            SqlValue::Null
+        );
+    }
 }
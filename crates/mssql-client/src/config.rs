@@ -2,6 +2,7 @@
 //!
 //! Supporting types (`RedirectConfig`, `TimeoutConfig`, `RetryPolicy`) live in
 //! the `types` submodule and are re-exported here for convenience.
+//! [`Config::validate`] checks a configuration for mistakes without connecting.
 //!
 //! ## Connection strings
 //!
@@ -24,6 +25,25 @@
 //! # }
 //! ```
 //!
+//! Every keyword also has a fluent setter on [`Config`], which avoids
+//! interpolating user input into a connection string. The two forms build the
+//! same configuration:
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use mssql_client::{Config, Credentials};
+//!
+//! let config = Config::new()
+//!     .host("myserver.database.windows.net")
+//!     .port(1433)
+//!     .database("mydb")
+//!     .credentials(Credentials::sql_server("user", "secret"))
+//!     .strict_mode(true);
+//! # let _ = config;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! **Quoting.** A value with `;` or `=` may be wrapped in single or double
 //! quotes; double the quote char to escape it: `Password="my;pass"`,
 //! `Password='it''s complex'`.
@@ -52,6 +72,7 @@
 //! |---------|---------|---------|-------------|
 //! | `Encrypt` | — | `false` | `strict` (TDS 8.0, SQL Server 2022+), `mandatory`/`true`, `optional`, `no_tls`, or a boolean. |
 //! | `TrustServerCertificate` | `Trust Server Certificate` | `false` | Skip certificate validation (development only). |
+//! | `Column Encryption Setting` | — | `Disabled` | `Enabled` turns on Always Encrypted (parameter encryption metadata and result decryption); requires the `always-encrypted` feature, and is an error without it. Register key store providers with `Config::with_column_encryption`. |
 //!
 //! ### Timeouts
 //!
@@ -81,8 +102,9 @@
 //!
 //! | Keyword | Aliases | Default | Description |
 //! |---------|---------|---------|-------------|
-//! | `MultipleActiveResultSets` | `MARS` | `false` | MARS (not fully supported). |
-//! | `Packet Size` | — | `4096` | TDS packet size in bytes. |
+//! | `MultipleActiveResultSets` | `MARS` | `false` | Accepted, but MARS is never negotiated (warns). |
+//! | `Packet Size` | `PacketSize` | `4096` | TDS packet size in bytes requested at login. The server may grant a different size. |
+//! | `TDS Version` | `TDSVersion`, `Protocol Version`, `ProtocolVersion` | `7.4` | TDS version requested at login: `7.0`, `7.1`, `7.2`, `7.3` (or `7.3A`), `7.3B`, `7.4`, or `8.0` (implies `Encrypt=strict`). Versions before 7.4 send no LOGIN7 feature extensions, so UTF-8 is not negotiated and Azure AD logins and Always Encrypted are rejected; use `7.3` to work around handshake problems with older servers. |
 //! | `SendStringParametersAsUnicode` | `Send String Parameters As Unicode` | `true` | When `false`, sends `String`/`&str` params as VARCHAR (Windows-1252) instead of NVARCHAR (UTF-16) so SQL Server can index-seek VARCHAR columns. |
 //!
 //! Booleans accept `true`/`false`/`yes`/`no`/`1`/`0` (case-insensitive); an
@@ -106,7 +128,9 @@
 //! connection string — it contains the password.
 
 mod types;
+mod validate;
 pub use types::*;
+pub use validate::ConfigWarning;
 
 use std::time::Duration;
 
@@ -130,6 +154,12 @@ fn parse_conn_bool(key: &str, value: &str) -> Result<bool, crate::error::Error>
     }
 }
 
+/// Logged when strict mode and `TrustServerCertificate` are combined, by
+/// whichever setter comes second.
+fn warn_strict_with_trust_server_certificate() {
+    tracing::warn!("{}", ConfigWarning::StrictWithTrustServerCertificate);
+}
+
 /// Split a connection string into key-value pairs, respecting quoted values.
 ///
 /// Per the ADO.NET specification:
@@ -242,7 +272,8 @@ fn non_empty(value: &str) -> Option<String> {
 ///
 /// This struct is marked `#[non_exhaustive]` to allow adding new fields
 /// in future releases without breaking semver. Use [`Config::default()`]
-/// or [`Config::from_connection_string()`] to construct instances.
+/// or [`Config::from_connection_string()`] to construct instances, and the
+/// fluent setters (one per connection-string keyword) to adjust them.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct Config {
@@ -282,7 +313,15 @@ pub struct Config {
     /// Paginate, narrow the SELECT, or raise the cap.
     pub max_response_size: usize,
 
-    /// TDS packet size.
+    /// TDS packet size requested in LOGIN7, in bytes. SQL Server accepts
+    /// 512 to 32767; [`Config::validate`] flags anything outside that.
+    ///
+    /// The server answers with the size it actually granted, which the
+    /// connection then uses for every request (see
+    /// [`Client::packet_size`](crate::Client::packet_size)). Larger packets
+    /// mean fewer round trips for big requests on high-latency links.
+    ///
+    /// Default: `4096`
     pub packet_size: u16,
 
     /// Whether to use TDS 8.0 strict mode.
@@ -294,7 +333,13 @@ pub struct Config {
     /// Instance name (for named instances).
     pub instance: Option<String>,
 
-    /// Whether to enable MARS (Multiple Active Result Sets).
+    /// Whether MARS (Multiple Active Result Sets) was requested.
+    ///
+    /// Accepted for connection-string compatibility only. MARS is not
+    /// supported (ARCHITECTURE.md ADR-006), so it is never negotiated: a
+    /// warning is logged at connect time and [`Config::validate`] reports
+    /// [`ConfigWarning::MarsNotSupported`]. Run concurrent queries on separate
+    /// pooled connections instead.
     pub mars: bool,
 
     /// Whether to require encryption (TLS).
@@ -328,6 +373,23 @@ pub struct Config {
     /// Retry policy for transient error handling.
     pub retry: RetryPolicy,
 
+    /// Retry the first query on a fresh connection if it fails transiently.
+    ///
+    /// During an Azure SQL failover the gateway can complete TCP setup and
+    /// login, then fail the very first request (error 40613, a reset
+    /// connection). When `true`, if the first [`query`](crate::Client::query)
+    /// or [`execute`](crate::Client::execute) after connecting fails with a
+    /// [transient](crate::Error::is_transient) error, the client reconnects
+    /// once with this configuration and re-runs that request. Later requests
+    /// are never retried.
+    ///
+    /// Opt-in because a statement that failed mid-flight may already have
+    /// run on the server. Set via `Retry First Query=true` in connection
+    /// strings.
+    ///
+    /// Default: `false`
+    pub retry_first_query: bool,
+
     /// Timeout configuration for various connection phases.
     pub timeouts: TimeoutConfig,
 
@@ -412,6 +474,103 @@ pub struct Config {
     /// Default: `false`
     pub statement_cache: bool,
 
+    /// Maximum number of prepared statements cached per connection.
+    ///
+    /// When the cache is full, the least recently used statement is evicted
+    /// and its server-side handle released with `sp_unprepare`, bounding the
+    /// number of prepared handles a long-lived connection holds. Only
+    /// consulted when [`statement_cache`](Self::statement_cache) is enabled.
+    /// Values below 1 are treated as 1. Set via `Statement Cache Size=N` in
+    /// connection strings.
+    ///
+    /// Default: `256`
+    pub statement_cache_size: usize,
+
+    /// Send identical positional parameter values only once.
+    ///
+    /// When `true`, parameters of a [`query`](crate::Client::query) or
+    /// [`execute`](crate::Client::execute) call whose type and value are
+    /// byte-for-byte identical on the wire are collapsed onto the first
+    /// occurrence's name, and references to the dropped names (`@p3`) in the
+    /// SQL text are rewritten (`@p1`). String literals, quoted identifiers and
+    /// comments are left untouched. Useful when a large value is compared in
+    /// several places of one statement.
+    ///
+    /// Opt-in because the SQL text the server sees differs from the one
+    /// passed in. Does not apply to Always Encrypted parameters. Set via
+    /// `Deduplicate Parameters=true` in connection strings.
+    ///
+    /// Default: `false`
+    pub deduplicate_params: bool,
+
+    /// Fail on response tokens the parser would otherwise skip.
+    ///
+    /// By default, tokens that carry nothing the driver surfaces (ColInfo,
+    /// TabName, Offset, COMPUTE BY rows) are consumed and dropped. When
+    /// `true`, receiving one fails the request with
+    /// [`Error::ProtocolError`](crate::Error::ProtocolError) naming the token
+    /// byte and its offset, so protocol bugs surface loudly in tests and CI
+    /// instead of corrupting data quietly. Set via `Strict Protocol=true` in
+    /// connection strings.
+    ///
+    /// Default: `false`
+    pub strict_protocol: bool,
+
+    /// Replace invalid UTF-16 in Unicode text columns instead of failing.
+    ///
+    /// SQL Server will store NVARCHAR data containing unpaired surrogates
+    /// (e.g. truncated emoji written by a client that split a pair). By
+    /// default such a value fails the row with
+    /// [`Error::Protocol`](crate::Error::Protocol) naming the column and the
+    /// byte offset of the bad code unit. When `true`, each unpaired surrogate
+    /// decodes to U+FFFD, as `String::from_utf16_lossy` does, so one dirty
+    /// cell no longer fails the whole query. Set via `Lenient UTF16=true` in
+    /// connection strings.
+    ///
+    /// Default: `false`
+    pub lenient_utf16: bool,
+
+    /// Run `SET XACT_ABORT ON` for every session.
+    ///
+    /// With `XACT_ABORT` on, any run-time error inside a transaction (a
+    /// constraint violation, a conversion error, a command timeout's
+    /// attention) rolls back the whole transaction server-side instead of
+    /// just the failing statement. The option is applied right after login
+    /// and re-applied after every connection reset, since RESETCONNECTION
+    /// (used by the pool on check-in) restores the login defaults.
+    ///
+    /// The driver's transaction tracking follows the server: an aborted
+    /// transaction is reported through the transaction ENVCHANGE, which
+    /// clears the descriptor the client sends, so a later `commit` or
+    /// `rollback` on a [`Client<InTransaction>`](crate::Client) surfaces the
+    /// server's "no corresponding BEGIN TRANSACTION" error (3902 / 3903)
+    /// rather than silently committing partial work.
+    ///
+    /// Default: `false`
+    pub xact_abort: bool,
+
+    /// Reject statements that would write, before they reach the server.
+    ///
+    /// A guardrail for services that must never write (analytics against a
+    /// read replica, reporting). Every statement passed to `query*`,
+    /// `execute*` and `simple_query` is scanned, and one containing a bare
+    /// `INSERT`, `UPDATE`, `DELETE`, `MERGE`, `TRUNCATE`, `INTO`, `CREATE`,
+    /// `ALTER`, `DROP`, `GRANT`, `REVOKE` or `DENY` keyword fails with
+    /// [`Error::WriteAttemptOnReadOnly`](crate::Error::WriteAttemptOnReadOnly).
+    /// String literals, comments, bracketed or quoted identifiers and
+    /// qualified names (`t.[update]`, `t.update`) are ignored. Bulk inserts are
+    /// rejected outright.
+    ///
+    /// This inspects the SQL text only: writes inside stored procedures
+    /// (`EXEC`, [`call_procedure`](crate::Client::call_procedure)) or dynamic
+    /// SQL are not seen. Pair it with a login that has only read permissions,
+    /// or with `ApplicationIntent=ReadOnly` against an availability group
+    /// secondary, for enforcement by the server. Set via
+    /// `Read Only Guard=true` in connection strings.
+    ///
+    /// Default: `false`
+    pub read_only_guard: bool,
+
     /// Always Encrypted configuration.
     ///
     /// When `Some`, the client will negotiate Always Encrypted support with the
@@ -425,6 +584,18 @@ pub struct Config {
     /// `Clone` while sharing the encryption configuration.
     #[cfg(feature = "always-encrypted")]
     pub column_encryption: Option<std::sync::Arc<crate::encryption::EncryptionConfig>>,
+
+    /// Custom host name resolver.
+    ///
+    /// When `None` (default), the host is resolved with the system resolver
+    /// ([`SystemResolver`](crate::resolver::SystemResolver)). Set via
+    /// [`Config::with_resolver`].
+    pub resolver: Option<std::sync::Arc<dyn crate::resolver::Resolver>>,
+
+    /// Expert hook that edits the LOGIN7 packet before it is encoded.
+    ///
+    /// Set via [`Config::login7_customizer`]. Default: `None`.
+    pub login7_customizer: Option<Login7Customizer>,
 }
 
 impl Default for Config {
@@ -450,6 +621,7 @@ impl Default for Config {
             no_tls: false, // Never plaintext by default
             redirect: RedirectConfig::default(),
             retry: RetryPolicy::default(),
+            retry_first_query: false,
             timeouts,
             tds_version: TdsVersion::V7_4, // Default to TDS 7.4 for broad compatibility
             application_intent: ApplicationIntent::default(),
@@ -458,8 +630,16 @@ impl Default for Config {
             multi_subnet_failover: false,
             send_string_parameters_as_unicode: true,
             statement_cache: false,
+            statement_cache_size: crate::statement_cache::DEFAULT_MAX_STATEMENTS,
+            deduplicate_params: false,
+            strict_protocol: false,
+            lenient_utf16: false,
+            xact_abort: false,
+            read_only_guard: false,
             #[cfg(feature = "always-encrypted")]
             column_encryption: None,
+            resolver: None,
+            login7_customizer: None,
         }
     }
 }
@@ -594,7 +774,7 @@ impl Config {
                 }
                 // --- Security ---
                 "trustservercertificate" | "trust server certificate" => {
-                    config.trust_server_certificate = parse_conn_bool(&key, value)?;
+                    config = config.trust_server_certificate(parse_conn_bool(&key, value)?);
                 }
                 "encrypt" => {
                     // Encrypt supports several non-boolean values beyond true/false:
@@ -605,7 +785,7 @@ impl Config {
                     //
                     // "mandatory" and "optional" are Microsoft.Data.SqlClient v5+ aliases.
                     if value.eq_ignore_ascii_case("strict") {
-                        config.strict_mode = true;
+                        config = config.strict_mode(true);
                         config.encrypt = true;
                         config.no_tls = false;
                     } else if value.eq_ignore_ascii_case("mandatory") {
@@ -645,39 +825,50 @@ impl Config {
                 }
                 // --- Always Encrypted ---
                 "column encryption setting" | "columnencryptionsetting" => {
-                    #[cfg(feature = "always-encrypted")]
-                    if value.eq_ignore_ascii_case("enabled") {
-                        config.column_encryption = Some(std::sync::Arc::new(
-                            crate::encryption::EncryptionConfig::new(),
-                        ));
-                    }
-                    #[cfg(not(feature = "always-encrypted"))]
+                    // ADO.NET accepts exactly Enabled / Disabled; anything else
+                    // is a typo that would otherwise silently leave Always
+                    // Encrypted off.
                     if value.eq_ignore_ascii_case("enabled") {
+                        #[cfg(feature = "always-encrypted")]
+                        {
+                            config.column_encryption = Some(std::sync::Arc::new(
+                                crate::encryption::EncryptionConfig::new(),
+                            ));
+                        }
+                        #[cfg(not(feature = "always-encrypted"))]
                         return Err(crate::error::Error::Config(
                             "Column Encryption Setting=Enabled requires the 'always-encrypted' feature. \
                              Enable it in your Cargo.toml: mssql-client = { features = [\"always-encrypted\"] }"
                                 .to_string(),
                         ));
+                    } else if value.eq_ignore_ascii_case("disabled") {
+                        #[cfg(feature = "always-encrypted")]
+                        {
+                            config.column_encryption = None;
+                        }
+                    } else {
+                        return Err(crate::error::Error::Config(format!(
+                            "invalid Column Encryption Setting: '{value}' (expected Enabled or Disabled)"
+                        )));
                     }
                 }
                 // --- Protocol ---
                 "multipleactiveresultsets" | "mars" => {
                     config.mars = parse_conn_bool(&key, value)?;
                 }
-                "packet size" => {
+                "packet size" | "packetsize" => {
                     config.packet_size = value.parse().map_err(|_| {
                         crate::error::Error::Config(format!("invalid packet size: {value}"))
                     })?;
                 }
                 "tdsversion" | "tds version" | "protocolversion" | "protocol version" => {
-                    config.tds_version = TdsVersion::parse(value).ok_or_else(|| {
+                    let version = TdsVersion::parse(value).ok_or_else(|| {
                         crate::error::Error::Config(format!(
-                            "invalid TDS version: {value}. Supported values: 7.3, 7.3A, 7.3B, 7.4, 8.0"
+                            "invalid TDS version: {value}. Supported values: 7.0, 7.1, 7.2, \
+                             7.3 (7.3A), 7.3B, 7.4, 8.0"
                         ))
                     })?;
-                    if config.tds_version.is_tds_8() {
-                        config.strict_mode = true;
-                    }
+                    config = config.tds_version(version);
                 }
                 // --- Connection resiliency ---
                 "connectretrycount" | "connect retry count" => {
@@ -719,6 +910,30 @@ impl Config {
                 "statement cache" | "statementcache" => {
                     config.statement_cache = parse_conn_bool(&key, value)?;
                 }
+                "statement cache size" | "statementcachesize" => {
+                    config.statement_cache_size = value.parse().map_err(|_| {
+                        crate::error::Error::Config(format!(
+                            "invalid statement cache size: {value}"
+                        ))
+                    })?;
+                }
+                // --- First-query retry (opt-in) ---
+                "retry first query" | "retryfirstquery" => {
+                    config.retry_first_query = parse_conn_bool(&key, value)?;
+                }
+                // --- Parameter de-duplication (opt-in) ---
+                "deduplicate parameters" | "deduplicateparameters" => {
+                    config.deduplicate_params = parse_conn_bool(&key, value)?;
+                }
+                "strict protocol" | "strictprotocol" => {
+                    config.strict_protocol = parse_conn_bool(&key, value)?;
+                }
+                "lenient utf16" | "lenientutf16" => {
+                    config.lenient_utf16 = parse_conn_bool(&key, value)?;
+                }
+                "read only guard" | "readonlyguard" => {
+                    config.read_only_guard = parse_conn_bool(&key, value)?;
+                }
                 // --- Known ADO.NET keywords not supported by this driver ---
                 "failover partner"
                 | "persist security info"
@@ -908,6 +1123,14 @@ impl Config {
         self
     }
 
+    /// Set the named instance (`Server=host\INSTANCE`), resolved through the
+    /// SQL Browser service at connect time.
+    #[must_use]
+    pub fn instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
     /// Set the database name.
     #[must_use]
     pub fn database(mut self, database: impl Into<String>) -> Self {
@@ -936,9 +1159,41 @@ impl Config {
         self
     }
 
+    /// Set the command timeout (`Command Timeout`).
+    #[must_use]
+    pub fn command_timeout(mut self, timeout: Duration) -> Self {
+        self.command_timeout = timeout;
+        self
+    }
+
+    /// Set the TDS packet size requested at login (`Packet Size`).
+    ///
+    /// See [`Config::packet_size`]; [`Config::validate`] flags sizes SQL
+    /// Server rejects.
+    #[must_use]
+    pub fn packet_size(mut self, size: u16) -> Self {
+        self.packet_size = size;
+        self
+    }
+
+    /// Request MARS (`MultipleActiveResultSets`). Not supported; see the
+    /// `mars` field.
+    #[must_use]
+    pub fn mars(mut self, enabled: bool) -> Self {
+        self.mars = enabled;
+        self
+    }
+
     /// Set trust server certificate option.
+    ///
+    /// Trusting the certificate defeats strict mode's certificate
+    /// validation, so combining the two logs a warning (and
+    /// [`Config::validate`] reports it as an error).
     #[must_use]
     pub fn trust_server_certificate(mut self, trust: bool) -> Self {
+        if trust && self.strict_mode {
+            warn_strict_with_trust_server_certificate();
+        }
         self.trust_server_certificate = trust;
         #[cfg(feature = "tls")]
         {
@@ -950,6 +1205,9 @@ impl Config {
     /// Enable TDS 8.0 strict mode.
     #[must_use]
     pub fn strict_mode(mut self, enabled: bool) -> Self {
+        if enabled && self.trust_server_certificate {
+            warn_strict_with_trust_server_certificate();
+        }
         self.strict_mode = enabled;
         #[cfg(feature = "tls")]
         {
@@ -1023,6 +1281,12 @@ impl Config {
     /// like rustls require TLS 1.2 or higher for security reasons, making it
     /// impossible to establish encrypted connections to these older servers.
     ///
+    /// It is also the way to measure the packet-read and row-decode path
+    /// without TLS in the numbers: against a local server (or the
+    /// `mssql-testing` mock server) with encryption off, connect, `query` and
+    /// `execute` all run over a plain TCP stream. The connection fails rather
+    /// than falling back if the server insists on encryption.
+    ///
     /// # Security implications
     ///
     /// When enabled:
@@ -1111,6 +1375,60 @@ impl Config {
         self
     }
 
+    /// Set the maximum number of prepared statements cached per connection.
+    ///
+    /// See [`Config::statement_cache_size`].
+    #[must_use]
+    pub fn with_statement_cache_size(mut self, size: usize) -> Self {
+        self.statement_cache_size = size;
+        self
+    }
+
+    /// Enable or disable de-duplication of identical parameter values.
+    ///
+    /// See [`Config::deduplicate_params`]. Off by default.
+    #[must_use]
+    pub fn with_param_deduplication(mut self, enabled: bool) -> Self {
+        self.deduplicate_params = enabled;
+        self
+    }
+
+    /// Enable or disable strict protocol parsing.
+    ///
+    /// See [`Config::strict_protocol`]. Off by default.
+    #[must_use]
+    pub fn strict_protocol(mut self, enabled: bool) -> Self {
+        self.strict_protocol = enabled;
+        self
+    }
+
+    /// Enable or disable lossy decoding of invalid UTF-16 text.
+    ///
+    /// See [`Config::lenient_utf16`]. Off by default.
+    #[must_use]
+    pub fn lenient_utf16(mut self, enabled: bool) -> Self {
+        self.lenient_utf16 = enabled;
+        self
+    }
+
+    /// Enable or disable `SET XACT_ABORT ON` for every session.
+    ///
+    /// See [`Config::xact_abort`]. Off by default.
+    #[must_use]
+    pub fn xact_abort(mut self, enabled: bool) -> Self {
+        self.xact_abort = enabled;
+        self
+    }
+
+    /// Reject write statements client-side.
+    ///
+    /// See [`Config::read_only_guard`]. Off by default.
+    #[must_use]
+    pub fn read_only_guard(mut self, enabled: bool) -> Self {
+        self.read_only_guard = enabled;
+        self
+    }
+
     /// Set the redirect handling configuration.
     #[must_use]
     pub fn redirect(mut self, redirect: RedirectConfig) -> Self {
@@ -1139,6 +1457,23 @@ impl Config {
         self
     }
 
+    /// Set the delay before the first reconnect attempt
+    /// (`ConnectRetryInterval`).
+    #[must_use]
+    pub fn connect_retry_interval(mut self, interval: Duration) -> Self {
+        self.retry.initial_backoff = interval;
+        self
+    }
+
+    /// Enable or disable retrying the first query on a fresh connection.
+    ///
+    /// See [`Config::retry_first_query`]. Off by default.
+    #[must_use]
+    pub fn with_first_query_retry(mut self, enabled: bool) -> Self {
+        self.retry_first_query = enabled;
+        self
+    }
+
     /// Set the timeout configuration.
     #[must_use]
     pub fn timeouts(mut self, timeouts: TimeoutConfig) -> Self {
@@ -1197,6 +1532,57 @@ impl Config {
         self.send_string_parameters_as_unicode = enabled;
         self
     }
+
+    /// Use a custom resolver for the server host name.
+    ///
+    /// Useful for service discovery, static host maps, or split-horizon DNS.
+    /// All returned addresses are tried (Happy Eyeballs ordering, or raced in
+    /// parallel with `MultiSubnetFailover`).
+    #[must_use]
+    pub fn with_resolver(
+        mut self,
+        resolver: std::sync::Arc<dyn crate::resolver::Resolver>,
+    ) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
+    /// Edit the LOGIN7 packet just before it is encoded and sent.
+    ///
+    /// An expert escape hatch for interop testing and unusual servers: the
+    /// callback sees the fully built [`Login7`](tds_protocol::login7::Login7)
+    /// (credentials, option flags, feature extensions) and may change any of
+    /// it, e.g. set an option flag this `Config` does not expose or append a
+    /// [`FeatureExtension`](tds_protocol::login7::FeatureExtension). It runs
+    /// on every login, including reconnects and redirects.
+    ///
+    /// **Misuse can break authentication or the session.** Nothing the
+    /// callback does is validated; clearing credentials, requesting features
+    /// the driver cannot speak, or changing the TDS version will fail the
+    /// login or corrupt later responses. Feature extensions that login
+    /// requires the server to acknowledge (FEDAUTH, Always Encrypted) still
+    /// fail the login if they are added here and not acknowledged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use mssql_client::Config;
+    ///
+    /// let config = Config::new().login7_customizer(Arc::new(|login| {
+    ///     // Ask for a specific client interface name.
+    ///     login.library_name = "my-interop-probe".into();
+    /// }));
+    /// # let _ = config;
+    /// ```
+    #[must_use]
+    pub fn login7_customizer(
+        mut self,
+        customizer: std::sync::Arc<dyn Fn(&mut tds_protocol::login7::Login7) + Send + Sync>,
+    ) -> Self {
+        self.login7_customizer = Some(Login7Customizer::new(customizer));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -1375,6 +1761,77 @@ mod tests {
         assert_eq!(policy.backoff_for_attempt(3), Duration::from_secs(5));
     }
 
+    #[test]
+    fn test_retry_policy_backoff_jitter() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(1000))
+            .backoff_multiplier(2.0)
+            .max_backoff(Duration::from_millis(3000));
+
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..64 {
+            let first = policy.backoff_for_attempt(1);
+            assert!(first >= Duration::from_millis(500) && first < Duration::from_millis(1500));
+            // The 2s base jitters within [1s, 3s), never past max_backoff.
+            let second = policy.backoff_for_attempt(2);
+            assert!(second >= Duration::from_millis(1000) && second <= Duration::from_millis(3000));
+            seen.insert(first);
+        }
+        assert!(seen.len() > 1, "jitter varies the backoff");
+    }
+
+    /// An operation that fails with `err` on its first `failures` calls.
+    fn flaky(
+        failures: u32,
+        err: fn() -> crate::Error,
+    ) -> (
+        std::sync::Arc<std::sync::atomic::AtomicU32>,
+        impl FnMut() -> std::future::Ready<Result<u32, crate::Error>>,
+    ) {
+        let calls = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let counter = calls.clone();
+        let op = move || {
+            let call = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::future::ready(if call < failures {
+                Err(err())
+            } else {
+                Ok(call)
+            })
+        };
+        (calls, op)
+    }
+
+    fn quick_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy::new()
+            .max_retries(max_retries)
+            .initial_backoff(Duration::from_millis(1))
+            .jitter(false)
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_run_retries_until_success() {
+        let (calls, op) = flaky(2, || crate::Error::ConnectionClosed);
+        let result = quick_policy(3).run(op).await.unwrap();
+        assert_eq!(result, 2);
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_run_gives_up_after_max_retries() {
+        let (calls, op) = flaky(10, || crate::Error::ConnectionClosed);
+        let err = quick_policy(2).run(op).await.unwrap_err();
+        assert!(matches!(err, crate::Error::ConnectionClosed));
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_run_returns_terminal_errors_at_once() {
+        let (calls, op) = flaky(1, || crate::Error::Query("syntax".into()));
+        let err = quick_policy(3).run(op).await.unwrap_err();
+        assert!(matches!(err, crate::Error::Query(_)));
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_config_retry_builder() {
         let config = Config::new().max_retries(5);
@@ -1501,6 +1958,19 @@ mod tests {
         let config =
             Config::from_connection_string("Server=localhost;ProtocolVersion=7.3;").unwrap();
         assert_eq!(config.tds_version, TdsVersion::V7_3A);
+
+        // Older versions down to 7.0 are accepted and leave strict mode off
+        for (value, version) in [
+            ("7.0", TdsVersion::V7_0),
+            ("7.1", TdsVersion::V7_1),
+            ("7.2", TdsVersion::V7_2),
+        ] {
+            let config =
+                Config::from_connection_string(&format!("Server=localhost;TDS Version={value};"))
+                    .unwrap();
+            assert_eq!(config.tds_version, version);
+            assert!(!config.strict_mode);
+        }
     }
 
     #[test]
@@ -1509,7 +1979,8 @@ mod tests {
         assert!(result.is_err());
 
         let result = Config::from_connection_string("Server=localhost;TDSVersion=9.0;");
-        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("7.0, 7.1, 7.2"), "{err}");
     }
 
     #[test]
@@ -2001,18 +2472,132 @@ mod tests {
         assert!(!config.statement_cache);
     }
 
+    #[test]
+    fn test_retry_first_query_config() {
+        assert!(!Config::new().retry_first_query);
+        assert!(Config::new().with_first_query_retry(true).retry_first_query);
+        let config =
+            Config::from_connection_string("Server=localhost;Retry First Query=true;").unwrap();
+        assert!(config.retry_first_query);
+    }
+
+    #[test]
+    fn test_deduplicate_params_config() {
+        assert!(!Config::new().deduplicate_params);
+        assert!(
+            Config::new()
+                .with_param_deduplication(true)
+                .deduplicate_params
+        );
+        let config =
+            Config::from_connection_string("Server=localhost;Deduplicate Parameters=true;")
+                .unwrap();
+        assert!(config.deduplicate_params);
+    }
+
+    #[test]
+    fn test_strict_protocol_config() {
+        assert!(!Config::new().strict_protocol);
+        assert!(Config::new().strict_protocol(true).strict_protocol);
+        let config =
+            Config::from_connection_string("Server=localhost;Strict Protocol=true;").unwrap();
+        assert!(config.strict_protocol);
+    }
+
+    #[test]
+    fn test_lenient_utf16_config() {
+        assert!(!Config::new().lenient_utf16);
+        assert!(Config::new().lenient_utf16(true).lenient_utf16);
+        let config =
+            Config::from_connection_string("Server=localhost;Lenient UTF16=true;").unwrap();
+        assert!(config.lenient_utf16);
+    }
+
+    #[test]
+    fn test_xact_abort_config() {
+        assert!(!Config::new().xact_abort);
+        assert!(Config::new().xact_abort(true).xact_abort);
+    }
+
+    #[test]
+    fn test_read_only_guard_config() {
+        assert!(!Config::new().read_only_guard);
+        assert!(Config::new().read_only_guard(true).read_only_guard);
+        let config =
+            Config::from_connection_string("Server=localhost;Read Only Guard=true;").unwrap();
+        assert!(config.read_only_guard);
+    }
+
+    #[test]
+    fn test_packet_size_config() {
+        assert_eq!(Config::new().packet_size, 4096);
+        let config = Config::from_connection_string("Server=localhost;PacketSize=16384;").unwrap();
+        assert_eq!(config.packet_size, 16384);
+        let config = Config::from_connection_string("Server=localhost;Packet Size=8192;").unwrap();
+        assert_eq!(config.packet_size, 8192);
+        assert!(Config::from_connection_string("Server=localhost;PacketSize=big;").is_err());
+    }
+
     #[test]
     fn test_statement_cache_builder() {
         assert!(Config::new().with_statement_cache(true).statement_cache);
         assert!(!Config::new().with_statement_cache(false).statement_cache);
     }
 
+    #[test]
+    fn test_statement_cache_size_config() {
+        assert_eq!(Config::new().statement_cache_size, 256);
+        assert_eq!(
+            Config::new()
+                .with_statement_cache_size(16)
+                .statement_cache_size,
+            16
+        );
+        let config =
+            Config::from_connection_string("Server=localhost;Statement Cache Size=32;").unwrap();
+        assert_eq!(config.statement_cache_size, 32);
+        assert!(
+            Config::from_connection_string("Server=localhost;Statement Cache Size=-1;").is_err()
+        );
+    }
+
     #[test]
     fn test_statement_cache_invalid_value() {
         let result = Config::from_connection_string("Server=localhost;Statement Cache=banana;");
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_column_encryption_setting() {
+        let result =
+            Config::from_connection_string("Server=localhost;Column Encryption Setting=Enabled;");
+        #[cfg(feature = "always-encrypted")]
+        assert!(result.unwrap().column_encryption.is_some());
+        #[cfg(not(feature = "always-encrypted"))]
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("'always-encrypted' feature")
+        );
+
+        let config = Config::from_connection_string(
+            "Server=localhost;Column Encryption Setting=Enabled;ColumnEncryptionSetting=disabled;",
+        );
+        #[cfg(feature = "always-encrypted")]
+        assert!(config.unwrap().column_encryption.is_none());
+        #[cfg(not(feature = "always-encrypted"))]
+        assert!(config.is_err());
+
+        let config =
+            Config::from_connection_string("Server=localhost;Column Encryption Setting=Disabled;");
+        assert!(config.is_ok());
+
+        let err = Config::from_connection_string("Server=localhost;Column Encryption Setting=on;")
+            .unwrap_err();
+        assert!(err.to_string().contains("Enabled or Disabled"), "got {err}");
+    }
+
     #[test]
     fn test_empty_values_become_none() {
         // Per ADO.NET, empty values reset optional fields to default (None)
@@ -2021,4 +2606,110 @@ mod tests {
         assert_eq!(config.database, None);
         assert_eq!(config.language, None);
     }
+
+    /// `Debug` covers every field except secrets, which are compared
+    /// separately.
+    fn assert_same_config(built: &Config, parsed: &Config) {
+        assert_eq!(format!("{built:?}"), format!("{parsed:?}"));
+        match (&built.credentials, &parsed.credentials) {
+            (
+                Credentials::SqlServer { password: a, .. },
+                Credentials::SqlServer { password: b, .. },
+            ) => assert_eq!(a, b),
+            (a, b) => assert_eq!(format!("{a:?}"), format!("{b:?}")),
+        }
+    }
+
+    #[test]
+    fn test_builder_matches_connection_string() {
+        let parsed = Config::from_connection_string(
+            "Server=db.internal\\SALES;Database=app;User Id=svc;Password=\"p;ss\";\
+             Application Name=billing;ApplicationIntent=ReadOnly;Workstation ID=ws1;\
+             Language=us_english;Connect Timeout=5;Command Timeout=60;Encrypt=true;\
+             TrustServerCertificate=true;MARS=true;Packet Size=8192;ConnectRetryCount=2;\
+             ConnectRetryInterval=3;MultiSubnetFailover=true;\
+             SendStringParametersAsUnicode=false;Statement Cache=true;\
+             Statement Cache Size=64;Retry First Query=true;Deduplicate Parameters=true;\
+             Strict Protocol=true;Lenient UTF16=true;Read Only Guard=true",
+        )
+        .unwrap();
+        let built = Config::new()
+            .host("db.internal")
+            .instance("SALES")
+            .database("app")
+            .credentials(Credentials::sql_server("svc", "p;ss"))
+            .application_name("billing")
+            .application_intent(ApplicationIntent::ReadOnly)
+            .workstation_id("ws1")
+            .language("us_english")
+            .connect_timeout(Duration::from_secs(5))
+            .command_timeout(Duration::from_secs(60))
+            .encrypt(true)
+            .trust_server_certificate(true)
+            .mars(true)
+            .packet_size(8192)
+            .max_retries(2)
+            .connect_retry_interval(Duration::from_secs(3))
+            .multi_subnet_failover(true)
+            .send_string_parameters_as_unicode(false)
+            .with_statement_cache(true)
+            .with_statement_cache_size(64)
+            .with_first_query_retry(true)
+            .with_param_deduplication(true)
+            .strict_protocol(true)
+            .lenient_utf16(true)
+            .read_only_guard(true);
+
+        assert_same_config(&built, &parsed);
+    }
+
+    #[test]
+    fn test_builder_matches_connection_string_encryption_modes() {
+        let cases = [
+            (
+                "Server=db,1444;Encrypt=strict",
+                Config::new().host("db").port(1444).strict_mode(true),
+            ),
+            (
+                "Server=db;Encrypt=no_tls",
+                Config::new().host("db").no_tls(true),
+            ),
+            (
+                "Server=db;Encrypt=optional",
+                Config::new().host("db").encrypt(false),
+            ),
+            (
+                "Server=db;TDS Version=7.3B",
+                Config::new().host("db").tds_version(TdsVersion::V7_3B),
+            ),
+            (
+                "Server=db;TDS Version=8.0",
+                Config::new().host("db").tds_version(TdsVersion::V8_0),
+            ),
+        ];
+        for (conn_str, built) in cases {
+            let parsed = Config::from_connection_string(conn_str).unwrap();
+            assert_same_config(&built, &parsed);
+        }
+    }
+
+    #[test]
+    fn test_strict_with_trust_server_certificate_is_flagged_either_way() {
+        let parsed =
+            Config::from_connection_string("Server=db;Encrypt=strict;TrustServerCertificate=true")
+                .unwrap();
+        let built = Config::new()
+            .host("db")
+            .trust_server_certificate(true)
+            .strict_mode(true);
+        assert_same_config(&built, &parsed);
+        for config in [built, parsed] {
+            assert!(
+                config
+                    .validate()
+                    .unwrap_err()
+                    .contains(&ConfigWarning::StrictWithTrustServerCertificate)
+            );
+        }
+    }
 }
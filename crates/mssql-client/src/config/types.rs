@@ -1,7 +1,13 @@
 //! Supporting configuration types for redirect handling, timeouts, and retry policies.
 
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
 use std::time::Duration;
 
+use tds_protocol::login7::Login7;
+
 /// Application workload intent for AlwaysOn Availability Group routing.
 ///
 /// When set to [`ReadOnly`](ApplicationIntent::ReadOnly), SQL Server routes the
@@ -82,10 +88,20 @@ impl RedirectConfig {
 #[derive(Debug, Clone)]
 pub struct TimeoutConfig {
     /// Time to establish TCP connection (default: 15s).
+    ///
+    /// Runs inside [`login_timeout`](Self::login_timeout), so a value longer
+    /// than `login_timeout` is effectively capped at it.
     pub connect_timeout: Duration,
     /// Time to complete TLS handshake (default: 10s).
     pub tls_timeout: Duration,
-    /// Time to complete login sequence (default: 30s).
+    /// Time to complete one connection attempt end-to-end: TCP connect, TLS,
+    /// PreLogin, Login7 and the LoginAck response (default: 30s).
+    ///
+    /// Exceeding it fails the attempt with
+    /// [`Error::LoginTimeout`](crate::Error::LoginTimeout). `connect_timeout`
+    /// and `tls_timeout` still bound their own steps within it, but cannot
+    /// extend it: when raising either past `login_timeout`, raise
+    /// `login_timeout` as well.
     pub login_timeout: Duration,
     /// Default timeout for command execution (default: 30s).
     ///
@@ -128,6 +144,8 @@ impl TimeoutConfig {
     }
 
     /// Set the TCP connection timeout.
+    ///
+    /// See [`TimeoutConfig::connect_timeout`].
     #[must_use]
     pub fn connect_timeout(mut self, timeout: Duration) -> Self {
         self.connect_timeout = timeout;
@@ -141,7 +159,9 @@ impl TimeoutConfig {
         self
     }
 
-    /// Set the login sequence timeout.
+    /// Set the timeout for one whole connection attempt.
+    ///
+    /// See [`TimeoutConfig::login_timeout`].
     #[must_use]
     pub fn login_timeout(mut self, timeout: Duration) -> Self {
         self.login_timeout = timeout;
@@ -266,23 +286,25 @@ impl RetryPolicy {
 
     /// Calculate the backoff duration for a given retry attempt.
     ///
-    /// Uses exponential backoff with optional jitter.
+    /// Uses exponential backoff capped at `max_backoff`. With jitter enabled
+    /// the result is scaled by a random factor in `[0.5, 1.5)` (and capped
+    /// again), so clients retrying the same outage do not reconnect in
+    /// lockstep.
     #[must_use]
     pub fn backoff_for_attempt(&self, attempt: u32) -> Duration {
         if attempt == 0 {
             return Duration::ZERO;
         }
 
+        let max = self.max_backoff.as_millis() as f64;
         let base = self.initial_backoff.as_millis() as f64
             * self
                 .backoff_multiplier
                 .powi(attempt.saturating_sub(1) as i32);
-        let capped = base.min(self.max_backoff.as_millis() as f64);
+        let capped = base.min(max);
 
         if self.jitter {
-            // Simple jitter: multiply by random factor between 0.5 and 1.5
-            // In production, this would use a proper RNG
-            Duration::from_millis(capped as u64)
+            Duration::from_millis((capped * (0.5 + jitter_fraction())).min(max) as u64)
         } else {
             Duration::from_millis(capped as u64)
         }
@@ -293,4 +315,99 @@ impl RetryPolicy {
     pub fn should_retry(&self, attempt: u32) -> bool {
         attempt < self.max_retries
     }
+
+    /// The backoff before retrying after `err` on `attempt`, or `None` when
+    /// `err` is not transient or the retries are used up.
+    pub(crate) fn retry_after(&self, attempt: u32, err: &crate::Error) -> Option<Duration> {
+        (err.is_transient() && self.should_retry(attempt))
+            .then(|| self.backoff_for_attempt(attempt + 1))
+    }
+
+    /// Run `op`, calling it again after each transient failure.
+    ///
+    /// `op` is retried while it fails with an error for which
+    /// [`Error::is_transient`](crate::Error::is_transient) holds and
+    /// [`should_retry`](Self::should_retry) allows another attempt, sleeping
+    /// [`backoff_for_attempt`](Self::backoff_for_attempt) in between. Any other
+    /// error, or the last transient one, is returned. Every attempt reruns the
+    /// whole operation, so wrap only idempotent or transaction-wrapped work.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn ex(config: mssql_client::Config) -> Result<(), mssql_client::Error> {
+    /// use mssql_client::{Client, RetryPolicy};
+    ///
+    /// // A dropped connection is retried too, on a fresh one.
+    /// let updated = RetryPolicy::new()
+    ///     .max_retries(3)
+    ///     .run(|| async {
+    ///         let mut client = Client::connect(config.clone()).await?;
+    ///         client
+    ///             .execute("UPDATE dbo.Orders SET status = 'shipped' WHERE id = 7", &[])
+    ///             .await
+    ///     })
+    ///     .await?;
+    /// # let _ = updated;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run<T, F, Fut>(&self, mut op: F) -> Result<T, crate::Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, crate::Error>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let err = match op().await {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+            let Some(backoff) = self.retry_after(attempt, &err) else {
+                return Err(err);
+            };
+            attempt += 1;
+            tracing::debug!(
+                attempt,
+                backoff_ms = backoff.as_millis() as u64,
+                error = %err,
+                "retrying operation after transient error"
+            );
+            tokio::time::sleep(backoff).await;
+        }
+    }
+}
+
+/// A random fraction in `[0, 1)` for backoff jitter.
+///
+/// Each `RandomState` is freshly keyed, which is entropy enough to spread
+/// retries without pulling in an RNG dependency.
+fn jitter_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Callback that adjusts the LOGIN7 packet before it is sent.
+///
+/// Set via [`Config::login7_customizer`](super::Config::login7_customizer);
+/// see there for when (not) to use it.
+#[derive(Clone)]
+pub struct Login7Customizer(Arc<dyn Fn(&mut Login7) + Send + Sync>);
+
+impl Login7Customizer {
+    /// Wrap a callback.
+    pub fn new(f: Arc<dyn Fn(&mut Login7) + Send + Sync>) -> Self {
+        Self(f)
+    }
+
+    /// Apply the callback to `login`.
+    pub(crate) fn apply(&self, login: &mut Login7) {
+        (self.0)(login);
+    }
+}
+
+impl std::fmt::Debug for Login7Customizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Login7Customizer(..)")
+    }
 }
@@ -0,0 +1,215 @@
+//! Offline validation of a [`Config`].
+
+use std::fmt;
+
+use super::Config;
+
+/// Smallest TDS packet size SQL Server accepts (`network packet size`).
+const MIN_PACKET_SIZE: u16 = 512;
+
+/// Largest TDS packet size SQL Server accepts (`network packet size`).
+const MAX_PACKET_SIZE: u16 = 32767;
+
+/// A problem found by [`Config::validate`].
+///
+/// Variants for which [`is_error`](Self::is_error) returns `true` describe a
+/// configuration that cannot connect as written; the rest are legal but
+/// insecure or ignored, and worth surfacing to the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConfigWarning {
+    /// No server host is configured.
+    MissingServer,
+    /// The packet size is outside the 512–32767 range SQL Server accepts.
+    PacketSizeOutOfRange(u16),
+    /// `Encrypt=strict` together with `TrustServerCertificate=true`: strict
+    /// (TDS 8.0) connections exist to enforce certificate validation.
+    StrictWithTrustServerCertificate,
+    /// `Encrypt=strict` together with `Encrypt=no_tls`: TDS 8.0 always
+    /// runs over TLS.
+    StrictWithoutTls,
+    /// `TrustServerCertificate=true` skips certificate validation, leaving
+    /// the connection open to man-in-the-middle attacks.
+    TrustServerCertificate,
+    /// TLS is disabled; credentials and data travel in plaintext.
+    PlaintextConnection,
+    /// `MultipleActiveResultSets=true`: MARS is not supported, so the
+    /// connection is made without it.
+    MarsNotSupported,
+}
+
+impl ConfigWarning {
+    /// Whether this issue prevents the configuration from connecting, as
+    /// opposed to merely being insecure.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        match self {
+            Self::MissingServer
+            | Self::PacketSizeOutOfRange(_)
+            | Self::StrictWithTrustServerCertificate
+            | Self::StrictWithoutTls => true,
+            Self::TrustServerCertificate | Self::PlaintextConnection | Self::MarsNotSupported => {
+                false
+            }
+        }
+    }
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingServer => f.write_str("no server is configured"),
+            Self::PacketSizeOutOfRange(size) => write!(
+                f,
+                "packet size {size} is outside the supported range \
+                 {MIN_PACKET_SIZE}-{MAX_PACKET_SIZE}"
+            ),
+            Self::StrictWithTrustServerCertificate => f.write_str(
+                "Encrypt=strict conflicts with TrustServerCertificate=true; \
+                 strict mode requires certificate validation",
+            ),
+            Self::StrictWithoutTls => {
+                f.write_str("Encrypt=strict conflicts with no_tls; TDS 8.0 requires TLS")
+            }
+            Self::TrustServerCertificate => f.write_str(
+                "TrustServerCertificate=true disables certificate validation \
+                 (development only)",
+            ),
+            Self::PlaintextConnection => {
+                f.write_str("TLS is disabled; credentials and data are sent in plaintext")
+            }
+            Self::MarsNotSupported => f.write_str(
+                "MultipleActiveResultSets is not supported; the connection is made without MARS",
+            ),
+        }
+    }
+}
+
+impl Config {
+    /// Check this configuration for mistakes without touching the network.
+    ///
+    /// Intended for CLI tools and config validators that want to report
+    /// problems before attempting a (slow, possibly failing) connect. Returns
+    /// every issue found; use [`ConfigWarning::is_error`] to tell conflicts
+    /// that would prevent connecting from insecure-but-legal settings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mssql_client::Config;
+    ///
+    /// let config = Config::from_connection_string(
+    ///     "Server=db;Encrypt=strict;TrustServerCertificate=true",
+    /// )
+    /// .unwrap();
+    /// let issues = config.validate().unwrap_err();
+    /// assert!(issues.iter().any(|w| w.is_error()));
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ConfigWarning>> {
+        let mut issues = Vec::new();
+
+        if self.host.trim().is_empty() {
+            issues.push(ConfigWarning::MissingServer);
+        }
+        if !(MIN_PACKET_SIZE..=MAX_PACKET_SIZE).contains(&self.packet_size) {
+            issues.push(ConfigWarning::PacketSizeOutOfRange(self.packet_size));
+        }
+
+        if self.strict_mode {
+            if self.trust_server_certificate {
+                issues.push(ConfigWarning::StrictWithTrustServerCertificate);
+            }
+            if self.no_tls {
+                issues.push(ConfigWarning::StrictWithoutTls);
+            }
+        } else if self.no_tls {
+            issues.push(ConfigWarning::PlaintextConnection);
+        } else if self.trust_server_certificate {
+            issues.push(ConfigWarning::TrustServerCertificate);
+        }
+
+        if self.mars {
+            issues.push(ConfigWarning::MarsNotSupported);
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn issues(conn_str: &str) -> Vec<ConfigWarning> {
+        Config::from_connection_string(conn_str)
+            .unwrap()
+            .validate()
+            .err()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_validate_clean_config() {
+        assert!(issues("Server=db;Encrypt=true").is_empty());
+        assert!(Config::new().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_reports_errors() {
+        assert_eq!(
+            Config::new().host("  ").validate().unwrap_err(),
+            vec![ConfigWarning::MissingServer]
+        );
+        assert_eq!(
+            issues("Server=db;Packet Size=100"),
+            vec![ConfigWarning::PacketSizeOutOfRange(100)]
+        );
+        assert_eq!(
+            issues("Server=db;Packet Size=40000"),
+            vec![ConfigWarning::PacketSizeOutOfRange(40000)]
+        );
+        assert_eq!(
+            issues("Server=db;Encrypt=strict;TrustServerCertificate=true"),
+            vec![ConfigWarning::StrictWithTrustServerCertificate]
+        );
+        assert!(
+            issues("Server=db;Encrypt=strict;TrustServerCertificate=true")
+                .iter()
+                .all(ConfigWarning::is_error)
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_insecure_settings_as_warnings() {
+        let found = issues("Server=db;TrustServerCertificate=true");
+        assert_eq!(found, vec![ConfigWarning::TrustServerCertificate]);
+        assert!(!found[0].is_error());
+
+        let found = issues("Server=db;Encrypt=no_tls");
+        assert_eq!(found, vec![ConfigWarning::PlaintextConnection]);
+        assert!(!found[0].is_error());
+    }
+
+    #[test]
+    fn test_validate_reports_ignored_mars() {
+        let found = issues("Server=db;MultipleActiveResultSets=true");
+        assert_eq!(found, vec![ConfigWarning::MarsNotSupported]);
+        assert!(!found[0].is_error());
+    }
+
+    #[test]
+    fn test_validate_strict_without_tls() {
+        let config = Config::new().strict_mode(true).no_tls(true);
+        assert!(
+            config
+                .validate()
+                .unwrap_err()
+                .contains(&ConfigWarning::StrictWithoutTls)
+        );
+    }
+}
@@ -0,0 +1,604 @@
+//! CSV export of query results.
+//!
+//! [`RowStream::write_csv`](crate::RowStream::write_csv) streams a result set
+//! straight from the network into an async writer as CSV, one row at a time,
+//! so exports of any size run in constant memory.
+//! [`QueryStream::write_csv`](crate::QueryStream::write_csv) does the same for
+//! an already-buffered result.
+//!
+//! Values are rendered the way SQL Server tools show them: `BIT` as `1`/`0`,
+//! binary as `0x`-prefixed uppercase hex, `UNIQUEIDENTIFIER` in uppercase,
+//! decimals with their full scale, and dates in ISO 8601 with a space
+//! separator (`CONVERT` style 121). Delimiter, quoting, the NULL marker,
+//! decimal separator and date formats are configurable through
+//! [`CsvOptions`].
+//!
+//! ```rust,no_run
+//! # use mssql_client::{Client, CsvOptions, Ready};
+//! # async fn ex(client: &mut Client<Ready>) -> Result<(), mssql_client::Error> {
+//! // Any `tokio::io::AsyncWrite`: a file, a socket, an HTTP response body...
+//! let mut out: Vec<u8> = Vec::new();
+//! let rows = client
+//!     .query_stream("SELECT * FROM orders", &[])
+//!     .await?
+//!     .write_csv(&mut out, &CsvOptions::new().delimiter(';'))
+//!     .await?;
+//! println!("exported {rows} rows");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Write as _;
+
+use mssql_types::SqlValue;
+
+use crate::error::Result;
+use crate::row::{Column, Row};
+
+/// When fields are wrapped in quotes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum CsvQuoting {
+    /// Quote only fields containing the delimiter, the quote character, or a
+    /// line break (RFC 4180).
+    #[default]
+    Necessary,
+    /// Quote every non-NULL field.
+    Always,
+    /// Quote every non-NULL field except numbers and `BIT` values.
+    NonNumeric,
+    /// Never quote. Fields containing the delimiter or a line break produce
+    /// output that cannot be parsed back unambiguously.
+    Never,
+}
+
+/// Formatting options for CSV export.
+///
+/// The defaults produce RFC 4180 CSV: comma-separated, CRLF line endings,
+/// quoting only where needed, a header row, and NULL as an empty field.
+///
+/// NULL is always written unquoted, so with [`CsvQuoting::Always`] or
+/// [`CsvQuoting::NonNumeric`] a NULL (`,,`) stays distinguishable from an
+/// empty string (`,"",`).
+///
+/// A date/time format chrono cannot render for its type makes the export fail
+/// with [`Error::Config`](crate::Error::Config) before anything is written.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CsvOptions {
+    /// Field delimiter.
+    ///
+    /// Default: `,`
+    pub delimiter: char,
+
+    /// Quote character. A quote inside a quoted field is doubled.
+    ///
+    /// Default: `"`
+    pub quote: char,
+
+    /// When fields are quoted.
+    ///
+    /// Default: [`CsvQuoting::Necessary`]
+    pub quoting: CsvQuoting,
+
+    /// Text written for NULL values.
+    ///
+    /// Default: empty
+    pub null: String,
+
+    /// Whether to write a header row of column names first.
+    ///
+    /// Default: `true`
+    pub header: bool,
+
+    /// Line terminator written after every record.
+    ///
+    /// Default: `\r\n`
+    pub line_terminator: String,
+
+    /// Decimal separator for `REAL`, `FLOAT`, `DECIMAL`, `NUMERIC` and
+    /// `MONEY` values, e.g. `,` for locales that pair it with a `;`
+    /// delimiter.
+    ///
+    /// Default: `.`
+    pub decimal_separator: char,
+
+    /// [`chrono` format](chrono::format::strftime) for `DATE` values.
+    ///
+    /// Default: `%Y-%m-%d`
+    #[cfg(feature = "chrono")]
+    pub date_format: String,
+
+    /// [`chrono` format](chrono::format::strftime) for `TIME` values.
+    ///
+    /// Default: `%H:%M:%S%.f`
+    #[cfg(feature = "chrono")]
+    pub time_format: String,
+
+    /// [`chrono` format](chrono::format::strftime) for `DATETIME`,
+    /// `DATETIME2` and `SMALLDATETIME` values.
+    ///
+    /// Default: `%Y-%m-%d %H:%M:%S%.f`
+    #[cfg(feature = "chrono")]
+    pub datetime_format: String,
+
+    /// [`chrono` format](chrono::format::strftime) for `DATETIMEOFFSET`
+    /// values.
+    ///
+    /// Default: `%Y-%m-%d %H:%M:%S%.f %:z`
+    #[cfg(feature = "chrono")]
+    pub datetimeoffset_format: String,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+            quoting: CsvQuoting::Necessary,
+            null: String::new(),
+            header: true,
+            line_terminator: "\r\n".to_string(),
+            decimal_separator: '.',
+            #[cfg(feature = "chrono")]
+            date_format: "%Y-%m-%d".to_string(),
+            #[cfg(feature = "chrono")]
+            time_format: "%H:%M:%S%.f".to_string(),
+            #[cfg(feature = "chrono")]
+            datetime_format: "%Y-%m-%d %H:%M:%S%.f".to_string(),
+            #[cfg(feature = "chrono")]
+            datetimeoffset_format: "%Y-%m-%d %H:%M:%S%.f %:z".to_string(),
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Create options with the RFC 4180 defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// See [`CsvOptions::delimiter`].
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// See [`CsvOptions::quote`].
+    #[must_use]
+    pub fn quote(mut self, quote: char) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// See [`CsvOptions::quoting`].
+    #[must_use]
+    pub fn quoting(mut self, quoting: CsvQuoting) -> Self {
+        self.quoting = quoting;
+        self
+    }
+
+    /// See [`CsvOptions::null`].
+    #[must_use]
+    pub fn null(mut self, null: impl Into<String>) -> Self {
+        self.null = null.into();
+        self
+    }
+
+    /// See [`CsvOptions::header`].
+    #[must_use]
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// See [`CsvOptions::line_terminator`].
+    #[must_use]
+    pub fn line_terminator(mut self, terminator: impl Into<String>) -> Self {
+        self.line_terminator = terminator.into();
+        self
+    }
+
+    /// See [`CsvOptions::decimal_separator`].
+    #[must_use]
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// See [`CsvOptions::date_format`].
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn date_format(mut self, format: impl Into<String>) -> Self {
+        self.date_format = format.into();
+        self
+    }
+
+    /// See [`CsvOptions::time_format`].
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn time_format(mut self, format: impl Into<String>) -> Self {
+        self.time_format = format.into();
+        self
+    }
+
+    /// See [`CsvOptions::datetime_format`].
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn datetime_format(mut self, format: impl Into<String>) -> Self {
+        self.datetime_format = format.into();
+        self
+    }
+
+    /// See [`CsvOptions::datetimeoffset_format`].
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn datetimeoffset_format(mut self, format: impl Into<String>) -> Self {
+        self.datetimeoffset_format = format.into();
+        self
+    }
+}
+
+/// How a rendered value is treated by [`CsvQuoting::NonNumeric`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Numeric,
+    Text,
+}
+
+/// Reject date/time formats that chrono cannot render.
+///
+/// chrono reports a bad format only while writing a value, which would leave a
+/// truncated field in the middle of the export. Each format is tried once on a
+/// sample of its type instead, before anything is written.
+pub(crate) fn validate_options(options: &CsvOptions) -> Result<()> {
+    #[cfg(feature = "chrono")]
+    {
+        let sample = chrono::DateTime::UNIX_EPOCH.fixed_offset();
+        let naive = sample.naive_utc();
+        let mut out = String::new();
+        let checks = [
+            (
+                "date_format",
+                &options.date_format,
+                write!(out, "{}", naive.date().format(&options.date_format)),
+            ),
+            (
+                "time_format",
+                &options.time_format,
+                write!(out, "{}", naive.time().format(&options.time_format)),
+            ),
+            (
+                "datetime_format",
+                &options.datetime_format,
+                write!(out, "{}", naive.format(&options.datetime_format)),
+            ),
+            (
+                "datetimeoffset_format",
+                &options.datetimeoffset_format,
+                write!(out, "{}", sample.format(&options.datetimeoffset_format)),
+            ),
+        ];
+        for (name, format, result) in checks {
+            if result.is_err() {
+                return Err(crate::error::Error::Config(format!(
+                    "invalid CSV {name} {format:?}: not a chrono format for this type"
+                )));
+            }
+        }
+    }
+    #[cfg(not(feature = "chrono"))]
+    let _ = options;
+    Ok(())
+}
+
+/// Encode the header record for `columns`.
+pub(crate) fn header_record(columns: &[Column], options: &CsvOptions) -> String {
+    let mut out = String::new();
+    for (i, column) in columns.iter().enumerate() {
+        if i > 0 {
+            out.push(options.delimiter);
+        }
+        push_field(&mut out, &column.name, FieldKind::Text, options);
+    }
+    out.push_str(&options.line_terminator);
+    out
+}
+
+/// Encode one row as a CSV record, line terminator included.
+pub(crate) fn row_record(row: &Row, options: &CsvOptions) -> String {
+    let mut out = String::new();
+    let mut field = String::new();
+    for i in 0..row.len() {
+        if i > 0 {
+            out.push(options.delimiter);
+        }
+        match row.get_raw(i) {
+            None | Some(SqlValue::Null) => out.push_str(&options.null),
+            Some(value) => {
+                field.clear();
+                let kind = format_value(&mut field, &value, options);
+                push_field(&mut out, &field, kind, options);
+            }
+        }
+    }
+    out.push_str(&options.line_terminator);
+    out
+}
+
+/// Append `field` to `out`, quoting it as `options.quoting` requires.
+fn push_field(out: &mut String, field: &str, kind: FieldKind, options: &CsvOptions) {
+    let quote = match options.quoting {
+        CsvQuoting::Always => true,
+        CsvQuoting::NonNumeric => kind == FieldKind::Text,
+        CsvQuoting::Never => false,
+        CsvQuoting::Necessary => field
+            .chars()
+            .any(|c| c == options.delimiter || c == options.quote || c == '\r' || c == '\n'),
+    };
+    if !quote {
+        out.push_str(field);
+        return;
+    }
+    out.push(options.quote);
+    for c in field.chars() {
+        if c == options.quote {
+            out.push(c);
+        }
+        out.push(c);
+    }
+    out.push(options.quote);
+}
+
+/// Render a non-NULL value into `out`, returning how it should be quoted.
+fn format_value(out: &mut String, value: &SqlValue, options: &CsvOptions) -> FieldKind {
+    // Writing into a String cannot fail.
+    match value {
+        SqlValue::Bool(v) => out.push(if *v { '1' } else { '0' }),
+        SqlValue::TinyInt(v) => {
+            let _ = write!(out, "{v}");
+        }
+        SqlValue::SmallInt(v) => {
+            let _ = write!(out, "{v}");
+        }
+        SqlValue::Int(v) => {
+            let _ = write!(out, "{v}");
+        }
+        SqlValue::BigInt(v) => {
+            let _ = write!(out, "{v}");
+        }
+        SqlValue::Float(v) => push_number(out, &v.to_string(), options),
+        SqlValue::Double(v) => push_number(out, &v.to_string(), options),
+        #[cfg(feature = "decimal")]
+        SqlValue::Decimal(v) | SqlValue::Money(v) | SqlValue::SmallMoney(v) => {
+            push_number(out, &v.to_string(), options);
+        }
+        SqlValue::String(s) | SqlValue::Xml(s) => {
+            out.push_str(s);
+            return FieldKind::Text;
+        }
+        SqlValue::Binary(bytes) => {
+            out.push_str("0x");
+            for b in bytes.iter() {
+                let _ = write!(out, "{b:02X}");
+            }
+            return FieldKind::Text;
+        }
+        #[cfg(feature = "uuid")]
+        SqlValue::Uuid(v) => {
+            let _ = write!(
+                out,
+                "{}",
+                v.hyphenated()
+                    .encode_upper(&mut uuid::Uuid::encode_buffer())
+            );
+            return FieldKind::Text;
+        }
+        #[cfg(feature = "chrono")]
+        SqlValue::Date(v) => {
+            let _ = write!(out, "{}", v.format(&options.date_format));
+            return FieldKind::Text;
+        }
+        #[cfg(feature = "chrono")]
+        SqlValue::Time(v) => {
+            let _ = write!(out, "{}", v.format(&options.time_format));
+            return FieldKind::Text;
+        }
+        #[cfg(feature = "chrono")]
+        SqlValue::DateTime(v) | SqlValue::SmallDateTime(v) => {
+            let _ = write!(out, "{}", v.format(&options.datetime_format));
+            return FieldKind::Text;
+        }
+        #[cfg(feature = "chrono")]
+        SqlValue::DateTimeOffset(v) => {
+            let _ = write!(out, "{}", v.format(&options.datetimeoffset_format));
+            return FieldKind::Text;
+        }
+        #[cfg(feature = "json")]
+        SqlValue::Json(v) => {
+            let _ = write!(out, "{v}");
+            return FieldKind::Text;
+        }
+        // NULL is handled by the caller; TVPs never appear in result sets.
+        _ => return FieldKind::Text,
+    }
+    FieldKind::Numeric
+}
+
+/// Append a formatted number, swapping in the configured decimal separator.
+fn push_number(out: &mut String, formatted: &str, options: &CsvOptions) {
+    if options.decimal_separator == '.' {
+        out.push_str(formatted);
+    } else {
+        out.extend(formatted.chars().map(|c| {
+            if c == '.' {
+                options.decimal_separator
+            } else {
+                c
+            }
+        }));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn columns(names: &[&str]) -> Vec<Column> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Column::new(*name, i, "NVARCHAR"))
+            .collect()
+    }
+
+    fn record(values: Vec<SqlValue>, options: &CsvOptions) -> String {
+        let names: Vec<String> = (0..values.len()).map(|i| format!("c{i}")).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        row_record(&Row::from_values(columns(&names), values), options)
+    }
+
+    #[test]
+    fn test_header_and_necessary_quoting() {
+        let options = CsvOptions::new();
+        assert_eq!(
+            header_record(&columns(&["id", "full, name"]), &options),
+            "id,\"full, name\"\r\n"
+        );
+        assert_eq!(
+            record(
+                vec![
+                    SqlValue::Int(7),
+                    SqlValue::String("say \"hi\"".into()),
+                    SqlValue::String("two\nlines".into()),
+                    SqlValue::String(String::new()),
+                    SqlValue::Null,
+                ],
+                &options
+            ),
+            "7,\"say \"\"hi\"\"\",\"two\nlines\",,\r\n"
+        );
+    }
+
+    #[test]
+    fn test_quoting_styles_keep_null_unquoted() {
+        let values = || {
+            vec![
+                SqlValue::Int(1),
+                SqlValue::String(String::new()),
+                SqlValue::Null,
+                SqlValue::Bool(true),
+            ]
+        };
+        let options = CsvOptions::new().line_terminator("\n");
+        assert_eq!(
+            record(values(), &options.clone().quoting(CsvQuoting::Always)),
+            "\"1\",\"\",,\"1\"\n"
+        );
+        assert_eq!(
+            record(values(), &options.clone().quoting(CsvQuoting::NonNumeric)),
+            "1,\"\",,1\n"
+        );
+        assert_eq!(
+            record(
+                vec![SqlValue::String("a,b".into())],
+                &options.quoting(CsvQuoting::Never)
+            ),
+            "a,b\n"
+        );
+    }
+
+    #[test]
+    fn test_null_marker_delimiter_and_decimal_separator() {
+        let options = CsvOptions::new()
+            .delimiter(';')
+            .decimal_separator(',')
+            .null("NULL")
+            .line_terminator("\n");
+        assert_eq!(
+            record(
+                vec![
+                    SqlValue::Double(1.5),
+                    SqlValue::Null,
+                    SqlValue::String("x;y".into())
+                ],
+                &options
+            ),
+            "1,5;NULL;\"x;y\"\n"
+        );
+    }
+
+    #[test]
+    fn test_sql_server_value_formats() {
+        let options = CsvOptions::new().line_terminator("\n");
+        assert_eq!(
+            record(
+                vec![
+                    SqlValue::Bool(false),
+                    SqlValue::Binary(bytes::Bytes::from_static(&[0x0a, 0xff])),
+                    SqlValue::BigInt(-5),
+                ],
+                &options
+            ),
+            "0,0x0AFF,-5\n"
+        );
+    }
+
+    #[cfg(all(feature = "chrono", feature = "decimal", feature = "uuid"))]
+    #[test]
+    fn test_temporal_decimal_and_uuid_formats() {
+        use chrono::{NaiveDate, TimeZone};
+
+        let datetime = NaiveDate::from_ymd_opt(2024, 3, 9)
+            .unwrap()
+            .and_hms_milli_opt(14, 5, 7, 250)
+            .unwrap();
+        let offset = chrono::FixedOffset::east_opt(2 * 3600)
+            .unwrap()
+            .from_local_datetime(&datetime)
+            .unwrap();
+        let options = CsvOptions::new().line_terminator("\n");
+        assert_eq!(
+            record(
+                vec![
+                    SqlValue::Date(datetime.date()),
+                    SqlValue::DateTime(datetime),
+                    SqlValue::DateTimeOffset(offset),
+                    SqlValue::Decimal(rust_decimal::Decimal::new(12_500, 3)),
+                    SqlValue::Uuid(uuid::Uuid::from_u128(0xabcdef)),
+                ],
+                &options
+            ),
+            "2024-03-09,2024-03-09 14:05:07.250,2024-03-09 14:05:07.250 +02:00,12.500,\
+             00000000-0000-0000-0000-000000ABCDEF\n"
+        );
+        assert_eq!(
+            record(
+                vec![SqlValue::Date(datetime.date())],
+                &options.date_format("%d/%m/%Y")
+            ),
+            "09/03/2024\n"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_invalid_temporal_formats_are_rejected() {
+        assert!(validate_options(&CsvOptions::new()).is_ok());
+        for options in [
+            CsvOptions::new().date_format("%Y-%Q"),
+            CsvOptions::new().time_format("%H:%"),
+            // A valid specifier the type cannot render: no offset on a naive value.
+            CsvOptions::new().datetime_format("%Y-%m-%d %z"),
+            CsvOptions::new().datetimeoffset_format("%Y-%Q"),
+        ] {
+            assert!(matches!(
+                validate_options(&options),
+                Err(crate::error::Error::Config(_))
+            ));
+        }
+    }
+}
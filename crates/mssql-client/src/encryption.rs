@@ -141,7 +141,15 @@ impl EncryptionConfig {
     }
 
     /// Register a key store provider.
+    ///
+    /// Providers are keyed by [`provider_name`](KeyStoreProvider::provider_name),
+    /// compared case-insensitively as SQL Server does for the
+    /// `KEY_STORE_PROVIDER_NAME` of a column master key. Registering a provider
+    /// under a name that is already taken replaces the earlier one.
     pub fn register_provider(&mut self, provider: impl KeyStoreProvider + 'static) {
+        let name = provider.provider_name().to_owned();
+        self.providers
+            .retain(|p| !p.provider_name().eq_ignore_ascii_case(&name));
         self.providers.push(Box::new(provider));
     }
 
@@ -159,14 +167,19 @@ impl EncryptionConfig {
         self
     }
 
-    /// Get a provider by name.
+    /// Get a provider by name (case-insensitive).
     pub fn get_provider(&self, name: &str) -> Option<&dyn KeyStoreProvider> {
         self.providers
             .iter()
-            .find(|p| p.provider_name() == name)
+            .find(|p| p.provider_name().eq_ignore_ascii_case(name))
             .map(|p| p.as_ref())
     }
 
+    /// Names of the registered key store providers, in registration order.
+    pub fn provider_names(&self) -> impl Iterator<Item = &str> {
+        self.providers.iter().map(|p| p.provider_name())
+    }
+
     /// Check if encryption is ready (enabled and has providers).
     #[must_use]
     pub fn is_ready(&self) -> bool {
@@ -794,6 +807,22 @@ fn money_cents(value: &rust_decimal::Decimal) -> Result<i64, EncryptionError> {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "always-encrypted")]
+    #[test]
+    fn provider_registry_is_keyed_case_insensitively() {
+        let config = EncryptionConfig::new()
+            .with_provider(mssql_auth::InMemoryKeyStore::new())
+            .with_provider(mssql_auth::InMemoryKeyStore::new());
+
+        assert_eq!(
+            config.provider_names().collect::<Vec<_>>(),
+            vec!["IN_MEMORY_KEY_STORE"],
+            "re-registering a name replaces the earlier provider"
+        );
+        assert!(config.get_provider("in_memory_key_store").is_some());
+        assert!(config.get_provider("AZURE_KEY_VAULT").is_none());
+    }
+
     /// Reference ciphertexts captured from a live deterministic Always Encrypted
     /// INSERT via Microsoft.Data.SqlClient 5.2.2. Encrypting our normalization
     /// with the same CEK must reproduce them byte-for-byte — proving the
@@ -22,16 +22,23 @@
 //! throttling (10928/10929) and service errors (40197/40501/40613), and
 //! failover (4060). Terminal server errors include syntax (102), invalid
 //! object/column (208/207), and constraint/unique violations (547/2627/2601).
+//!
 //! For server errors, [`Error::class`] exposes the TDS severity (classes 11-16
 //! are user errors; 17+ indicate resource or system problems).
 //!
+//! Errors during login are classified further: a rejected login (18456) is
+//! [`Error::Authentication`] (terminal), and a database that cannot be opened
+//! (4060/40613) is [`Error::DatabaseUnavailable`] (transient).
+//!
 //! ## Retrying
 //!
 //! Retry transient errors with capped exponential backoff plus jitter and a
 //! small attempt limit (3-5). The pool and the configured `RetryPolicy` handle
 //! common connection-level cases automatically; apply application-level retries
 //! around whole logical operations, and only when the work is idempotent or
-//! transaction-wrapped.
+//! transaction-wrapped. `RetryPolicy::run` wraps such an operation, and
+//! `Client::execute_with_retry` reruns a single statement the server failed
+//! with a transient error.
 
 use std::sync::Arc;
 
@@ -116,6 +123,34 @@ pub enum Error {
         procedure: Option<String>,
         /// Line number in the SQL batch or procedure.
         line: u32,
+        /// Every other ERROR and INFO message the server sent for the failed
+        /// request, in arrival order (empty when the error was the only one).
+        ///
+        /// SQL Server often reports a failure as several messages (e.g. a
+        /// constraint violation followed by "The statement has been
+        /// terminated."); this keeps the complete server output for logging.
+        additional: Vec<ServerMessage>,
+    },
+
+    /// The server accepted the credentials but could not open the database
+    /// for the session (error 4060, or 40613 on Azure SQL).
+    ///
+    /// Raised during login only. The database may be missing or mid-failover,
+    /// so this is treated as transient; a misspelled database name keeps
+    /// failing until the configuration is fixed.
+    #[error("database unavailable (server error {number}): {message}")]
+    DatabaseUnavailable {
+        /// Server error number.
+        number: i32,
+        /// Error class/severity (0-25).
+        class: u8,
+        /// Error state.
+        state: u8,
+        /// Error message.
+        message: String,
+        /// Every other ERROR and INFO message the server sent with the
+        /// failed login, in arrival order.
+        additional: Vec<ServerMessage>,
     },
 
     /// Configuration error.
@@ -140,7 +175,8 @@ pub enum Error {
         port: u16,
     },
 
-    /// Login/authentication response timeout occurred.
+    /// A connection attempt did not complete within
+    /// [`TimeoutConfig::login_timeout`](crate::TimeoutConfig::login_timeout).
     #[error("login timed out for {host}:{port}")]
     LoginTimeout {
         /// Target host.
@@ -183,6 +219,15 @@ pub enum Error {
     #[error("invalid identifier: {0}")]
     InvalidIdentifier(String),
 
+    /// A write statement was rejected by
+    /// [`Config::read_only_guard`](crate::Config::read_only_guard) before it
+    /// was sent.
+    #[error("write statement rejected on read-only connection: found {keyword}")]
+    WriteAttemptOnReadOnly {
+        /// The write keyword that triggered the rejection, uppercased.
+        keyword: &'static str,
+    },
+
     /// Query cancellation error.
     #[error("query cancellation failed: {0}")]
     Cancel(String),
@@ -217,6 +262,53 @@ pub enum Error {
     Encryption(String),
 }
 
+/// A single ERROR or INFO message sent by the server.
+///
+/// Carried in [`Error::Server::additional`](Error::Server) so error handlers can
+/// log the full diagnostic output of a failed request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ServerMessage {
+    /// Message number.
+    pub number: i32,
+    /// Message class/severity (0-25; 10 and below are informational).
+    pub class: u8,
+    /// Message state.
+    pub state: u8,
+    /// Message text.
+    pub message: String,
+    /// Server name that produced the message.
+    pub server: Option<String>,
+    /// Stored procedure name (if applicable).
+    pub procedure: Option<String>,
+    /// Line number in the SQL batch or procedure.
+    pub line: u32,
+}
+
+impl ServerMessage {
+    /// Whether this message is an error (severity above 10) rather than an
+    /// informational message.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        self.class > 10
+    }
+}
+
+impl std::fmt::Display for ServerMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} (severity {}, state {}): {}{}",
+            if self.is_error() { "error" } else { "info" },
+            self.number,
+            self.class,
+            self.state,
+            self.message,
+            format_server_location(&self.server, &self.procedure, &self.line)
+        )
+    }
+}
+
 // Note: From<mssql_tls::TlsError> and From<tds_protocol::ProtocolError> are
 // derived via #[from] on the enum variants above, preserving the full error chain.
 
@@ -270,8 +362,10 @@ impl From<mssql_auth::EncryptionError> for Error {
 impl Error {
     /// Check if this error is transient and may succeed on retry.
     ///
-    /// Transient errors include timeouts, connection issues, and
-    /// certain server errors that may resolve themselves.
+    /// Transient errors include timeouts, connection issues,
+    /// [`Error::DatabaseUnavailable`], and certain server errors that may
+    /// resolve themselves. A rejected login (18456) is reported as
+    /// [`Error::Authentication`] and is not transient.
     ///
     /// Per ADR-009, the following server error codes are considered transient:
     /// - 1205: Deadlock victim
@@ -293,6 +387,7 @@ impl Error {
             | Self::ConnectionClosed
             | Self::Connection(_)
             | Self::Routing { .. }
+            | Self::DatabaseUnavailable { .. }
             | Self::Io(_) => true,
             Self::Server { number, .. } => Self::is_transient_server_error(*number),
             _ => false,
@@ -360,6 +455,7 @@ impl Error {
         match self {
             Self::Config(_)
             | Self::InvalidIdentifier(_)
+            | Self::WriteAttemptOnReadOnly { .. }
             | Self::Protocol(_)
             | Self::ProtocolError(_)
             | Self::Type(_)
@@ -421,9 +517,16 @@ impl Error {
     }
 
     /// Check if this is a server error with a specific number.
+    ///
+    /// Also matches [`Error::DatabaseUnavailable`], which is a classified
+    /// server error.
     #[must_use]
     pub fn is_server_error(&self, number: i32) -> bool {
-        matches!(self, Self::Server { number: n, .. } if *n == number)
+        matches!(
+            self,
+            Self::Server { number: n, .. } | Self::DatabaseUnavailable { number: n, .. }
+                if *n == number
+        )
     }
 
     /// Get the error class/severity if this is a server error.
@@ -436,7 +539,7 @@ impl Error {
     #[must_use]
     pub fn class(&self) -> Option<u8> {
         match self {
-            Self::Server { class, .. } => Some(*class),
+            Self::Server { class, .. } | Self::DatabaseUnavailable { class, .. } => Some(*class),
             _ => None,
         }
     }
@@ -446,6 +549,19 @@ impl Error {
     pub fn severity(&self) -> Option<u8> {
         self.class()
     }
+
+    /// Get the other ERROR/INFO messages the server sent alongside this error.
+    ///
+    /// Returns an empty slice for non-server errors.
+    #[must_use]
+    pub fn additional_messages(&self) -> &[ServerMessage] {
+        match self {
+            Self::Server { additional, .. } | Self::DatabaseUnavailable { additional, .. } => {
+                additional
+            }
+            _ => &[],
+        }
+    }
 }
 
 /// Format the server/procedure/line suffix for server error Display.
@@ -493,6 +609,7 @@ mod tests {
             server: None,
             procedure: None,
             line: 1,
+            additional: Vec::new(),
         }
     }
 
@@ -654,4 +771,32 @@ mod tests {
             .is_server_error(102)
         );
     }
+
+    #[test]
+    fn test_additional_messages() {
+        assert!(make_server_error(547).additional_messages().is_empty());
+        assert!(Error::CommandTimeout.additional_messages().is_empty());
+
+        let info = ServerMessage {
+            number: 3621,
+            class: 0,
+            state: 0,
+            message: "The statement has been terminated.".into(),
+            server: Some("srv".into()),
+            procedure: None,
+            line: 1,
+        };
+        assert!(!info.is_error());
+        assert_eq!(
+            info.to_string(),
+            "info 3621 (severity 0, state 0): The statement has been terminated. \
+             [server: srv, line: 1]"
+        );
+
+        let mut err = make_server_error(547);
+        if let Error::Server { additional, .. } = &mut err {
+            additional.push(info.clone());
+        }
+        assert_eq!(err.additional_messages(), &[info]);
+    }
 }
@@ -0,0 +1,287 @@
+//! Protocol-ordered query events: result-set boundaries, rows, completions and
+//! server messages as a single incremental stream.
+//!
+//! [`RowStream`](crate::RowStream) flattens a response into rows. Tools that
+//! relay everything the server sends (a proxy, a gRPC passthrough, a SQL
+//! console) also need to know where one result set ends and the next begins,
+//! what each statement's row count was, and which `PRINT`/`RAISERROR ... WITH
+//! NOWAIT` messages arrived in between. [`EventStream`] yields those as
+//! [`QueryEvent`]s in the order the tokens appear on the wire.
+//!
+//! ```no_run
+//! # use mssql_client::{Client, QueryEvent, Ready};
+//! # async fn ex(client: &mut Client<Ready>) -> Result<(), mssql_client::Error> {
+//! let mut events = client
+//!     .query_events("SELECT 1 AS a; PRINT 'between'; SELECT 2 AS b", &[])
+//!     .await?;
+//! while let Some(event) = events.try_next().await? {
+//!     match event {
+//!         QueryEvent::NewResultSet(columns) => println!("{} column(s)", columns.len()),
+//!         QueryEvent::Row(row) => println!("{:?}", row.get_raw(0)),
+//!         QueryEvent::Done { count, .. } => println!("done: {count:?}"),
+//!         QueryEvent::Message(msg) => println!("server: {msg}"),
+//!         _ => {}
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use tds_protocol::token::{ColMetaData, Token};
+
+use crate::Client;
+use crate::client::response::server_info_message;
+use crate::error::{Error, Result, ServerMessage};
+use crate::row::{Column, Row};
+use crate::row_source::{Pull, RowSource};
+use crate::state::{ConnectionState, Ready};
+
+/// One item of a query response, in protocol order.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum QueryEvent {
+    /// A result set begins; subsequent [`Row`](Self::Row) events use these
+    /// columns until the next `NewResultSet`.
+    NewResultSet(Vec<Column>),
+    /// A row of the current result set.
+    Row(Row),
+    /// A statement (or procedure) completed.
+    Done {
+        /// The DONE token's current-command code: SQL Server's identifier for
+        /// the kind of statement that completed (e.g. `0xC1` for `SELECT`,
+        /// `0xC3` for `INSERT`). Lets a batch runner tell which statement a
+        /// count belongs to.
+        command: u16,
+        /// The row count when the server reported one — `None` under
+        /// `SET NOCOUNT ON` or for statements that do not count rows.
+        count: Option<u64>,
+    },
+    /// An informational message (`PRINT`, severity ≤ 10 `RAISERROR`).
+    Message(ServerMessage),
+}
+
+/// An incrementally read stream of [`QueryEvent`]s.
+///
+/// Obtain one from [`Client::query_events`](crate::Client::query_events). Like
+/// [`RowStream`](crate::RowStream) it borrows the client for its lifetime and
+/// reads packets only as events are pulled; dropping it early is safe, and
+/// the next request on the connection cancels the unread remainder.
+#[must_use = "streams must be consumed; dropping a stream discards remaining events"]
+pub struct EventStream<'a, S: ConnectionState = Ready> {
+    client: &'a mut Client<S>,
+    source: RowSource,
+    /// Columns for the current result set.
+    row_meta: Arc<crate::row::ColMetaData>,
+    /// Protocol metadata for decoding raw rows of the current result set.
+    meta: ColMetaData,
+    /// Pre-resolved column decryptor for the current Always Encrypted result set.
+    #[cfg(feature = "always-encrypted")]
+    decryptor: Option<Arc<crate::column_decryptor::ColumnDecryptor>>,
+    finished: bool,
+}
+
+impl<'a, S: ConnectionState> EventStream<'a, S> {
+    /// Construct a stream over a request that has just been sent. Called by
+    /// `Client::query_events`.
+    pub(crate) fn new(client: &'a mut Client<S>, source: RowSource) -> Self {
+        Self {
+            client,
+            source,
+            row_meta: Arc::new(crate::row::ColMetaData::new(Vec::new())),
+            meta: ColMetaData::default(),
+            #[cfg(feature = "always-encrypted")]
+            decryptor: None,
+            finished: false,
+        }
+    }
+
+    /// Whether the stream has been fully consumed.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Pull the next event, reading more packets from the connection as needed.
+    ///
+    /// Returns `Ok(None)` once the response is fully drained — at which point
+    /// the connection is clean for the next request. A server error token
+    /// ends the stream and is surfaced as [`Error::Server`] once the rest of
+    /// the response has been read; events after it are discarded.
+    pub async fn try_next(&mut self) -> Result<Option<QueryEvent>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        loop {
+            match self.source.pull()? {
+                Pull::Token(Token::ColMetaData(meta)) => {
+                    self.switch_result_set(meta).await?;
+                    return Ok(Some(QueryEvent::NewResultSet(
+                        self.row_meta.columns.to_vec(),
+                    )));
+                }
+                Pull::Token(Token::Row(raw)) => {
+                    return Ok(Some(QueryEvent::Row(self.decode_raw(&raw)?)));
+                }
+                Pull::Token(Token::NbcRow(nbc)) => {
+                    return Ok(Some(QueryEvent::Row(self.decode_nbc(&nbc)?)));
+                }
+                Pull::Token(Token::Info(info)) => {
+                    return Ok(Some(QueryEvent::Message(server_info_message(&info))));
+                }
+                Pull::Token(Token::Error(err)) => {
+                    // Read the rest of the response so the error carries the
+                    // server's later messages and the connection is clean.
+                    self.finished = true;
+                    return Err(self
+                        .client
+                        .finish_streamed_error(&err, Vec::new(), &mut self.source)
+                        .await);
+                }
+                Pull::Token(Token::Done(done)) => {
+                    return self.done_event(
+                        done.status.error,
+                        done.cur_cmd,
+                        done.status.count,
+                        done.row_count,
+                    );
+                }
+                Pull::Token(Token::DoneProc(done)) => {
+                    return self.done_event(
+                        done.status.error,
+                        done.cur_cmd,
+                        done.status.count,
+                        done.row_count,
+                    );
+                }
+                Pull::Token(Token::DoneInProc(done)) => {
+                    return self.done_event(
+                        done.status.error,
+                        done.cur_cmd,
+                        done.status.count,
+                        done.row_count,
+                    );
+                }
+                Pull::Token(Token::EnvChange(env)) => {
+                    self.client.apply_env_change(&env);
+                }
+                Pull::Token(_) => {
+                    // Order / ReturnStatus / ReturnValue, etc. — not surfaced.
+                }
+                Pull::NeedMore => match self.client.read_response_packet().await? {
+                    Some((payload, is_eom)) => self.source.push_packet(payload, is_eom),
+                    None => {
+                        self.finish();
+                        return Err(Error::ConnectionClosed);
+                    }
+                },
+                Pull::End => {
+                    self.finish();
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    /// Drain the remaining events into a vector.
+    pub async fn collect_all(mut self) -> Result<Vec<QueryEvent>> {
+        let mut out = Vec::new();
+        while let Some(event) = self.try_next().await? {
+            out.push(event);
+        }
+        Ok(out)
+    }
+
+    /// Stop the stream early and leave the connection reusable.
+    ///
+    /// See [`RowStream::cancel`](crate::RowStream::cancel).
+    pub async fn cancel(mut self) -> Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+        self.client.cancel_in_flight_response().await
+    }
+
+    /// Turn a DONE / DONEPROC / DONEINPROC token into an event, or an error
+    /// when the server flagged the statement as failed.
+    fn done_event(
+        &mut self,
+        error: bool,
+        command: u16,
+        has_count: bool,
+        row_count: u64,
+    ) -> Result<Option<QueryEvent>> {
+        if error {
+            self.finish();
+            return Err(Error::Query(
+                "query failed (server set error flag in DONE token)".to_string(),
+            ));
+        }
+        Ok(Some(QueryEvent::Done {
+            command,
+            count: has_count.then_some(row_count),
+        }))
+    }
+
+    /// Mark the stream finished and the connection clean for the next request.
+    fn finish(&mut self) {
+        self.finished = true;
+        self.client.note_response_drained();
+    }
+
+    /// Adopt a new result set's metadata.
+    async fn switch_result_set(&mut self, meta: ColMetaData) -> Result<()> {
+        self.row_meta = Arc::new(crate::row::ColMetaData::new(Client::<S>::build_columns(
+            &meta,
+        )));
+        #[cfg(feature = "always-encrypted")]
+        {
+            self.decryptor = self.client.resolve_decryptor(&meta).await?.map(Arc::new);
+        }
+        self.meta = meta;
+        Ok(())
+    }
+
+    /// Decode a raw row against the current result set's metadata.
+    fn decode_raw(&self, raw: &tds_protocol::token::RawRow) -> Result<Row> {
+        #[cfg(feature = "always-encrypted")]
+        if let Some(ref dec) = self.decryptor {
+            return crate::column_parser::convert_raw_row_decrypted(
+                raw,
+                &self.meta,
+                &self.row_meta,
+                dec,
+                self.client.lenient_utf16(),
+            );
+        }
+        crate::column_parser::convert_raw_row(
+            raw,
+            &self.meta,
+            &self.row_meta,
+            self.client.lenient_utf16(),
+        )
+    }
+
+    /// Decode a null-bitmap-compressed row against the current metadata.
+    fn decode_nbc(&self, nbc: &tds_protocol::token::NbcRow) -> Result<Row> {
+        #[cfg(feature = "always-encrypted")]
+        if let Some(ref dec) = self.decryptor {
+            return crate::column_parser::convert_nbc_row_decrypted(
+                nbc,
+                &self.meta,
+                &self.row_meta,
+                dec,
+                self.client.lenient_utf16(),
+            );
+        }
+        crate::column_parser::convert_nbc_row(
+            nbc,
+            &self.meta,
+            &self.row_meta,
+            self.client.lenient_utf16(),
+        )
+    }
+}
@@ -0,0 +1,121 @@
+//! Feature extensions acknowledged by the server at login.
+//!
+//! LOGIN7 can request optional protocol features (federated authentication,
+//! Always Encrypted, UTF-8 support, ...); the server confirms the ones it
+//! enabled in a FEATUREEXTACK token. The driver records that acknowledgement
+//! so callers can check what is actually in effect on a connection.
+
+use bytes::Bytes;
+use tds_protocol::login7::FeatureId;
+use tds_protocol::token::FeatureExtAck;
+
+/// The feature extensions the server acknowledged during login.
+///
+/// Obtained via [`Client::enabled_features`](crate::Client::enabled_features).
+/// A server may acknowledge features the driver did not request (e.g. UTF-8
+/// support) and features this crate does not know by name; the latter are
+/// reported by [`ids`](Self::ids).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnabledFeatures {
+    acks: Vec<(u8, Bytes)>,
+}
+
+impl EnabledFeatures {
+    /// Record the features from a FEATUREEXTACK token.
+    pub(crate) fn extend_from_ack(&mut self, ack: &FeatureExtAck) {
+        for feature in &ack.features {
+            self.acks.push((feature.feature_id, feature.data.clone()));
+        }
+    }
+
+    /// Whether the server acknowledged `feature`.
+    #[must_use]
+    pub fn contains(&self, feature: FeatureId) -> bool {
+        self.data(feature).is_some()
+    }
+
+    /// The acknowledgement data the server sent for `feature`, if it was
+    /// acknowledged (e.g. the negotiated Always Encrypted version).
+    #[must_use]
+    pub fn data(&self, feature: FeatureId) -> Option<&[u8]> {
+        self.acks
+            .iter()
+            .find(|(id, _)| *id == feature as u8)
+            .map(|(_, data)| data.as_ref())
+    }
+
+    /// Whether the server will send `_UTF8` collation data as UTF-8.
+    ///
+    /// True when the server acknowledged [`FeatureId::Utf8Support`] with the
+    /// "supported" bit set (SQL Server 2019 and later).
+    #[must_use]
+    pub fn utf8_support(&self) -> bool {
+        self.data(FeatureId::Utf8Support)
+            .and_then(|data| data.first())
+            .is_some_and(|flags| flags & 0x01 != 0)
+    }
+
+    /// Raw IDs of every acknowledged feature, in the order the server sent them.
+    pub fn ids(&self) -> impl Iterator<Item = u8> + '_ {
+        self.acks.iter().map(|(id, _)| *id)
+    }
+
+    /// Whether the server acknowledged no features at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.acks.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use tds_protocol::token::FeatureAck;
+
+    #[test]
+    fn test_enabled_features_from_ack() {
+        let mut features = EnabledFeatures::default();
+        assert!(features.is_empty());
+
+        features.extend_from_ack(&FeatureExtAck {
+            features: vec![
+                FeatureAck {
+                    feature_id: FeatureId::ColumnEncryption as u8,
+                    data: Bytes::from_static(&[0x01]),
+                },
+                FeatureAck {
+                    feature_id: 0x42,
+                    data: Bytes::new(),
+                },
+            ],
+        });
+
+        assert!(features.contains(FeatureId::ColumnEncryption));
+        assert_eq!(
+            features.data(FeatureId::ColumnEncryption),
+            Some(&[0x01][..])
+        );
+        assert!(!features.contains(FeatureId::FedAuth));
+        assert_eq!(features.ids().collect::<Vec<_>>(), vec![0x04, 0x42]);
+        assert!(!features.utf8_support());
+    }
+
+    #[test]
+    fn test_utf8_support_reads_ack_flag() {
+        let ack = |data: &'static [u8]| {
+            let mut features = EnabledFeatures::default();
+            features.extend_from_ack(&FeatureExtAck {
+                features: vec![FeatureAck {
+                    feature_id: FeatureId::Utf8Support as u8,
+                    data: Bytes::from_static(data),
+                }],
+            });
+            features
+        };
+
+        assert!(ack(&[0x01]).utf8_support());
+        assert!(!ack(&[0x00]).utf8_support());
+        assert!(!ack(&[]).utf8_support());
+    }
+}
@@ -36,6 +36,8 @@
 //! - `db.operation`: Query operation type (SELECT, INSERT, etc.)
 //! - `server.address`: Server hostname
 //! - `server.port`: Server port
+//! - `db.mssql.session_id`: Server session ID (SPID), for correlating a span
+//!   with server-side DMVs and Extended Events
 //!
 //! `db.statement` is sanitized by default ([`SanitizationConfig`]) so literal
 //! parameter values are replaced with placeholders before being recorded; opt
@@ -100,6 +102,8 @@ pub mod attributes {
     pub const DB_ISOLATION_LEVEL: &str = "db.mssql.isolation_level";
     /// Connection ID.
     pub const DB_CONNECTION_ID: &str = "db.connection_id";
+    /// Server session ID (SPID), for joining spans to server-side captures.
+    pub const DB_MSSQL_SESSION_ID: &str = "db.mssql.session_id";
     /// Error type.
     pub const ERROR_TYPE: &str = "error.type";
 }
@@ -251,6 +255,8 @@ pub struct InstrumentationContext {
     pub server_port: u16,
     /// Database name.
     pub database: Option<String>,
+    /// Server session ID (SPID) reported at login.
+    pub session_id: Option<u16>,
     /// Sanitization configuration.
     pub sanitization: SanitizationConfig,
     /// Operation-level metrics (duration histogram, operation/error counters)
@@ -265,6 +271,7 @@ impl std::fmt::Debug for InstrumentationContext {
             .field("server_address", &self.server_address)
             .field("server_port", &self.server_port)
             .field("database", &self.database)
+            .field("session_id", &self.session_id)
             .field("sanitization", &self.sanitization)
             .finish_non_exhaustive()
     }
@@ -280,6 +287,7 @@ impl InstrumentationContext {
             server_address,
             server_port,
             database: None,
+            session_id: None,
             sanitization: SanitizationConfig::default(),
             metrics,
         }
@@ -298,6 +306,13 @@ impl InstrumentationContext {
         self
     }
 
+    /// Set the server session ID recorded on every span.
+    #[must_use]
+    pub fn with_session_id(mut self, session_id: Option<u16>) -> Self {
+        self.session_id = session_id;
+        self
+    }
+
     /// Set the sanitization configuration.
     #[must_use]
     pub fn with_sanitization(mut self, config: SanitizationConfig) -> Self {
@@ -317,6 +332,13 @@ impl InstrumentationContext {
             attrs.push(KeyValue::new(attributes::DB_NAME, db.clone()));
         }
 
+        if let Some(session_id) = self.session_id {
+            attrs.push(KeyValue::new(
+                attributes::DB_MSSQL_SESSION_ID,
+                i64::from(session_id),
+            ));
+        }
+
         attrs
     }
 
@@ -433,6 +455,12 @@ impl InstrumentationContext {
         self
     }
 
+    /// Set the server session ID (no-op).
+    #[must_use]
+    pub fn with_session_id(self, _session_id: Option<u16>) -> Self {
+        self
+    }
+
     /// Set the sanitization configuration (no-op).
     #[must_use]
     pub fn with_sanitization(self, _config: SanitizationConfig) -> Self {
@@ -700,6 +728,23 @@ impl OperationTimer {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_base_attributes_include_session_id() {
+        let key = opentelemetry::Key::from_static_str(attributes::DB_MSSQL_SESSION_ID);
+
+        let ctx = InstrumentationContext::new("localhost".into(), 1433);
+        assert!(!ctx.base_attributes().iter().any(|kv| kv.key == key));
+
+        let ctx = ctx.with_session_id(Some(57));
+        let attr = ctx
+            .base_attributes()
+            .into_iter()
+            .find(|kv| kv.key == key)
+            .unwrap();
+        assert_eq!(attr.value, opentelemetry::Value::I64(57));
+    }
+
     #[test]
     fn test_extract_operation() {
         assert_eq!(extract_operation("SELECT * FROM users"), "SELECT");
@@ -5,7 +5,7 @@
 // Module dependency graph (acyclic):
 //
 //   client ──→ config, state, error, stream, transaction, statement_cache
-//     ├── connect.rs ──→ config, state, instrumentation, mssql_tls, mssql_codec, tds_protocol
+//     ├── connect.rs ──→ config, state, resolver, instrumentation, mssql_tls, mssql_codec, tds_protocol
 //     ├── params.rs  ──→ mssql_types, tds_protocol
 //     └── response.rs ──→ error, mssql_codec, tds_protocol
 //   procedure ──→ client, error, state, stream, tds_protocol
@@ -14,6 +14,7 @@
 //   config ──→ mssql_auth, mssql_tls, tds_protocol
 //   bulk ──→ error, mssql_types, tds_protocol
 //   cancel ──→ error, mssql_codec, mssql_tls
+//   resolver ──→ (tokio only)
 //   encryption ──→ mssql_auth, tds_protocol
 //   column_parser ──→ error, mssql_types, tds_protocol
 
@@ -22,14 +23,18 @@ pub mod blob_stream;
 pub(crate) mod browser;
 pub mod bulk;
 pub mod cancel;
+pub mod catalog;
 pub mod change_tracking;
 pub mod client;
 #[cfg(feature = "always-encrypted")]
 pub(crate) mod column_decryptor;
 pub(crate) mod column_parser;
 pub mod config;
+pub mod csv;
 pub mod encryption;
 pub mod error;
+pub mod event_stream;
+pub mod features;
 #[cfg(all(windows, feature = "filestream"))]
 #[allow(unsafe_code)] // Win32 FFI for OpenSqlFilestream; see SAFETY comments in each unsafe block
 pub mod filestream;
@@ -37,7 +42,10 @@ pub mod from_row;
 pub mod instrumentation;
 pub(crate) mod plp;
 pub mod procedure;
+pub mod product_version;
 pub mod query;
+pub(crate) mod read_only;
+pub mod resolver;
 pub mod row;
 // Sans-IO incremental token decoder driving the streaming read path.
 pub(crate) mod row_source;
@@ -45,7 +53,8 @@ pub mod row_stream;
 pub mod state;
 // Wired into the buffered `query` path behind the off-by-default
 // `Config::statement_cache` flag. The cache types stay crate-private; only the
-// `StatementCacheStats` snapshot is re-exported (below) for observability.
+// `StatementCacheStats` and `CachedStatementInfo` snapshots are re-exported
+// (below) for observability.
 pub(crate) mod statement_cache;
 pub mod stream;
 pub mod to_params;
@@ -55,13 +64,21 @@ pub(crate) mod validation;
 
 // Re-export commonly used types
 pub use bulk::{
-    BulkColumn, BulkInsert, BulkInsertBuilder, BulkInsertResult, BulkOptions, BulkWriter,
+    BulkColumn, BulkInsert, BulkInsertBuilder, BulkInsertResult, BulkOptions, BulkUpsertResult,
+    BulkWriter,
 };
 pub use cancel::CancelHandle;
+pub use catalog::TableInfo;
 pub use client::Client;
-pub use config::{ApplicationIntent, Config, RedirectConfig, RetryPolicy, TimeoutConfig};
-pub use error::{Error, SharedIoError};
-pub use statement_cache::StatementCacheStats;
+pub use config::{
+    ApplicationIntent, Config, ConfigWarning, RedirectConfig, RetryPolicy, TimeoutConfig,
+};
+pub use csv::{CsvOptions, CsvQuoting};
+pub use error::{Error, ServerMessage, SharedIoError};
+pub use event_stream::{EventStream, QueryEvent};
+pub use features::EnabledFeatures;
+pub use statement_cache::{CachedStatementInfo, StatementCacheStats};
+pub use tokio_util::sync::CancellationToken;
 // Sub-error types carried by `Error` variants and the `FromSql`/`ToSql` trait
 // return type. Re-exported so downstream crates can name them (e.g. match on
 // `Error::Type(e)`, or write `fn from_sql(..) -> Result<Self, TypeError>`)
@@ -99,27 +116,28 @@ pub use tds_protocol::token::Collation;
 // namespace, so `#[derive(FromRow)]` and `impl FromRow` coexist (as with
 // serde's `Serialize`).
 #[cfg(feature = "derive")]
-pub use mssql_derive::{FromRow, ToParams, Tvp};
+pub use mssql_derive::{FromRow, SqlEnum, ToParams, Tvp};
 
 /// Items the derive macros' generated code references. Not public API: hidden
 /// from docs and exempt from stability guarantees. Centralizing them here
 /// keeps the proc-macro crate decoupled from internal restructuring.
 #[doc(hidden)]
 pub mod __private {
-    pub use mssql_types::{ToSql, TypeError};
+    pub use mssql_types::{FromSql, SqlValue, ToSql, TypeError};
 }
 
 // Re-export TDS version for configuration
 pub use from_row::{FromRow, MapRows, RowIteratorExt};
 pub use mssql_auth::Credentials;
+pub use tds_protocol::login7::{FeatureExtension, FeatureId, Login7};
 pub use tds_protocol::version::TdsVersion;
 
 // Secure credential types (with zeroize feature)
 #[cfg(feature = "zeroize")]
 pub use mssql_auth::{SecretString, SecureCredentials};
 pub use mssql_types::{
-    Binary, Char, EncryptedParamType, FromSql, NChar, SqlTyped, SqlValue, ToSql, TypedNull, binary,
-    char, nchar, null,
+    Binary, Char, EncryptedParamType, FromSql, HierarchyId, NChar, NullAsEmpty, SqlTyped, SqlValue,
+    ToSql, TypedNull, binary, char, nchar, null,
 };
 #[cfg(feature = "chrono")]
 pub use mssql_types::{
@@ -129,7 +147,9 @@ pub use mssql_types::{
 #[cfg(feature = "decimal")]
 pub use mssql_types::{Money, Numeric, SmallMoney, numeric};
 pub use procedure::ProcedureBuilder;
-pub use query::in_params;
+pub use product_version::ProductVersion;
+pub use query::{BatchErrorPolicy, Query, in_params};
+pub use resolver::{Resolver, SystemResolver};
 pub use row::{Column, Row};
 pub use state::{Connected, ConnectionState, Disconnected, InTransaction, ProtocolState, Ready};
 
@@ -186,7 +206,7 @@ pub mod __bench {
                 Token::Row(raw) => {
                     let m = meta.as_ref().expect("ColMetaData precedes ROW tokens");
                     rows.push(
-                        crate::column_parser::convert_raw_row(&raw, m, &row_meta)
+                        crate::column_parser::convert_raw_row(&raw, m, &row_meta, false)
                             .expect("benchmark row must convert"),
                     );
                 }
@@ -196,14 +216,14 @@ pub mod __bench {
         rows
     }
 }
-pub use blob_stream::BlobStream;
+pub use blob_stream::{BlobStream, BlobStreamReader};
 pub use row_stream::RowStream;
 pub use stream::{
     ExecuteResult, MultiResultStream, OutputParam, ProcedureResult, QueryStream, ResultSet,
 };
 pub use to_params::{NamedParam, ParamList, ToParams};
 pub use transaction::{IsolationLevel, SavePoint};
-pub use tvp::{Tvp, TvpColumn, TvpRow, TvpValue};
+pub use tvp::{Tvp, TvpColumn, TvpRow, TvpSchema, TvpValue};
 
 // FILESTREAM support (Windows only)
 #[cfg(all(windows, feature = "filestream"))]
@@ -280,12 +300,23 @@ mod auto_trait_tests {
         assert_sync::<RowStream<'_>>();
     }
 
+    #[test]
+    fn event_stream_is_send_sync() {
+        assert_send::<EventStream<'_>>();
+        assert_sync::<EventStream<'_>>();
+    }
+
     #[test]
     fn blob_stream_is_send_sync() {
         assert_send::<BlobStream<'_>>();
         assert_sync::<BlobStream<'_>>();
     }
 
+    #[test]
+    fn blob_stream_reader_is_send() {
+        assert_send::<BlobStreamReader<'_>>();
+    }
+
     #[test]
     fn multi_result_stream_is_send_sync() {
         assert_send::<MultiResultStream<'_>>();
@@ -0,0 +1,123 @@
+//! SQL Server product version.
+//!
+//! [`ProductVersion`] is the `major.minor.build.revision` quad reported by
+//! `SERVERPROPERTY('ProductVersion')` (e.g. `16.0.4225.2` for a SQL Server
+//! 2022 cumulative update). Obtain it from
+//! [`Client::product_version`](crate::Client::product_version), which queries
+//! the server once per connection and caches the result.
+
+use std::fmt;
+
+/// A SQL Server product version, ordered by `major`, then `minor`, `build`
+/// and `revision`.
+///
+/// ```rust
+/// use mssql_client::ProductVersion;
+///
+/// let v = ProductVersion::parse("16.0.4225.2").unwrap();
+/// assert_eq!(v.major, 16);
+/// assert!(v >= ProductVersion::new(15, 0, 0, 0));
+/// assert!(v.supports_tds8());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProductVersion {
+    /// Major version (13 = 2016, 14 = 2017, 15 = 2019, 16 = 2022).
+    pub major: u32,
+    /// Minor version.
+    pub minor: u32,
+    /// Build number.
+    pub build: u32,
+    /// Revision number.
+    pub revision: u32,
+}
+
+impl ProductVersion {
+    /// Create a version from its four components.
+    #[must_use]
+    pub const fn new(major: u32, minor: u32, build: u32, revision: u32) -> Self {
+        Self {
+            major,
+            minor,
+            build,
+            revision,
+        }
+    }
+
+    /// Parse a `major.minor.build.revision` string.
+    ///
+    /// Missing trailing components are treated as 0 (`"16.0"` is `16.0.0.0`).
+    /// Returns `None` for empty, non-numeric, or over-long input.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = [0u32; 4];
+        for (i, part) in s.trim().split('.').enumerate() {
+            *parts.get_mut(i)? = part.parse().ok()?;
+        }
+        let [major, minor, build, revision] = parts;
+        Some(Self::new(major, minor, build, revision))
+    }
+
+    /// Whether the server has the JSON functions (`OPENJSON`, `FOR JSON`,
+    /// `JSON_VALUE`): SQL Server 2016 (13.x) and later.
+    #[must_use]
+    pub const fn supports_json(&self) -> bool {
+        self.major >= 13
+    }
+
+    /// Whether the server supports TDS 8.0 strict encryption
+    /// (`Encrypt=strict`): SQL Server 2022 (16.x) and later.
+    #[must_use]
+    pub const fn supports_tds8(&self) -> bool {
+        self.major >= 16
+    }
+}
+
+impl fmt::Display for ProductVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            self.major, self.minor, self.build, self.revision
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            ProductVersion::parse("16.0.4225.2"),
+            Some(ProductVersion::new(16, 0, 4225, 2))
+        );
+        assert_eq!(
+            ProductVersion::parse("12.0"),
+            Some(ProductVersion::new(12, 0, 0, 0))
+        );
+        assert_eq!(ProductVersion::parse(""), None);
+        assert_eq!(ProductVersion::parse("16.x.1.2"), None);
+        assert_eq!(ProductVersion::parse("16.0.1.2.3"), None);
+    }
+
+    #[test]
+    fn test_ordering_and_display() {
+        let cu = ProductVersion::parse("15.0.4345.5").unwrap();
+        assert!(cu > ProductVersion::new(15, 0, 2000, 5));
+        assert!(cu < ProductVersion::new(16, 0, 0, 0));
+        assert_eq!(cu.to_string(), "15.0.4345.5");
+    }
+
+    #[test]
+    fn test_feature_predicates() {
+        let v2014 = ProductVersion::new(12, 0, 6024, 0);
+        let v2016 = ProductVersion::new(13, 0, 1601, 5);
+        let v2022 = ProductVersion::new(16, 0, 1000, 6);
+        assert!(!v2014.supports_json());
+        assert!(v2016.supports_json());
+        assert!(!v2016.supports_tds8());
+        assert!(v2022.supports_tds8());
+    }
+}
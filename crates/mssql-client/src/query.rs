@@ -2,6 +2,8 @@
 
 use std::fmt::Write;
 
+use crate::error::Error;
+
 /// Generate an IN clause SQL fragment with positional parameters.
 ///
 /// Returns a string like `(@p1, @p2, @p3)` for use in `WHERE column IN (...)`
@@ -42,10 +44,195 @@ pub fn in_params(start: usize, count: usize) -> String {
     s
 }
 
+/// A statement with typed query hints, rendered as a single trailing
+/// `OPTION (...)` clause.
+///
+/// Covers the common parameter-sniffing mitigations without hand-writing the
+/// hint syntax. Adding the same hint twice keeps one copy; a second
+/// [`option_maxdop`](Self::option_maxdop) replaces the first. The SQL must be a
+/// single statement without an `OPTION` clause of its own, since SQL Server
+/// accepts only one per statement.
+///
+/// # Examples
+///
+/// ```
+/// use mssql_client::Query;
+///
+/// let sql = Query::new("SELECT * FROM orders WHERE customer_id = @p1;")
+///     .option_recompile()
+///     .option_maxdop(4)
+///     .to_sql();
+/// assert_eq!(
+///     sql,
+///     "SELECT * FROM orders WHERE customer_id = @p1 OPTION (RECOMPILE, MAXDOP 4)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    sql: String,
+    hints: Vec<QueryHint>,
+}
+
+/// One typed `OPTION` hint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueryHint {
+    Recompile,
+    MaxDop(u16),
+    OptimizeForUnknown,
+}
+
+impl Query {
+    /// Start from a statement's SQL text.
+    pub fn new(sql: impl Into<String>) -> Self {
+        Self {
+            sql: sql.into(),
+            hints: Vec::new(),
+        }
+    }
+
+    /// Add `RECOMPILE`: compile a fresh plan for the actual parameter values
+    /// on every execution.
+    #[must_use]
+    pub fn option_recompile(self) -> Self {
+        self.with_hint(QueryHint::Recompile)
+    }
+
+    /// Add `MAXDOP n`: cap the statement's degree of parallelism (`0` lets
+    /// the server choose).
+    #[must_use]
+    pub fn option_maxdop(mut self, n: u16) -> Self {
+        self.hints.retain(|h| !matches!(h, QueryHint::MaxDop(_)));
+        self.with_hint(QueryHint::MaxDop(n))
+    }
+
+    /// Add `OPTIMIZE FOR UNKNOWN`: plan for average parameter statistics
+    /// instead of the values sniffed at compile time.
+    #[must_use]
+    pub fn option_optimize_for_unknown(self) -> Self {
+        self.with_hint(QueryHint::OptimizeForUnknown)
+    }
+
+    fn with_hint(mut self, hint: QueryHint) -> Self {
+        if !self.hints.contains(&hint) {
+            self.hints.push(hint);
+        }
+        self
+    }
+
+    /// Render the statement with its hints, ready for
+    /// [`Client::query`](crate::Client::query) and friends.
+    ///
+    /// A trailing `;` is dropped so the clause stays part of the statement.
+    /// Without hints the SQL is returned unchanged.
+    #[must_use]
+    pub fn to_sql(&self) -> String {
+        if self.hints.is_empty() {
+            return self.sql.clone();
+        }
+        let mut sql = self
+            .sql
+            .trim_end()
+            .trim_end_matches(';')
+            .trim_end()
+            .to_string();
+        sql.push_str(" OPTION (");
+        for (i, hint) in self.hints.iter().enumerate() {
+            if i > 0 {
+                sql.push_str(", ");
+            }
+            match hint {
+                QueryHint::Recompile => sql.push_str("RECOMPILE"),
+                // write! on String is infallible
+                QueryHint::MaxDop(n) => write!(sql, "MAXDOP {n}").unwrap(),
+                QueryHint::OptimizeForUnknown => sql.push_str("OPTIMIZE FOR UNKNOWN"),
+            }
+        }
+        sql.push(')');
+        sql
+    }
+}
+
+impl std::fmt::Display for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_sql())
+    }
+}
+
+/// What [`Client::execute_batch`](crate::Client::execute_batch) does when one
+/// of its statements fails.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BatchErrorPolicy {
+    /// Stop at the first failing statement; later statements are not sent.
+    #[default]
+    StopOnError,
+    /// Run the remaining statements after a statement fails, so every
+    /// failure is reported alongside the statements that succeeded.
+    ContinueOnError,
+}
+
+impl BatchErrorPolicy {
+    /// Whether the batch carries on after `error`.
+    ///
+    /// Only server errors (the statement failed, the connection is fine) are
+    /// continued past; any other error stops the batch under either policy,
+    /// since the connection may no longer be usable.
+    pub(crate) fn continues_after(self, error: &Error) -> bool {
+        self == Self::ContinueOnError && matches!(error, Error::Server { .. })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_batch_error_policy_continues_only_after_server_errors() {
+        let server = Error::Server {
+            number: 208,
+            class: 16,
+            state: 1,
+            message: "Invalid object name".into(),
+            server: None,
+            procedure: None,
+            line: 1,
+            additional: Vec::new(),
+        };
+        assert!(BatchErrorPolicy::ContinueOnError.continues_after(&server));
+        assert!(!BatchErrorPolicy::StopOnError.continues_after(&server));
+        assert!(!BatchErrorPolicy::ContinueOnError.continues_after(&Error::ConnectionClosed));
+        assert_eq!(BatchErrorPolicy::default(), BatchErrorPolicy::StopOnError);
+    }
+
+    #[test]
+    fn test_query_hints_merge_into_one_option_clause() {
+        let sql = Query::new("SELECT * FROM t WHERE id = @p1")
+            .option_recompile()
+            .option_optimize_for_unknown()
+            .option_maxdop(1)
+            .to_sql();
+        assert_eq!(
+            sql,
+            "SELECT * FROM t WHERE id = @p1 OPTION (RECOMPILE, OPTIMIZE FOR UNKNOWN, MAXDOP 1)"
+        );
+    }
+
+    #[test]
+    fn test_query_hints_deduplicate_and_replace_maxdop() {
+        let query = Query::new("SELECT 1 ; \n")
+            .option_maxdop(8)
+            .option_recompile()
+            .option_recompile()
+            .option_maxdop(2);
+        assert_eq!(query.to_sql(), "SELECT 1 OPTION (RECOMPILE, MAXDOP 2)");
+        assert_eq!(query.to_string(), query.to_sql());
+    }
+
+    #[test]
+    fn test_query_without_hints_is_unchanged() {
+        assert_eq!(Query::new("SELECT 1;").to_sql(), "SELECT 1;");
+    }
+
     #[test]
     fn test_in_params_single() {
         assert_eq!(in_params(1, 1), "(@p1)");
@@ -0,0 +1,168 @@
+//! Client-side write detection for [`Config::read_only_guard`](crate::Config::read_only_guard).
+//!
+//! Scans a T-SQL batch for keywords that modify data or schema. String
+//! literals, comments, quoted identifiers, variables and qualified names
+//! (`t.update`) are skipped, so only bare keywords count. This is a guardrail
+//! against accidental writes, not a parser: anything it cannot see (the body
+//! of a stored procedure, dynamic SQL built inside the batch) is not checked.
+
+/// Keywords rejected by the guard. `INTO` covers `SELECT ... INTO`, which
+/// creates a table.
+const WRITE_KEYWORDS: &[&str] = &[
+    "INSERT", "UPDATE", "DELETE", "MERGE", "TRUNCATE", "INTO", "CREATE", "ALTER", "DROP", "GRANT",
+    "REVOKE", "DENY",
+];
+
+/// Return the first write keyword in `sql`, uppercased, if any.
+pub(crate) fn find_write_keyword(sql: &str) -> Option<&'static str> {
+    let bytes = sql.as_bytes();
+    let mut i = 0;
+    // Whether the previous significant byte was `.`, making the next word
+    // part of a qualified name rather than a keyword.
+    let mut after_dot = false;
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        match b {
+            b'\'' => {
+                i = skip_quoted(bytes, i, b'\'');
+                after_dot = false;
+            }
+            b'"' => {
+                i = skip_quoted(bytes, i, b'"');
+                after_dot = false;
+            }
+            b'[' => {
+                i = skip_quoted(bytes, i, b']');
+                after_dot = false;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = skip_block_comment(bytes, i);
+            }
+            b'.' => {
+                after_dot = true;
+                i += 1;
+            }
+            b'@' | b'#' => {
+                // Variables and temp table names are never keywords.
+                i += 1;
+                while i < bytes.len() && is_ident_byte(bytes[i]) {
+                    i += 1;
+                }
+                after_dot = false;
+            }
+            _ if b.is_ascii_alphabetic() || b == b'_' => {
+                let start = i;
+                while i < bytes.len() && is_ident_byte(bytes[i]) {
+                    i += 1;
+                }
+                if !after_dot {
+                    let word = &sql[start..i];
+                    if let Some(keyword) =
+                        WRITE_KEYWORDS.iter().find(|k| k.eq_ignore_ascii_case(word))
+                    {
+                        return Some(keyword);
+                    }
+                }
+                after_dot = false;
+            }
+            _ if b.is_ascii_whitespace() => i += 1,
+            _ => {
+                after_dot = false;
+                i += 1;
+            }
+        }
+    }
+    None
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'@' | b'#' | b'$') || !b.is_ascii()
+}
+
+/// Skip a quoted run starting at `start` (the opening quote). A doubled
+/// closing quote is an escape. Returns the index after the closing quote, or
+/// the end of input if unterminated.
+fn skip_quoted(bytes: &[u8], start: usize, close: u8) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        if bytes[i] == close {
+            if bytes.get(i + 1) == Some(&close) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Skip a `/* ... */` comment starting at `start`. T-SQL block comments nest.
+fn skip_block_comment(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0usize;
+    let mut i = start;
+    while i < bytes.len() {
+        if bytes[i] == b'/' && bytes.get(i + 1) == Some(&b'*') {
+            depth += 1;
+            i += 2;
+        } else if bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/') {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_pass() {
+        for sql in [
+            "SELECT * FROM orders WHERE id = @p1",
+            "WITH recent AS (SELECT id FROM t) SELECT * FROM recent",
+            "SET NOCOUNT ON; SELECT 1",
+            "EXEC dbo.report_totals",
+            "SELECT o.[update], o.delete_flag FROM o",
+            "SELECT 'DELETE FROM t' AS example -- DROP TABLE t\n",
+            "SELECT 1 /* outer /* UPDATE */ still comment */",
+            "SELECT \"insert\" FROM t",
+            "SELECT @insert, #create FROM t",
+            "SELECT t.update FROM t",
+        ] {
+            assert_eq!(find_write_keyword(sql), None, "{sql}");
+        }
+    }
+
+    #[test]
+    fn test_writes_are_found() {
+        for (sql, keyword) in [
+            ("INSERT INTO t VALUES (1)", "INSERT"),
+            ("  update t SET x = 1", "UPDATE"),
+            ("SELECT 1; DELETE FROM t", "DELETE"),
+            (
+                "WITH c AS (SELECT 1 AS x) MERGE INTO t USING c ON 1=0",
+                "MERGE",
+            ),
+            ("SELECT * INTO backup FROM t", "INTO"),
+            ("/* note */ CREATE TABLE t (x INT)", "CREATE"),
+            ("TRUNCATE TABLE t", "TRUNCATE"),
+            ("SELECT 'it''s' ; DROP TABLE t", "DROP"),
+            ("GRANT SELECT ON t TO u", "GRANT"),
+        ] {
+            assert_eq!(find_write_keyword(sql), Some(keyword), "{sql}");
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Host name resolution and TCP connection establishment.
+//!
+//! By default the driver resolves the server host name with the system
+//! resolver ([`tokio::net::lookup_host`]) and connects using a
+//! [Happy Eyeballs](https://datatracker.ietf.org/doc/html/rfc8305)-style
+//! strategy: resolved addresses are interleaved by family (IPv6 first), the
+//! first attempt starts immediately, and each subsequent attempt starts after
+//! a short delay or as soon as the previous attempt fails. The first socket to
+//! connect wins and the remaining attempts are cancelled.
+//!
+//! Environments with special DNS needs (service discovery, static host maps,
+//! split-horizon DNS) can plug in their own [`Resolver`] via
+//! [`Config::with_resolver`](crate::Config::with_resolver).
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use std::future::Future;
+//! use std::net::SocketAddr;
+//! use std::pin::Pin;
+//! use std::sync::Arc;
+//!
+//! use mssql_client::Config;
+//! use mssql_client::resolver::Resolver;
+//!
+//! #[derive(Debug)]
+//! struct StaticResolver(SocketAddr);
+//!
+//! impl Resolver for StaticResolver {
+//!     fn resolve<'a>(
+//!         &'a self,
+//!         _host: &'a str,
+//!         _port: u16,
+//!     ) -> Pin<Box<dyn Future<Output = std::io::Result<Vec<SocketAddr>>> + Send + 'a>> {
+//!         Box::pin(async move { Ok(vec![self.0]) })
+//!     }
+//! }
+//!
+//! let config = Config::new()
+//!     .host("db.internal")
+//!     .with_resolver(Arc::new(StaticResolver("10.0.0.5:1433".parse().unwrap())));
+//! # let _ = config;
+//! ```
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::time::Duration;
+
+use tokio::net::TcpStream;
+use tokio::task::JoinSet;
+
+/// Delay before starting the next connection attempt while the previous one
+/// is still pending (RFC 8305 recommends 250 ms).
+pub(crate) const CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+/// Future returned by [`Resolver::resolve`].
+pub type ResolveFuture<'a> =
+    Pin<Box<dyn Future<Output = std::io::Result<Vec<SocketAddr>>> + Send + 'a>>;
+
+/// An asynchronous host name resolver.
+///
+/// Implementations return every address the host resolves to; the driver
+/// decides the connection order. Returning an empty list is treated as a
+/// resolution failure.
+pub trait Resolver: std::fmt::Debug + Send + Sync {
+    /// Resolve `host` to the socket addresses to try for `port`.
+    fn resolve<'a>(&'a self, host: &'a str, port: u16) -> ResolveFuture<'a>;
+}
+
+/// The default resolver, backed by the system resolver via
+/// [`tokio::net::lookup_host`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+impl Resolver for SystemResolver {
+    fn resolve<'a>(&'a self, host: &'a str, port: u16) -> ResolveFuture<'a> {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((host, port)).await?;
+            Ok(addrs.collect())
+        })
+    }
+}
+
+/// Order addresses for connection attempts: IPv6 first, then alternate
+/// between families, preserving the resolver's order within each family.
+pub(crate) fn interleave_families(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+    let (v6, v4): (Vec<_>, Vec<_>) = addrs.into_iter().partition(SocketAddr::is_ipv6);
+    let mut ordered = Vec::with_capacity(v6.len() + v4.len());
+    let mut v6 = v6.into_iter();
+    let mut v4 = v4.into_iter();
+    loop {
+        match (v6.next(), v4.next()) {
+            (None, None) => break,
+            (a, b) => ordered.extend(a.into_iter().chain(b)),
+        }
+    }
+    ordered
+}
+
+/// Connect to one address and disable Nagle.
+async fn connect_one(addr: SocketAddr) -> std::io::Result<(TcpStream, SocketAddr)> {
+    let stream = TcpStream::connect(addr).await?;
+    stream.set_nodelay(true)?;
+    Ok((stream, addr))
+}
+
+/// Connect to the first reachable address using staggered parallel attempts.
+///
+/// Attempts start in [`interleave_families`] order, `attempt_delay` apart; a
+/// failed attempt starts the next one immediately. The caller bounds the
+/// whole operation with the connect timeout.
+pub(crate) async fn connect_happy_eyeballs(
+    addrs: Vec<SocketAddr>,
+    attempt_delay: Duration,
+) -> std::io::Result<TcpStream> {
+    let addr_count = addrs.len();
+    let mut pending = interleave_families(addrs).into_iter().peekable();
+    let mut attempts = JoinSet::new();
+    let mut last_error: Option<std::io::Error> = None;
+
+    loop {
+        if let Some(addr) = pending.next() {
+            tracing::debug!(addr = %addr, "starting TCP connection attempt");
+            attempts.spawn(connect_one(addr));
+        }
+
+        if attempts.is_empty() {
+            break;
+        }
+
+        let more_pending = pending.peek().is_some();
+        tokio::select! {
+            biased;
+            joined = attempts.join_next() => match joined {
+                Some(Ok(Ok((stream, addr)))) => {
+                    tracing::debug!(addr = %addr, "TCP connection established");
+                    attempts.abort_all();
+                    return Ok(stream);
+                }
+                Some(Ok(Err(e))) => {
+                    tracing::debug!(error = %e, "TCP connection attempt failed");
+                    last_error = Some(e);
+                }
+                Some(Err(join_err)) => {
+                    last_error = Some(std::io::Error::other(join_err.to_string()));
+                }
+                None => {}
+            },
+            () = tokio::time::sleep(attempt_delay), if more_pending => {}
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::ConnectionRefused,
+            format!("all {addr_count} connection attempts failed"),
+        )
+    }))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn addr(s: &str) -> SocketAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_interleave_prefers_ipv6() {
+        let ordered = interleave_families(vec![
+            addr("10.0.0.1:1433"),
+            addr("10.0.0.2:1433"),
+            addr("[::1]:1433"),
+            addr("10.0.0.3:1433"),
+            addr("[::2]:1433"),
+        ]);
+        assert_eq!(
+            ordered,
+            vec![
+                addr("[::1]:1433"),
+                addr("10.0.0.1:1433"),
+                addr("[::2]:1433"),
+                addr("10.0.0.2:1433"),
+                addr("10.0.0.3:1433"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interleave_single_family() {
+        let addrs = vec![addr("10.0.0.1:1433"), addr("10.0.0.2:1433")];
+        assert_eq!(interleave_families(addrs.clone()), addrs);
+    }
+
+    #[tokio::test]
+    async fn test_happy_eyeballs_skips_unreachable_address() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let good = listener.local_addr().unwrap();
+
+        // Bind then drop to get a local port with nothing listening.
+        let closed = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let bad = closed.local_addr().unwrap();
+        drop(closed);
+
+        let stream = connect_happy_eyeballs(vec![bad, good], Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), good);
+    }
+
+    #[tokio::test]
+    async fn test_happy_eyeballs_all_fail() {
+        let closed = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let bad = closed.local_addr().unwrap();
+        drop(closed);
+
+        assert!(
+            connect_happy_eyeballs(vec![bad], CONNECTION_ATTEMPT_DELAY)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_system_resolver_resolves_ip_literal() {
+        let addrs = SystemResolver.resolve("127.0.0.1", 1433).await.unwrap();
+        assert_eq!(addrs, vec![addr("127.0.0.1:1433")]);
+    }
+}
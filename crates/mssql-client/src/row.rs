@@ -12,7 +12,8 @@
 //! - `get<T>()` - Type-converting accessor with allocation only if needed
 
 use std::borrow::Cow;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
 
 use bytes::Bytes;
 
@@ -241,6 +242,9 @@ fn type_name_to_id(name: &str) -> u8 {
 pub struct ColMetaData {
     /// Column definitions.
     pub columns: Arc<[Column]>,
+    /// Column indexes keyed by ASCII-lowercased name, built on the first
+    /// case-insensitive lookup and shared by every row of the result set.
+    name_index: OnceLock<HashMap<String, Vec<usize>>>,
 }
 
 impl ColMetaData {
@@ -248,6 +252,7 @@ impl ColMetaData {
     pub fn new(columns: Vec<Column>) -> Self {
         Self {
             columns: columns.into(),
+            name_index: OnceLock::new(),
         }
     }
 
@@ -276,6 +281,44 @@ impl ColMetaData {
             .iter()
             .position(|c| c.name.eq_ignore_ascii_case(name))
     }
+
+    /// Find a column index by name (case-insensitive), rejecting ambiguity.
+    ///
+    /// Unlike [`find_by_name`](Self::find_by_name), which returns the first
+    /// match, a name matching several columns that differ only in case
+    /// (`UserName` and `USERNAME` for `username`) is an error. The name index
+    /// is built once, on the first call.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TypeError::TypeMismatch`] if the name is ambiguous.
+    pub fn find_by_name_ci(&self, name: &str) -> Result<Option<usize>, TypeError> {
+        let index = self.name_index.get_or_init(|| {
+            let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+            for (i, col) in self.columns.iter().enumerate() {
+                index
+                    .entry(col.name.to_ascii_lowercase())
+                    .or_default()
+                    .push(i);
+            }
+            index
+        });
+        match index.get(&name.to_ascii_lowercase()).map(Vec::as_slice) {
+            None => Ok(None),
+            Some([i]) => Ok(Some(*i)),
+            Some(candidates) => Err(TypeError::TypeMismatch {
+                expected: "unambiguous column name",
+                actual: format!(
+                    "column '{name}' matches {} columns differing only in case",
+                    candidates
+                        .iter()
+                        .map(|&i| format!("'{}'", self.columns[i].name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }),
+        }
+    }
 }
 
 /// A row from a query result.
@@ -297,6 +340,10 @@ impl ColMetaData {
 /// Multiple `Row` instances from the same result set share the `metadata`.
 /// The `buffer` and `slices` are unique per row but use `Arc` for cheap cloning.
 ///
+/// A row owns everything it refers to and borrows nothing from the connection,
+/// so it is `Send + Sync + 'static`: rows can be handed to another thread
+/// (a `rayon` pool, `spawn_blocking`) after the query finishes.
+///
 /// # Access Patterns
 ///
 /// - **Zero-copy:** `get_bytes()`, `get_str()` (when UTF-8 valid)
@@ -457,6 +504,48 @@ impl Row {
         self.get_str(index).map(|cow| cow.into_owned())
     }
 
+    /// Append a binary column's bytes to a caller-owned buffer.
+    ///
+    /// Returns the number of bytes appended. Reusing one `Vec<u8>` across
+    /// rows (clearing it between reads) amortizes allocation when scanning
+    /// many `VARBINARY` values, where [`get::<Vec<u8>>`](Self::get) would
+    /// allocate a fresh vector per row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TypeError::UnexpectedNull`] if the column is NULL, or
+    /// [`TypeError::TypeMismatch`] if the index is out of bounds or the
+    /// column is not binary. `buf` is left unchanged on error.
+    pub fn read_binary_into(&self, index: usize, buf: &mut Vec<u8>) -> Result<usize, TypeError> {
+        let parsed;
+        let value = match self.values {
+            Some(ref values) => values.get(index),
+            None => match self.slices.get(index) {
+                Some(slice) => {
+                    parsed = self.parse_value(index, slice)?;
+                    Some(&parsed)
+                }
+                None => None,
+            },
+        };
+
+        match value {
+            Some(SqlValue::Binary(bytes)) => {
+                buf.extend_from_slice(bytes);
+                Ok(bytes.len())
+            }
+            Some(SqlValue::Null) => Err(TypeError::UnexpectedNull),
+            Some(other) => Err(TypeError::TypeMismatch {
+                expected: "binary value",
+                actual: other.type_name().to_string(),
+            }),
+            None => Err(TypeError::TypeMismatch {
+                expected: "valid column index",
+                actual: format!("index {index} out of bounds"),
+            }),
+        }
+    }
+
     // ========================================================================
     // Streaming Access (LOB support)
     // ========================================================================
@@ -556,8 +645,10 @@ impl Row {
                 actual: format!("index {index} out of bounds"),
             })?;
 
+        // Let the target type decide what NULL means (most reject it with
+        // `UnexpectedNull`; adapters such as `NullAsEmpty` accept it).
         if slice.is_null {
-            return Err(TypeError::UnexpectedNull);
+            return T::from_sql(&SqlValue::Null);
         }
 
         // Parse via SqlValue then convert to target type
@@ -627,6 +718,41 @@ impl Row {
         }
     }
 
+    /// Get a value by column name, matching the name case-insensitively.
+    ///
+    /// Like [`get_by_name`](Self::get_by_name), but a name that matches
+    /// several columns differing only in case is an error rather than the
+    /// first of them; see [`ColMetaData::find_by_name_ci`].
+    pub fn get_by_name_ci<T: FromSql>(&self, name: &str) -> Result<T, TypeError> {
+        let index =
+            self.metadata
+                .find_by_name_ci(name)?
+                .ok_or_else(|| TypeError::TypeMismatch {
+                    expected: "valid column name",
+                    actual: format!("column '{name}' not found"),
+                })?;
+
+        self.get(index)
+    }
+
+    /// Try to get a value by column name, matching the name
+    /// case-insensitively.
+    ///
+    /// Returns `Ok(None)` when the column is NULL or no column with this
+    /// name exists, as [`try_get_by_name`](Self::try_get_by_name) does.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TypeError`] if the name matches several columns that differ
+    /// only in case, or if the column value cannot be decoded or converted
+    /// to `T`.
+    pub fn try_get_by_name_ci<T: FromSql>(&self, name: &str) -> Result<Option<T>, TypeError> {
+        match self.metadata.find_by_name_ci(name)? {
+            Some(index) => self.try_get(index),
+            None => Ok(None),
+        }
+    }
+
     // ========================================================================
     // Raw Value Access (backward compatibility)
     // ========================================================================
@@ -828,6 +954,45 @@ mod tests {
         assert_eq!(meta.find_by_name("unknown"), None);
     }
 
+    #[test]
+    fn test_get_by_name_ci_resolves_mixed_case() {
+        let row = Row::from_values(
+            vec![
+                Column::new("UserName", 0, "NVARCHAR"),
+                Column::new("userId", 1, "INT"),
+            ],
+            vec![SqlValue::String("alice".into()), SqlValue::Int(7)],
+        );
+
+        assert_eq!(row.get_by_name_ci::<String>("username").unwrap(), "alice");
+        assert_eq!(row.get_by_name_ci::<i32>("USERID").unwrap(), 7);
+        assert_eq!(row.try_get_by_name_ci::<i32>("UserId").unwrap(), Some(7));
+        assert_eq!(row.try_get_by_name_ci::<i32>("missing").unwrap(), None);
+        assert!(row.get_by_name_ci::<i32>("missing").is_err());
+    }
+
+    #[test]
+    fn test_get_by_name_ci_rejects_columns_differing_only_in_case() {
+        let row = Row::from_values(
+            vec![
+                Column::new("UserName", 0, "NVARCHAR"),
+                Column::new("USERNAME", 1, "NVARCHAR"),
+            ],
+            vec![SqlValue::String("a".into()), SqlValue::String("b".into())],
+        );
+
+        for name in ["username", "UserName", "USERNAME"] {
+            let err = row.get_by_name_ci::<String>(name).unwrap_err();
+            assert!(
+                err.to_string().contains("'UserName', 'USERNAME'"),
+                "unexpected error: {err}"
+            );
+            assert!(row.try_get_by_name_ci::<String>(name).is_err());
+        }
+        // The existing lookups keep returning the first match.
+        assert_eq!(row.get_by_name::<String>("username").unwrap(), "a");
+    }
+
     #[test]
     fn test_row_from_values_backward_compat() {
         let columns = vec![
@@ -933,6 +1098,29 @@ mod tests {
         assert_eq!(a.as_deref(), Some("not a number"));
     }
 
+    /// `NullAsEmpty` reads NULL as `""` through both the buffer-backed and the
+    /// value-backed row paths, while `String` still rejects it.
+    #[test]
+    fn test_get_null_as_empty() {
+        let buffer = Arc::new(Bytes::new());
+        let slices: Arc<[ColumnSlice]> = vec![ColumnSlice::null()].into();
+        let meta = Arc::new(ColMetaData::new(vec![Column::new("name", 0, "NVARCHAR")]));
+        let lazy = Row::new(buffer, slices, meta);
+        let cached = Row::from_values(
+            vec![Column::new("name", 0, "NVARCHAR")],
+            vec![SqlValue::Null],
+        );
+
+        for row in [lazy, cached] {
+            let name: crate::NullAsEmpty = row.get_by_name("name").unwrap();
+            assert_eq!(name.as_str(), "");
+            assert!(matches!(
+                row.get::<String>(0),
+                Err(TypeError::UnexpectedNull)
+            ));
+        }
+    }
+
     #[test]
     fn test_row_get_stream() {
         let buffer = Arc::new(Bytes::from_static(b"Hello, World!"));
@@ -989,4 +1177,68 @@ mod tests {
         // Unknown column returns None
         assert!(row.get_stream_by_name("unknown").is_none());
     }
+
+    #[test]
+    fn test_row_read_binary_into() {
+        let columns = vec![
+            Column::new("a", 0, "VARBINARY"),
+            Column::new("b", 1, "VARBINARY"),
+            Column::new("n", 2, "VARBINARY"),
+            Column::new("i", 3, "INT"),
+        ];
+        let row = Row::from_values(
+            columns,
+            vec![
+                SqlValue::Binary(Bytes::from_static(b"abc")),
+                SqlValue::Binary(Bytes::from_static(b"de")),
+                SqlValue::Null,
+                SqlValue::Int(1),
+            ],
+        );
+
+        let mut buf = Vec::new();
+        assert_eq!(row.read_binary_into(0, &mut buf).unwrap(), 3);
+        assert_eq!(row.read_binary_into(1, &mut buf).unwrap(), 2);
+        assert_eq!(buf, b"abcde");
+
+        assert!(matches!(
+            row.read_binary_into(2, &mut buf),
+            Err(TypeError::UnexpectedNull)
+        ));
+        assert!(matches!(
+            row.read_binary_into(3, &mut buf),
+            Err(TypeError::TypeMismatch { .. })
+        ));
+        assert!(row.read_binary_into(99, &mut buf).is_err());
+        assert_eq!(buf, b"abcde");
+    }
+
+    #[test]
+    fn test_rows_share_metadata_across_threads() {
+        let meta = Arc::new(ColMetaData::new(vec![
+            Column::new("id", 0, "INT"),
+            Column::new("name", 1, "NVARCHAR"),
+        ]));
+        let rows: Vec<Row> = (1..=3)
+            .map(|i| {
+                Row::from_values_shared(
+                    Arc::clone(&meta),
+                    vec![SqlValue::Int(i), SqlValue::String(format!("row {i}"))],
+                )
+            })
+            .collect();
+        assert!(rows.iter().all(|row| Arc::ptr_eq(row.metadata(), &meta)));
+
+        let handle = std::thread::spawn(move || {
+            rows.iter()
+                .map(|row| {
+                    assert_eq!(row.get_by_name::<String>("name").unwrap().len(), 5);
+                    row.get::<i32>(0).unwrap()
+                })
+                .sum::<i32>()
+        });
+        assert_eq!(handle.join().unwrap(), 6);
+        // The rows were dropped on the other thread; only ours remains.
+        assert_eq!(Arc::strong_count(&meta), 1);
+    }
 }
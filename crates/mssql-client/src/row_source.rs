@@ -55,6 +55,8 @@ pub(crate) struct RowSource {
     /// Whether Always Encrypted was negotiated (selects the encrypted
     /// `ColMetaData` layout, mirroring the eager parser).
     encryption_enabled: bool,
+    /// Reject tokens the parser would skip (`Config::strict_protocol`).
+    strict: bool,
     /// Set once the end-of-message packet has been pushed: no more bytes will
     /// arrive, so a short buffer is a truncated stream rather than `NeedMore`.
     eom: bool,
@@ -67,10 +69,24 @@ impl RowSource {
             buf: Bytes::new(),
             metadata: None,
             encryption_enabled,
+            strict: false,
             eom: false,
         }
     }
 
+    /// Parse in strict mode; see [`TokenParser::with_strict`].
+    pub(crate) fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Start from known column metadata, for a caller that consumed the
+    /// `ColMetaData` token itself and hands over the bytes after it.
+    pub(crate) fn with_metadata(mut self, metadata: ColMetaData) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
     /// Append one packet's payload to the rolling buffer.
     ///
     /// `is_eom` is the packet's END_OF_MESSAGE status: once a packet with it set
@@ -122,8 +138,9 @@ impl RowSource {
         // the slice; we track consumption ourselves via `remaining()`. A partial
         // token leaves `self.buf` untouched so the retry re-parses from the same
         // position once more bytes are appended.
-        let mut parser =
-            TokenParser::new(self.buf.clone()).with_encryption(self.encryption_enabled);
+        let mut parser = TokenParser::new(self.buf.clone())
+            .with_encryption(self.encryption_enabled)
+            .with_strict(self.strict);
 
         match parser.next_token_with_metadata(self.metadata.as_ref()) {
             Ok(Some(token)) => {
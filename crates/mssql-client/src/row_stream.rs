@@ -116,7 +116,8 @@ impl<'a, S: ConnectionState> RowStream<'a, S> {
     ///
     /// Returns `Ok(None)` once the response is fully drained — at which point
     /// the connection is clean for the next request. A server error token in
-    /// the stream is surfaced here as [`Error::Server`].
+    /// the stream is surfaced here as [`Error::Server`], once the rest of the
+    /// response has been read.
     pub async fn try_next(&mut self) -> Result<Option<Row>> {
         if self.finished {
             return Ok(None);
@@ -132,8 +133,13 @@ impl<'a, S: ConnectionState> RowStream<'a, S> {
                     self.switch_result_set(meta).await?;
                 }
                 Pull::Token(Token::Error(err)) => {
-                    self.finish();
-                    return Err(crate::client::response::server_token_to_error(&err));
+                    // Read the rest of the response so the error carries the
+                    // server's later messages and the connection is clean.
+                    self.finished = true;
+                    return Err(self
+                        .client
+                        .finish_streamed_error(&err, Vec::new(), &mut self.source)
+                        .await);
                 }
                 Pull::Token(Token::Done(done)) => {
                     if done.status.error {
@@ -146,10 +152,10 @@ impl<'a, S: ConnectionState> RowStream<'a, S> {
                     // final DONE followed by Pull::End, may still come.
                 }
                 Pull::Token(Token::EnvChange(env)) => {
-                    // Keep the transaction descriptor in sync with raw
-                    // BEGIN/COMMIT/ROLLBACK seen mid-stream, as the buffered
-                    // readers do.
-                    self.client.apply_transaction_env_change(&env);
+                    // Keep the transaction descriptor and current database
+                    // in sync with raw BEGIN/COMMIT/ROLLBACK or USE seen
+                    // mid-stream, as the buffered readers do.
+                    self.client.apply_env_change(&env);
                 }
                 Pull::Token(_) => {
                     // Info / Order / DoneProc / DoneInProc, etc.
@@ -182,6 +188,107 @@ impl<'a, S: ConnectionState> RowStream<'a, S> {
         Ok(out)
     }
 
+    /// Serialize every remaining row into an async writer, returning the
+    /// number of rows written.
+    ///
+    /// `serialize` turns each row into the bytes to write (a CSV line, a JSON
+    /// document, ...). Each row is written before the next is pulled from the
+    /// network, so a slow writer applies backpressure to the server instead of
+    /// rows piling up in memory. The writer is flushed once all rows are
+    /// written.
+    ///
+    /// If writing fails, the rest of the response is cancelled (as with
+    /// [`cancel`](Self::cancel)) so the connection stays reusable, and the
+    /// write error is returned.
+    pub async fn write_into<W, F>(mut self, writer: &mut W, mut serialize: F) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+        F: FnMut(&Row) -> bytes::Bytes,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let mut rows = 0u64;
+        while let Some(row) = self.try_next().await? {
+            if let Err(e) = writer.write_all(&serialize(&row)).await {
+                if let Err(cancel_err) = self.cancel().await {
+                    tracing::debug!(error = %cancel_err, "failed to cancel stream after write error");
+                }
+                return Err(Error::from(e));
+            }
+            rows += 1;
+        }
+        writer.flush().await.map_err(Error::from)?;
+        Ok(rows)
+    }
+
+    /// Export every remaining row as CSV into an async writer, returning the
+    /// number of data rows written.
+    ///
+    /// Rows are streamed from the network and formatted one at a time, as in
+    /// [`write_into`](Self::write_into), so exports of any size run in
+    /// constant memory. When [`CsvOptions::header`](crate::CsvOptions::header)
+    /// is set, the current result set's column names are written first. A
+    /// multi-statement batch is exported as one flat CSV with a single
+    /// header. See the [`csv`](crate::csv) module for value formatting.
+    pub async fn write_csv<W>(self, writer: &mut W, options: &crate::CsvOptions) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        if let Err(e) = crate::csv::validate_options(options) {
+            if let Err(cancel_err) = self.cancel().await {
+                tracing::debug!(error = %cancel_err, "failed to cancel stream after invalid CSV options");
+            }
+            return Err(e);
+        }
+        if options.header {
+            let header = crate::csv::header_record(self.columns(), options);
+            if let Err(e) = writer.write_all(header.as_bytes()).await {
+                if let Err(cancel_err) = self.cancel().await {
+                    tracing::debug!(error = %cancel_err, "failed to cancel stream after write error");
+                }
+                return Err(Error::from(e));
+            }
+        }
+        self.write_into(writer, |row| crate::csv::row_record(row, options).into())
+            .await
+    }
+
+    /// Send every remaining row into a bounded channel, returning the number
+    /// of rows sent.
+    ///
+    /// Each row is sent before the next is pulled from the network, so when
+    /// the channel is full the stream waits for consumers to catch up instead
+    /// of buffering. An error reading the result is sent as the last item, so
+    /// consumers can tell a failed result from a complete one; it is returned
+    /// only if no receiver is left to take it. If every receiver is dropped,
+    /// the rest of the response is cancelled (as with [`cancel`](Self::cancel))
+    /// and the rows sent so far are counted. `tx` is dropped on return.
+    pub async fn send_to(mut self, tx: tokio::sync::mpsc::Sender<Result<Row>>) -> Result<u64> {
+        let mut rows = 0u64;
+        loop {
+            let item = match self.try_next().await {
+                Ok(Some(row)) => Ok(row),
+                Ok(None) => return Ok(rows),
+                Err(e) => Err(e),
+            };
+            let failed = item.is_err();
+            match tx.send(item).await {
+                Ok(()) if failed => return Ok(rows),
+                Ok(()) => rows += 1,
+                Err(tokio::sync::mpsc::error::SendError(item)) => {
+                    // Every receiver is gone; nobody wants the rest.
+                    item?;
+                    if let Err(cancel_err) = self.cancel().await {
+                        tracing::debug!(error = %cancel_err, "failed to cancel stream after channel closed");
+                    }
+                    return Ok(rows);
+                }
+            }
+        }
+    }
+
     /// Stop the stream early and leave the connection reusable.
     ///
     /// Sends an Attention to the server and drains to its acknowledgement so the
@@ -233,9 +340,15 @@ impl<'a, S: ConnectionState> RowStream<'a, S> {
                 &self.meta,
                 &self.row_meta,
                 dec,
+                self.client.lenient_utf16(),
             );
         }
-        crate::column_parser::convert_raw_row(raw, &self.meta, &self.row_meta)
+        crate::column_parser::convert_raw_row(
+            raw,
+            &self.meta,
+            &self.row_meta,
+            self.client.lenient_utf16(),
+        )
     }
 
     /// Decode a null-bitmap-compressed row against the current metadata.
@@ -247,8 +360,14 @@ impl<'a, S: ConnectionState> RowStream<'a, S> {
                 &self.meta,
                 &self.row_meta,
                 dec,
+                self.client.lenient_utf16(),
             );
         }
-        crate::column_parser::convert_nbc_row(nbc, &self.meta, &self.row_meta)
+        crate::column_parser::convert_nbc_row(
+            nbc,
+            &self.meta,
+            &self.row_meta,
+            self.client.lenient_utf16(),
+        )
     }
 }
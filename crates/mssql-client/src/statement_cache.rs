@@ -48,16 +48,23 @@ pub struct PreparedStatement {
     sql: String,
     /// Timestamp when this statement was prepared.
     created_at: Instant,
+    /// Number of cache lookups that reused this handle.
+    hit_count: u64,
+    /// Timestamp of the last lookup that reused this handle, or of preparation.
+    last_used: Instant,
 }
 
 impl PreparedStatement {
     /// Create a new prepared statement.
     pub fn new(handle: i32, sql: String) -> Self {
+        let now = Instant::now();
         Self {
             handle,
             sql_hash: hash_sql(&sql),
             sql,
-            created_at: Instant::now(),
+            created_at: now,
+            hit_count: 0,
+            last_used: now,
         }
     }
 
@@ -90,6 +97,33 @@ impl PreparedStatement {
     pub fn age(&self) -> std::time::Duration {
         self.created_at.elapsed()
     }
+
+    /// Get the number of cache hits that reused this handle.
+    #[must_use]
+    pub fn hit_count(&self) -> u64 {
+        self.hit_count
+    }
+
+    /// Get the timestamp this handle was last reused (or prepared).
+    #[must_use]
+    pub fn last_used(&self) -> Instant {
+        self.last_used
+    }
+}
+
+/// A read-only description of one statement in the prepared-statement cache.
+///
+/// Obtained via [`Client::statement_cache_entries`](crate::Client::statement_cache_entries).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachedStatementInfo {
+    /// Hash of the cache key (parameter declarations plus SQL text).
+    pub sql_hash: u64,
+    /// Server-assigned prepared-statement handle.
+    pub handle: i32,
+    /// Number of executions that reused the handle instead of re-preparing.
+    pub hit_count: u64,
+    /// When the handle was last reused, or prepared if it never was.
+    pub last_used: Instant,
 }
 
 /// LRU cache for prepared statements.
@@ -142,7 +176,9 @@ impl StatementCache {
     /// This updates the LRU order.
     pub fn get(&mut self, sql: &str) -> Option<i32> {
         let hash = hash_sql(sql);
-        if let Some(stmt) = self.cache.get(&hash) {
+        if let Some(stmt) = self.cache.get_mut(&hash) {
+            stmt.hit_count += 1;
+            stmt.last_used = Instant::now();
             self.hits += 1;
             tracing::trace!(sql = sql, handle = stmt.handle, "statement cache hit");
             Some(stmt.handle)
@@ -218,6 +254,22 @@ impl StatementCache {
         statements.into_iter()
     }
 
+    /// Describe every cached statement, most recently used first.
+    ///
+    /// Does not change the LRU order or the hit statistics.
+    #[must_use]
+    pub fn entries(&self) -> Vec<CachedStatementInfo> {
+        self.cache
+            .iter()
+            .map(|(_, stmt)| CachedStatementInfo {
+                sql_hash: stmt.sql_hash,
+                handle: stmt.handle,
+                hit_count: stmt.hit_count,
+                last_used: stmt.last_used,
+            })
+            .collect()
+    }
+
     /// Get the number of cached statements.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -482,6 +534,32 @@ mod tests {
         assert!(stmt.age().as_millis() >= 10);
     }
 
+    #[test]
+    fn test_statement_cache_entries() {
+        let mut cache = StatementCache::new(10);
+        cache.insert(PreparedStatement::new(1, "SELECT 1".to_string()));
+        cache.insert(PreparedStatement::new(2, "SELECT 2".to_string()));
+        let prepared_at = cache.peek("SELECT 1").unwrap().last_used();
+
+        cache.get("SELECT 1");
+        cache.get("SELECT 1");
+        let (hits, misses) = (cache.hits(), cache.misses());
+
+        let entries = cache.entries();
+        assert_eq!(entries.len(), 2);
+        // Most recently used first.
+        assert_eq!(entries[0].handle, 1);
+        assert_eq!(entries[0].sql_hash, hash_sql("SELECT 1"));
+        assert_eq!(entries[0].hit_count, 2);
+        assert!(entries[0].last_used >= prepared_at);
+        assert_eq!(entries[1].handle, 2);
+        assert_eq!(entries[1].hit_count, 0);
+
+        // Read-only: neither the statistics nor the LRU order move.
+        assert_eq!((cache.hits(), cache.misses()), (hits, misses));
+        assert_eq!(cache.entries()[0].handle, 1);
+    }
+
     #[test]
     fn test_statement_cache_config_default() {
         let config = StatementCacheConfig::default();
@@ -40,7 +40,7 @@ use std::task::{Context, Poll};
 use futures_core::Stream;
 use tds_protocol::token::{ColMetaData, NbcRow, RawRow};
 
-use crate::error::Error;
+use crate::error::{Error, ServerMessage};
 use crate::row::{Column, Row};
 
 /// A row that may be already decoded or still held as raw TDS bytes.
@@ -67,8 +67,8 @@ pub(crate) enum PendingRow {
 /// the size of the raw response payload regardless of how you iterate. For
 /// genuinely large result sets, use
 /// [`Client::query_stream`](crate::Client::query_stream) (incremental, peak
-/// memory ~one row) or page with `OFFSET`/`FETCH` in SQL rather than relying on
-/// this type to bound memory.
+/// memory ~one packet plus one row) or page with `OFFSET`/`FETCH` in SQL rather
+/// than relying on this type to bound memory.
 ///
 /// # Example
 ///
@@ -99,8 +99,14 @@ pub struct QueryStream<'a> {
     /// this state without duplicating the derived-key material.
     #[cfg(feature = "always-encrypted")]
     decryptor: Option<std::sync::Arc<crate::column_decryptor::ColumnDecryptor>>,
+    /// Decode invalid UTF-16 lossily (`Config::lenient_utf16`).
+    lenient_utf16: bool,
     /// Whether the stream has completed.
     finished: bool,
+    /// Row count the server reported for the response, if any.
+    rows_affected: Option<u64>,
+    /// Zero-based sort-key columns from the ORDER token, if the server sent one.
+    order_columns: Option<Vec<usize>>,
     /// Lifetime tied to the connection.
     _marker: std::marker::PhantomData<&'a ()>,
 }
@@ -120,7 +126,10 @@ impl QueryStream<'_> {
             meta: None,
             #[cfg(feature = "always-encrypted")]
             decryptor: None,
+            lenient_utf16: false,
             finished: false,
+            rows_affected: None,
+            order_columns: None,
             _marker: std::marker::PhantomData,
         }
     }
@@ -144,7 +153,10 @@ impl QueryStream<'_> {
             meta: Some(meta),
             #[cfg(feature = "always-encrypted")]
             decryptor,
+            lenient_utf16: false,
             finished: false,
+            rows_affected: None,
+            order_columns: None,
             _marker: std::marker::PhantomData,
         }
     }
@@ -158,17 +170,64 @@ impl QueryStream<'_> {
             meta: None,
             #[cfg(feature = "always-encrypted")]
             decryptor: None,
+            lenient_utf16: false,
             finished: true,
+            rows_affected: None,
+            order_columns: None,
             _marker: std::marker::PhantomData,
         }
     }
 
+    /// Attach the row count read alongside the rows.
+    pub(crate) fn with_rows_affected(mut self, rows_affected: Option<u64>) -> Self {
+        self.rows_affected = rows_affected;
+        self
+    }
+
+    /// Attach the sort-key columns from the result's ORDER token.
+    pub(crate) fn with_order_columns(mut self, order_columns: Option<Vec<usize>>) -> Self {
+        self.order_columns = order_columns;
+        self
+    }
+
+    /// Decode invalid UTF-16 lossily; see `Config::lenient_utf16`.
+    pub(crate) fn with_lenient_utf16(mut self, enabled: bool) -> Self {
+        self.lenient_utf16 = enabled;
+        self
+    }
+
     /// Get the column metadata for this result set.
     #[must_use]
     pub fn columns(&self) -> &[Column] {
         &self.row_meta.columns
     }
 
+    /// Total row count the server reported with this response, if any.
+    ///
+    /// Summed over every statement that sent a count, like
+    /// [`ExecuteResult::rows_affected`]. For `DELETE ... OUTPUT` and similar
+    /// statements this is the number of rows modified, alongside the `OUTPUT`
+    /// rows in the stream itself. `None` when no count was reported (e.g.
+    /// under `SET NOCOUNT ON`).
+    ///
+    /// The whole response has been read by the time `query` returns, so the
+    /// count is available before, during and after iterating the rows.
+    #[must_use]
+    pub fn rows_affected(&self) -> Option<u64> {
+        self.rows_affected
+    }
+
+    /// Columns the result is sorted by, as zero-based indexes into
+    /// [`columns`](Self::columns), most significant first.
+    ///
+    /// Taken from the ORDER token that SQL Server sends ahead of the rows of an
+    /// ordered result. `None` when the server sent no ORDER token. That does not
+    /// prove the rows are unordered. The token carries no sort direction.
+    #[must_use]
+    pub fn order_columns(&self) -> Option<&[usize]> {
+        self.order_columns.as_deref()
+    }
+
     /// Check if the stream has finished.
     #[must_use]
     pub fn is_finished(&self) -> bool {
@@ -199,6 +258,40 @@ impl QueryStream<'_> {
         Ok(out)
     }
 
+    /// Export every remaining row as CSV into an async writer, returning the
+    /// number of data rows written.
+    ///
+    /// The result is already buffered, so this only saves the per-row
+    /// formatting code. For exports too large to buffer, use
+    /// [`RowStream::write_csv`](crate::RowStream::write_csv) on a
+    /// [`Client::query_stream`](crate::Client::query_stream) instead. See the
+    /// [`csv`](crate::csv) module for value formatting.
+    pub async fn write_csv<W>(
+        mut self,
+        writer: &mut W,
+        options: &crate::CsvOptions,
+    ) -> Result<u64, Error>
+    where
+        W: tokio::io::AsyncWrite + Unpin + ?Sized,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        crate::csv::validate_options(options)?;
+        if options.header {
+            let header = crate::csv::header_record(self.columns(), options);
+            writer.write_all(header.as_bytes()).await?;
+        }
+        let mut rows = 0u64;
+        for row in self.by_ref() {
+            let record = crate::csv::row_record(&row?, options);
+            writer.write_all(record.as_bytes()).await?;
+            rows += 1;
+        }
+        writer.flush().await?;
+        self.finished = true;
+        Ok(rows)
+    }
+
     /// Try to get the next row synchronously (without async).
     ///
     /// Returns `None` when no more rows are available or the next pending
@@ -224,9 +317,15 @@ impl QueryStream<'_> {
                         meta,
                         &self.row_meta,
                         dec,
+                        self.lenient_utf16,
                     );
                 }
-                crate::column_parser::convert_raw_row(&raw, meta, &self.row_meta)
+                crate::column_parser::convert_raw_row(
+                    &raw,
+                    meta,
+                    &self.row_meta,
+                    self.lenient_utf16,
+                )
             }
             PendingRow::Nbc(nbc) => {
                 let meta = self
@@ -240,9 +339,15 @@ impl QueryStream<'_> {
                         meta,
                         &self.row_meta,
                         dec,
+                        self.lenient_utf16,
                     );
                 }
-                crate::column_parser::convert_nbc_row(&nbc, meta, &self.row_meta)
+                crate::column_parser::convert_nbc_row(
+                    &nbc,
+                    meta,
+                    &self.row_meta,
+                    self.lenient_utf16,
+                )
             }
         }
     }
@@ -295,13 +400,19 @@ impl Iterator for QueryStream<'_> {
 
 /// Result of a non-query execution.
 ///
-/// Contains the number of affected rows and any output parameters.
+/// Contains the number of affected rows and any output parameters. Returned
+/// by [`Client::execute_detailed`](crate::Client::execute_detailed).
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 #[must_use]
 pub struct ExecuteResult {
     /// Number of rows affected by the statement.
-    pub rows_affected: u64,
+    ///
+    /// Summed over every DONE token whose count bit was set. `Some(0)` means
+    /// the statement ran and matched no rows; `None` means the server never
+    /// reported a count (DDL, a statement skipped by control flow, or
+    /// `SET NOCOUNT ON`).
+    pub rows_affected: Option<u64>,
     /// Output parameters from stored procedures.
     pub output_params: Vec<OutputParam>,
 }
@@ -320,7 +431,7 @@ impl ExecuteResult {
     /// Create a new execute result.
     pub fn new(rows_affected: u64) -> Self {
         Self {
-            rows_affected,
+            rows_affected: Some(rows_affected),
             output_params: Vec::new(),
         }
     }
@@ -328,7 +439,7 @@ impl ExecuteResult {
     /// Create a result with output parameters.
     pub fn with_outputs(rows_affected: u64, output_params: Vec<OutputParam>) -> Self {
         Self {
-            rows_affected,
+            rows_affected: Some(rows_affected),
             output_params,
         }
     }
@@ -377,6 +488,14 @@ pub struct ProcedureResult {
     pub return_value: i32,
     /// Total number of rows affected by statements within the procedure.
     pub rows_affected: u64,
+    /// Rows affected by each statement inside the procedure, in execution
+    /// order.
+    ///
+    /// One entry per DONEINPROC token that carried a row count, so statements
+    /// that report no count (under `SET NOCOUNT ON`, or `DECLARE`/`IF`
+    /// control flow) are skipped. Useful for audit and ETL procedures that
+    /// report per-step totals; `rows_affected` is the overall sum.
+    pub statement_row_counts: Vec<u64>,
     /// Output parameters returned by the procedure.
     pub output_params: Vec<OutputParam>,
     /// Result sets produced by SELECT statements within the procedure.
@@ -389,6 +508,7 @@ impl ProcedureResult {
         Self {
             return_value: 0,
             rows_affected: 0,
+            statement_row_counts: Vec::new(),
             output_params: Vec::new(),
             result_sets: Vec::new(),
         }
@@ -473,6 +593,8 @@ pub struct ResultSet {
     /// the underlying decryptor state instead of duplicating derived keys).
     #[cfg(feature = "always-encrypted")]
     decryptor: Option<std::sync::Arc<crate::column_decryptor::ColumnDecryptor>>,
+    /// Decode invalid UTF-16 lossily (`Config::lenient_utf16`).
+    lenient_utf16: bool,
 }
 
 impl ResultSet {
@@ -488,6 +610,7 @@ impl ResultSet {
             meta: None,
             #[cfg(feature = "always-encrypted")]
             decryptor: None,
+            lenient_utf16: false,
         }
     }
 
@@ -511,9 +634,16 @@ impl ResultSet {
             meta: Some(meta),
             #[cfg(feature = "always-encrypted")]
             decryptor,
+            lenient_utf16: false,
         }
     }
 
+    /// Decode invalid UTF-16 lossily; see `Config::lenient_utf16`.
+    pub(crate) fn with_lenient_utf16(mut self, enabled: bool) -> Self {
+        self.lenient_utf16 = enabled;
+        self
+    }
+
     /// Get the column metadata.
     #[must_use]
     pub fn columns(&self) -> &[Column] {
@@ -568,9 +698,15 @@ impl ResultSet {
                         meta,
                         &self.row_meta,
                         dec,
+                        self.lenient_utf16,
                     );
                 }
-                crate::column_parser::convert_raw_row(&raw, meta, &self.row_meta)
+                crate::column_parser::convert_raw_row(
+                    &raw,
+                    meta,
+                    &self.row_meta,
+                    self.lenient_utf16,
+                )
             }
             PendingRow::Nbc(nbc) => {
                 let meta = self
@@ -584,9 +720,15 @@ impl ResultSet {
                         meta,
                         &self.row_meta,
                         dec,
+                        self.lenient_utf16,
                     );
                 }
-                crate::column_parser::convert_nbc_row(&nbc, meta, &self.row_meta)
+                crate::column_parser::convert_nbc_row(
+                    &nbc,
+                    meta,
+                    &self.row_meta,
+                    self.lenient_utf16,
+                )
             }
         }
     }
@@ -603,7 +745,10 @@ impl ResultSet {
             meta: self.meta,
             #[cfg(feature = "always-encrypted")]
             decryptor: self.decryptor,
+            lenient_utf16: self.lenient_utf16,
             finished: false,
+            rows_affected: None,
+            order_columns: None,
             _marker: std::marker::PhantomData,
         }
     }
@@ -641,6 +786,8 @@ pub struct MultiResultStream<'a> {
     result_sets: Vec<ResultSet>,
     /// Current result set index (0-based).
     current_result: usize,
+    /// INFO messages the server sent with the batch.
+    messages: Vec<ServerMessage>,
     /// Lifetime tied to the connection.
     _marker: std::marker::PhantomData<&'a ()>,
 }
@@ -651,10 +798,26 @@ impl<'a> MultiResultStream<'a> {
         Self {
             result_sets,
             current_result: 0,
+            messages: Vec::new(),
             _marker: std::marker::PhantomData,
         }
     }
 
+    /// Attach the INFO messages received with the result sets.
+    pub(crate) fn with_messages(mut self, messages: Vec<ServerMessage>) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Informational messages (PRINT output, `SET STATISTICS` output, and
+    /// similar) the server sent with the batch, in the order received.
+    ///
+    /// Errors are not included; a server error fails the query instead.
+    #[must_use]
+    pub fn messages(&self) -> &[ServerMessage] {
+        &self.messages
+    }
+
     /// Get the current result set index (0-based).
     #[must_use]
     pub fn current_result_index(&self) -> usize {
@@ -746,7 +909,7 @@ mod tests {
     #[test]
     fn test_execute_result() {
         let result = ExecuteResult::new(42);
-        assert_eq!(result.rows_affected, 42);
+        assert_eq!(result.rows_affected, Some(42));
         assert!(result.output_params.is_empty());
     }
 
@@ -822,7 +985,7 @@ mod tests {
         }];
 
         let result = ExecuteResult::with_outputs(10, outputs);
-        assert_eq!(result.rows_affected, 10);
+        assert_eq!(result.rows_affected, Some(10));
         assert!(result.get_output("ReturnValue").is_some());
         assert!(result.get_output("returnvalue").is_some()); // case-insensitive
         assert!(result.get_output("NotFound").is_none());
@@ -19,6 +19,11 @@ pub enum IsolationLevel {
     ///
     /// Transactions can only read committed data. Prevents dirty reads
     /// but allows non-repeatable reads and phantom reads.
+    ///
+    /// In a database with `READ_COMMITTED_SNAPSHOT ON` (the default in Azure
+    /// SQL Database) reads use row versions instead of shared locks, so they
+    /// neither block nor are blocked by writers. This needs nothing from
+    /// the client.
     #[default]
     ReadCommitted,
 
@@ -36,8 +41,12 @@ pub enum IsolationLevel {
 
     /// Snapshot isolation.
     ///
-    /// Uses row versioning to provide a point-in-time view of data.
-    /// Requires snapshot isolation to be enabled on the database.
+    /// Uses row versioning to provide a point-in-time view of data: every
+    /// read in the transaction sees the data as of its first read, and
+    /// readers do not block writers. Requires `ALLOW_SNAPSHOT_ISOLATION ON`
+    /// in the database, which
+    /// [`Client::begin_transaction_with_isolation`](crate::Client::begin_transaction_with_isolation)
+    /// checks.
     Snapshot,
 }
 
@@ -48,6 +48,29 @@
 //!
 //! - `#[mssql(type_name = "schema.TypeName")]` - SQL Server TVP type name (required)
 //! - `#[mssql(rename = "column_name")]` - Map field to different column name
+//!
+//! ## Reusing a Schema
+//!
+//! [`TvpValue::new`] resolves the table type's columns every time. When the
+//! same type is sent many times, resolve it once as a [`TvpSchema`] and build
+//! each value with [`TvpValue::with_schema`]:
+//!
+//! ```rust,no_run
+//! # async fn ex(client: &mut mssql_client::Client<mssql_client::Ready>) -> Result<(), mssql_client::Error> {
+//! use mssql_client::{TvpColumn, TvpRow, TvpSchema, TvpValue};
+//! use mssql_types::SqlValue;
+//!
+//! let schema = TvpSchema::new("dbo.UserIdList", vec![TvpColumn::new("UserId", "INT", 0)])?;
+//! for batch in [[1, 2], [3, 4]] {
+//!     let rows = batch.iter().map(|id| TvpRow::new(vec![SqlValue::Int(*id)])).collect();
+//!     let tvp = TvpValue::with_schema(&schema, rows);
+//!     client.execute("EXEC GetUserDetails @UserIds = @p1", &[&tvp]).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
 
 use mssql_types::{SqlValue, ToSql, TvpColumnDef, TvpColumnType, TvpData, TypeError};
 
@@ -147,6 +170,71 @@ pub trait Tvp {
     fn to_row(&self) -> Result<TvpRow, TypeError>;
 }
 
+/// The column schema of a TVP type, resolved once and shared.
+///
+/// Every [`TvpValue::new`] re-parses its columns' SQL types when it is sent.
+/// A `TvpSchema` does that once; values built from it with
+/// [`TvpValue::with_schema`] reuse the parsed definitions. Clones share one
+/// allocation, so a schema can be kept for the life of the application.
+#[derive(Debug, Clone)]
+pub struct TvpSchema {
+    inner: Arc<TvpSchemaInner>,
+}
+
+#[derive(Debug)]
+struct TvpSchemaInner {
+    type_name: String,
+    columns: Vec<TvpColumn>,
+    column_defs: Vec<TvpColumnDef>,
+}
+
+impl TvpSchema {
+    /// Resolve a schema from a type name (`schema.TypeName` or `TypeName`)
+    /// and its columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a column's SQL type is not supported in a TVP.
+    pub fn new(type_name: impl Into<String>, columns: Vec<TvpColumn>) -> Result<Self, TypeError> {
+        let column_defs = column_defs(&columns)?;
+        Ok(Self {
+            inner: Arc::new(TvpSchemaInner {
+                type_name: type_name.into(),
+                columns,
+                column_defs,
+            }),
+        })
+    }
+
+    /// Resolve the schema of a [`Tvp`] type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a column's SQL type is not supported in a TVP.
+    pub fn of<T: Tvp>() -> Result<Self, TypeError> {
+        Self::new(T::type_name(), T::columns())
+    }
+
+    /// Get the SQL Server type name.
+    pub fn type_name(&self) -> &str {
+        &self.inner.type_name
+    }
+
+    /// Get the column definitions.
+    pub fn columns(&self) -> &[TvpColumn] {
+        &self.inner.columns
+    }
+
+    /// Whether `columns` still have the SQL types this schema resolved.
+    fn describes(&self, columns: &[TvpColumn]) -> bool {
+        columns.len() == self.inner.columns.len()
+            && columns
+                .iter()
+                .zip(&self.inner.columns)
+                .all(|(a, b)| a.sql_type == b.sql_type)
+    }
+}
+
 /// A table-valued parameter value that can be passed to a stored procedure.
 ///
 /// This wraps a collection of `Tvp` items and provides the necessary metadata
@@ -156,9 +244,15 @@ pub struct TvpValue {
     /// The SQL Server type name.
     pub type_name: String,
     /// Column definitions.
+    ///
+    /// For a value built with [`TvpValue::with_schema`], a copy of the
+    /// schema's columns. Editing them makes the value re-parse its columns
+    /// when sent instead of reusing the schema's.
     pub columns: Vec<TvpColumn>,
     /// The rows of data.
     pub rows: Vec<TvpRow>,
+    /// Pre-resolved columns, when built from a [`TvpSchema`].
+    schema: Option<TvpSchema>,
 }
 
 impl TvpValue {
@@ -174,6 +268,7 @@ impl TvpValue {
             type_name: T::type_name().to_string(),
             columns: T::columns(),
             rows: rows?,
+            schema: None,
         })
     }
 
@@ -183,9 +278,29 @@ impl TvpValue {
             type_name: T::type_name().to_string(),
             columns: T::columns(),
             rows: Vec::new(),
+            schema: None,
         }
     }
 
+    /// Create a TVP value from rows of a pre-resolved schema.
+    ///
+    /// The schema's column definitions are reused as-is rather than parsed
+    /// again. Each row must have one value per schema column; a mismatch is
+    /// reported when the parameter is sent.
+    pub fn with_schema(schema: &TvpSchema, rows: Vec<TvpRow>) -> Self {
+        Self {
+            type_name: schema.type_name().to_string(),
+            columns: schema.columns().to_vec(),
+            rows,
+            schema: Some(schema.clone()),
+        }
+    }
+
+    /// Get the schema this value was built with, if any.
+    pub fn schema(&self) -> Option<&TvpSchema> {
+        self.schema.as_ref()
+    }
+
     /// Get the number of rows.
     pub fn len(&self) -> usize {
         self.rows.len()
@@ -215,20 +330,12 @@ impl ToSql for TvpValue {
             (String::new(), self.type_name.clone())
         };
 
-        // Convert TvpColumn to TvpColumnDef
-        let columns: Vec<TvpColumnDef> = self
-            .columns
-            .iter()
-            .map(|col| {
-                let column_type = TvpColumnType::from_sql_type(&col.sql_type).ok_or_else(|| {
-                    TypeError::UnsupportedConversion {
-                        from: col.sql_type.clone(),
-                        to: "TvpColumnType",
-                    }
-                })?;
-                Ok(TvpColumnDef::nullable(column_type))
-            })
-            .collect::<Result<Vec<_>, TypeError>>()?;
+        let columns = match &self.schema {
+            Some(resolved) if resolved.describes(&self.columns) => {
+                resolved.inner.column_defs.clone()
+            }
+            _ => column_defs(&self.columns)?,
+        };
 
         // Convert rows
         let rows: Vec<Vec<SqlValue>> = self.rows.iter().map(|row| row.values.clone()).collect();
@@ -249,6 +356,22 @@ impl ToSql for TvpValue {
     }
 }
 
+/// Convert `TvpColumn`s to the wire column definitions.
+fn column_defs(columns: &[TvpColumn]) -> Result<Vec<TvpColumnDef>, TypeError> {
+    columns
+        .iter()
+        .map(|col| {
+            let column_type = TvpColumnType::from_sql_type(&col.sql_type).ok_or_else(|| {
+                TypeError::UnsupportedConversion {
+                    from: col.sql_type.clone(),
+                    to: "TvpColumnType",
+                }
+            })?;
+            Ok(TvpColumnDef::nullable(column_type))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -314,6 +437,53 @@ mod tests {
         assert!(tvp.is_empty());
     }
 
+    #[test]
+    fn test_tvp_schema_is_resolved_once_and_shared() {
+        let schema = TvpSchema::of::<TestUserId>().unwrap();
+        assert_eq!(schema.type_name(), "dbo.UserIdList");
+        assert_eq!(schema.columns().len(), 1);
+
+        let values: Vec<TvpValue> = (0..3)
+            .map(|i| TvpValue::with_schema(&schema, vec![TvpRow::new(vec![SqlValue::Int(i)])]))
+            .collect();
+        assert!(
+            values
+                .iter()
+                .all(|v| Arc::ptr_eq(&v.schema().unwrap().inner, &schema.inner))
+        );
+
+        let value = values[2].to_sql().unwrap();
+        let data = value.as_tvp().unwrap();
+        assert_eq!(data.schema, "dbo");
+        assert_eq!(data.type_name, "UserIdList");
+        assert_eq!(data.columns, [TvpColumnDef::nullable(TvpColumnType::Int)]);
+        assert_eq!(data.rows, [vec![SqlValue::Int(2)]]);
+    }
+
+    #[test]
+    fn test_tvp_value_with_schema_exposes_columns() {
+        let schema = TvpSchema::of::<TestUserId>().unwrap();
+        let mut value =
+            TvpValue::with_schema(&schema, vec![TvpRow::new(vec![SqlValue::BigInt(7)])]);
+        assert_eq!(value.columns.len(), 1);
+        assert_eq!(value.columns[0].name, "UserId");
+        assert_eq!(value.columns[0].sql_type, "INT");
+
+        // An edited column is honored rather than the schema's.
+        value.columns[0].sql_type = "BIGINT".to_string();
+        let sent = value.to_sql().unwrap();
+        assert_eq!(
+            sent.as_tvp().unwrap().columns,
+            [TvpColumnDef::nullable(TvpColumnType::BigInt)]
+        );
+    }
+
+    #[test]
+    fn test_tvp_schema_rejects_unknown_sql_type() {
+        let err = TvpSchema::new("dbo.Bad", vec![TvpColumn::new("c", "GEOGRAPHY", 0)]).unwrap_err();
+        assert!(matches!(err, TypeError::UnsupportedConversion { .. }));
+    }
+
     #[test]
     fn test_tvp_column() {
         let col = TvpColumn::new("TestCol", "NVARCHAR(100)", 0);
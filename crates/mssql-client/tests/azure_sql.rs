@@ -153,6 +153,7 @@ fn test_azure_transient_error_detection() {
         server: Some("myserver.database.windows.net".into()),
         procedure: None,
         line: 0,
+        additional: Vec::new(),
     };
     assert!(err.is_transient(), "40501 should be transient");
 
@@ -165,6 +166,7 @@ fn test_azure_transient_error_detection() {
         server: Some("myserver.database.windows.net".into()),
         procedure: None,
         line: 0,
+        additional: Vec::new(),
     };
     assert!(err.is_transient(), "40613 should be transient");
 
@@ -177,6 +179,7 @@ fn test_azure_transient_error_detection() {
         server: Some("myserver.database.windows.net".into()),
         procedure: None,
         line: 0,
+        additional: Vec::new(),
     };
     assert!(err.is_transient(), "10928 should be transient");
 
@@ -189,6 +192,7 @@ fn test_azure_transient_error_detection() {
         server: Some("myserver.database.windows.net".into()),
         procedure: None,
         line: 0,
+        additional: Vec::new(),
     };
     assert!(err.is_transient(), "49918 should be transient");
 }
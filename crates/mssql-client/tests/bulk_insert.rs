@@ -3062,7 +3062,7 @@ async fn test_bulk_finish_command_timeout() {
     let builder = BulkInsertBuilder::new(&table)
         .with_typed_columns(vec![BulkColumn::new("id", "INT", 0).unwrap()]);
 
-    // Skip schema discovery: `bulk_insert()`'s `SELECT TOP 0 *` would itself
+    // Skip schema discovery: `bulk_insert()`'s `SELECT TOP 0` would itself
     // block on the exclusive lock and time out during setup. The INSERT BULK
     // prelude only puts the connection in bulk-load mode (no data lock), so it
     // succeeds — leaving the BulkLoad data transfer in finish() as the only
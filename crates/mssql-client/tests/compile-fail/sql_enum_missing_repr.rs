@@ -0,0 +1,10 @@
+use mssql_derive::SqlEnum;
+
+/// SqlEnum must be told how the enum is stored.
+#[derive(SqlEnum)]
+enum Status {
+    Active,
+    Inactive,
+}
+
+fn main() {}
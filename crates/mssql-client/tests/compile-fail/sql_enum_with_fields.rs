@@ -0,0 +1,11 @@
+use mssql_derive::SqlEnum;
+
+/// SqlEnum can only map variants without fields.
+#[derive(SqlEnum)]
+#[mssql(repr = "str")]
+enum Status {
+    Active,
+    Failed(String),
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-//! Compile-fail tests for derive macros (FromRow, ToParams, Tvp).
+//! Compile-fail tests for derive macros (FromRow, ToParams, Tvp, SqlEnum).
 //!
 //! These tests verify that invalid derive macro usage produces
 //! compile errors rather than silently generating broken code.
@@ -1,11 +1,16 @@
-//! Runtime tests for the `#[derive(FromRow)]` and `#[derive(ToParams)]` macros.
+//! Runtime tests for the `#[derive(FromRow)]`, `#[derive(ToParams)]`,
+//! `#[derive(Tvp)]` and `#[derive(SqlEnum)]` macros.
 //!
 //! The compile-fail suite proves the macros reject bad input, but nothing
 //! exercised the *generated* code. These build a struct via each derive and run
-//! the generated `from_row` / `to_params` against real values.
-#![allow(clippy::unwrap_used)]
+//! the generated `from_row` / `to_params` / `columns` / `from_sql` against real
+//! values.
+#![allow(clippy::unwrap_used, clippy::panic)]
 
-use mssql_client::{Column, FromRow, Row, SqlValue, ToParams};
+use chrono::{NaiveDate, NaiveDateTime};
+use mssql_client::{
+    Column, FromRow, FromSql, Row, SqlEnum, SqlValue, ToParams, ToSql, Tvp, TypeError,
+};
 
 #[derive(FromRow)]
 struct User {
@@ -52,3 +57,162 @@ fn derived_to_params_emits_named_params() {
     assert_eq!(params[1].name, "name");
     assert_eq!(params[1].value, SqlValue::String("Ada".to_string()));
 }
+
+#[derive(Tvp)]
+#[mssql(type_name = "dbo.OrderLine")]
+struct OrderLine {
+    quantity: Option<i32>,
+    shipped_on: NaiveDate,
+    updated_at: Option<NaiveDateTime>,
+    #[mssql(sql_type = "DECIMAL(18,4)")]
+    price: rust_decimal::Decimal,
+}
+
+#[test]
+fn derived_tvp_infers_column_types() {
+    // Option<T> declares T's type; an explicit sql_type wins over inference.
+    let columns: Vec<(String, String)> = OrderLine::columns()
+        .into_iter()
+        .map(|c| (c.name, c.sql_type))
+        .collect();
+    assert_eq!(
+        columns,
+        [
+            ("quantity".to_string(), "INT".to_string()),
+            ("shipped_on".to_string(), "DATE".to_string()),
+            ("updated_at".to_string(), "DATETIME2".to_string()),
+            ("price".to_string(), "DECIMAL(18,4)".to_string()),
+        ]
+    );
+
+    let line = OrderLine {
+        quantity: None,
+        shipped_on: NaiveDate::from_ymd_opt(2026, 1, 2).unwrap(),
+        updated_at: None,
+        price: rust_decimal::Decimal::new(125_000, 4),
+    };
+    let row = line.to_row().unwrap();
+    assert_eq!(row.get(0), Some(&SqlValue::Null));
+    assert_eq!(row.get(2), Some(&SqlValue::Null));
+    assert_eq!(row.values.len(), 4);
+}
+
+#[derive(Debug, PartialEq, SqlEnum)]
+#[mssql(repr = "u8")]
+enum Priority {
+    Low = 1,
+    High = 2,
+}
+
+#[derive(Debug, PartialEq, SqlEnum)]
+#[mssql(repr = "str", rename_all = "SCREAMING_SNAKE_CASE")]
+enum Status {
+    Pending,
+    #[mssql(rename = "IN_PROGRESS")]
+    Running,
+    Done,
+}
+
+#[test]
+fn derived_sql_enum_maps_integer_values() {
+    assert_eq!(
+        Priority::from_sql(&SqlValue::TinyInt(2)).unwrap(),
+        Priority::High
+    );
+    assert_eq!(Priority::Low.to_sql().unwrap(), SqlValue::TinyInt(1));
+    assert_eq!(Priority::Low.sql_type(), 0u8.sql_type());
+
+    match Priority::from_sql(&SqlValue::TinyInt(7)) {
+        Err(TypeError::UnknownEnumValue { target_type, value }) => {
+            assert_eq!(target_type, "Priority");
+            assert_eq!(value, "7");
+        }
+        other => panic!("expected UnknownEnumValue, got {other:?}"),
+    }
+    assert!(matches!(
+        Priority::from_sql(&SqlValue::Null),
+        Err(TypeError::UnexpectedNull)
+    ));
+}
+
+#[test]
+fn derived_sql_enum_maps_string_values() {
+    assert_eq!(
+        Status::from_sql(&SqlValue::String("IN_PROGRESS".into())).unwrap(),
+        Status::Running
+    );
+    assert_eq!(
+        Status::from_sql(&SqlValue::String("PENDING".into())).unwrap(),
+        Status::Pending
+    );
+    assert_eq!(
+        Status::Done.to_sql().unwrap(),
+        SqlValue::String("DONE".into())
+    );
+
+    match Status::from_sql(&SqlValue::String("Running".into())) {
+        Err(TypeError::UnknownEnumValue { target_type, value }) => {
+            assert_eq!(target_type, "Status");
+            assert_eq!(value, "Running");
+        }
+        other => panic!("expected UnknownEnumValue, got {other:?}"),
+    }
+}
+
+#[derive(FromRow)]
+struct Job {
+    priority: Priority,
+    status: Option<Status>,
+}
+
+#[test]
+fn derived_sql_enum_reads_as_from_row_field() {
+    let columns = vec![
+        Column::new("priority", 0, "TINYINT".to_string()),
+        Column::new("status", 1, "NVARCHAR".to_string()),
+    ];
+    let row = Row::from_values(
+        columns.clone(),
+        vec![SqlValue::TinyInt(1), SqlValue::String("DONE".into())],
+    );
+    let job = Job::from_row(&row).unwrap();
+    assert_eq!(job.priority, Priority::Low);
+    assert_eq!(job.status, Some(Status::Done));
+
+    let row = Row::from_values(columns, vec![SqlValue::TinyInt(9), SqlValue::Null]);
+    assert!(Job::from_row(&row).is_err());
+}
+
+#[derive(FromRow)]
+#[mssql(case_insensitive)]
+struct Account {
+    #[mssql(rename = "username")]
+    name: String,
+    email: Option<String>,
+}
+
+#[test]
+fn derived_from_row_case_insensitive_rejects_ambiguous_columns() {
+    let row = Row::from_values(
+        vec![
+            Column::new("UserName", 0, "NVARCHAR".to_string()),
+            Column::new("EMAIL", 1, "NVARCHAR".to_string()),
+        ],
+        vec![
+            SqlValue::String("ada".into()),
+            SqlValue::String("ada@example.com".into()),
+        ],
+    );
+    let account = Account::from_row(&row).unwrap();
+    assert_eq!(account.name, "ada");
+    assert_eq!(account.email.as_deref(), Some("ada@example.com"));
+
+    let row = Row::from_values(
+        vec![
+            Column::new("UserName", 0, "NVARCHAR".to_string()),
+            Column::new("USERNAME", 1, "NVARCHAR".to_string()),
+        ],
+        vec![SqlValue::String("a".into()), SqlValue::String("b".into())],
+    );
+    assert!(Account::from_row(&row).is_err());
+}
@@ -68,6 +68,7 @@ fn test_server_error_display() {
         server: Some("SQLSERVER01".into()),
         procedure: Some("sp_calculate".into()),
         line: 42,
+        additional: Vec::new(),
     };
     let msg = err.to_string();
     assert!(msg.contains("8134"));
@@ -84,6 +85,7 @@ fn test_server_error_without_optional_fields() {
         server: None,
         procedure: None,
         line: 1,
+        additional: Vec::new(),
     };
     let msg = err.to_string();
     assert!(msg.contains("102"));
@@ -135,6 +137,22 @@ fn test_routing_error_display() {
     assert!(msg.contains("11000"));
 }
 
+#[test]
+fn test_database_unavailable_display() {
+    let err = Error::DatabaseUnavailable {
+        number: 4060,
+        class: 11,
+        state: 1,
+        message: "Cannot open database \"sales\" requested by the login.".into(),
+        additional: Vec::new(),
+    };
+    assert_eq!(
+        err.to_string(),
+        "database unavailable (server error 4060): \
+         Cannot open database \"sales\" requested by the login."
+    );
+}
+
 #[test]
 fn test_too_many_redirects_display() {
     let err = Error::TooManyRedirects { max: 5 };
@@ -222,6 +240,7 @@ fn test_is_protocol_error() {
             server: None,
             procedure: None,
             line: 1,
+            additional: Vec::new(),
         }
         .is_protocol_error()
     );
@@ -237,6 +256,7 @@ fn test_error_class_severity_equivalence() {
         server: None,
         procedure: None,
         line: 1,
+        additional: Vec::new(),
     };
 
     // class() and severity() should return the same value
@@ -255,6 +275,7 @@ fn test_error_severity_ranges() {
         server: None,
         procedure: None,
         line: 1,
+        additional: Vec::new(),
     };
     assert!(info.severity().unwrap() <= 10);
 
@@ -267,6 +288,7 @@ fn test_error_severity_ranges() {
         server: None,
         procedure: None,
         line: 1,
+        additional: Vec::new(),
     };
     let sev = user_err.severity().unwrap();
     assert!((11..=16).contains(&sev));
@@ -280,6 +302,7 @@ fn test_error_severity_ranges() {
         server: None,
         procedure: None,
         line: 1,
+        additional: Vec::new(),
     };
     let sev = resource_err.severity().unwrap();
     assert!((17..=19).contains(&sev));
@@ -293,6 +316,7 @@ fn test_error_severity_ranges() {
         server: None,
         procedure: None,
         line: 1,
+        additional: Vec::new(),
     };
     let sev = system_err.severity().unwrap();
     assert!((20..=25).contains(&sev));
@@ -380,6 +404,7 @@ fn make_azure_error(number: i32, message: &str) -> Error {
         server: Some("myserver.database.windows.net".into()),
         procedure: None,
         line: 1,
+        additional: Vec::new(),
     }
 }
 
@@ -431,6 +456,7 @@ fn make_server_error(number: i32, class: u8, message: &str) -> Error {
         server: Some("SQLSERVER01".into()),
         procedure: None,
         line: 1,
+        additional: Vec::new(),
     }
 }
 
@@ -493,6 +519,7 @@ fn test_error_debug_format() {
         server: Some("SERVER".into()),
         procedure: Some("sp_test".into()),
         line: 42,
+        additional: Vec::new(),
     };
 
     let debug = format!("{err:?}");
@@ -518,6 +545,7 @@ fn test_all_error_variants_are_debug() {
             server: None,
             procedure: None,
             line: 1,
+            additional: Vec::new(),
         },
         Error::Config("test".into()),
         Error::ConnectTimeout {
@@ -692,6 +720,16 @@ fn test_every_variant_classified() {
             "transient",
         ),
         (Error::from(std::io::Error::other("test")), "transient"),
+        (
+            Error::DatabaseUnavailable {
+                number: 4060,
+                class: 11,
+                state: 1,
+                message: "test".into(),
+                additional: Vec::new(),
+            },
+            "transient",
+        ),
         (Error::InvalidIdentifier("test".into()), "terminal"),
         (Error::Cancel("test".into()), "terminal"),
     ];
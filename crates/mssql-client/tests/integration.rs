@@ -109,7 +109,7 @@ async fn test_connection_to_nonexistent_database() {
         .await
         .expect_err("connecting to a nonexistent database must fail at login");
     assert!(
-        err.is_server_error(4060),
+        matches!(err, Error::DatabaseUnavailable { number: 4060, .. }),
         "expected SQL Server error 4060 (cannot open database), got: {err:?}"
     );
 }
@@ -283,6 +283,57 @@ async fn test_parameterized_query_string() {
     client.close().await.expect("Failed to close");
 }
 
+#[tokio::test]
+#[ignore = "Requires SQL Server"]
+async fn test_parameterized_decimal_round_trip() {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    let config = get_test_config().expect("SQL Server config required");
+    let mut client = Client::connect(config).await.expect("Failed to connect");
+
+    client
+        .execute(
+            "CREATE TABLE #test_decimal_param (id INT, amount DECIMAL(18,4))",
+            &[],
+        )
+        .await
+        .expect("Create table failed");
+
+    let values = ["0.00", "-12345.6789", "99999999999999.9999"];
+    for (id, text) in values.iter().enumerate() {
+        let id = id as i32;
+        let amount = Decimal::from_str(text).unwrap();
+        client
+            .execute(
+                "INSERT INTO #test_decimal_param (id, amount) VALUES (@p1, @p2)",
+                &[&id, &amount],
+            )
+            .await
+            .expect("Insert failed");
+    }
+
+    let rows = client
+        .query("SELECT amount FROM #test_decimal_param ORDER BY id", &[])
+        .await
+        .expect("Query failed");
+
+    let mut read = Vec::new();
+    for result in rows {
+        let row = result.expect("Row should be valid");
+        let amount: Decimal = row.get(0).expect("Should get amount");
+        read.push(amount);
+    }
+
+    let expected: Vec<Decimal> = values
+        .iter()
+        .map(|v| Decimal::from_str(v).unwrap())
+        .collect();
+    assert_eq!(read, expected);
+
+    client.close().await.expect("Failed to close");
+}
+
 #[tokio::test]
 #[ignore = "Requires SQL Server"]
 async fn test_parameterized_query_multiple_params() {
@@ -1316,6 +1367,104 @@ async fn test_transaction_isolation_repeatable_read() {
     client.close().await.expect("Failed to close");
 }
 
+#[tokio::test]
+#[ignore = "Requires SQL Server"]
+async fn test_transaction_isolation_snapshot() {
+    use mssql_client::IsolationLevel;
+    use std::time::Duration;
+
+    let config = get_test_config().expect("SQL Server config required");
+    let mut client = Client::connect(config.clone())
+        .await
+        .expect("Failed to connect");
+    let mut writer = Client::connect(config).await.expect("Failed to connect");
+
+    // Remember the database setting so it can be put back afterwards.
+    let was_allowed = client
+        .query(
+            "SELECT CAST(snapshot_isolation_state AS INT) \
+             FROM sys.databases WHERE database_id = DB_ID()",
+            &[],
+        )
+        .await
+        .expect("Query failed")
+        .filter_map(|r| r.ok())
+        .next()
+        .map(|row| row.get::<i32>(0).unwrap() == 1)
+        .expect("row");
+    client
+        .execute(
+            "ALTER DATABASE CURRENT SET ALLOW_SNAPSHOT_ISOLATION ON",
+            &[],
+        )
+        .await
+        .expect("Enable snapshot isolation failed");
+    // A permanent table: the row versions live in the test database.
+    client
+        .execute(
+            "DROP TABLE IF EXISTS dbo.iso_snapshot; \
+             CREATE TABLE dbo.iso_snapshot (id INT PRIMARY KEY, value INT); \
+             INSERT INTO dbo.iso_snapshot VALUES (1, 10)",
+            &[],
+        )
+        .await
+        .expect("Create table failed");
+
+    let mut tx = client
+        .begin_transaction_with_isolation(IsolationLevel::Snapshot)
+        .await
+        .expect("Begin failed");
+
+    let read = "SELECT value FROM dbo.iso_snapshot WHERE id = 1";
+    let first: i32 = tx
+        .query(read, &[])
+        .await
+        .expect("Query failed")
+        .filter_map(|r| r.ok())
+        .next()
+        .map(|row| row.get(0).unwrap())
+        .expect("row");
+    assert_eq!(first, 10);
+
+    // The snapshot reader holds no locks, so the writer is not blocked.
+    let updated = tokio::time::timeout(
+        Duration::from_secs(5),
+        writer.execute("UPDATE dbo.iso_snapshot SET value = 20 WHERE id = 1", &[]),
+    )
+    .await
+    .expect("Writer must not be blocked by a snapshot reader")
+    .expect("Update failed");
+    assert_eq!(updated, 1);
+
+    // The transaction still sees the data as of its first read.
+    let second: i32 = tx
+        .query(read, &[])
+        .await
+        .expect("Query failed")
+        .filter_map(|r| r.ok())
+        .next()
+        .map(|row| row.get(0).unwrap())
+        .expect("row");
+    assert_eq!(second, first, "Snapshot reads must be repeatable");
+
+    let mut client = tx.commit().await.expect("Commit failed");
+    client
+        .execute("DROP TABLE dbo.iso_snapshot", &[])
+        .await
+        .expect("Drop table failed");
+    if !was_allowed {
+        client
+            .execute(
+                "ALTER DATABASE CURRENT SET ALLOW_SNAPSHOT_ISOLATION OFF",
+                &[],
+            )
+            .await
+            .expect("Restore snapshot isolation failed");
+    }
+    client.close().await.expect("Failed to close");
+    writer.close().await.expect("Failed to close");
+}
+
 // =============================================================================
 // Statement Cache Tests (TEST-019)
 // =============================================================================
@@ -1560,38 +1709,19 @@ async fn should_skip_tls_tests() -> bool {
             // Check server major version
             // SQL Server 2017+ (major 14+) generally supports TLS 1.2 out of the box
             // Earlier versions need TLS 1.2 cumulative updates which may not be installed
-            let rows = client
-                .query(
-                    "SELECT CAST(SERVERPROPERTY('ProductVersion') AS NVARCHAR(128))",
-                    &[],
-                )
-                .await;
-
-            let mut should_skip = false;
-            if let Ok(rows) = rows {
-                for result in rows {
-                    if let Ok(row) = result {
-                        if let Ok(version) = row.get::<String>(0) {
-                            let major: i32 = version
-                                .split('.')
-                                .next()
-                                .and_then(|s| s.parse().ok())
-                                .unwrap_or(0);
-
-                            if major < 14 {
-                                println!(
-                                    "Skipping TLS test: SQL Server major version {major} < 14 \
-                                     (may not support TLS 1.2 without updates)"
-                                );
-                                should_skip = true;
-                            }
-                        }
-                    }
+            let should_skip = match client.product_version().await {
+                Ok(version) if version.major < 14 => {
+                    println!(
+                        "Skipping TLS test: SQL Server major version {} < 14 \
+                         (may not support TLS 1.2 without updates)",
+                        version.major
+                    );
+                    true
                 }
-            } else {
+                Ok(_) => false,
                 // If we can't check version, skip to be safe
-                should_skip = true;
-            }
+                Err(_) => true,
+            };
 
             let _ = client.close().await;
             should_skip
@@ -410,6 +410,79 @@ async fn test_rpc_roundtrip_datetimeoffset() {
     }
 }
 
+/// Assert the server received `@p1` as `base_type` (not a string it had to
+/// parse) and that it equals `literal` cast to that type server-side.
+#[cfg(feature = "chrono")]
+async fn assert_param_matches_cast(
+    client: &mut Client<mssql_client::Ready>,
+    param: &(dyn mssql_client::ToSql + Sync),
+    base_type: &str,
+    literal: &str,
+) {
+    let sql = format!(
+        "SELECT CAST(SQL_VARIANT_PROPERTY(@p1, 'BaseType') AS NVARCHAR(128)) AS t, \
+         CASE WHEN @p1 = CAST('{literal}' AS {base_type}) THEN 1 ELSE 0 END AS eq"
+    );
+    let rows = client
+        .query(&sql, &[param])
+        .await
+        .unwrap_or_else(|e| panic!("Query failed for {literal}: {e}"));
+    let row = rows.into_iter().next().expect("row").expect("row err");
+    let declared: String = row.get(0).expect("get base type");
+    let eq: i32 = row.get(1).expect("get eq");
+    assert_eq!(
+        Some(declared.as_str()),
+        base_type.split('(').next(),
+        "{literal} was sent with the wrong type"
+    );
+    assert_eq!(eq, 1, "{literal} differs from CAST(... AS {base_type})");
+}
+
+/// Date/time parameters go out as native DATEN/TIMEN/DATETIME2N/DATETIMEOFFSETN
+/// values, so the server compares them without culture-dependent string
+/// parsing and keeps all seven fractional digits and the offset.
+#[cfg(feature = "chrono")]
+#[tokio::test]
+#[ignore = "Requires SQL Server"]
+async fn test_rpc_temporal_params_match_server_cast() {
+    use chrono::{DateTime, NaiveDate, NaiveTime};
+
+    let mut client = connect().await;
+
+    let date = NaiveDate::from_ymd_opt(2026, 4, 16).unwrap();
+    assert_param_matches_cast(&mut client, &date, "date", "2026-04-16").await;
+
+    let time = NaiveTime::from_hms_nano_opt(1, 2, 3, 456_789_100).unwrap();
+    assert_param_matches_cast(&mut client, &time, "time(7)", "01:02:03.4567891").await;
+
+    let datetime = date.and_time(NaiveTime::from_hms_nano_opt(12, 34, 56, 789_123_400).unwrap());
+    assert_param_matches_cast(
+        &mut client,
+        &datetime,
+        "datetime2(7)",
+        "2026-04-16T12:34:56.7891234",
+    )
+    .await;
+
+    let dto = DateTime::parse_from_rfc3339("2026-04-16T09:30:00.1234567+05:30").unwrap();
+    assert_param_matches_cast(
+        &mut client,
+        &dto,
+        "datetimeoffset(7)",
+        "2026-04-16T09:30:00.1234567+05:30",
+    )
+    .await;
+
+    // Equality compares the UTC instant; the offset itself must survive too.
+    let rows = client
+        .query("SELECT DATEPART(TZOFFSET, @p1) AS tz", &[&dto])
+        .await
+        .expect("Query failed");
+    let row = rows.into_iter().next().expect("row").expect("row err");
+    let tz: i32 = row.get(0).expect("get tz");
+    assert_eq!(tz, 330, "DATETIMEOFFSET offset not preserved");
+}
+
 // =============================================================================
 // Decimal (decimal feature — default)
 // =============================================================================
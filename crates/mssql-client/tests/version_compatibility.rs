@@ -185,36 +185,20 @@ async fn test_product_version() {
     let config = get_test_config().expect("SQL Server config required");
     let mut client = Client::connect(config).await.expect("Failed to connect");
 
-    // Use CAST to avoid SQL_VARIANT type issues
-    // Note: ProductMajorVersion returns NULL in SQL Server 2014 RTM, so we parse from ProductVersion
-    let rows = client
-        .query(
-            "SELECT CAST(SERVERPROPERTY('ProductVersion') AS NVARCHAR(128)) AS Version",
-            &[],
-        )
+    let version = client
+        .product_version()
         .await
-        .expect("Query should succeed");
+        .expect("Should get product version");
+    let major_num = version.major;
 
-    for result in rows {
-        let row = result.expect("Row should be valid");
-        let version: String = row.get(0).expect("Should get version");
-
-        // Parse major version from ProductVersion string (e.g., "16.0.4225.2" -> 16)
-        let major_num: i32 = version
-            .split('.')
-            .next()
-            .and_then(|s| s.parse().ok())
-            .expect("Should parse major version from ProductVersion");
-
-        println!("Product Version: {version}, Major: {major_num}");
-
-        // Major version should be 10+ (SQL Server 2008+)
-        // 10 = 2008, 11 = 2012, 12 = 2014, 13 = 2016, 14 = 2017, 15 = 2019, 16 = 2022
-        assert!(
-            major_num >= 10,
-            "Should be SQL Server 2008 or later (major >= 10), got: {major_num}"
-        );
-    }
+    println!("Product Version: {version}, Major: {major_num}");
+
+    // Major version should be 10+ (SQL Server 2008+)
+    // 10 = 2008, 11 = 2012, 12 = 2014, 13 = 2016, 14 = 2017, 15 = 2019, 16 = 2022
+    assert!(
+        major_num >= 10,
+        "Should be SQL Server 2008 or later (major >= 10), got: {major_num}"
+    );
 
     client.close().await.expect("Failed to close");
 }
@@ -570,24 +554,11 @@ async fn test_sql_2017_features() {
     let mut client = Client::connect(config).await.expect("Failed to connect");
 
     // Check if we're on 2017+ (major version 14+)
-    let rows = client
-        .query(
-            "SELECT CAST(SERVERPROPERTY('ProductVersion') AS NVARCHAR(128))",
-            &[],
-        )
+    let major_version = client
+        .product_version()
         .await
-        .expect("Version query should succeed");
-
-    let mut major_version = 0;
-    for result in rows {
-        let row = result.expect("Row should be valid");
-        let version: String = row.get(0).expect("Should get version");
-        major_version = version
-            .split('.')
-            .next()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(0);
-    }
+        .expect("Version query should succeed")
+        .major;
 
     if major_version < 14 {
         println!(
@@ -651,25 +622,11 @@ async fn test_sql_2019_features() {
     let mut client = Client::connect(config).await.expect("Failed to connect");
 
     // Check if we're on 2019+ (major version 15+)
-    // Note: ProductMajorVersion returns NULL in SQL Server 2014 RTM, so parse from ProductVersion
-    let rows = client
-        .query(
-            "SELECT CAST(SERVERPROPERTY('ProductVersion') AS NVARCHAR(128))",
-            &[],
-        )
+    let major_version = client
+        .product_version()
         .await
-        .expect("Version query should succeed");
-
-    let mut major_version = 0;
-    for result in rows {
-        let row = result.expect("Row should be valid");
-        let version: String = row.get(0).expect("Should get version");
-        major_version = version
-            .split('.')
-            .next()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(0);
-    }
+        .expect("Version query should succeed")
+        .major;
 
     if major_version < 15 {
         println!(
@@ -895,36 +852,23 @@ async fn test_tds_version_negotiation() {
     let mut client = Client::connect(config).await.expect("Failed to connect");
 
     // Get server version info
-    // Note: ProductMajorVersion returns NULL in SQL Server 2014 RTM, so parse from ProductVersion
-    let rows = client
-        .query(
-            "SELECT CAST(SERVERPROPERTY('ProductVersion') AS NVARCHAR(128)) AS Version",
-            &[],
-        )
+    let version = client
+        .product_version()
         .await
         .expect("Query should succeed");
-
-    for result in rows {
-        let row = result.expect("Row should be valid");
-        let version: String = row.get(0).expect("Should get version");
-        let major_num: i32 = version
-            .split('.')
-            .next()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(0);
-
-        println!("Connected to SQL Server {version} (major: {major_num})");
-
-        // Verify expected TDS version support
-        if major_num >= 16 {
-            println!("  SQL Server 2022+ detected - supports TDS 7.4 and 8.0");
-        } else if major_num >= 14 {
-            println!("  SQL Server 2017+ detected - supports TDS 7.4");
-        } else if major_num >= 11 {
-            println!("  SQL Server 2012+ detected - supports TDS 7.4");
-        } else if major_num >= 10 {
-            println!("  SQL Server 2008+ detected - supports TDS 7.3");
-        }
+    let major_num = version.major;
+
+    println!("Connected to SQL Server {version} (major: {major_num})");
+
+    // Verify expected TDS version support
+    if version.supports_tds8() {
+        println!("  SQL Server 2022+ detected - supports TDS 7.4 and 8.0");
+    } else if major_num >= 14 {
+        println!("  SQL Server 2017+ detected - supports TDS 7.4");
+    } else if major_num >= 11 {
+        println!("  SQL Server 2012+ detected - supports TDS 7.4");
+    } else if major_num >= 10 {
+        println!("  SQL Server 2008+ detected - supports TDS 7.3");
     }
 
     client.close().await.expect("Failed to close");
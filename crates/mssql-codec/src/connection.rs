@@ -7,7 +7,9 @@ use std::sync::Arc;
 
 use bytes::{Bytes, BytesMut};
 use futures_util::{SinkExt, StreamExt};
-use tds_protocol::packet::{PACKET_HEADER_SIZE, PacketHeader, PacketStatus, PacketType};
+use tds_protocol::packet::{
+    MAX_PACKET_SIZE, PACKET_HEADER_SIZE, PacketHeader, PacketStatus, PacketType,
+};
 use tokio::io::{AsyncRead, AsyncWrite, ReadHalf, WriteHalf};
 use tokio::sync::{Mutex, Notify};
 
@@ -200,8 +202,30 @@ where
     /// Read a single packet from the connection.
     ///
     /// This is lower-level than `read_message` and doesn't perform reassembly.
+    /// Cancellation is honored the same way: once an Attention has been sent,
+    /// the rest of the cancelled response is discarded and the read returns
+    /// [`CodecError::Cancelled`] after the DONE_ATTN acknowledgement.
     pub async fn read_packet(&mut self) -> Result<Option<Packet>, CodecError> {
+        if self.is_cancelling() {
+            return self.drain_after_cancel().await.map(|_| None);
+        }
+
         match self.reader.next().await {
+            Some(Ok(packet)) if self.is_cancelling() => {
+                // The cancel flag was set while this read was parked: the
+                // packet is part of the cancelled response (possibly already
+                // carrying the acknowledgement), so feed it to the drain
+                // rather than surfacing it.
+                self.assembler.clear();
+                if let Some(message) = self.assembler.push(packet) {
+                    if Self::payload_ends_with_attention_done(&message.payload) {
+                        tracing::debug!("received DONE with ATTENTION, cancellation complete");
+                        self.finish_cancel();
+                        return Err(CodecError::Cancelled);
+                    }
+                }
+                self.drain_pending_cancel().await.map(|_| None)
+            }
             Some(result) => result.map(Some),
             None => Ok(None),
         }
@@ -235,6 +259,11 @@ where
     /// first packet. This causes SQL Server to reset connection state (temp
     /// tables, SET options, isolation level, etc.) before executing the command.
     /// Per TDS spec, this flag MUST only be set on the first packet of a message.
+    ///
+    /// Payloads larger than `max_packet_size - 8` are split across packets.
+    /// `max_packet_size` is capped at the TDS maximum (65535); sizes that leave
+    /// no room for payload are rejected. Packet IDs restart at 1 for every
+    /// message and wrap (skipping 0) on messages longer than 255 packets.
     pub async fn send_message_with_reset(
         &mut self,
         packet_type: PacketType,
@@ -242,6 +271,13 @@ where
         max_packet_size: usize,
         reset_connection: bool,
     ) -> Result<(), CodecError> {
+        let max_packet_size = max_packet_size.min(MAX_PACKET_SIZE);
+        if max_packet_size <= PACKET_HEADER_SIZE {
+            return Err(CodecError::Encoding(format!(
+                "packet size {max_packet_size} leaves no room for payload \
+                 (header is {PACKET_HEADER_SIZE} bytes)"
+            )));
+        }
         let max_payload = max_packet_size - PACKET_HEADER_SIZE;
         // An empty payload must still produce one header-only EOM packet:
         // `[]chunks()` yields zero chunks, which would send nothing at all and
@@ -255,6 +291,8 @@ where
         let total_chunks = chunks.len();
 
         let mut writer = self.writer.lock().await;
+        // PacketID numbers packets within a message, starting at 1.
+        writer.codec_mut().reset_packet_id();
 
         for (i, chunk) in chunks.into_iter().enumerate() {
             let is_first = i == 0;
@@ -281,12 +319,55 @@ where
         Ok(())
     }
 
+    /// Send one packet of a message whose payload is produced incrementally,
+    /// such as a streamed PLP parameter too large to hold in memory.
+    ///
+    /// Send the packets of a message in order. `first` restarts packet
+    /// numbering at 1. `status` carries END_OF_MESSAGE on the last packet
+    /// only, and RESETCONNECTION, if wanted, on the first only. To abandon a
+    /// partly sent message, finish it with `END_OF_MESSAGE | IGNORE_EVENT`
+    /// and the server discards it. Every packet but the last should be
+    /// filled to the negotiated packet size.
+    pub async fn send_message_packet(
+        &mut self,
+        packet_type: PacketType,
+        status: PacketStatus,
+        payload: Bytes,
+        first: bool,
+    ) -> Result<(), CodecError> {
+        if PACKET_HEADER_SIZE + payload.len() > MAX_PACKET_SIZE {
+            return Err(CodecError::Encoding(format!(
+                "packet payload of {} bytes exceeds the TDS maximum packet size",
+                payload.len()
+            )));
+        }
+        let mut writer = self.writer.lock().await;
+        if first {
+            writer.codec_mut().reset_packet_id();
+        }
+        let header = PacketHeader::new(packet_type, status, 0);
+        writer
+            .send(Packet::new(header, BytesMut::from(&payload[..])))
+            .await
+    }
+
     /// Flush the write buffer.
     pub async fn flush(&mut self) -> Result<(), CodecError> {
         let mut writer = self.writer.lock().await;
         writer.flush().await
     }
 
+    /// Flush the write buffer and shut down the write half of the transport.
+    ///
+    /// The peer sees end-of-stream (a TCP FIN, preceded by a TLS close_notify
+    /// on encrypted streams) and can tear its session down immediately rather
+    /// than waiting for the socket to time out. No further messages can be
+    /// sent afterwards.
+    pub async fn shutdown(&mut self) -> Result<(), CodecError> {
+        let mut writer = self.writer.lock().await;
+        writer.close().await
+    }
+
     /// Drain messages after cancellation until DONE with ATTENTION is received.
     ///
     /// Returns [`CodecError::Cancelled`] once the acknowledgement is consumed;
@@ -296,7 +377,12 @@ where
 
         // Clear any partial message
         self.assembler.clear();
+        self.drain_pending_cancel().await
+    }
 
+    /// Continue draining after cancellation without discarding a partial
+    /// message already in the assembler.
+    async fn drain_pending_cancel(&mut self) -> Result<Option<Message>, CodecError> {
         loop {
             match self.reader.next().await {
                 Some(Ok(packet)) => {
@@ -555,6 +641,130 @@ mod tests {
         assert_eq!(all.len(), 16 + 8 + 4, "exactly two packets must be sent");
     }
 
+    /// Split a raw byte stream into `(status, packet_id, payload_len)` triples.
+    fn split_packets(mut raw: &[u8]) -> Vec<(PacketStatus, u8, usize)> {
+        let mut packets = Vec::new();
+        while !raw.is_empty() {
+            let length = u16::from_be_bytes([raw[2], raw[3]]) as usize;
+            packets.push((
+                PacketStatus::from_bits_truncate(raw[1]),
+                raw[6],
+                length - PACKET_HEADER_SIZE,
+            ));
+            raw = &raw[length..];
+        }
+        packets
+    }
+
+    /// A message larger than the packet size is framed as a sequence of
+    /// full packets plus a short last one: packet IDs count up from 1
+    /// (wrapping past 255 without using 0), only the last packet is EOM, and
+    /// the next message starts numbering at 1 again.
+    #[tokio::test]
+    async fn test_large_message_packet_ids_and_framing() {
+        use tokio::io::AsyncReadExt;
+
+        let (client_io, mut server_io) = tokio::io::duplex(4096);
+        let reader = tokio::spawn(async move {
+            let mut all = Vec::new();
+            server_io.read_to_end(&mut all).await.expect("read packets");
+            all
+        });
+
+        // max_packet_size 64 → 56-byte payloads; 300 full packets + 10 bytes.
+        let first = Bytes::from((0..300 * 56 + 10).map(|i| i as u8).collect::<Vec<_>>());
+        let mut conn = Connection::new(client_io);
+        conn.send_message(PacketType::SqlBatch, first.clone(), 64)
+            .await
+            .expect("large send should succeed");
+        conn.send_message(PacketType::SqlBatch, Bytes::from_static(b"abc"), 64)
+            .await
+            .expect("second send should succeed");
+        drop(conn);
+
+        let all = reader.await.expect("reader task");
+        let packets = split_packets(&all);
+        assert_eq!(packets.len(), 302, "301 packets + 1 for the second message");
+
+        let (large, second) = packets.split_at(301);
+        for (i, (status, id, len)) in large.iter().enumerate() {
+            let expected_id = (i % 255 + 1) as u8;
+            assert_eq!(*id, expected_id, "packet {i} has the wrong ID");
+            let is_last = i == large.len() - 1;
+            assert_eq!(status.contains(PacketStatus::END_OF_MESSAGE), is_last);
+            assert_eq!(*len, if is_last { 10 } else { 56 });
+        }
+        assert_eq!(second[0], (PacketStatus::END_OF_MESSAGE, 1, 3));
+
+        // Reassembled payload is byte-identical.
+        let mut payload = Vec::new();
+        let mut raw = &all[..];
+        for (_, _, len) in large {
+            payload.extend_from_slice(&raw[PACKET_HEADER_SIZE..PACKET_HEADER_SIZE + len]);
+            raw = &raw[PACKET_HEADER_SIZE + len..];
+        }
+        assert_eq!(payload, first);
+    }
+
+    /// Packets sent one at a time restart numbering on `first` and keep the
+    /// caller's status bits.
+    #[tokio::test]
+    async fn test_send_message_packet_numbers_a_streamed_message() {
+        use tokio::io::AsyncReadExt;
+
+        let (client_io, mut server_io) = tokio::io::duplex(4096);
+        let reader = tokio::spawn(async move {
+            let mut all = Vec::new();
+            server_io.read_to_end(&mut all).await.expect("read packets");
+            all
+        });
+
+        let mut conn = Connection::new(client_io);
+        for (status, first) in [
+            (PacketStatus::RESET_CONNECTION, true),
+            (PacketStatus::NORMAL, false),
+            (PacketStatus::END_OF_MESSAGE, false),
+            (
+                PacketStatus::END_OF_MESSAGE | PacketStatus::IGNORE_EVENT,
+                true,
+            ),
+        ] {
+            conn.send_message_packet(PacketType::Rpc, status, Bytes::from_static(b"xy"), first)
+                .await
+                .expect("packet sends");
+        }
+        drop(conn);
+
+        let all = reader.await.expect("reader task");
+        assert_eq!(
+            split_packets(&all),
+            vec![
+                (PacketStatus::RESET_CONNECTION, 1, 2),
+                (PacketStatus::NORMAL, 2, 2),
+                (PacketStatus::END_OF_MESSAGE, 3, 2),
+                (
+                    PacketStatus::END_OF_MESSAGE | PacketStatus::IGNORE_EVENT,
+                    1,
+                    2
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_packet_size_without_payload_room_is_rejected() {
+        let (client_io, _server_io) = tokio::io::duplex(64);
+        let mut conn = Connection::new(client_io);
+        let result = conn
+            .send_message(
+                PacketType::SqlBatch,
+                Bytes::from_static(b"x"),
+                PACKET_HEADER_SIZE,
+            )
+            .await;
+        assert!(matches!(result, Err(CodecError::Encoding(_))));
+    }
+
     #[test]
     fn test_attention_packet_header() {
         // Verify attention packet header construction
@@ -698,6 +908,53 @@ mod tests {
         );
     }
 
+    /// The streaming path reads packet by packet; a cancel that fires while
+    /// `read_packet()` is parked must drain the cancelled response the same
+    /// way, instead of handing the DONE_ATTN acknowledgement up as an
+    /// ordinary final DONE and leaving the cancel flag latched.
+    #[tokio::test]
+    async fn test_cancel_mid_read_packet_drains_to_attention_ack() {
+        use std::task::{Context, Poll};
+        use tokio::io::AsyncWriteExt;
+
+        let (client_io, mut server_io) = tokio::io::duplex(4096);
+        let mut conn = Connection::new(client_io);
+        let cancel = conn.cancel_handle();
+
+        let mut read_fut = Box::pin(conn.read_packet());
+        let waker = std::task::Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        assert!(matches!(read_fut.as_mut().poll(&mut cx), Poll::Pending));
+
+        cancel.cancel().await.expect("send attention");
+        server_io
+            .write_all(&raw_message(&done_token(0x0020))) // DONE_ATTN ack
+            .await
+            .unwrap();
+        server_io
+            .write_all(&raw_message(&done_token(0x0010))) // next response
+            .await
+            .unwrap();
+
+        let result = read_fut.await;
+        assert!(
+            matches!(result, Err(CodecError::Cancelled)),
+            "parked packet read must report Cancelled, got {result:?}"
+        );
+        assert!(!conn.is_cancelling(), "cancel flag must be cleared");
+
+        let packet = conn
+            .read_packet()
+            .await
+            .expect("next read")
+            .expect("next packet");
+        assert_eq!(
+            u16::from_le_bytes([packet.payload[1], packet.payload[2]]),
+            0x0010,
+            "next response must come through untouched"
+        );
+    }
+
     /// Cancellation requested before the read starts takes the drain path and
     /// must behave identically to the mid-read race.
     #[tokio::test]
@@ -18,6 +18,9 @@ pub struct Message {
     pub packet_type: PacketType,
     /// The complete message payload (all packets combined).
     pub payload: Bytes,
+    /// Server process ID (SPID) from the packet header. The server stamps
+    /// every packet of its responses with the session's SPID.
+    pub spid: u16,
 }
 
 impl Message {
@@ -27,6 +30,7 @@ impl Message {
         Self {
             packet_type: packet.header.packet_type,
             payload: packet.payload.freeze(),
+            spid: packet.header.spid,
         }
     }
 
@@ -105,6 +109,7 @@ impl MessageAssembler {
             let message = Message {
                 packet_type: self.packet_type.take().expect("packet_type set above"),
                 payload: self.buffer.split().freeze(),
+                spid: packet.header.spid,
             };
             self.packet_count = 0;
             Some(message)
@@ -197,6 +202,16 @@ mod tests {
         assert_eq!(assembler.packet_count(), 0);
     }
 
+    #[test]
+    fn test_message_carries_spid() {
+        let mut assembler = MessageAssembler::new();
+        let mut packet = make_packet(true, b"ack");
+        packet.header.spid = 57;
+
+        let message = assembler.push(packet).expect("should complete message");
+        assert_eq!(message.spid, 57);
+    }
+
     #[test]
     fn test_clear() {
         let mut assembler = MessageAssembler::new();
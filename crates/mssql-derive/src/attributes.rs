@@ -13,6 +13,8 @@ pub(crate) struct FieldConfig {
     pub default: bool,
     /// Flatten nested struct.
     pub flatten: bool,
+    /// Explicit SQL type, overriding inference from the Rust type.
+    pub sql_type: Option<String>,
 }
 
 /// Struct-level configuration extracted from `#[mssql(...)]` attributes.
@@ -22,6 +24,10 @@ pub(crate) struct StructConfig {
     pub type_name: Option<String>,
     /// Rename all fields using a casing convention.
     pub rename_all: Option<String>,
+    /// How a `SqlEnum` is stored: an integer type name or `"str"`.
+    pub repr: Option<String>,
+    /// Match `FromRow` column names case-insensitively.
+    pub case_insensitive: bool,
 }
 
 /// Parse field-level `#[mssql(...)]` attributes.
@@ -49,6 +55,14 @@ pub(crate) fn parse_field_config(attrs: &[Attribute]) -> FieldConfig {
                 config.default = true;
             } else if meta.path.is_ident("flatten") {
                 config.flatten = true;
+            } else if meta.path.is_ident("sql_type") {
+                let value: Expr = meta.value()?.parse()?;
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) = value
+                {
+                    config.sql_type = Some(lit.value());
+                }
             }
             Ok(())
         });
@@ -83,6 +97,16 @@ pub(crate) fn parse_struct_config(attrs: &[Attribute]) -> StructConfig {
                 {
                     config.rename_all = Some(lit.value());
                 }
+            } else if meta.path.is_ident("repr") {
+                let value: Expr = meta.value()?.parse()?;
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) = value
+                {
+                    config.repr = Some(lit.value());
+                }
+            } else if meta.path.is_ident("case_insensitive") {
+                config.case_insensitive = true;
             }
             Ok(())
         });
@@ -32,6 +32,12 @@ pub(crate) fn impl_from_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
         }
     };
 
+    let (get_by_name, try_get_by_name) = if struct_config.case_insensitive {
+        (quote!(get_by_name_ci), quote!(try_get_by_name_ci))
+    } else {
+        (quote!(get_by_name), quote!(try_get_by_name))
+    };
+
     let mut field_extractions = Vec::new();
 
     for field in fields {
@@ -66,12 +72,12 @@ pub(crate) fn impl_from_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
             // a type mismatch must not silently become the default value.
             if is_option_type(field_type) {
                 field_extractions.push(quote! {
-                    #field_name: row.try_get_by_name(#column_name)
+                    #field_name: row.#try_get_by_name(#column_name)
                         .map_err(mssql_client::Error::from)?
                 });
             } else {
                 field_extractions.push(quote! {
-                    #field_name: row.try_get_by_name(#column_name)
+                    #field_name: row.#try_get_by_name(#column_name)
                         .map_err(mssql_client::Error::from)?
                         .unwrap_or_else(::std::default::Default::default)
                 });
@@ -79,13 +85,13 @@ pub(crate) fn impl_from_row(input: &DeriveInput) -> syn::Result<TokenStream2> {
         } else if is_option_type(field_type) {
             // Option types map NULL to None but propagate decode errors
             field_extractions.push(quote! {
-                #field_name: row.try_get_by_name(#column_name)
+                #field_name: row.#try_get_by_name(#column_name)
                     .map_err(mssql_client::Error::from)?
             });
         } else {
             // Required fields use get_by_name which returns Result
             field_extractions.push(quote! {
-                #field_name: row.get_by_name(#column_name)
+                #field_name: row.#get_by_name(#column_name)
                     .map_err(mssql_client::Error::from)?
             });
         }
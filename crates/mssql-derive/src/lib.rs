@@ -13,6 +13,7 @@
 //! - `#[derive(FromRow)]` - Convert database rows to structs
 //! - `#[derive(ToParams)]` - Convert structs to query parameters
 //! - `#[derive(Tvp)]` - Table-valued parameter support
+//! - `#[derive(SqlEnum)]` - Map a C-like enum to an integer or string column
 //!
 //! ## Example
 //!
@@ -44,6 +45,7 @@ use syn::parse_macro_input;
 mod attributes;
 mod from_row;
 mod naming;
+mod sql_enum;
 mod to_params;
 mod tvp;
 
@@ -63,6 +65,9 @@ mod tvp;
 /// ### Struct Attributes
 ///
 /// - `#[mssql(rename_all = "snake_case")]` - Apply naming convention to all fields
+/// - `#[mssql(case_insensitive)]` - Read columns with `Row::get_by_name_ci`,
+///   so a name matching several columns that differ only in case is an
+///   error instead of reading the first of them
 ///
 /// ## Example
 ///
@@ -143,6 +148,14 @@ pub fn derive_to_params(input: TokenStream) -> TokenStream {
 ///
 /// - `#[mssql(rename = "column_name")]` - Map field to a different column name
 /// - `#[mssql(skip)]` - Don't include this field in the TVP
+/// - `#[mssql(sql_type = "DECIMAL(18,4)")]` - Declare the column with this SQL
+///   type instead of the one inferred from the field's Rust type
+///
+/// Column types are inferred from the field type: `i32` is `INT`, `String`
+/// is `NVARCHAR(MAX)`, `NaiveDate` is `DATE`, `NaiveDateTime` is `DATETIME2`,
+/// `DateTime<Tz>` is `DATETIMEOFFSET`, `Decimal` is `DECIMAL(38,10)`, and
+/// `Option<T>` is the type of `T`. Unrecognized types fall back to
+/// `NVARCHAR(MAX)`.
 ///
 /// ## Example
 ///
@@ -175,3 +188,58 @@ pub fn derive_tvp(input: TokenStream) -> TokenStream {
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+/// Derive macro for implementing `FromSql` and `ToSql` on a C-like enum.
+///
+/// This macro maps each variant to an integer or string column value, so the
+/// enum can be read with `row.get()`, used as a `FromRow` field, and bound as
+/// a parameter. Reading a value that matches no variant fails with
+/// `TypeError::UnknownEnumValue`.
+///
+/// ## Attributes
+///
+/// ### Enum Attributes (Required)
+///
+/// - `#[mssql(repr = "i32")]` - Store the variant's discriminant as `u8`
+///   (`TINYINT`), `i16`, `i32` or `i64`
+/// - `#[mssql(repr = "str")]` - Store the variant's name as a string
+///
+/// ### Enum Attributes (Optional)
+///
+/// - `#[mssql(rename_all = "SCREAMING_SNAKE_CASE")]` - Apply a naming
+///   convention to all variant names (`repr = "str"` only)
+///
+/// ### Variant Attributes
+///
+/// - `#[mssql(rename = "IN_PROGRESS")]` - Store this variant under a
+///   different name (`repr = "str"` only)
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// #[derive(SqlEnum)]
+/// #[mssql(repr = "u8")]
+/// enum Priority {
+///     Low = 1,
+///     High = 2,
+/// }
+///
+/// #[derive(SqlEnum)]
+/// #[mssql(repr = "str")]
+/// enum Status {
+///     #[mssql(rename = "IN_PROGRESS")]
+///     InProgress,
+///     Done,
+/// }
+///
+/// let status: Status = row.get(0)?;
+/// client.execute("UPDATE jobs SET status = @p1", &[&Status::Done]).await?;
+/// ```
+#[proc_macro_derive(SqlEnum, attributes(mssql))]
+pub fn derive_sql_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    match sql_enum::impl_sql_enum(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
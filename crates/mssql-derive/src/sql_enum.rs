@@ -0,0 +1,138 @@
+//! `#[derive(SqlEnum)]` implementation.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields};
+
+use crate::attributes::{parse_field_config, parse_struct_config};
+use crate::naming::apply_rename_all;
+
+/// Integer types an enum can be stored as, one per SQL integer type.
+const INTEGER_REPRS: [&str; 4] = ["u8", "i16", "i32", "i64"];
+
+pub(crate) fn impl_sql_enum(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let struct_config = parse_struct_config(&input.attrs);
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "SqlEnum can only be derived for enums",
+            ));
+        }
+    };
+
+    let repr = struct_config.repr.ok_or_else(|| {
+        syn::Error::new_spanned(
+            input,
+            "SqlEnum requires #[mssql(repr = \"...\")] with one of \"u8\", \"i16\", \"i32\", \"i64\" or \"str\"",
+        )
+    })?;
+    if repr != "str" && !INTEGER_REPRS.contains(&repr.as_str()) {
+        return Err(syn::Error::new_spanned(
+            input,
+            format!(
+                "unsupported repr \"{repr}\": expected one of \"u8\", \"i16\", \"i32\", \"i64\" or \"str\""
+            ),
+        ));
+    }
+
+    let mut idents = Vec::new();
+    let mut names = Vec::new();
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "SqlEnum variants cannot have fields",
+            ));
+        }
+        let config = parse_field_config(&variant.attrs);
+        if config.rename.is_some() && repr != "str" {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "#[mssql(rename)] only applies to repr = \"str\"",
+            ));
+        }
+        names.push(config.rename.unwrap_or_else(|| {
+            apply_rename_all(
+                &variant.ident.to_string(),
+                struct_config.rename_all.as_deref(),
+            )
+        }));
+        idents.push(&variant.ident);
+    }
+
+    let (from_sql, to_sql, sql_type) = if repr == "str" {
+        (
+            quote! {
+                let raw = <::std::string::String as mssql_client::__private::FromSql>::from_sql(value)?;
+                match raw.as_str() {
+                    #(#names => Ok(Self::#idents),)*
+                    _ => Err(mssql_client::__private::TypeError::UnknownEnumValue {
+                        target_type: #name_str,
+                        value: raw,
+                    }),
+                }
+            },
+            quote! {
+                let raw: &str = match self {
+                    #(Self::#idents => #names,)*
+                };
+                mssql_client::__private::ToSql::to_sql(raw)
+            },
+            quote! { mssql_client::__private::ToSql::sql_type("") },
+        )
+    } else {
+        let repr = format_ident!("{}", repr);
+        (
+            quote! {
+                let raw = <#repr as mssql_client::__private::FromSql>::from_sql(value)?;
+                #(
+                    if raw == Self::#idents as #repr {
+                        return Ok(Self::#idents);
+                    }
+                )*
+                Err(mssql_client::__private::TypeError::UnknownEnumValue {
+                    target_type: #name_str,
+                    value: raw.to_string(),
+                })
+            },
+            quote! {
+                let raw: #repr = match self {
+                    #(Self::#idents => Self::#idents as #repr,)*
+                };
+                mssql_client::__private::ToSql::to_sql(&raw)
+            },
+            quote! { mssql_client::__private::ToSql::sql_type(&<#repr>::default()) },
+        )
+    };
+
+    Ok(quote! {
+        impl #impl_generics mssql_client::__private::FromSql for #name #ty_generics #where_clause {
+            fn from_sql(
+                value: &mssql_client::__private::SqlValue,
+            ) -> ::std::result::Result<Self, mssql_client::__private::TypeError> {
+                #from_sql
+            }
+        }
+
+        impl #impl_generics mssql_client::__private::ToSql for #name #ty_generics #where_clause {
+            fn to_sql(
+                &self,
+            ) -> ::std::result::Result<mssql_client::__private::SqlValue, mssql_client::__private::TypeError>
+            {
+                #to_sql
+            }
+
+            fn sql_type(&self) -> &'static str {
+                #sql_type
+            }
+        }
+    })
+}
@@ -57,8 +57,10 @@ pub(crate) fn impl_tvp(input: &DeriveInput) -> syn::Result<TokenStream2> {
             apply_rename_all(&field_name.to_string(), struct_config.rename_all.as_deref())
         });
 
-        // Infer SQL type from Rust type
-        let sql_type = infer_sql_type(field_type);
+        // An explicit sql_type wins; otherwise infer it from the Rust type
+        let sql_type = config
+            .sql_type
+            .unwrap_or_else(|| infer_sql_type(field_type).to_string());
 
         column_defs.push(quote! {
             mssql_client::TvpColumn::new(#column_name, #sql_type, #ordinal)
@@ -93,6 +95,9 @@ pub(crate) fn impl_tvp(input: &DeriveInput) -> syn::Result<TokenStream2> {
 }
 
 /// Infer SQL type string from Rust type.
+///
+/// `Option<T>` infers from `T`: every TVP column is declared nullable, so the
+/// `Option` itself adds nothing to the type.
 fn infer_sql_type(ty: &Type) -> &'static str {
     if let Type::Path(type_path) = ty {
         if let Some(segment) = type_path.path.segments.last() {
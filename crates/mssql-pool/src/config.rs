@@ -32,6 +32,18 @@ pub struct PoolConfig {
     /// Whether to test connections on checkout.
     pub test_on_checkout: bool,
 
+    /// Only test connections on checkout that have sat idle at least this
+    /// long.
+    ///
+    /// A connection returned moments ago is almost certainly still alive, so
+    /// skipping its check saves a round trip on hot paths while long-idle
+    /// connections, the ones a firewall or NAT gateway may have silently
+    /// dropped, are still verified. Only applies when
+    /// [`test_on_checkout`](Self::test_on_checkout) is enabled.
+    ///
+    /// Default: `None` (test every checkout)
+    pub test_on_checkout_after_idle: Option<Duration>,
+
     /// Whether to test connections on checkin.
     pub test_on_checkin: bool,
 
@@ -50,6 +62,19 @@ pub struct PoolConfig {
     /// so checkout availability is minimally impacted during checking.
     pub test_while_idle: bool,
 
+    /// Ping idle connections that have gone unused for this long.
+    ///
+    /// Firewalls, NAT gateways and Azure SQL's load balancer silently drop
+    /// TCP sessions that carry no traffic (Azure's cutoff is 30 minutes),
+    /// leaving the pool holding connections that fail on first use. When
+    /// set, the background reaper runs the health check query on every idle
+    /// connection whose last use or ping is older than this interval and
+    /// discards any that fail. Checked-out connections are never pinged.
+    /// Must be greater than zero.
+    ///
+    /// Default: `None` (no keepalive)
+    pub keepalive_interval: Option<Duration>,
+
     /// Whether to execute sp_reset_connection on return.
     pub sp_reset_connection: bool,
 
@@ -65,6 +90,43 @@ pub struct PoolConfig {
     /// - `SELECT GETDATE()` - Check server can execute functions
     /// - `SELECT 1 FROM sys.databases WHERE name = 'mydb'` - Check database exists
     pub health_check_query: Arc<str>,
+
+    /// How long a single health check may take before the connection is
+    /// considered broken.
+    ///
+    /// A half-open TCP session never answers, so without a bound a check on
+    /// checkout would hang `get()` until the OS gives up on the socket. Kept
+    /// short so a dead connection is replaced quickly. Must be greater than
+    /// zero.
+    pub health_check_timeout: Duration,
+
+    /// Maximum number of connections a single `get()` may discard for failing
+    /// the checkout health check.
+    ///
+    /// A failed connection is discarded and the next idle one tried; once
+    /// this many have failed, `get()` gives up with
+    /// [`PoolError::UnhealthyConnection`](crate::PoolError::UnhealthyConnection)
+    /// rather than churning through the whole pool while the server is
+    /// unreachable. When the idle set runs out first, a new connection is
+    /// opened instead.
+    pub max_checkout_health_failures: u32,
+
+    /// Maximum number of prepared statements each connection caches.
+    ///
+    /// Overrides the client config's `statement_cache_size` for every pooled
+    /// connection; once the cap is reached, the least recently used statement
+    /// is unprepared on the server. `None` keeps the client config's value.
+    pub max_prepared_statements: Option<usize>,
+
+    /// Clear a connection's prepared-statement cache when it holds more than
+    /// this many statements at return.
+    ///
+    /// The flagged connection runs `sp_unprepare` for every cached handle on
+    /// its next checkout, before it is handed out. With
+    /// [`sp_reset_connection`](Self::sp_reset_connection) enabled the reset
+    /// already releases the handles, so this mainly matters for pools that
+    /// keep sessions across checkouts. `None` (default) never clears.
+    pub prepared_statement_clear_threshold: Option<usize>,
 }
 
 impl Default for PoolConfig {
@@ -76,11 +138,17 @@ impl Default for PoolConfig {
             idle_timeout: Duration::from_secs(600),
             max_lifetime: Duration::from_secs(1800),
             test_on_checkout: true,
+            test_on_checkout_after_idle: None,
             test_on_checkin: false,
             health_check_interval: Duration::from_secs(30),
             test_while_idle: false,
+            keepalive_interval: None,
             sp_reset_connection: true,
             health_check_query: Arc::from(DEFAULT_HEALTH_CHECK_QUERY),
+            health_check_timeout: Duration::from_secs(5),
+            max_checkout_health_failures: 3,
+            max_prepared_statements: None,
+            prepared_statement_clear_threshold: None,
         }
     }
 }
@@ -134,6 +202,16 @@ impl PoolConfig {
         self
     }
 
+    /// Only test connections on checkout once they have been idle for
+    /// `threshold`.
+    ///
+    /// See [`PoolConfig::test_on_checkout_after_idle`].
+    #[must_use]
+    pub fn test_on_checkout_after_idle(mut self, threshold: Duration) -> Self {
+        self.test_on_checkout_after_idle = Some(threshold);
+        self
+    }
+
     /// Enable or disable testing connections on checkin.
     #[must_use]
     pub fn test_on_checkin(mut self, enabled: bool) -> Self {
@@ -160,6 +238,16 @@ impl PoolConfig {
         self
     }
 
+    /// Ping idle connections that have been unused for `interval`.
+    ///
+    /// Keeps long-lived idle connections from being dropped by firewall or
+    /// Azure idle cutoffs; pick an interval comfortably below the cutoff.
+    #[must_use]
+    pub fn keepalive_interval(mut self, interval: Duration) -> Self {
+        self.keepalive_interval = Some(interval);
+        self
+    }
+
     /// Enable or disable sp_reset_connection on return.
     #[must_use]
     pub fn sp_reset_connection(mut self, enabled: bool) -> Self {
@@ -198,6 +286,42 @@ impl PoolConfig {
         self
     }
 
+    /// Set the timeout for a single health check.
+    #[must_use]
+    pub fn health_check_timeout(mut self, timeout: Duration) -> Self {
+        self.health_check_timeout = timeout;
+        self
+    }
+
+    /// Set how many connections one checkout may discard for failing the
+    /// health check before it returns an error.
+    ///
+    /// See [`PoolConfig::max_checkout_health_failures`].
+    #[must_use]
+    pub fn max_checkout_health_failures(mut self, max: u32) -> Self {
+        self.max_checkout_health_failures = max;
+        self
+    }
+
+    /// Cap the number of prepared statements cached per connection.
+    ///
+    /// See [`PoolConfig::max_prepared_statements`].
+    #[must_use]
+    pub fn max_prepared_statements(mut self, max: usize) -> Self {
+        self.max_prepared_statements = Some(max);
+        self
+    }
+
+    /// Clear a returned connection's prepared statements once it caches more
+    /// than `threshold` of them.
+    ///
+    /// See [`PoolConfig::prepared_statement_clear_threshold`].
+    #[must_use]
+    pub fn prepared_statement_clear_threshold(mut self, threshold: usize) -> Self {
+        self.prepared_statement_clear_threshold = Some(threshold);
+        self
+    }
+
     /// Validate the configuration.
     pub fn validate(&self) -> Result<(), crate::error::PoolError> {
         if self.max_connections == 0 {
@@ -210,6 +334,26 @@ impl PoolConfig {
                 "min_connections cannot be greater than max_connections".into(),
             ));
         }
+        if self.max_checkout_health_failures == 0 {
+            return Err(crate::error::PoolError::Configuration(
+                "max_checkout_health_failures must be greater than 0".into(),
+            ));
+        }
+        if self.max_prepared_statements == Some(0) {
+            return Err(crate::error::PoolError::Configuration(
+                "max_prepared_statements must be greater than 0".into(),
+            ));
+        }
+        if self.health_check_timeout.is_zero() {
+            return Err(crate::error::PoolError::Configuration(
+                "health_check_timeout must be greater than 0".into(),
+            ));
+        }
+        if self.keepalive_interval == Some(Duration::ZERO) {
+            return Err(crate::error::PoolError::Configuration(
+                "keepalive_interval must be greater than 0".into(),
+            ));
+        }
         Ok(())
     }
 }
@@ -228,7 +372,13 @@ mod tests {
         assert!(config.test_on_checkout);
         assert!(!config.test_on_checkin);
         assert!(!config.test_while_idle);
+        assert!(config.keepalive_interval.is_none());
         assert_eq!(&*config.health_check_query, DEFAULT_HEALTH_CHECK_QUERY);
+        assert!(config.test_on_checkout_after_idle.is_none());
+        assert_eq!(config.health_check_timeout, Duration::from_secs(5));
+        assert_eq!(config.max_checkout_health_failures, 3);
+        assert_eq!(config.max_prepared_statements, None);
+        assert_eq!(config.prepared_statement_clear_threshold, None);
     }
 
     #[test]
@@ -301,6 +451,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_prepared_statement_limits() {
+        let config = PoolConfig::new()
+            .max_prepared_statements(64)
+            .prepared_statement_clear_threshold(32);
+        assert_eq!(config.max_prepared_statements, Some(64));
+        assert_eq!(config.prepared_statement_clear_threshold, Some(32));
+        assert!(config.validate().is_ok());
+
+        let err = PoolConfig::new()
+            .max_prepared_statements(0)
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("max_prepared_statements"));
+    }
+
+    #[test]
+    fn test_keepalive_interval_validation() {
+        assert!(
+            PoolConfig::new()
+                .keepalive_interval(Duration::from_secs(30))
+                .validate()
+                .is_ok()
+        );
+
+        // A zero period would panic tokio::time::interval in the reaper task.
+        let err = PoolConfig::new()
+            .keepalive_interval(Duration::ZERO)
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("keepalive_interval"));
+    }
+
+    #[test]
+    fn test_checkout_health_check_limits() {
+        let config = PoolConfig::new()
+            .test_on_checkout_after_idle(Duration::from_secs(5))
+            .health_check_timeout(Duration::from_millis(500))
+            .max_checkout_health_failures(2);
+        assert_eq!(
+            config.test_on_checkout_after_idle,
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(config.health_check_timeout, Duration::from_millis(500));
+        assert_eq!(config.max_checkout_health_failures, 2);
+        assert!(config.validate().is_ok());
+
+        let err = PoolConfig::new()
+            .max_checkout_health_failures(0)
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("max_checkout_health_failures"));
+
+        // Every check would time out and discard every idle connection.
+        let err = PoolConfig::new()
+            .health_check_timeout(Duration::ZERO)
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("health_check_timeout"));
+    }
+
     #[test]
     fn test_config_equal_min_max() {
         let config = PoolConfig::new().min_connections(5).max_connections(5);
@@ -181,6 +181,17 @@ impl ConnectionMetadata {
         }
     }
 
+    /// Check if a keepalive ping is due: neither used nor pinged within
+    /// `interval`.
+    #[must_use]
+    pub fn needs_keepalive(&self, interval: std::time::Duration) -> bool {
+        let last_activity = match self.last_checked_at {
+            Some(checked) => checked.max(self.last_used_at),
+            None => self.last_used_at,
+        };
+        last_activity.elapsed() > interval
+    }
+
     /// Mark the connection as checked out.
     pub fn mark_checkout(&mut self) {
         self.last_used_at = std::time::Instant::now();
@@ -255,6 +266,23 @@ mod tests {
         assert_eq!(meta.state, ConnectionState::Idle);
     }
 
+    #[test]
+    fn test_connection_metadata_needs_keepalive() {
+        let mut meta = ConnectionMetadata::new(1);
+        let past = std::time::Instant::now() - Duration::from_secs(120);
+        meta.last_used_at = past;
+        assert!(meta.needs_keepalive(Duration::from_secs(60)));
+
+        // A recent ping resets the keepalive clock even without use.
+        meta.mark_health_check();
+        assert!(!meta.needs_keepalive(Duration::from_secs(60)));
+
+        // And so does recent use.
+        meta.last_checked_at = Some(past);
+        meta.mark_checkin();
+        assert!(!meta.needs_keepalive(Duration::from_secs(60)));
+    }
+
     #[test]
     fn test_health_check_result_healthy() {
         let result = HealthCheckResult::healthy(Duration::from_millis(5));
@@ -98,6 +98,10 @@ struct PooledEntry {
     /// Set to true when `test_on_checkin` is enabled and the connection
     /// is returned to the pool. Checked (and cleared) on next checkout.
     needs_health_check: bool,
+    /// Whether the prepared-statement cache must be cleared before reuse.
+    /// Set at checkin when the cache exceeds
+    /// `prepared_statement_clear_threshold`.
+    needs_statement_cache_clear: bool,
 }
 
 struct PoolInner {
@@ -209,10 +213,13 @@ impl Pool {
     /// Internal constructor supporting an optional OTel pool name label.
     async fn new_inner(
         config: PoolConfig,
-        client_config: ClientConfig,
+        mut client_config: ClientConfig,
         pool_name: Option<String>,
     ) -> Result<Self, PoolError> {
         config.validate()?;
+        if let Some(max) = config.max_prepared_statements {
+            client_config.statement_cache_size = max;
+        }
 
         let otel_metrics = DatabaseMetrics::new(
             pool_name.as_deref(),
@@ -242,7 +249,12 @@ impl Pool {
         // a Pool dropped without close() would keep the idle connections
         // and the ticking task alive for the life of the process (#190).
         let reaper_inner = Arc::downgrade(&inner);
-        let reaper_interval = config.health_check_interval;
+        // Tick at least as often as the keepalive interval so pings are not
+        // delayed past it by a longer health check interval.
+        let reaper_interval = match config.keepalive_interval {
+            Some(keepalive) => config.health_check_interval.min(keepalive),
+            None => config.health_check_interval,
+        };
         tokio::spawn(async move {
             loop {
                 let weak = Weak::clone(&reaper_inner);
@@ -313,12 +325,14 @@ impl Pool {
 
             let id = self.next_connection_id();
             match Client::connect(self.client_config.clone()).await {
-                Ok(client) => {
+                Ok(mut client) => {
+                    client.set_pooled(true);
                     let metadata = ConnectionMetadata::new(id);
                     let entry = PooledEntry {
                         client,
                         metadata,
                         needs_health_check: false,
+                        needs_statement_cache_clear: false,
                     };
                     self.inner.idle_connections.lock().push_back(entry);
                     self.inner.total_connections.fetch_add(1, Ordering::Relaxed);
@@ -355,6 +369,8 @@ impl Pool {
     /// - Removes connections that exceed `idle_timeout` (keeping at least `min_connections`)
     /// - When `test_while_idle` is enabled, pings remaining idle connections
     ///   and discards any that fail the health check
+    /// - When `keepalive_interval` is set, pings idle connections unused for
+    ///   that long and discards any that fail
     async fn reaper_task(pool: Weak<PoolInner>, interval: Duration) {
         let mut ticker = tokio::time::interval(interval);
         ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
@@ -457,7 +473,8 @@ impl Pool {
             // Health-check idle connections if configured.
             // Connections are popped one at a time so checkout availability
             // is only reduced by one during each individual check.
-            if inner.config.test_while_idle {
+            let keepalive = inner.config.keepalive_interval;
+            if inner.config.test_while_idle || keepalive.is_some() {
                 let health_query = &*inner.config.health_check_query;
                 let check_interval = inner.config.health_check_interval;
 
@@ -474,8 +491,11 @@ impl Pool {
                     let entry = inner.idle_connections.lock().pop_front();
                     let Some(mut entry) = entry else { break };
 
-                    // Skip connections that were recently checked.
-                    if !entry.metadata.needs_health_check(check_interval) {
+                    // Skip connections that were recently checked or used.
+                    let due = (inner.config.test_while_idle
+                        && entry.metadata.needs_health_check(check_interval))
+                        || keepalive.is_some_and(|k| entry.metadata.needs_keepalive(k));
+                    if !due {
                         inner.idle_connections.lock().push_back(entry);
                         continue;
                     }
@@ -483,7 +503,7 @@ impl Pool {
                     // Run health check with a timeout so a dead connection
                     // can't block the reaper indefinitely.
                     let healthy = match timeout(
-                        Duration::from_secs(10),
+                        inner.config.health_check_timeout,
                         entry.client.query(health_query, &[]),
                     )
                     .await
@@ -555,6 +575,23 @@ impl Pool {
     /// if the pool is not at capacity. If all connections are in use and the
     /// pool is at capacity, this will wait until a connection becomes available
     /// or the timeout is reached.
+    ///
+    /// Waiters queue on a FIFO semaphore, so under contention connections are
+    /// handed out in the order `get` was called; a burst of new callers cannot
+    /// starve one that has been waiting longer.
+    ///
+    /// An idle connection that fails its checkout health check, or does not
+    /// answer within [`PoolConfig::health_check_timeout`], is discarded and
+    /// the next idle connection is tried; a new connection is opened once the
+    /// idle set is exhausted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PoolError::Timeout`] if no connection becomes available within
+    /// [`PoolConfig::connection_timeout`], [`PoolError::UnhealthyConnection`]
+    /// if [`PoolConfig::max_checkout_health_failures`] idle connections fail
+    /// their health check, and [`PoolError::PoolClosed`] if the pool is
+    /// closed.
     pub async fn get(&self) -> Result<PooledConnection, PoolError> {
         let acquisition_start = Instant::now();
 
@@ -606,88 +643,94 @@ impl Pool {
             }
         };
 
-        // Try to get an idle connection first, skipping expired ones
-        let entry = loop {
+        // Try to get an idle connection first, skipping expired ones and
+        // discarding any that fail their checkout health check.
+        let mut failed_checks = 0u32;
+        let reused = loop {
             let candidate = {
                 let mut idle = self.inner.idle_connections.lock();
                 idle.pop_front()
             };
+            let Some(mut entry) = candidate else {
+                break None;
+            };
 
-            match candidate {
-                Some(entry) => {
-                    // Check if connection exceeds max_lifetime
-                    if entry.metadata.is_expired(self.config.max_lifetime) {
-                        tracing::debug!(
-                            connection_id = entry.metadata.id,
-                            "discarding expired connection on checkout"
-                        );
-                        {
-                            let mut metrics = self.inner.metrics.lock();
-                            metrics.connections_closed += 1;
-                            metrics.connections_lifetime_expired += 1;
-                        }
-                        self.inner.otel_metrics.record_connection_closed();
-                        // Don't return permit - we'll try to get another connection
-                        continue;
-                    }
-                    break Some(entry);
+            // Check if connection exceeds max_lifetime
+            if entry.metadata.is_expired(self.config.max_lifetime) {
+                tracing::debug!(
+                    connection_id = entry.metadata.id,
+                    "discarding expired connection on checkout"
+                );
+                {
+                    let mut metrics = self.inner.metrics.lock();
+                    metrics.connections_closed += 1;
+                    metrics.connections_lifetime_expired += 1;
                 }
-                None => break None,
+                self.inner.otel_metrics.record_connection_closed();
+                // Don't return permit - we'll try to get another connection
+                continue;
             }
-        };
-
-        let (client, mut metadata) = match entry {
-            Some(mut entry) => {
-                tracing::trace!(connection_id = entry.metadata.id, "reusing idle connection");
-
-                // Perform health check if configured (test_on_checkout) or if the
-                // connection was marked for check at checkin time (test_on_checkin).
-                if self.config.test_on_checkout || entry.needs_health_check {
-                    if !self
-                        .health_check(&mut entry.client, entry.metadata.id)
-                        .await
-                    {
-                        tracing::debug!(
-                            connection_id = entry.metadata.id,
-                            "discarding unhealthy connection, will create new"
-                        );
-                        self.inner.metrics.lock().connections_closed += 1;
-                        self.inner.otel_metrics.record_connection_closed();
 
-                        // Connection is unhealthy, create a new one instead
-                        let id = self.next_connection_id();
-                        tracing::debug!(
-                            connection_id = id,
-                            "creating new connection after health check failure"
-                        );
+            tracing::trace!(connection_id = entry.metadata.id, "reusing idle connection");
+
+            // Release prepared handles if the connection was flagged at
+            // checkin, then perform a health check if configured
+            // (test_on_checkout, limited to connections idle longer than
+            // test_on_checkout_after_idle) or if the connection was marked for
+            // check at checkin time (test_on_checkin).
+            let cache_cleared = !entry.needs_statement_cache_clear
+                || self
+                    .clear_statement_cache(&mut entry.client, entry.metadata.id)
+                    .await;
+            let idle_long_enough = self
+                .config
+                .test_on_checkout_after_idle
+                .is_none_or(|threshold| entry.metadata.last_used_at.elapsed() >= threshold);
+            let needs_check =
+                (self.config.test_on_checkout && idle_long_enough) || entry.needs_health_check;
+            if !cache_cleared {
+                tracing::debug!(
+                    connection_id = entry.metadata.id,
+                    "discarding connection whose statement cache could not be cleared"
+                );
+            } else if !needs_check
+                || self
+                    .health_check(&mut entry.client, entry.metadata.id)
+                    .await
+            {
+                break Some((entry.client, entry.metadata));
+            } else {
+                tracing::debug!(
+                    connection_id = entry.metadata.id,
+                    "discarding unhealthy connection on checkout"
+                );
+            }
+            self.inner.metrics.lock().connections_closed += 1;
+            self.inner.otel_metrics.record_connection_closed();
 
-                        match Client::connect(self.client_config.clone()).await {
-                            Ok(client) => {
-                                self.inner.total_connections.fetch_add(1, Ordering::Relaxed);
-                                self.inner.metrics.lock().connections_created += 1;
-                                self.inner.otel_metrics.record_connection_created();
-                                (client, ConnectionMetadata::new(id))
-                            }
-                            Err(e) => {
-                                drop(permit);
-                                self.inner.metrics.lock().checkouts_failed += 1;
-                                return Err(PoolError::Connection(e));
-                            }
-                        }
-                    } else {
-                        (entry.client, entry.metadata)
-                    }
-                } else {
-                    (entry.client, entry.metadata)
-                }
+            // Try the next idle connection, unless so many have failed that
+            // the server itself is likely unreachable.
+            failed_checks += 1;
+            if failed_checks >= self.config.max_checkout_health_failures {
+                drop(permit);
+                self.inner.metrics.lock().checkouts_failed += 1;
+                self.inner.record_pool_status();
+                return Err(PoolError::UnhealthyConnection(format!(
+                    "{failed_checks} connections failed the checkout health check"
+                )));
             }
+        };
+
+        let (client, mut metadata) = match reused {
+            Some(connection) => connection,
             None => {
                 // No idle connection, create a new one
                 let id = self.next_connection_id();
                 tracing::debug!(connection_id = id, "creating new connection");
 
                 match Client::connect(self.client_config.clone()).await {
-                    Ok(client) => {
+                    Ok(mut client) => {
+                        client.set_pooled(true);
                         self.inner.total_connections.fetch_add(1, Ordering::Relaxed);
                         self.inner.metrics.lock().connections_created += 1;
                         self.inner.otel_metrics.record_connection_created();
@@ -742,7 +785,8 @@ impl Pool {
         // Try to acquire a permit without waiting
         let permit = match self.inner.semaphore.clone().try_acquire_owned() {
             Ok(permit) => permit,
-            Err(_) => {
+            Err(tokio::sync::TryAcquireError::Closed) => return Err(PoolError::PoolClosed),
+            Err(tokio::sync::TryAcquireError::NoPermits) => {
                 // No permits available (pool at capacity with all connections in use)
                 return Ok(None);
             }
@@ -843,7 +887,10 @@ impl Pool {
     /// closed flag and exits on its next tick, so it may linger briefly).
     /// Connections currently checked out are closed when their handles are
     /// dropped rather than returned to the pool, so the pool holds no
-    /// connections once all outstanding handles are gone. Idempotent.
+    /// connections once all outstanding handles are gone. Tasks waiting in
+    /// [`Pool::get`] for a free slot are woken immediately with
+    /// [`PoolError::PoolClosed`] instead of running out their acquire
+    /// timeout. Idempotent.
     pub async fn close(&self) {
         // Set the closed flag *and* drain the idle queue while holding the
         // idle lock, so this is atomic with respect to `Drop for
@@ -856,6 +903,10 @@ impl Pool {
             self.inner.closed.store(true, Ordering::Release);
             std::mem::take(&mut *idle)
         };
+        // Wake every task queued on a permit; `get()` maps the closed
+        // semaphore to `PoolClosed`. Permits already handed out are
+        // unaffected and are released as their connections are dropped.
+        self.inner.semaphore.close();
         let closed = drained.len() as u64;
         drop(drained); // closes the TCP sockets
 
@@ -888,6 +939,31 @@ impl Pool {
             .fetch_add(1, Ordering::Relaxed)
     }
 
+    /// Unprepare every statement cached on a connection before reuse.
+    ///
+    /// Returns `false` if the connection failed while doing so and must be
+    /// discarded.
+    async fn clear_statement_cache(&self, client: &mut Client<Ready>, connection_id: u64) -> bool {
+        match client.clear_statement_cache().await {
+            Ok(cleared) => {
+                tracing::debug!(
+                    connection_id = connection_id,
+                    cleared = cleared,
+                    "cleared prepared-statement cache"
+                );
+                true
+            }
+            Err(e) => {
+                tracing::debug!(
+                    connection_id = connection_id,
+                    error = %e,
+                    "clearing prepared-statement cache failed"
+                );
+                false
+            }
+        }
+    }
+
     /// Perform a health check on a connection.
     ///
     /// Returns `true` if the connection is healthy, `false` otherwise.
@@ -899,7 +975,15 @@ impl Pool {
             "performing health check"
         );
 
-        match client.query(health_query, &[]).await {
+        // Bounded so a half-open socket that never answers can't hang the
+        // caller.
+        match timeout(
+            self.config.health_check_timeout,
+            client.query(health_query, &[]),
+        )
+        .await
+        .unwrap_or(Err(mssql_client::Error::CommandTimeout))
+        {
             Ok(rows) => {
                 // Consume the result set
                 for _ in rows {}
@@ -1010,6 +1094,13 @@ impl PoolBuilder {
         self
     }
 
+    /// Ping idle connections that have been unused for `interval`.
+    #[must_use]
+    pub fn keepalive_interval(mut self, interval: std::time::Duration) -> Self {
+        self.pool_config.keepalive_interval = Some(interval);
+        self
+    }
+
     /// Enable or disable `sp_reset_connection` on return.
     #[must_use]
     pub fn sp_reset_connection(mut self, enabled: bool) -> Self {
@@ -1101,9 +1192,9 @@ pub struct PoolMetrics {
     pub resets_performed: u64,
     /// Connection resets that failed.
     ///
-    /// Currently always 0 because the RESETCONNECTION flag is set on the TDS
-    /// packet header and cannot fail independently. Reserved for future use
-    /// if an explicit `sp_reset_connection` path is added.
+    /// Only explicit [`PooledConnection::reset`] calls can fail here: the
+    /// lazy reset on return sets the RESETCONNECTION flag on the next
+    /// request's packet header and cannot fail independently.
     pub resets_failed: u64,
     /// Connections closed due to idle timeout expiration.
     pub connections_idle_expired: u64,
@@ -1193,7 +1284,9 @@ impl PooledConnection {
     /// Returns the underlying client. The connection will not be returned
     /// to the pool when this `PooledConnection` is dropped.
     pub fn detach(mut self) -> Option<Client<Ready>> {
-        self.client.take()
+        let mut client = self.client.take()?;
+        client.set_pooled(false);
+        Some(client)
     }
 
     /// Execute a query on this pooled connection.
@@ -1226,6 +1319,47 @@ impl PooledConnection {
             .map_err(PoolError::Connection)
     }
 
+    /// Reset server-side session state now, rather than on the next request.
+    ///
+    /// Sends a request with the RESETCONNECTION flag (the TDS-level
+    /// equivalent of `sp_reset_connection`) and waits for the server's DONE
+    /// token, so when this returns `Ok` the reset has been confirmed and the
+    /// connection is clean. Returned connections are otherwise reset lazily
+    /// with the first request of their next checkout
+    /// ([`PoolConfig::sp_reset_connection`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PoolError`] if the connection was detached or the reset
+    /// fails. A failed reset leaves the session in an unknown state, so the
+    /// connection is dropped rather than returned to the pool, and counted in
+    /// [`PoolMetrics::resets_failed`].
+    pub async fn reset(&mut self) -> Result<(), PoolError> {
+        let client = self.client.as_mut().ok_or(PoolError::ConnectionCreation(
+            "connection detached or invalid".to_string(),
+        ))?;
+        match client.reset_connection().await {
+            Ok(was_pending) => {
+                // A pending reset was already counted when it was marked at
+                // check-in.
+                if !was_pending {
+                    self.pool.metrics.lock().resets_performed += 1;
+                }
+                Ok(())
+            }
+            Err(err) => {
+                tracing::warn!(
+                    connection_id = self.metadata.id,
+                    error = %err,
+                    "connection reset failed; dropping connection rather than returning it to the pool"
+                );
+                self.pool.metrics.lock().resets_failed += 1;
+                self.client = None;
+                Err(PoolError::Connection(err))
+            }
+        }
+    }
+
     /// Run a closure inside a database transaction, keeping the connection in
     /// the pool.
     ///
@@ -1393,10 +1527,20 @@ impl Drop for PooledConnection {
                 );
             }
 
+            // Flag connections whose prepared-statement cache grew past the
+            // threshold; the `sp_unprepare` calls run on the next checkout
+            // since Drop cannot await.
+            let needs_statement_cache_clear = self
+                .pool
+                .config
+                .prepared_statement_clear_threshold
+                .is_some_and(|threshold| client.statement_cache_stats().entries > threshold);
+
             let entry = PooledEntry {
                 client,
                 metadata: self.metadata.clone(),
                 needs_health_check,
+                needs_statement_cache_clear,
             };
 
             // Re-check the closed flag while holding the idle lock and push
@@ -1462,6 +1606,43 @@ mod tests {
         );
     }
 
+    /// `close()` must wake tasks blocked in `get()` with `PoolClosed`
+    /// rather than leaving them to hit the acquire timeout.
+    #[tokio::test]
+    async fn test_close_wakes_waiting_get() {
+        let pool_config = PoolConfig::new()
+            .min_connections(0)
+            .max_connections(1)
+            .connection_timeout(Duration::from_secs(60));
+        let client_config = ClientConfig::from_connection_string(
+            "Server=localhost;User Id=sa;Password=unused;TrustServerCertificate=true",
+        )
+        .unwrap();
+        let pool = Arc::new(Pool::new(pool_config, client_config).await.unwrap());
+
+        // Hold the only slot so the next `get()` has to queue.
+        let held = Arc::clone(&pool.inner.semaphore)
+            .acquire_owned()
+            .await
+            .unwrap();
+        let waiter = tokio::spawn({
+            let pool = Arc::clone(&pool);
+            async move { pool.get().await }
+        });
+        while pool.inner.wait_queue_depth.load(Ordering::Relaxed) == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        pool.close().await;
+        let result = timeout(Duration::from_secs(5), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(result, Err(PoolError::PoolClosed)));
+        assert!(matches!(pool.try_get(), Err(PoolError::PoolClosed)));
+        drop(held);
+    }
+
     #[test]
     fn test_pool_status_utilization() {
         let status = PoolStatus {
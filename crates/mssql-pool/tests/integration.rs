@@ -1366,3 +1366,49 @@ async fn test_pool_with_transaction_keeps_connection_poolable() {
 
     pool.close().await;
 }
+
+/// `max_prepared_statements` caps each connection's prepared-statement cache,
+/// and `prepared_statement_clear_threshold` unprepares an oversized cache
+/// before the connection is handed out again.
+#[tokio::test]
+#[ignore = "Requires SQL Server"]
+async fn test_pool_bounds_prepared_statement_cache() {
+    let client_config = get_test_config()
+        .expect("SQL Server config required")
+        .with_statement_cache(true);
+
+    let pool_config = mssql_driver_pool::PoolConfig::new()
+        .max_connections(1)
+        .sp_reset_connection(false)
+        .max_prepared_statements(2)
+        .prepared_statement_clear_threshold(1);
+
+    let pool = Pool::builder()
+        .client_config(client_config)
+        .pool_config(pool_config)
+        .build()
+        .await
+        .expect("Failed to create pool");
+
+    let mut conn = pool.get().await.expect("get connection");
+    for sql in ["SELECT @p1 AS a", "SELECT @p1 AS b", "SELECT @p1 AS c"] {
+        let rows = conn.query(sql, &[&1i32]).await.expect("query");
+        for _ in rows {}
+    }
+    let entries = conn.client().unwrap().statement_cache_stats().entries;
+    assert_eq!(
+        entries, 2,
+        "cache must be capped at max_prepared_statements"
+    );
+    drop(conn);
+
+    let conn = pool.get().await.expect("reuse connection");
+    assert_eq!(
+        conn.client().unwrap().statement_cache_stats().entries,
+        0,
+        "cache above the threshold must be cleared before reuse"
+    );
+    drop(conn);
+
+    pool.close().await;
+}
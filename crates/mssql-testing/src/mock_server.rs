@@ -7,6 +7,8 @@
 //!
 //! - Simulates TDS protocol handshake (prelogin, login)
 //! - Configurable responses for SQL queries
+//! - Scripted response sequences and exact ERROR/DONE tokens for retry and
+//!   error-handling tests
 //! - Support for multiple concurrent connections
 //! - Recorded packet replay for regression testing
 //!
@@ -35,8 +37,8 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 use tds_protocol::types::TypeId;
 use tds_protocol::{
-    DoneStatus, EnvChangeType, PACKET_HEADER_SIZE, PacketHeader, PacketStatus, PacketType,
-    TokenType,
+    Done, DoneStatus, EnvChangeType, PACKET_HEADER_SIZE, PacketHeader, PacketStatus, PacketType,
+    ServerError, TokenType,
 };
 use thiserror::Error;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
@@ -93,6 +95,13 @@ pub enum MockResponse {
     /// Return raw pre-encoded TDS tokens.
     Raw(Bytes),
 
+    /// Send raw pre-encoded TDS tokens without ending the message.
+    ///
+    /// The packets go out without END_OF_MESSAGE, so the response stays open
+    /// like a long-running query's, until the client cancels it with an
+    /// Attention (answered with DONE_ATTN).
+    Unfinished(Bytes),
+
     /// Execute a custom handler.
     Custom(Arc<dyn Fn(&str) -> MockResponse + Send + Sync>),
 }
@@ -118,6 +127,7 @@ impl fmt::Debug for MockResponse {
                 .finish(),
             Self::RowsAffected(n) => f.debug_tuple("RowsAffected").field(n).finish(),
             Self::Raw(data) => f.debug_tuple("Raw").field(&data.len()).finish(),
+            Self::Unfinished(data) => f.debug_tuple("Unfinished").field(&data.len()).finish(),
             Self::Custom(_) => f.debug_tuple("Custom").field(&"<fn>").finish(),
         }
     }
@@ -157,6 +167,60 @@ impl MockResponse {
     pub fn rows(columns: Vec<MockColumn>, rows: Vec<Vec<ScalarValue>>) -> Self {
         Self::Rows { columns, rows }
     }
+
+    /// Create a response that sends `columns` and `rows` but never finishes.
+    ///
+    /// No DONE follows the rows, so the statement looks like it is still
+    /// running: see [`MockResponse::Unfinished`].
+    pub fn unfinished_rows(columns: Vec<MockColumn>, rows: Vec<Vec<ScalarValue>>) -> Self {
+        let mut buf = BytesMut::new();
+        encode_colmetadata(&mut buf, &columns);
+        for row in &rows {
+            encode_row(&mut buf, &columns, row);
+        }
+        Self::Unfinished(buf.freeze())
+    }
+
+    /// Create an error response carrying every field of `error`.
+    ///
+    /// Unlike [`MockResponse::error`], the state, server, procedure and line
+    /// are sent as given. The ERROR token is followed by a DONE with the
+    /// ERROR bit set, as SQL Server ends a failed batch.
+    pub fn server_error(error: &ServerError) -> Self {
+        let mut buf = BytesMut::new();
+        encode_server_error(&mut buf, error);
+        let mut status = DoneStatus::default();
+        status.error = true;
+        encode_done_status(&mut buf, status, 0);
+        Self::Raw(buf.freeze())
+    }
+
+    /// Create a response that is a single DONE token with exactly these
+    /// status bits (COUNT, ERROR, MORE, ...) and row count.
+    pub fn done(status: DoneStatus, row_count: u64) -> Self {
+        let mut buf = BytesMut::new();
+        encode_done_status(&mut buf, status, row_count);
+        Self::Raw(buf.freeze())
+    }
+
+    /// Answer successive SQL batches with `responses` in order, repeating the
+    /// last one once the script runs out.
+    ///
+    /// The position is shared by every connection to the server, so "fail
+    /// twice, then succeed" holds even when the client reconnects between
+    /// attempts. Steps may themselves be [`MockResponse::Custom`] handlers.
+    pub fn sequence(responses: impl IntoIterator<Item = MockResponse>) -> Self {
+        let responses: Vec<MockResponse> = responses.into_iter().collect();
+        let next = std::sync::atomic::AtomicUsize::new(0);
+        Self::Custom(Arc::new(move |sql| {
+            let index = next.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            match responses.get(index).or_else(|| responses.last()) {
+                Some(Self::Custom(handler)) => handler(sql),
+                Some(response) => response.clone(),
+                None => Self::empty(),
+            }
+        }))
+    }
 }
 
 /// Scalar value for mock responses.
@@ -294,6 +358,17 @@ impl MockColumn {
         Self::new(name, TypeId::NVarChar).with_max_length(max_len * 2)
     }
 
+    /// Create a VARBINARY(MAX) column. Its values are sent in PLP format.
+    pub fn varbinary_max(name: impl Into<String>) -> Self {
+        Self::new(name, TypeId::BigVarBinary).with_max_length(MAX_TYPE_LENGTH)
+    }
+
+    /// Whether this is a MAX column, whose values are sent in PLP format.
+    fn is_max(&self) -> bool {
+        self.max_length == Some(MAX_TYPE_LENGTH)
+            && matches!(self.type_id, TypeId::NVarChar | TypeId::BigVarBinary)
+    }
+
     /// Set the maximum length.
     pub fn with_max_length(mut self, len: u32) -> Self {
         self.max_length = Some(len);
@@ -329,11 +404,20 @@ pub struct MockServerConfig {
     /// Route every login back to this server's own address (resolved after
     /// bind). Simulates a routing loop.
     login_routing_to_self: bool,
+    /// Acknowledge the UTF-8 support feature extension in the login
+    /// response (FEATUREEXTACK), as SQL Server 2019+ does.
+    utf8_support: bool,
     /// Socket address to bind (default `127.0.0.1:0`).
     bind_addr: String,
     /// Drop the first N accepted connections before any handshake, to
     /// simulate transient connection failures (for connect-retry tests).
     fail_first_n: usize,
+    /// Packet size announced in the login PacketSize ENVCHANGE.
+    packet_size: u16,
+    /// SPID stamped in the header of every packet sent after PreLogin.
+    spid: u16,
+    /// When set, reject every LOGIN7 with this ERROR token.
+    login_error: Option<ServerError>,
 }
 
 /// Builder for `MockTdsServer`.
@@ -354,8 +438,12 @@ impl MockServerBuilder {
                 tls_acceptor: None,
                 login_routing: None,
                 login_routing_to_self: false,
+                utf8_support: false,
                 bind_addr: "127.0.0.1:0".to_string(),
                 fail_first_n: 0,
+                packet_size: MOCK_PACKET_SIZE as u16,
+                spid: 0,
+                login_error: None,
             },
         }
     }
@@ -391,6 +479,13 @@ impl MockServerBuilder {
         self
     }
 
+    /// Acknowledge the UTF-8 support feature extension at login, simulating
+    /// SQL Server 2019 or later.
+    pub fn with_utf8_support(mut self) -> Self {
+        self.config.utf8_support = true;
+        self
+    }
+
     /// Respond to every LOGIN7 with a routing token pointing back at this
     /// server's own address, simulating an endless redirect loop.
     pub fn with_login_routing_to_self(mut self) -> Self {
@@ -416,6 +511,39 @@ impl MockServerBuilder {
         self
     }
 
+    /// Announce `size` as the negotiated packet size in the login
+    /// PacketSize ENVCHANGE instead of the default 4096, regardless of what
+    /// the client requested. Pair with
+    /// [`largest_request_packet`](MockTdsServer::largest_request_packet) to
+    /// check the client splits its requests at the granted size.
+    pub fn with_packet_size(mut self, size: u16) -> Self {
+        self.config.packet_size = size;
+        self
+    }
+
+    /// Report `version` in LOGINACK instead of TDS 7.4, as an older server
+    /// negotiating the client down would.
+    pub fn with_tds_version(mut self, version: tds_protocol::TdsVersion) -> Self {
+        self.config.tds_version = version.raw();
+        self
+    }
+
+    /// Reject every login with `error` followed by a DONE with the ERROR bit
+    /// set, as SQL Server does for a bad password (18456) or an unopenable
+    /// database (4060).
+    pub fn with_login_error(mut self, error: ServerError) -> Self {
+        self.config.login_error = Some(error);
+        self
+    }
+
+    /// Stamp `spid` in the header of every packet the server sends from the
+    /// login response on, as SQL Server does with the session's SPID. The
+    /// default leaves the field zero.
+    pub fn with_spid(mut self, spid: u16) -> Self {
+        self.config.spid = spid;
+        self
+    }
+
     /// Enable TLS with an auto-generated self-signed certificate.
     ///
     /// When enabled, the mock server will advertise `ENCRYPT_ON` in PreLogin
@@ -461,6 +589,29 @@ pub struct MockTdsServer {
     connection_count: Arc<Mutex<usize>>,
     /// Cumulative number of connections ever accepted (never decremented).
     total_connections: Arc<Mutex<usize>>,
+    /// What the client sent, across all connections.
+    log: Arc<SessionLog>,
+}
+
+/// Client traffic recorded by every session of a [`MockTdsServer`].
+#[derive(Default)]
+struct SessionLog {
+    /// Number of requests received with the RESETCONNECTION status bit set.
+    reset_requests: Mutex<usize>,
+    /// Payloads of every LOGIN7 request received, in arrival order.
+    login_requests: Mutex<Vec<Bytes>>,
+    /// Number of Attention packets received.
+    attention_requests: Mutex<usize>,
+    /// Largest packet (header included) of any request after login.
+    largest_request_packet: Mutex<usize>,
+    /// Payloads of every RPC request received, in arrival order.
+    rpc_requests: Mutex<Vec<Bytes>>,
+    /// Text of every SQL batch received, in arrival order.
+    sql_batches: Mutex<Vec<String>>,
+    /// Payloads of every SQL batch received, in arrival order.
+    sql_batch_requests: Mutex<Vec<Bytes>>,
+    /// Payloads of every BulkLoad message received, in arrival order.
+    bulk_load_requests: Mutex<Vec<Bytes>>,
 }
 
 impl MockTdsServer {
@@ -481,6 +632,7 @@ impl MockTdsServer {
         let config = Arc::new(config);
         let connection_count = Arc::new(Mutex::new(0usize));
         let total_connections = Arc::new(Mutex::new(0usize));
+        let log = Arc::new(SessionLog::default());
 
         let server = Self {
             addr,
@@ -488,6 +640,7 @@ impl MockTdsServer {
             config: config.clone(),
             connection_count: connection_count.clone(),
             total_connections: total_connections.clone(),
+            log: log.clone(),
         };
 
         // Spawn the accept loop
@@ -512,12 +665,13 @@ impl MockTdsServer {
                                 } else {
                                     let config = config.clone();
                                     let count = connection_count.clone();
+                                    let log = log.clone();
                                     tokio::spawn(async move {
                                         {
                                             let mut c = count.lock().await;
                                             *c += 1;
                                         }
-                                        if let Err(e) = handle_connection(stream, config).await {
+                                        if let Err(e) = handle_connection(stream, config, log).await {
                                             tracing::debug!("Connection error: {}", e);
                                         }
                                         {
@@ -577,6 +731,89 @@ impl MockTdsServer {
         *self.total_connections.lock().await
     }
 
+    /// Get the number of requests received with the RESETCONNECTION bit set
+    /// in the TDS packet header, across all connections.
+    ///
+    /// Requests the client abandoned with IGNORE_EVENT are not counted; the
+    /// server discards their reset along with the rest of the message.
+    pub async fn reset_request_count(&self) -> usize {
+        *self.log.reset_requests.lock().await
+    }
+
+    /// Get the payloads of every LOGIN7 request received, in arrival order.
+    ///
+    /// Lets tests inspect what the client put in the login packet (type
+    /// flags, option flags, feature extensions).
+    pub async fn login_requests(&self) -> Vec<Bytes> {
+        self.log.login_requests.lock().await.clone()
+    }
+
+    /// Get the access tokens sent in the FEDAUTH feature extension of every
+    /// LOGIN7 request received, in arrival order.
+    ///
+    /// Only SecurityToken-workflow logins carry a token; each is decoded from
+    /// its UTF-16LE wire form.
+    pub async fn fed_auth_tokens(&self) -> Vec<String> {
+        let logins = self.log.login_requests.lock().await;
+        logins
+            .iter()
+            .flat_map(|login| login_features(login))
+            .filter(|(id, _)| *id == FEATURE_FED_AUTH)
+            .filter_map(|(_, data)| {
+                // Options(1), then the DWORD-length-prefixed token.
+                let token = data.get(5..)?;
+                let units: Vec<u16> = token
+                    .chunks_exact(2)
+                    .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                    .collect();
+                String::from_utf16(&units).ok()
+            })
+            .collect()
+    }
+
+    /// Get the number of Attention (cancel) packets received, across all
+    /// connections. Each one is answered with a DONE carrying the ATTN flag.
+    pub async fn attention_count(&self) -> usize {
+        *self.log.attention_requests.lock().await
+    }
+
+    /// Get the size in bytes, header included, of the largest packet the
+    /// client sent after login, across all connections.
+    ///
+    /// Lets tests check that requests are split at the packet size the
+    /// server announced (see [`MockServerBuilder::with_packet_size`]).
+    pub async fn largest_request_packet(&self) -> usize {
+        *self.log.largest_request_packet.lock().await
+    }
+
+    /// Get the payloads of every RPC request received, in arrival order.
+    ///
+    /// Requests the client abandoned with IGNORE_EVENT are not included;
+    /// like SQL Server, the mock discards them without a response.
+    pub async fn rpc_requests(&self) -> Vec<Bytes> {
+        self.log.rpc_requests.lock().await.clone()
+    }
+
+    /// Get the text of every SQL batch received after login, in arrival
+    /// order, across all connections.
+    pub async fn sql_batches(&self) -> Vec<String> {
+        self.log.sql_batches.lock().await.clone()
+    }
+
+    /// Get the payloads of every SQL batch received after login, ALL_HEADERS
+    /// included, in arrival order, across all connections.
+    pub async fn sql_batch_requests(&self) -> Vec<Bytes> {
+        self.log.sql_batch_requests.lock().await.clone()
+    }
+
+    /// Get the payloads of every BulkLoad message received, in arrival order.
+    ///
+    /// Each one is answered with the row count of its closing DONE token, as
+    /// if every row had been inserted.
+    pub async fn bulk_load_requests(&self) -> Vec<Bytes> {
+        self.log.bulk_load_requests.lock().await.clone()
+    }
+
     /// Stop the server.
     pub fn stop(&self) {
         let _ = self.shutdown_tx.send(());
@@ -589,8 +826,39 @@ impl Drop for MockTdsServer {
     }
 }
 
+/// Connection string for a client of a mock server listening on `port`.
+///
+/// Logs in as `sa` without TLS and with connect retries disabled. Append
+/// `;Key=Value` pairs to exercise other options.
+pub fn connection_string(port: u16) -> String {
+    format!("Server=127.0.0.1,{port};User Id=sa;Password=test;Encrypt=no_tls;ConnectRetryCount=0")
+}
+
+/// Client configuration for a mock server listening on `port`.
+///
+/// Parsed from [`connection_string`].
+pub fn client_config(port: u16) -> mssql_client::Config {
+    mssql_client::Config::from_connection_string(&connection_string(port))
+        .expect("mock connection string parses")
+}
+
+/// Connect a client to `server` with [`client_config`].
+///
+/// # Panics
+///
+/// Panics if the connection fails.
+pub async fn connect(server: &MockTdsServer) -> mssql_client::Client<mssql_client::Ready> {
+    mssql_client::Client::connect(client_config(server.port()))
+        .await
+        .expect("connect to mock server")
+}
+
 /// Handle a single client connection.
-async fn handle_connection(mut stream: TcpStream, config: Arc<MockServerConfig>) -> Result<()> {
+async fn handle_connection(
+    mut stream: TcpStream,
+    config: Arc<MockServerConfig>,
+    log: Arc<SessionLog>,
+) -> Result<()> {
     // Step 1: Handle PRELOGIN over raw TCP
     let prelogin_request = read_packet(&mut stream).await?;
     if prelogin_request.packet_type != PacketType::PreLogin {
@@ -627,7 +895,7 @@ async fn handle_connection(mut stream: TcpStream, config: Arc<MockServerConfig>)
             .map_err(|e| MockServerError::Protocol(format!("TLS handshake failed: {e}")))?;
 
         // Continue login and query processing over TLS
-        let session_result = handle_session(&mut tls_stream, &config).await;
+        let session_result = handle_session(&mut tls_stream, &config, &log).await;
 
         // CRITICAL: explicitly shut down the TLS stream so rustls sends a
         // close_notify alert to the peer before the TCP socket closes.
@@ -653,7 +921,7 @@ async fn handle_connection(mut stream: TcpStream, config: Arc<MockServerConfig>)
     } else {
         // Continue over plaintext TCP. No TLS close_notify dance needed;
         // the TCP half-close is unambiguous.
-        handle_session(&mut stream, &config).await
+        handle_session(&mut stream, &config, &log).await
     }
 }
 
@@ -661,7 +929,11 @@ async fn handle_connection(mut stream: TcpStream, config: Arc<MockServerConfig>)
 ///
 /// This is generic over the stream type so it works with both plaintext
 /// `TcpStream` and encrypted `TlsStream`.
-async fn handle_session<S>(stream: &mut S, config: &MockServerConfig) -> Result<()>
+async fn handle_session<S>(
+    stream: &mut S,
+    config: &MockServerConfig,
+    log: &SessionLog,
+) -> Result<()>
 where
     S: AsyncRead + AsyncWrite + Unpin,
 {
@@ -673,7 +945,11 @@ where
             login_request.packet_type
         )));
     }
-    send_login_response(stream, config).await?;
+    log.login_requests
+        .lock()
+        .await
+        .push(login_request.payload.clone());
+    send_login_response(stream, config, &login_request.payload).await?;
 
     // Handle SQL batches and RPC requests
     loop {
@@ -685,21 +961,49 @@ where
             Err(e) => return Err(e),
         };
 
+        {
+            let mut largest = log.largest_request_packet.lock().await;
+            *largest = (*largest).max(packet.largest_packet);
+        }
+        if packet.ignored {
+            // The client gave up on this message part way through; the server
+            // discards it, RESETCONNECTION included, and sends nothing back.
+            continue;
+        }
+        if packet.status.contains(PacketStatus::RESET_CONNECTION) {
+            *log.reset_requests.lock().await += 1;
+        }
+
         match packet.packet_type {
             PacketType::SqlBatch => {
                 let sql = decode_sql_batch(&packet.payload)?;
+                log.sql_batches.lock().await.push(sql.clone());
+                log.sql_batch_requests
+                    .lock()
+                    .await
+                    .push(packet.payload.clone());
                 let response = find_response(&sql, config);
-                send_query_response(stream, response).await?;
+                send_query_response(stream, response, config.spid).await?;
             }
             PacketType::Rpc => {
+                log.rpc_requests.lock().await.push(packet.payload.clone());
                 let response = config
                     .default_response
                     .clone()
                     .unwrap_or(MockResponse::empty());
-                send_query_response(stream, response).await?;
+                send_query_response(stream, response, config.spid).await?;
+            }
+            PacketType::BulkLoad => {
+                log.bulk_load_requests
+                    .lock()
+                    .await
+                    .push(packet.payload.clone());
+                let rows = bulk_load_row_count(&packet.payload)?;
+                send_query_response(stream, MockResponse::RowsAffected(rows), config.spid).await?;
             }
             PacketType::Attention => {
-                send_attention_ack(stream).await?;
+                *log.attention_requests.lock().await += 1;
+                send_attention_ack(stream, config.spid).await?;
             }
             _ => {
                 tracing::debug!("Unexpected packet type: {:?}", packet.packet_type);
@@ -710,6 +1014,21 @@ where
     Ok(())
 }
 
+/// Read the row count from the DONE token that ends a BulkLoad message.
+fn bulk_load_row_count(payload: &[u8]) -> Result<u64> {
+    // DONE: token (1), status (2), current command (2), row count (8).
+    const DONE_LEN: usize = 13;
+    let done = payload
+        .len()
+        .checked_sub(DONE_LEN)
+        .map(|start| &payload[start..])
+        .filter(|done| done[0] == TokenType::Done as u8)
+        .ok_or_else(|| MockServerError::Protocol("BulkLoad does not end with DONE".into()))?;
+    let mut count = [0u8; 8];
+    count.copy_from_slice(&done[5..]);
+    Ok(u64::from_le_bytes(count))
+}
+
 /// Parse the ENCRYPTION option from a PreLogin payload.
 ///
 /// Returns the raw encryption byte (0x00=Off, 0x01=On, 0x02=NotSupported, 0x03=Required).
@@ -735,7 +1054,12 @@ fn parse_prelogin_encryption(payload: &[u8]) -> u8 {
 /// Parsed TDS packet.
 struct Packet {
     packet_type: PacketType,
+    status: PacketStatus,
     payload: Bytes,
+    /// Size of the largest packet of the message, header included.
+    largest_packet: usize,
+    /// The last packet carried IGNORE_EVENT, so the message is discarded.
+    ignored: bool,
 }
 
 /// Read a complete TDS packet from the stream.
@@ -748,6 +1072,8 @@ async fn read_packet<S: AsyncRead + Unpin>(stream: &mut S) -> Result<Packet> {
         PacketHeader::decode(&mut cursor).map_err(|e| MockServerError::Protocol(e.to_string()))?;
 
     let payload_len = header.payload_length();
+    let mut largest_packet = PACKET_HEADER_SIZE + payload_len;
+    let mut last_status = header.status;
     let mut payload = vec![0u8; payload_len];
     if payload_len > 0 {
         stream.read_exact(&mut payload).await?;
@@ -772,6 +1098,8 @@ async fn read_packet<S: AsyncRead + Unpin>(stream: &mut S) -> Result<Packet> {
             }
 
             full_payload.extend_from_slice(&next_payload);
+            largest_packet = largest_packet.max(PACKET_HEADER_SIZE + next_payload_len);
+            last_status = next_header.status;
 
             if next_header.is_end_of_message() {
                 break;
@@ -781,29 +1109,71 @@ async fn read_packet<S: AsyncRead + Unpin>(stream: &mut S) -> Result<Packet> {
 
     Ok(Packet {
         packet_type: header.packet_type,
+        status: header.status,
         payload: full_payload.freeze(),
+        largest_packet,
+        ignored: last_status.contains(PacketStatus::IGNORE_EVENT),
     })
 }
 
-/// Write a TDS packet to the stream.
+/// Length in COLMETADATA that marks a MAX column.
+const MAX_TYPE_LENGTH: u32 = 0xFFFF;
+
+/// Chunk size for PLP values in MAX columns.
+const PLP_CHUNK_SIZE: usize = 8000;
+
+/// Default packet size the mock announces in its login ENVCHANGE; responses
+/// are split into packets of at most this many bytes, as a real server does.
+const MOCK_PACKET_SIZE: usize = 4096;
+
+/// Write a TDS message to the stream, split into packets of at most
+/// [`MOCK_PACKET_SIZE`] bytes. Only the last packet carries END_OF_MESSAGE, so
+/// large values (e.g. PLP chunks) straddle packet boundaries like they do
+/// against SQL Server.
 async fn write_packet<S: AsyncWrite + Unpin>(
     stream: &mut S,
     packet_type: PacketType,
     payload: &[u8],
+    spid: u16,
 ) -> Result<()> {
-    let total_len = PACKET_HEADER_SIZE + payload.len();
-    let header = PacketHeader {
-        packet_type,
-        status: PacketStatus::END_OF_MESSAGE,
-        length: total_len as u16,
-        spid: 0,
-        packet_id: 1,
-        window: 0,
-    };
+    write_packets(stream, packet_type, payload, true, spid).await
+}
 
-    let mut buf = BytesMut::with_capacity(total_len);
-    header.encode(&mut buf);
-    buf.extend_from_slice(payload);
+/// Write `payload` as one or more packets, marking the last one
+/// END_OF_MESSAGE only when `end_of_message` is true.
+async fn write_packets<S: AsyncWrite + Unpin>(
+    stream: &mut S,
+    packet_type: PacketType,
+    payload: &[u8],
+    end_of_message: bool,
+    spid: u16,
+) -> Result<()> {
+    let max_payload = MOCK_PACKET_SIZE - PACKET_HEADER_SIZE;
+    let packet_count = payload.len().div_ceil(max_payload).max(1);
+    let mut buf = BytesMut::with_capacity(payload.len() + packet_count * PACKET_HEADER_SIZE);
+    // Packet IDs are a single byte that wraps past 255, as in real TDS.
+    let mut packet_id: u8 = 1;
+
+    for index in 0..packet_count {
+        let start = index * max_payload;
+        let chunk = &payload[start..payload.len().min(start + max_payload)];
+        let status = if end_of_message && index + 1 == packet_count {
+            PacketStatus::END_OF_MESSAGE
+        } else {
+            PacketStatus::NORMAL
+        };
+        let header = PacketHeader {
+            packet_type,
+            status,
+            length: (PACKET_HEADER_SIZE + chunk.len()) as u16,
+            spid,
+            packet_id,
+            window: 0,
+        };
+        header.encode(&mut buf);
+        buf.extend_from_slice(chunk);
+        packet_id = packet_id.wrapping_add(1);
+    }
 
     stream.write_all(&buf).await?;
     stream.flush().await?;
@@ -856,7 +1226,7 @@ async fn send_prelogin_response_with_encryption(
     // ENCRYPTION data (at offset 17)
     response.put_u8(encryption);
 
-    write_packet(stream, PacketType::PreLogin, &response).await
+    write_packet(stream, PacketType::PreLogin, &response, 0).await
 }
 
 /// Send LOGIN7 response (LoginAck + EnvChange + Done).
@@ -864,31 +1234,60 @@ async fn send_prelogin_response_with_encryption(
 /// When `login_routing` is configured, the response is an ENVCHANGE Routing
 /// token followed by Done — the login is not acknowledged, mirroring the
 /// Azure SQL Gateway redirect flow.
+///
+/// A FEDAUTH feature extension in the login is acknowledged, as Azure SQL
+/// does after validating the access token.
 async fn send_login_response<S: AsyncWrite + Unpin>(
     stream: &mut S,
     config: &MockServerConfig,
+    login: &[u8],
 ) -> Result<()> {
     let mut response = BytesMut::new();
 
     if let Some((host, port)) = &config.login_routing {
         encode_env_change_routing(&mut response, host, *port);
         encode_done(&mut response, 0, false);
-        return write_packet(stream, PacketType::TabularResult, &response).await;
+        return write_packet(stream, PacketType::TabularResult, &response, config.spid).await;
+    }
+
+    if let Some(error) = &config.login_error {
+        encode_server_error(&mut response, error);
+        let mut status = DoneStatus::default();
+        status.error = true;
+        encode_done_status(&mut response, status, 0);
+        return write_packet(stream, PacketType::TabularResult, &response, config.spid).await;
     }
 
     // EnvChange: Database
     encode_env_change(&mut response, EnvChangeType::Database, &config.database, "");
 
     // EnvChange: PacketSize
-    encode_env_change(&mut response, EnvChangeType::PacketSize, "4096", "4096");
+    let packet_size = config.packet_size.to_string();
+    encode_env_change(
+        &mut response,
+        EnvChangeType::PacketSize,
+        &packet_size,
+        &packet_size,
+    );
 
     // LoginAck
     encode_login_ack(&mut response, &config.server_name, config.tds_version);
 
+    let mut acks: Vec<(u8, &[u8])> = Vec::new();
+    if login_features(login).any(|(id, _)| id == FEATURE_FED_AUTH) {
+        acks.push((FEATURE_FED_AUTH, &[]));
+    }
+    if config.utf8_support {
+        acks.push((FEATURE_UTF8_SUPPORT, &[0x01]));
+    }
+    if !acks.is_empty() {
+        encode_feature_ext_ack(&mut response, &acks);
+    }
+
     // Done
     encode_done(&mut response, 0, false);
 
-    write_packet(stream, PacketType::TabularResult, &response).await
+    write_packet(stream, PacketType::TabularResult, &response, config.spid).await
 }
 
 /// Encode an EnvChange token.
@@ -940,6 +1339,63 @@ fn encode_env_change_routing(dst: &mut BytesMut, host: &str, port: u16) {
     dst.put_u16_le(0); // OldValue: zero-length US_VARBYTE
 }
 
+/// LOGIN7 feature ID for UTF-8 support (MS-TDS 2.2.6.4).
+const FEATURE_UTF8_SUPPORT: u8 = 0x0A;
+
+/// LOGIN7 feature ID for federated authentication (MS-TDS 2.2.6.4).
+const FEATURE_FED_AUTH: u8 = 0x02;
+
+/// Iterate the FeatureExt entries of a LOGIN7 payload per MS-TDS 2.2.6.4.
+///
+/// `ibExtension` (fixed header offset 56) points at a DWORD holding the
+/// offset of the FeatureExt block: { FeatureId(1), FeatureDataLen(4), data }*
+/// terminated by 0xFF. Yields nothing when the login has no extension or the
+/// block is malformed.
+fn login_features(login: &[u8]) -> impl Iterator<Item = (u8, &[u8])> {
+    const OPTION_FLAGS3: usize = 27;
+    const F_EXTENSION: u8 = 0x10;
+    const IB_EXTENSION: usize = 56;
+
+    let read_u16 = |at: usize| {
+        login
+            .get(at..at + 2)
+            .map(|b| usize::from(u16::from_le_bytes([b[0], b[1]])))
+    };
+    let read_u32 = |at: usize| {
+        login
+            .get(at..at + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+    };
+
+    let mut cursor = login
+        .get(OPTION_FLAGS3)
+        .filter(|flags| *flags & F_EXTENSION != 0)
+        .and_then(|_| read_u16(IB_EXTENSION))
+        .and_then(read_u32);
+    std::iter::from_fn(move || {
+        let at = cursor?;
+        let id = *login.get(at).filter(|id| **id != 0xFF)?;
+        let len = read_u32(at + 1)?;
+        let data = login.get(at + 5..at + 5 + len)?;
+        cursor = Some(at + 5 + len);
+        Some((id, data))
+    })
+}
+
+/// Encode a FEATUREEXTACK token per MS-TDS 2.2.7.11.
+///
+/// Layout: token(1) + { FeatureId(1), FeatureAckDataLen(4), data }* +
+/// terminator 0xFF.
+fn encode_feature_ext_ack(dst: &mut BytesMut, features: &[(u8, &[u8])]) {
+    dst.put_u8(TokenType::FeatureExtAck as u8);
+    for (id, data) in features {
+        dst.put_u8(*id);
+        dst.put_u32_le(data.len() as u32);
+        dst.put_slice(data);
+    }
+    dst.put_u8(0xFF);
+}
+
 /// Encode a LoginAck token.
 fn encode_login_ack(dst: &mut BytesMut, server_name: &str, tds_version: u32) {
     let name_utf16: Vec<u16> = server_name.encode_utf16().collect();
@@ -958,14 +1414,12 @@ fn encode_login_ack(dst: &mut BytesMut, server_name: &str, tds_version: u32) {
         dst.put_u16_le(*c);
     }
 
-    // Program version
-    dst.put_u32_le(0x10000000); // 16.0.0.0
+    // Program version: major, minor, build (high byte first)
+    dst.put_slice(&[16, 0, 0x10, 0x81]); // 16.0.4225
 }
 
 /// Encode a Done token.
 fn encode_done(dst: &mut BytesMut, row_count: u64, more: bool) {
-    dst.put_u8(TokenType::Done as u8);
-
     let mut bits = 0u16;
     if row_count > 0 {
         bits |= 0x0010; // DONE_COUNT
@@ -973,11 +1427,17 @@ fn encode_done(dst: &mut BytesMut, row_count: u64, more: bool) {
     if more {
         bits |= 0x0001; // DONE_MORE
     }
-    let status = DoneStatus::from_bits(bits);
+    encode_done_status(dst, DoneStatus::from_bits(bits), row_count);
+}
 
-    dst.put_u16_le(status.to_bits());
-    dst.put_u16_le(0xC1); // cur_cmd: SELECT
-    dst.put_u64_le(row_count);
+/// Encode a Done token with explicit status bits.
+fn encode_done_status(dst: &mut BytesMut, status: DoneStatus, row_count: u64) {
+    Done {
+        status,
+        cur_cmd: 0xC1, // SELECT
+        row_count,
+    }
+    .encode(dst);
 }
 
 /// Decode SQL from a SQL_BATCH packet payload.
@@ -1017,6 +1477,16 @@ fn decode_sql_batch(payload: &Bytes) -> Result<String> {
 
 /// Find the response for a SQL query.
 fn find_response(sql: &str, config: &MockServerConfig) -> MockResponse {
+    let response = match_response(sql, config);
+    // Custom handlers decide the response from the SQL text.
+    match response {
+        MockResponse::Custom(handler) => handler(sql),
+        other => other,
+    }
+}
+
+/// Look up the configured response for a SQL batch.
+fn match_response(sql: &str, config: &MockServerConfig) -> MockResponse {
     // Normalize SQL for matching
     let normalized = sql.trim().to_uppercase();
 
@@ -1043,20 +1513,22 @@ fn find_response(sql: &str, config: &MockServerConfig) -> MockResponse {
 async fn send_query_response<S: AsyncWrite + Unpin>(
     stream: &mut S,
     response: MockResponse,
+    spid: u16,
 ) -> Result<()> {
     let mut buf = BytesMut::new();
 
     match response {
         MockResponse::Scalar(value) => {
             // Single column, single row result
-            encode_colmetadata(&mut buf, &[MockColumn::new("", value.type_id())]);
-            encode_row(&mut buf, &[value.clone()]);
+            let columns = [MockColumn::new("", value.type_id())];
+            encode_colmetadata(&mut buf, &columns);
+            encode_row(&mut buf, &columns, &[value.clone()]);
             encode_done(&mut buf, 1, false);
         }
         MockResponse::Rows { columns, rows } => {
             encode_colmetadata(&mut buf, &columns);
             for row in &rows {
-                encode_row(&mut buf, row);
+                encode_row(&mut buf, &columns, row);
             }
             encode_done(&mut buf, rows.len() as u64, false);
         }
@@ -1074,14 +1546,17 @@ async fn send_query_response<S: AsyncWrite + Unpin>(
         MockResponse::Raw(data) => {
             buf.extend_from_slice(&data);
         }
-        MockResponse::Custom(_handler) => {
-            // For custom handlers, we'd need the SQL here
-            // For now, just send empty result
+        MockResponse::Unfinished(data) => {
+            return write_packets(stream, PacketType::TabularResult, &data, false, spid).await;
+        }
+        MockResponse::Custom(_) => {
+            // Resolved by `find_response` for SQL batches; RPC requests carry
+            // no SQL text to hand to the handler, so send an empty result.
             encode_done(&mut buf, 0, false);
         }
     }
 
-    write_packet(stream, PacketType::TabularResult, &buf).await
+    write_packet(stream, PacketType::TabularResult, &buf, spid).await
 }
 
 /// Encode COLMETADATA token.
@@ -1128,27 +1603,84 @@ fn encode_colmetadata(dst: &mut BytesMut, columns: &[MockColumn]) {
 }
 
 /// Encode ROW token.
-fn encode_row(dst: &mut BytesMut, values: &[ScalarValue]) {
+///
+/// A NULL's encoding depends on the column's length prefix, so `columns`
+/// must describe `values`.
+fn encode_row(dst: &mut BytesMut, columns: &[MockColumn], values: &[ScalarValue]) {
     dst.put_u8(TokenType::Row as u8);
-    for value in values {
-        value.encode(dst);
+    for (i, value) in values.iter().enumerate() {
+        let ushort_len = columns.get(i).is_some_and(|col| {
+            matches!(
+                col.type_id,
+                TypeId::NVarChar | TypeId::NChar | TypeId::BigVarBinary | TypeId::BigBinary
+            )
+        });
+        let max = columns.get(i).is_some_and(MockColumn::is_max);
+        match value {
+            ScalarValue::Null if max => dst.put_u64_le(u64::MAX),
+            ScalarValue::Binary(data) if max => encode_plp(dst, data),
+            ScalarValue::String(s) if max => {
+                let bytes: Vec<u8> = s.encode_utf16().flat_map(u16::to_le_bytes).collect();
+                encode_plp(dst, &bytes);
+            }
+            // USHORTLEN types mark NULL with 0xFFFF, not a zero length.
+            ScalarValue::Null if ushort_len => dst.put_u16_le(0xFFFF),
+            _ => value.encode(dst),
+        }
+    }
+}
+
+/// Encode a PLP value with a known length, split into chunks of at most
+/// [`PLP_CHUNK_SIZE`] bytes.
+fn encode_plp(dst: &mut BytesMut, data: &[u8]) {
+    dst.put_u64_le(data.len() as u64);
+    for chunk in data.chunks(PLP_CHUNK_SIZE) {
+        dst.put_u32_le(chunk.len() as u32);
+        dst.extend_from_slice(chunk);
     }
+    dst.put_u32_le(0); // terminator
 }
 
 /// Encode ERROR token.
 fn encode_error(dst: &mut BytesMut, number: i32, message: &str, severity: u8) {
-    let msg_utf16: Vec<u16> = message.encode_utf16().collect();
-    let server_utf16: Vec<u16> = "MockServer".encode_utf16().collect();
+    encode_server_error(
+        dst,
+        &ServerError {
+            number,
+            state: 1,
+            class: severity,
+            message: message.to_string(),
+            server: "MockServer".to_string(),
+            procedure: String::new(),
+            line: 1,
+        },
+    );
+}
+
+/// Encode an ERROR token with every field of `error`.
+fn encode_server_error(dst: &mut BytesMut, error: &ServerError) {
+    let msg_utf16: Vec<u16> = error.message.encode_utf16().collect();
+    let server_utf16: Vec<u16> = error.server.encode_utf16().collect();
+    let proc_utf16: Vec<u16> = error.procedure.encode_utf16().collect();
 
     // ERROR: number (4) + state (1) + class (1) + message (us_varchar) +
     //        server (b_varchar) + procedure (b_varchar) + line (4)
-    let data_len = (4 + 1 + 1 + 2 + msg_utf16.len() * 2 + 1 + server_utf16.len() * 2 + 1) + 4;
+    let data_len = 4
+        + 1
+        + 1
+        + 2
+        + msg_utf16.len() * 2
+        + 1
+        + server_utf16.len() * 2
+        + 1
+        + proc_utf16.len() * 2
+        + 4;
 
     dst.put_u8(TokenType::Error as u8);
     dst.put_u16_le(data_len as u16);
-    dst.put_i32_le(number);
-    dst.put_u8(1); // state
-    dst.put_u8(severity); // class
+    dst.put_i32_le(error.number);
+    dst.put_u8(error.state);
+    dst.put_u8(error.class);
 
     // Message (US_VARCHAR)
     dst.put_u16_le(msg_utf16.len() as u16);
@@ -1162,15 +1694,18 @@ fn encode_error(dst: &mut BytesMut, number: i32, message: &str, severity: u8) {
         dst.put_u16_le(*c);
     }
 
-    // Procedure name (B_VARCHAR) - empty
-    dst.put_u8(0);
+    // Procedure name (B_VARCHAR)
+    dst.put_u8(proc_utf16.len() as u8);
+    for c in &proc_utf16 {
+        dst.put_u16_le(*c);
+    }
 
     // Line number
-    dst.put_i32_le(1);
+    dst.put_i32_le(error.line);
 }
 
 /// Send attention acknowledgment.
-async fn send_attention_ack<S: AsyncWrite + Unpin>(stream: &mut S) -> Result<()> {
+async fn send_attention_ack<S: AsyncWrite + Unpin>(stream: &mut S, spid: u16) -> Result<()> {
     let mut buf = BytesMut::new();
 
     // DONE with ATTN flag
@@ -1180,7 +1715,7 @@ async fn send_attention_ack<S: AsyncWrite + Unpin>(stream: &mut S) -> Result<()>
     buf.put_u16_le(0);
     buf.put_u64_le(0);
 
-    write_packet(stream, PacketType::TabularResult, &buf).await
+    write_packet(stream, PacketType::TabularResult, &buf, spid).await
 }
 
 /// Recorded packet for replay testing.
@@ -1301,6 +1836,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mock_response_sequence_repeats_last() {
+        let MockResponse::Custom(handler) = MockResponse::sequence([
+            MockResponse::error(1205, "deadlock"),
+            MockResponse::affected(1),
+        ]) else {
+            panic!("Expected custom response");
+        };
+        assert!(matches!(
+            handler("x"),
+            MockResponse::Error { number: 1205, .. }
+        ));
+        assert!(matches!(handler("x"), MockResponse::RowsAffected(1)));
+        assert!(matches!(handler("x"), MockResponse::RowsAffected(1)));
+    }
+
+    #[tokio::test]
+    async fn test_packet_ids_wrap_past_255() {
+        let max_payload = MOCK_PACKET_SIZE - PACKET_HEADER_SIZE;
+        let payload = vec![0u8; max_payload * 258];
+        let mut out = Vec::new();
+        write_packet(&mut out, PacketType::TabularResult, &payload, 0)
+            .await
+            .unwrap();
+
+        let ids: Vec<u8> = out
+            .chunks(MOCK_PACKET_SIZE)
+            .map(|packet| packet[6])
+            .collect();
+        assert_eq!(ids.len(), 258);
+        assert_eq!(&ids[..2], &[1, 2]);
+        assert_eq!(&ids[254..], &[255, 0, 1, 2]);
+    }
+
     #[test]
     fn test_scalar_value_encode_int() {
         let value = ScalarValue::Int(42);
@@ -0,0 +1,51 @@
+//! Behavior tests for `ApplicationIntent` on the wire.
+//!
+//! `ApplicationIntent=ReadOnly` must set the READONLY_INTENT bit (0x20) of
+//! the LOGIN7 TypeFlags byte, which is what lets an availability group
+//! listener or Azure SQL route the session to a readable secondary. The
+//! default `ReadWrite` intent leaves the bit clear.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Client, Config};
+use mssql_testing::mock_server::{MockTdsServer, connection_string};
+
+/// Offset of TypeFlags in the LOGIN7 fixed header: Length, TDSVersion,
+/// PacketSize, ClientProgVer, ClientPID, ConnectionID (4 bytes each), then
+/// OptionFlags1 and OptionFlags2.
+const TYPE_FLAGS_OFFSET: usize = 26;
+const READ_ONLY_INTENT: u8 = 0x20;
+
+async fn login_type_flags(intent: Option<&str>) -> u8 {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let intent = intent.map_or(String::new(), |i| format!(";ApplicationIntent={i}"));
+    let config =
+        Config::from_connection_string(&format!("{}{intent}", connection_string(server.port())))
+            .expect("config parses");
+    let client = Client::connect(config).await.expect("connect");
+    let _ = client.close().await;
+
+    let logins = server.login_requests().await;
+    assert_eq!(logins.len(), 1, "one LOGIN7 per connection");
+    logins[0][TYPE_FLAGS_OFFSET]
+}
+
+#[tokio::test]
+async fn read_only_intent_sets_login_type_flag() {
+    let flags = login_type_flags(Some("ReadOnly")).await;
+    assert_eq!(flags & READ_ONLY_INTENT, READ_ONLY_INTENT);
+}
+
+#[tokio::test]
+async fn read_write_intent_leaves_login_type_flag_clear() {
+    assert_eq!(
+        login_type_flags(Some("ReadWrite")).await & READ_ONLY_INTENT,
+        0
+    );
+    assert_eq!(login_type_flags(None).await & READ_ONLY_INTENT, 0);
+}
@@ -0,0 +1,50 @@
+//! Behavior tests for logging in with a pre-acquired Azure AD access token.
+//!
+//! `Credentials::azure_token` logs in over the FEDAUTH SecurityToken
+//! workflow: the token travels in the LOGIN7 FEDAUTH feature extension and
+//! the server acknowledges the feature, with no FEDAUTHINFO round trip.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Client, Config, Credentials};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect, connection_string};
+
+const TOKEN: &str = "eyJ0eXAiOiJKV1QiLCJhbGciOiJSUzI1NiJ9.e30.c2lnbmF0dXJl";
+
+#[tokio::test]
+async fn access_token_is_sent_in_login_and_the_handshake_completes() {
+    mssql_testing::tls::ensure_crypto_provider_for_test();
+    let server = MockTdsServer::builder()
+        .with_tls()
+        .with_response("SELECT 1", MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts");
+    let config = Config::from_connection_string(&format!(
+        "{};Encrypt=true;TrustServerCertificate=true",
+        connection_string(server.port())
+    ))
+    .expect("config parses")
+    .credentials(Credentials::azure_token(TOKEN));
+
+    let mut client = Client::connect(config).await.expect("token login");
+
+    assert_eq!(server.fed_auth_tokens().await, [TOKEN]);
+    let rows = client.query("SELECT 1", &[]).await.expect("query");
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn sql_login_sends_no_access_token() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let client = connect(&server).await;
+
+    assert!(server.fed_auth_tokens().await.is_empty());
+    let _ = client.close().await;
+}
@@ -0,0 +1,194 @@
+//! Behavior tests for blob streaming (`Client::read_blob`,
+//! `Client::write_blob`).
+//!
+//! A multi-megabyte `VARBINARY(MAX)` value is read chunk by chunk from the
+//! socket and written from an `AsyncRead` as PLP chunks of the requested
+//! size, and a source that fails part way leaves the connection usable.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use mssql_client::Error;
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+use tokio::io::{AsyncRead, AsyncReadExt, ReadBuf};
+
+const READ_SQL: &str = "SELECT id, data FROM files";
+const WRITE_SQL: &str = "UPDATE files SET data = @p1";
+const BLOB_LEN: usize = 3 * 1024 * 1024 + 17;
+
+/// Deterministic contents that never contain the unknown-length PLP marker.
+fn blob() -> Vec<u8> {
+    (0..BLOB_LEN).map(|i| (i % 251) as u8).collect()
+}
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(
+            READ_SQL,
+            MockResponse::rows(
+                vec![MockColumn::int("id"), MockColumn::varbinary_max("data")],
+                vec![vec![ScalarValue::Int(1), ScalarValue::Binary(blob())]],
+            ),
+        )
+        .with_response("SELECT 1", MockResponse::scalar_int(1))
+        .with_default_response(MockResponse::affected(1))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+/// Split the streamed parameter at the end of an RPC payload into its PLP
+/// chunks.
+fn plp_chunks(rpc: &[u8]) -> Vec<&[u8]> {
+    let marker = 0xFFFF_FFFF_FFFF_FFFEu64.to_le_bytes();
+    let start = rpc
+        .windows(marker.len())
+        .position(|w| w == marker)
+        .expect("unknown-length PLP header")
+        + marker.len();
+
+    let mut rest = &rpc[start..];
+    let mut chunks = Vec::new();
+    loop {
+        let len = u32::from_le_bytes(rest[..4].try_into().unwrap()) as usize;
+        rest = &rest[4..];
+        if len == 0 {
+            break;
+        }
+        chunks.push(&rest[..len]);
+        rest = &rest[len..];
+    }
+    assert!(rest.is_empty(), "the blob is the last parameter");
+    chunks
+}
+
+/// A source that fails on every read.
+struct BrokenSource;
+
+impl AsyncRead for BrokenSource {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        _buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Err(std::io::Error::other("source broke")))
+    }
+}
+
+#[tokio::test]
+async fn read_blob_streams_a_multi_megabyte_value() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let mut reader = client.read_blob(READ_SQL, &[]).await.unwrap();
+    let mut data = Vec::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    let mut reads = 0;
+    loop {
+        let n = reader.read(&mut buf).await.unwrap();
+        if n == 0 {
+            break;
+        }
+        if reads == 0 {
+            // Handed out as chunks arrive, not after buffering the value.
+            assert!(n < BLOB_LEN);
+        }
+        reads += 1;
+        data.extend_from_slice(&buf[..n]);
+    }
+    drop(reader);
+
+    assert!(reads > 1);
+    assert_eq!(data.len(), BLOB_LEN);
+    assert!(data == blob());
+
+    // Reading to the end drained the response.
+    assert!(!client.is_in_flight());
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn write_blob_sends_plp_chunks_of_the_requested_size() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+    let source = blob();
+    let chunk_size = 64 * 1024;
+
+    let affected = client
+        .write_blob(WRITE_SQL, &[], &mut &source[..], chunk_size)
+        .await
+        .unwrap();
+    assert_eq!(affected, 1);
+
+    let requests = server.rpc_requests().await;
+    assert_eq!(requests.len(), 1);
+    let chunks = plp_chunks(&requests[0]);
+    assert_eq!(chunks.len(), BLOB_LEN.div_ceil(chunk_size));
+    let (last, full) = chunks.split_last().unwrap();
+    assert!(full.iter().all(|c| c.len() == chunk_size));
+    assert_eq!(last.len(), BLOB_LEN % chunk_size);
+    assert!(chunks.concat() == source);
+
+    // Framed at the negotiated packet size, not one oversized packet.
+    assert_eq!(
+        server.largest_request_packet().await,
+        client.packet_size() as usize
+    );
+
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn write_blob_abandons_the_request_when_the_source_fails() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    // Enough data to put several packets on the wire before the failure.
+    let prefix = blob();
+    let mut source = (&prefix[..100_000]).chain(BrokenSource);
+    let err = client
+        .write_blob(WRITE_SQL, &[], &mut source, 8 * 1024)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Io(_)), "unexpected error: {err:?}");
+    assert!(err.to_string().contains("source broke"));
+
+    // The server discarded the partial request and sent nothing back, so the
+    // next request's response is its own.
+    assert!(!client.is_in_flight());
+    assert!(server.rpc_requests().await.is_empty());
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn write_blob_keeps_a_pending_reset_when_the_source_fails() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+    client.mark_needs_reset();
+
+    let prefix = blob();
+    let mut source = (&prefix[..100_000]).chain(BrokenSource);
+    client
+        .write_blob(WRITE_SQL, &[], &mut source, 8 * 1024)
+        .await
+        .unwrap_err();
+
+    // The abandoned request's RESETCONNECTION was discarded with it, so the
+    // reset is still owed to the next request.
+    assert!(client.needs_reset());
+    assert_eq!(server.reset_request_count().await, 0);
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.count(), 1);
+    assert_eq!(server.reset_request_count().await, 1);
+    let _ = client.close().await;
+}
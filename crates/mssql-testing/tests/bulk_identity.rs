@@ -0,0 +1,171 @@
+//! Behavior tests for `Client::bulk_insert` against identity columns.
+//!
+//! Schema discovery selects only the builder's columns, so a builder that
+//! leaves out the IDENTITY column loads the rest and the server generates
+//! it. Listing the identity column is rejected before the server enters
+//! bulk-load mode, unless `keep_identity` asks for the `KEEP_IDENTITY` hint.
+//! A builder with no column list is not checked at all.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{BulkColumn, BulkInsertBuilder, Client, Error, Ready, SqlValue};
+use mssql_testing::mock_server::{self, MockColumn, MockResponse, MockTdsServer};
+
+const IDENTITY_METADATA: &str = "SELECT TOP 0 id, name FROM dbo.Orders";
+const NAME_METADATA: &str = "SELECT TOP 0 name FROM dbo.Orders";
+const ALL_METADATA: &str = "SELECT TOP 0 * FROM dbo.Orders";
+
+/// `id INT IDENTITY NOT NULL, name NVARCHAR(50)`, as `SELECT TOP 0` reports
+/// them: COLMETADATA (the identity column carries `fIdentity`), then DONE.
+fn identity_metadata() -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(2);
+
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0010); // fIdentity
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(2);
+    for unit in "id".encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0009); // nullable, updateable
+    buf.put_u8(0xE7); // NVARCHAR
+    buf.put_u16_le(100);
+    buf.put_slice(&[0x09, 0x04, 0xD0, 0x00, 0x34]); // collation
+    buf.put_u8(4);
+    for unit in "name".encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0);
+    buf.put_u16_le(0xC1); // cur_cmd: SELECT
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+async fn connect() -> (MockTdsServer, Client<Ready>) {
+    let server = MockTdsServer::builder()
+        .with_response(IDENTITY_METADATA, MockResponse::Raw(identity_metadata()))
+        .with_response(ALL_METADATA, MockResponse::Raw(identity_metadata()))
+        .with_response(
+            NAME_METADATA,
+            MockResponse::rows(vec![MockColumn::nvarchar("name", 50)], vec![]),
+        )
+        .with_response("SELECT 1", MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+fn with_id() -> BulkInsertBuilder {
+    BulkInsertBuilder::new("dbo.Orders").with_typed_columns(vec![
+        BulkColumn::new("id", "INT", 0).unwrap(),
+        BulkColumn::new("name", "NVARCHAR(50)", 1).unwrap(),
+    ])
+}
+
+async fn insert_bulk_statements(server: &MockTdsServer) -> Vec<String> {
+    server
+        .sql_batches()
+        .await
+        .into_iter()
+        .filter(|sql| sql.starts_with("INSERT BULK"))
+        .collect()
+}
+
+#[tokio::test]
+async fn omitted_identity_column_is_not_discovered() {
+    let (server, mut client) = connect().await;
+    let builder = BulkInsertBuilder::new("dbo.Orders")
+        .with_typed_columns(vec![BulkColumn::new("name", "NVARCHAR(50)", 0).unwrap()]);
+
+    let mut writer = client.bulk_insert(&builder).await.expect("bulk insert");
+    writer
+        .send_row_values(&[SqlValue::String("first".into())])
+        .unwrap();
+    writer.finish().await.unwrap();
+
+    let batches = server.sql_batches().await;
+    assert!(
+        batches.iter().any(|sql| sql == NAME_METADATA),
+        "{batches:#?}"
+    );
+    assert_eq!(
+        insert_bulk_statements(&server).await,
+        ["INSERT BULK dbo.Orders (name NVARCHAR(50)) WITH (CHECK_CONSTRAINTS, KEEP_NULLS)"]
+    );
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn listed_identity_column_is_rejected_before_bulk_load() {
+    let (server, mut client) = connect().await;
+
+    match client.bulk_insert(&with_id()).await {
+        Err(Error::Config(message)) => {
+            assert!(message.contains("`id`") && message.contains("IDENTITY"));
+        }
+        Err(other) => panic!("unexpected error: {other:?}"),
+        Ok(_) => panic!("identity column must be rejected"),
+    }
+    assert!(insert_bulk_statements(&server).await.is_empty());
+    assert!(server.bulk_load_requests().await.is_empty());
+
+    let rows = client.query("SELECT 1", &[]).await.expect("reusable");
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn identity_table_without_column_list_is_not_rejected() {
+    let (server, mut client) = connect().await;
+
+    let writer = client
+        .bulk_insert(&BulkInsertBuilder::new("dbo.Orders"))
+        .await
+        .expect("bulk insert");
+    writer.finish().await.unwrap();
+
+    let batches = server.sql_batches().await;
+    assert!(
+        batches.iter().any(|sql| sql == ALL_METADATA),
+        "{batches:#?}"
+    );
+    assert_eq!(
+        insert_bulk_statements(&server).await,
+        ["INSERT BULK dbo.Orders WITH (CHECK_CONSTRAINTS, KEEP_NULLS)"]
+    );
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn keep_identity_loads_the_given_values() {
+    let (server, mut client) = connect().await;
+
+    let mut writer = client
+        .bulk_insert(&with_id().keep_identity(true))
+        .await
+        .expect("bulk insert");
+    writer
+        .send_row_values(&[SqlValue::Int(42), SqlValue::String("answer".into())])
+        .unwrap();
+    writer.finish().await.unwrap();
+
+    assert_eq!(
+        insert_bulk_statements(&server).await,
+        ["INSERT BULK dbo.Orders (id INT, name NVARCHAR(50)) \
+          WITH (CHECK_CONSTRAINTS, KEEP_NULLS, KEEP_IDENTITY)"]
+    );
+    assert_eq!(server.bulk_load_requests().await.len(), 1);
+    let _ = client.close().await;
+}
@@ -0,0 +1,105 @@
+//! Behavior tests for `Client::bulk_insert` end to end.
+//!
+//! The `INSERT BULK` statement is sent, rows are written to the connection
+//! as one BulkLoad message (flushed every `batch_size` rows by
+//! `BulkWriter::write_row`), and the result carries the server's row count.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{BulkColumn, BulkInsertBuilder, Client, Ready, SqlValue};
+use mssql_testing::mock_server::{self, MockColumn, MockResponse, MockTdsServer};
+
+const ROW_COUNT: usize = 10_000;
+const BATCH_SIZE: usize = 1_000;
+
+async fn connect() -> (MockTdsServer, Client<Ready>) {
+    let server = MockTdsServer::builder()
+        .with_response(
+            "SELECT TOP 0 id, name FROM dbo.Events",
+            MockResponse::rows(
+                vec![MockColumn::int("id"), MockColumn::nvarchar("name", 50)],
+                vec![],
+            ),
+        )
+        .with_response("SELECT 1", MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+fn events(batch_size: usize) -> BulkInsertBuilder {
+    BulkInsertBuilder::new("dbo.Events")
+        .with_typed_columns(vec![
+            BulkColumn::new("id", "INT", 0).unwrap(),
+            BulkColumn::new("name", "NVARCHAR(50)", 1).unwrap(),
+        ])
+        .batch_size(batch_size)
+}
+
+fn row(i: usize) -> [SqlValue; 2] {
+    [
+        SqlValue::Int(i as i32),
+        SqlValue::String(format!("event {i}")),
+    ]
+}
+
+#[tokio::test]
+async fn ten_thousand_rows_are_flushed_in_batches_and_counted() {
+    let (server, mut client) = connect().await;
+    let packet_size = client.packet_size() as usize;
+
+    let mut writer = client.bulk_insert(&events(BATCH_SIZE)).await.unwrap();
+    for i in 0..ROW_COUNT {
+        writer.write_row_values(&row(i)).await.unwrap();
+        // Never more than one batch is held in memory.
+        assert!(writer.buffered_rows() < BATCH_SIZE);
+    }
+    assert_eq!(writer.total_rows(), ROW_COUNT as u64);
+    let result = writer.finish().await.unwrap();
+    assert_eq!(result.rows_affected, ROW_COUNT as u64);
+
+    let batches = server.sql_batches().await;
+    assert!(
+        batches.iter().any(|sql| sql
+            == "INSERT BULK dbo.Events (id INT, name NVARCHAR(50)) \
+                WITH (CHECK_CONSTRAINTS, KEEP_NULLS, ROWS_PER_BATCH = 1000)"),
+        "{batches:#?}"
+    );
+    // One message for all the rows, framed at the negotiated packet size.
+    assert_eq!(server.bulk_load_requests().await.len(), 1);
+    assert_eq!(server.largest_request_packet().await, packet_size);
+
+    assert!(!client.is_in_flight());
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn flushing_does_not_change_the_bulk_load_message() {
+    let (server, mut client) = connect().await;
+
+    let mut writer = client.bulk_insert(&events(BATCH_SIZE)).await.unwrap();
+    for i in 0..ROW_COUNT {
+        writer.write_row_values(&row(i)).await.unwrap();
+    }
+    writer.finish().await.unwrap();
+
+    // Without a batch size every row stays buffered until finish().
+    let mut writer = client.bulk_insert(&events(0)).await.unwrap();
+    for i in 0..ROW_COUNT {
+        writer.write_row_values(&row(i)).await.unwrap();
+    }
+    assert_eq!(writer.buffered_rows(), ROW_COUNT);
+    let result = writer.finish().await.unwrap();
+    assert_eq!(result.rows_affected, ROW_COUNT as u64);
+
+    let messages = server.bulk_load_requests().await;
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0] == messages[1]);
+    let _ = client.close().await;
+}
@@ -0,0 +1,123 @@
+//! Behavior tests for `Client::bulk_upsert`.
+//!
+//! Rows are bulk-loaded into a staging temp table created from the target,
+//! merged into the target on the key columns, and the staging table is
+//! dropped afterwards, including when the `MERGE` fails.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{BulkInsertBuilder, Client, Error, Ready, SqlValue};
+use mssql_testing::mock_server::{
+    self, MockColumn, MockResponse, MockServerBuilder, MockTdsServer,
+};
+
+const CREATE_STAGING: &str = "IF OBJECT_ID('tempdb..#bulk_upsert') IS NOT NULL \
+     DROP TABLE #bulk_upsert; SELECT TOP 0 id, name INTO #bulk_upsert FROM dbo.Users \
+     UNION ALL SELECT TOP 0 id, name FROM dbo.Users";
+const STAGING_METADATA: &str = "SELECT TOP 0 id, name FROM #bulk_upsert";
+const MERGE: &str = "MERGE dbo.Users WITH (HOLDLOCK) AS t USING #bulk_upsert AS s \
+     ON t.id = s.id WHEN MATCHED THEN UPDATE SET t.name = s.name \
+     WHEN NOT MATCHED BY TARGET THEN INSERT (id, name) VALUES (s.id, s.name);";
+const DROP_STAGING: &str =
+    "IF OBJECT_ID('tempdb..#bulk_upsert') IS NOT NULL DROP TABLE #bulk_upsert";
+
+fn server(merge: MockResponse) -> MockServerBuilder {
+    MockTdsServer::builder()
+        .with_response(
+            STAGING_METADATA,
+            MockResponse::rows(
+                vec![MockColumn::int("id"), MockColumn::nvarchar("name", 100)],
+                vec![],
+            ),
+        )
+        .with_response(MERGE, merge)
+        .with_response("SELECT 1", MockResponse::scalar_int(1))
+        .with_default_response(MockResponse::affected(3))
+}
+
+async fn connect(builder: MockServerBuilder) -> (MockTdsServer, Client<Ready>) {
+    let server = builder.build().await.expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+fn users() -> BulkInsertBuilder {
+    BulkInsertBuilder::new("dbo.Users").with_columns(&["id", "name"])
+}
+
+fn rows() -> Vec<Vec<SqlValue>> {
+    ["Alice", "Bob", "Carol"]
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            vec![
+                SqlValue::Int(i as i32 + 1),
+                SqlValue::String(name.to_string()),
+            ]
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn bulk_upsert_stages_merges_and_drops_the_staging_table() {
+    let (server, mut client) = connect(server(MockResponse::affected(3))).await;
+    let before = server.sql_batches().await.len();
+
+    let result = client
+        .bulk_upsert(&users(), &["id"], rows())
+        .await
+        .expect("upsert");
+    assert_eq!(result.rows_staged, 3);
+    assert_eq!(result.rows_merged, 3);
+
+    let batches = server.sql_batches().await.split_off(before);
+    assert_eq!(batches.len(), 5, "{batches:#?}");
+    assert_eq!(batches[0], CREATE_STAGING);
+    assert_eq!(batches[1], STAGING_METADATA);
+    assert!(batches[2].starts_with("INSERT BULK #bulk_upsert (id "));
+    assert_eq!(batches[3], MERGE);
+    assert_eq!(batches[4], DROP_STAGING);
+    assert_eq!(server.bulk_load_requests().await.len(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn a_failed_merge_still_drops_the_staging_table() {
+    let (server, mut client) = connect(server(MockResponse::error(
+        8672,
+        "The MERGE statement attempted to UPDATE or DELETE the same row more than once.",
+    )))
+    .await;
+
+    let err = client
+        .bulk_upsert(&users(), &["id"], rows())
+        .await
+        .expect_err("merge fails");
+    assert!(
+        matches!(err, Error::Server { number: 8672, .. }),
+        "got {err:?}"
+    );
+
+    let batches = server.sql_batches().await;
+    assert_eq!(batches.last().map(String::as_str), Some(DROP_STAGING));
+    assert!(!client.is_in_flight());
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn an_unknown_key_column_fails_before_any_request() {
+    let (server, mut client) = connect(server(MockResponse::affected(3))).await;
+    let before = server.sql_batches().await.len();
+
+    let err = client
+        .bulk_upsert(&users(), &["email"], rows())
+        .await
+        .expect_err("unknown key");
+    assert!(matches!(err, Error::Config(_)), "got {err:?}");
+    assert_eq!(server.sql_batches().await.len(), before);
+    let _ = client.close().await;
+}
@@ -0,0 +1,89 @@
+//! Behavior tests for `CancelHandle` against a result that never finishes.
+//!
+//! The server sends column metadata and a couple of rows, then stalls
+//! without a final DONE. Cancelling from another task must write an
+//! Attention packet, the pending read must end with `Error::Cancelled` once
+//! the server's DONE_ATTN arrives, and the connection must stay usable.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::time::Duration;
+
+use mssql_client::{Client, Error};
+use mssql_testing::mock_server::{self, MockColumn, MockResponse, MockTdsServer, ScalarValue};
+
+const SLOW_QUERY: &str = "SELECT id FROM big_table";
+
+/// `SELECT id` with rows 1 and 2 and no DONE: the result is still running.
+fn unfinished_response() -> MockResponse {
+    MockResponse::unfinished_rows(
+        vec![MockColumn::int("id")],
+        vec![vec![ScalarValue::Int(1)], vec![ScalarValue::Int(2)]],
+    )
+}
+
+async fn connect() -> (MockTdsServer, Client<mssql_client::Ready>) {
+    let server = MockTdsServer::builder()
+        .with_response(SLOW_QUERY, unfinished_response())
+        .with_default_response(MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+/// Cancel through `handle` after a short delay, from another task.
+fn cancel_soon(handle: mssql_client::cancel::CancelHandle) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.cancel().await.expect("attention sent");
+    })
+}
+
+#[tokio::test]
+async fn cancel_interrupts_streamed_result() {
+    let (server, mut client) = connect().await;
+    let handle = client.cancel_handle();
+
+    let mut rows = client.query_stream(SLOW_QUERY, &[]).await.expect("query");
+    for expected in [1, 2] {
+        let row = rows.try_next().await.expect("row").expect("row present");
+        assert_eq!(row.get::<i32>(0).unwrap(), expected);
+    }
+
+    let canceller = cancel_soon(handle);
+    let err = rows
+        .try_next()
+        .await
+        .expect_err("stalled stream must end on cancel");
+    assert!(matches!(err, Error::Cancelled), "got {err:?}");
+    drop(rows);
+    canceller.await.expect("cancel task");
+
+    assert_eq!(server.attention_count().await, 1);
+    assert_eq!(client.execute("SELECT 1", &[]).await.expect("reusable"), 1);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn cancel_interrupts_buffered_query() {
+    let (server, mut client) = connect().await;
+    let canceller = cancel_soon(client.cancel_handle());
+
+    let err = client
+        .query(SLOW_QUERY, &[])
+        .await
+        .err()
+        .expect("stalled query must end on cancel");
+    assert!(matches!(err, Error::Cancelled), "got {err:?}");
+    canceller.await.expect("cancel task");
+
+    assert_eq!(server.attention_count().await, 1);
+    assert_eq!(client.execute("SELECT 1", &[]).await.expect("reusable"), 1);
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,85 @@
+//! Behavior tests for `Client::list_databases` and `Client::list_tables`.
+//!
+//! Both run a catalog query and map the rows into owned values; the schema
+//! filter of `list_tables` is sent as a bound parameter.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Client, TableInfo};
+use mssql_testing::mock_server::{self, MockColumn, MockResponse, MockTdsServer, ScalarValue};
+
+const DATABASES_QUERY: &str = "SELECT name FROM sys.databases ORDER BY name";
+const TABLES_QUERY: &str = "SELECT s.name, t.name FROM sys.tables t \
+     JOIN sys.schemas s ON s.schema_id = t.schema_id ORDER BY s.name, t.name";
+
+fn tables(rows: &[(&str, &str)]) -> MockResponse {
+    MockResponse::rows(
+        vec![
+            MockColumn::nvarchar("schema", 128),
+            MockColumn::nvarchar("name", 128),
+        ],
+        rows.iter()
+            .map(|(schema, name)| {
+                vec![
+                    ScalarValue::String((*schema).into()),
+                    ScalarValue::String((*name).into()),
+                ]
+            })
+            .collect(),
+    )
+}
+
+fn table(schema: &str, name: &str) -> TableInfo {
+    TableInfo {
+        schema: schema.into(),
+        name: name.into(),
+    }
+}
+
+async fn connect() -> (MockTdsServer, Client<mssql_client::Ready>) {
+    let server = MockTdsServer::builder()
+        .with_response(
+            DATABASES_QUERY,
+            MockResponse::rows(
+                vec![MockColumn::nvarchar("name", 128)],
+                vec![
+                    vec![ScalarValue::String("master".into())],
+                    vec![ScalarValue::String("sales".into())],
+                ],
+            ),
+        )
+        .with_response(TABLES_QUERY, tables(&[("dbo", "orders"), ("audit", "log")]))
+        // Parameterized (schema-filtered) requests arrive as RPCs.
+        .with_default_response(tables(&[("audit", "log")]))
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+#[tokio::test]
+async fn list_databases_returns_names() {
+    let (_server, mut client) = connect().await;
+
+    let databases = client.list_databases().await.expect("list_databases");
+    assert_eq!(databases, vec!["master", "sales"]);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn list_tables_maps_schema_and_name() {
+    let (_server, mut client) = connect().await;
+
+    let all = client.list_tables(None).await.expect("list_tables");
+    assert_eq!(all, vec![table("dbo", "orders"), table("audit", "log")]);
+    assert_eq!(all[0].to_string(), "[dbo].[orders]");
+
+    let audit = client.list_tables(Some("audit")).await.expect("filtered");
+    assert_eq!(audit, vec![table("audit", "log")]);
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,55 @@
+//! Behavior tests for `Client::close`.
+//!
+//! `close` flushes and shuts down the stream, so the server sees the
+//! disconnect and ends the session without waiting for a socket timeout.
+//! Dropping a client without `close` still closes its socket.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::time::Duration;
+
+use mssql_testing::mock_server::{MockTdsServer, connect};
+
+#[tokio::test]
+async fn close_ends_server_session() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+    client.execute("SELECT 1", &[]).await.expect("execute");
+    assert_eq!(server.connection_count().await, 1);
+
+    client.close().await.expect("close succeeds");
+
+    for _ in 0..200 {
+        if server.connection_count().await == 0 {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("server session still open after close");
+}
+
+#[tokio::test]
+async fn drop_without_close_ends_server_session() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+    client.execute("SELECT 1", &[]).await.expect("execute");
+    assert_eq!(server.connection_count().await, 1);
+
+    drop(client);
+
+    for _ in 0..200 {
+        if server.connection_count().await == 0 {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("server session still open after drop");
+}
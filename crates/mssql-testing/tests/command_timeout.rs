@@ -0,0 +1,76 @@
+//! Behavior tests for `TimeoutConfig::command_timeout` against a server
+//! that never finishes a result.
+//!
+//! The server sends column metadata and a row, then stalls without a final
+//! DONE. Once the command timeout expires the client must send an Attention
+//! to cancel the statement on the server, drain the DONE_ATTN, and fail with
+//! `Error::CommandTimeout`, leaving the connection usable.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::time::{Duration, Instant};
+
+use mssql_client::{Client, Error, Ready, TimeoutConfig};
+use mssql_testing::mock_server::{
+    MockColumn, MockResponse, MockTdsServer, ScalarValue, client_config,
+};
+
+const HUNG_QUERY: &str = "SELECT id FROM locked_table";
+const TIMEOUT: Duration = Duration::from_millis(200);
+
+/// `SELECT id` with one row and no DONE: the statement is still running.
+fn unfinished_response() -> MockResponse {
+    MockResponse::unfinished_rows(vec![MockColumn::int("id")], vec![vec![ScalarValue::Int(1)]])
+}
+
+async fn connect() -> (MockTdsServer, Client<Ready>) {
+    let server = MockTdsServer::builder()
+        .with_response(HUNG_QUERY, unfinished_response())
+        .with_default_response(MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts");
+    let config =
+        client_config(server.port()).timeouts(TimeoutConfig::new().command_timeout(TIMEOUT));
+    let client = Client::connect(config).await.expect("connect");
+    (server, client)
+}
+
+#[tokio::test]
+async fn query_times_out_and_cancels_a_hung_statement() {
+    let (server, mut client) = connect().await;
+
+    let started = Instant::now();
+    let err = client
+        .query(HUNG_QUERY, &[])
+        .await
+        .err()
+        .expect("hung query must time out");
+    assert!(matches!(err, Error::CommandTimeout), "got {err:?}");
+    assert!(started.elapsed() >= TIMEOUT);
+
+    assert_eq!(server.attention_count().await, 1);
+    assert!(!client.is_in_flight());
+    assert_eq!(client.execute("SELECT 1", &[]).await.expect("reusable"), 1);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn execute_times_out_and_cancels_a_hung_statement() {
+    let (server, mut client) = connect().await;
+
+    let err = client
+        .execute(HUNG_QUERY, &[])
+        .await
+        .expect_err("hung statement must time out");
+    assert!(matches!(err, Error::CommandTimeout), "got {err:?}");
+
+    assert_eq!(server.attention_count().await, 1);
+    assert!(!client.is_in_flight());
+    assert_eq!(client.execute("SELECT 1", &[]).await.expect("reusable"), 1);
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,54 @@
+//! Behavior tests for `Client::reset_connection`.
+//!
+//! `reset_connection` resets the session eagerly by sending a request with
+//! the RESETCONNECTION status bit set (the TDS-level trigger for
+//! `sp_reset_connection`). These tests prove the bit actually reaches the
+//! server, exactly once per reset, against a mock TDS server.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_testing::mock_server::{MockTdsServer, connect};
+
+#[tokio::test]
+async fn reset_connection_sends_reset_bit_immediately() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let was_pending = client.reset_connection().await.expect("reset succeeds");
+    assert!(!was_pending);
+    assert_eq!(server.reset_request_count().await, 1);
+    assert!(
+        !client.needs_reset(),
+        "the reset must not linger as pending"
+    );
+
+    // The following request must not carry the bit again.
+    client.execute("SELECT 1", &[]).await.expect("execute");
+    assert_eq!(server.reset_request_count().await, 1);
+
+    let _ = client.close().await;
+}
+
+/// Control: `mark_needs_reset` defers the bit to the next request, so the
+/// counter proves `reset_connection` (not ordinary traffic) set it above.
+#[tokio::test]
+async fn mark_needs_reset_defers_to_next_request() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    client.mark_needs_reset();
+    assert_eq!(server.reset_request_count().await, 0);
+
+    client.execute("SELECT 1", &[]).await.expect("execute");
+    assert_eq!(server.reset_request_count().await, 1);
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,118 @@
+//! Behavior tests for CSV export (`RowStream::write_csv`,
+//! `QueryStream::write_csv`).
+//!
+//! Both paths write a header, quote fields that need it, and keep NULL
+//! distinguishable from an empty string.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{CsvOptions, CsvQuoting};
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+
+const EXPORT_SQL: &str = "SELECT id, name FROM things";
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(
+            EXPORT_SQL,
+            MockResponse::rows(
+                vec![
+                    MockColumn::int("id"),
+                    MockColumn::nvarchar("name", 50).with_nullable(true),
+                ],
+                vec![
+                    vec![ScalarValue::Int(1), ScalarValue::String("plain".into())],
+                    vec![ScalarValue::Int(2), ScalarValue::String("a, \"b\"".into())],
+                    vec![ScalarValue::Int(3), ScalarValue::String(String::new())],
+                    vec![ScalarValue::Int(4), ScalarValue::Null],
+                ],
+            ),
+        )
+        .build()
+        .await
+        .expect("server starts")
+}
+
+#[tokio::test]
+async fn row_stream_write_csv_streams_rfc4180() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let mut out = Vec::new();
+    let rows = client
+        .query_stream(EXPORT_SQL, &[])
+        .await
+        .unwrap()
+        .write_csv(&mut out, &CsvOptions::new())
+        .await
+        .expect("export");
+
+    assert_eq!(rows, 4);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "id,name\r\n1,plain\r\n2,\"a, \"\"b\"\"\"\r\n3,\r\n4,\r\n"
+    );
+
+    // The response was fully drained; the connection is clean.
+    assert!(!client.is_in_flight());
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn query_stream_write_csv_honors_options() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let options = CsvOptions::new()
+        .delimiter('|')
+        .quoting(CsvQuoting::NonNumeric)
+        .null("NULL")
+        .header(false)
+        .line_terminator("\n");
+    let mut out = Vec::new();
+    let rows = client
+        .query(EXPORT_SQL, &[])
+        .await
+        .unwrap()
+        .write_csv(&mut out, &options)
+        .await
+        .expect("export");
+
+    assert_eq!(rows, 4);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "1|\"plain\"\n2|\"a, \"\"b\"\"\"\n3|\"\"\n4|NULL\n"
+    );
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn write_csv_rejects_invalid_date_format_before_writing() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+    let options = CsvOptions::new().datetime_format("%Y-%Q");
+
+    let mut out = Vec::new();
+    let err = client
+        .query_stream(EXPORT_SQL, &[])
+        .await
+        .unwrap()
+        .write_csv(&mut out, &options)
+        .await
+        .expect_err("invalid format");
+    assert!(matches!(err, mssql_client::Error::Config(_)), "got {err:?}");
+    assert!(out.is_empty(), "nothing written");
+
+    let err = client
+        .query(EXPORT_SQL, &[])
+        .await
+        .unwrap()
+        .write_csv(&mut out, &options)
+        .await
+        .expect_err("invalid format");
+    assert!(matches!(err, mssql_client::Error::Config(_)), "got {err:?}");
+    assert!(out.is_empty(), "nothing written");
+    let _ = client.close().await;
+}
@@ -0,0 +1,87 @@
+//! Behavior tests for `Client::execute_batch` and `BatchErrorPolicy`.
+//!
+//! Under `StopOnError` the batch ends at the first failing statement; under
+//! `ContinueOnError` a server error is recorded and the remaining statements
+//! still run, giving one result per statement.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{BatchErrorPolicy, Error};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+const STATEMENTS: [&str; 3] = [
+    "UPDATE a SET x = 1",
+    "UPDATE missing SET x = 1",
+    "UPDATE b SET x = 1",
+];
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(STATEMENTS[0], MockResponse::affected(2))
+        .with_response(
+            STATEMENTS[1],
+            MockResponse::error(208, "Invalid object name 'missing'"),
+        )
+        .with_response(STATEMENTS[2], MockResponse::affected(3))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+fn assert_invalid_object(result: &Result<u64, Error>) {
+    assert!(
+        matches!(result, Err(Error::Server { number: 208, .. })),
+        "got {result:?}"
+    );
+}
+
+#[tokio::test]
+async fn stop_on_error_ends_at_first_failure() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let results = client
+        .execute_batch(&STATEMENTS, BatchErrorPolicy::StopOnError)
+        .await;
+    assert_eq!(results.len(), 2);
+    assert_eq!(*results[0].as_ref().unwrap(), 2);
+    assert_invalid_object(&results[1]);
+
+    // The connection is still usable after the failed statement.
+    assert_eq!(client.execute(STATEMENTS[2], &[]).await.unwrap(), 3);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn continue_on_error_runs_every_statement() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let results = client
+        .execute_batch(&STATEMENTS, BatchErrorPolicy::ContinueOnError)
+        .await;
+    assert_eq!(results.len(), 3);
+    assert_eq!(*results[0].as_ref().unwrap(), 2);
+    assert_invalid_object(&results[1]);
+    assert_eq!(*results[2].as_ref().unwrap(), 3);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn execute_batch_in_transaction() {
+    let server = start_server().await;
+    let client = connect(&server).await;
+
+    let mut tx = client.begin_transaction().await.expect("begin");
+    let results = tx
+        .execute_batch(&STATEMENTS, BatchErrorPolicy::ContinueOnError)
+        .await;
+    assert_eq!(results.len(), 3);
+    assert!(results[2].is_ok());
+    let client = tx.rollback().await.expect("rollback");
+
+    let _ = client.close().await;
+}
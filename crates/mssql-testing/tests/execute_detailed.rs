@@ -0,0 +1,73 @@
+//! Behavior tests for `Client::execute_detailed`.
+//!
+//! `execute_detailed` must keep the DONE token's count bit: a statement that
+//! matched zero rows (`DONE_COUNT` set, count 0) reports `Some(0)`, while a
+//! response with no count at all reports `None`. Plain `execute` collapses
+//! both to `0`.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+/// A final DONE token with the given status bits and row count.
+fn done(status: u16, row_count: u64) -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(status);
+    buf.put_u16_le(0xC5); // cur_cmd: UPDATE
+    buf.put_u64_le(row_count);
+    buf.freeze()
+}
+
+const DONE_FINAL: u16 = 0x0000;
+const DONE_COUNT: u16 = 0x0010;
+
+#[tokio::test]
+async fn execute_detailed_distinguishes_zero_match_from_no_count() {
+    let server = MockTdsServer::builder()
+        .with_response(
+            "UPDATE t SET x = 1 WHERE 1 = 0",
+            MockResponse::Raw(done(DONE_COUNT, 0)),
+        )
+        .with_response(
+            "CREATE TABLE t (x INT)",
+            MockResponse::Raw(done(DONE_FINAL, 0)),
+        )
+        .with_response("UPDATE t SET x = 1", MockResponse::affected(3))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let zero = client
+        .execute_detailed("UPDATE t SET x = 1 WHERE 1 = 0", &[])
+        .await
+        .expect("execute");
+    assert_eq!(zero.rows_affected, Some(0), "count bit set with 0 rows");
+
+    let none = client
+        .execute_detailed("CREATE TABLE t (x INT)", &[])
+        .await
+        .expect("execute");
+    assert_eq!(none.rows_affected, None, "no count bit means no count");
+
+    let three = client
+        .execute_detailed("UPDATE t SET x = 1", &[])
+        .await
+        .expect("execute");
+    assert_eq!(three.rows_affected, Some(3));
+
+    // `execute` keeps its collapsed u64 contract.
+    assert_eq!(
+        client
+            .execute("CREATE TABLE t (x INT)", &[])
+            .await
+            .expect("execute"),
+        0
+    );
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,130 @@
+//! Behavior tests for `Client::execute_returning`.
+//!
+//! A `DELETE ... OUTPUT DELETED.*` response carries both the OUTPUT rows and
+//! a DONE row count. `execute_returning` must hand back both; a response
+//! without a count (`SET NOCOUNT ON`) reports `0` alongside the rows.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{Error, FromRow, Row};
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+
+#[derive(Debug, PartialEq)]
+struct Deleted {
+    id: i32,
+}
+
+impl FromRow for Deleted {
+    fn from_row(row: &Row) -> Result<Self, Error> {
+        Ok(Self { id: row.get(0)? })
+    }
+}
+
+/// `OUTPUT DELETED.id` with one row (`id = 9`) and a final DONE carrying no
+/// count, as sent under `SET NOCOUNT ON`.
+fn nocount_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(1);
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(2);
+    for unit in "id".encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+
+    buf.put_u8(0xD1); // ROW
+    buf.put_u8(4);
+    buf.put_i32_le(9);
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0x0000); // final, no count
+    buf.put_u16_le(0xC4); // cur_cmd: DELETE
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(
+            "DELETE FROM jobs OUTPUT DELETED.id",
+            MockResponse::rows(
+                vec![MockColumn::int("id")],
+                vec![vec![ScalarValue::Int(3)], vec![ScalarValue::Int(5)]],
+            ),
+        )
+        .with_response(
+            "SET NOCOUNT ON; DELETE FROM jobs OUTPUT DELETED.id",
+            MockResponse::Raw(nocount_response()),
+        )
+        .build()
+        .await
+        .expect("server starts")
+}
+
+#[tokio::test]
+async fn execute_returning_keeps_rows_and_count() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let (rows, count): (Vec<Deleted>, u64) = client
+        .execute_returning("DELETE FROM jobs OUTPUT DELETED.id", &[])
+        .await
+        .expect("execute_returning");
+    assert_eq!(rows, vec![Deleted { id: 3 }, Deleted { id: 5 }]);
+    assert_eq!(count, 2);
+
+    // The buffered query stream exposes the same count.
+    let stream = client
+        .query("DELETE FROM jobs OUTPUT DELETED.id", &[])
+        .await
+        .expect("query");
+    assert_eq!(stream.rows_affected(), Some(2));
+    drop(stream);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn execute_returning_reports_zero_without_count() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let (rows, count): (Vec<Deleted>, u64) = client
+        .execute_returning("SET NOCOUNT ON; DELETE FROM jobs OUTPUT DELETED.id", &[])
+        .await
+        .expect("execute_returning");
+    assert_eq!(rows, vec![Deleted { id: 9 }]);
+    assert_eq!(count, 0);
+
+    let stream = client
+        .query("SET NOCOUNT ON; DELETE FROM jobs OUTPUT DELETED.id", &[])
+        .await
+        .expect("query");
+    assert_eq!(stream.rows_affected(), None);
+    drop(stream);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn execute_returning_in_transaction() {
+    let server = start_server().await;
+    let client = connect(&server).await;
+
+    let mut tx = client.begin_transaction().await.expect("begin");
+    let (rows, count): (Vec<Deleted>, u64) = tx
+        .execute_returning("DELETE FROM jobs OUTPUT DELETED.id", &[])
+        .await
+        .expect("execute_returning");
+    assert_eq!(rows.len(), 2);
+    assert_eq!(count, 2);
+    let client = tx.rollback().await.expect("rollback");
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,45 @@
+//! Behavior tests for LOGIN7 feature-extension acknowledgement.
+//!
+//! The client requests UTF-8 support on every TDS 7.4 login. A SQL Server
+//! 2019+ server acknowledges it in a FEATUREEXTACK token, which surfaces via
+//! `Client::enabled_features`; older servers send no acknowledgement, and the
+//! login must still succeed because UTF-8 support is optional.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Client, FeatureId};
+use mssql_testing::mock_server::{MockTdsServer, client_config, connect};
+
+#[tokio::test]
+async fn utf8_support_ack_is_recorded() {
+    let server = MockTdsServer::builder()
+        .with_utf8_support()
+        .build()
+        .await
+        .expect("server starts");
+    let client = connect(&server).await;
+
+    let features = client.enabled_features();
+    assert!(features.contains(FeatureId::Utf8Support));
+    assert!(features.utf8_support());
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn missing_utf8_ack_does_not_fail_login() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let client = Client::connect(client_config(server.port()))
+        .await
+        .expect("login succeeds without a UTF-8 acknowledgement");
+
+    assert!(client.enabled_features().is_empty());
+    assert!(!client.enabled_features().utf8_support());
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,227 @@
+//! Behavior tests for `Config::retry_first_query`.
+//!
+//! During an Azure SQL failover the gateway can accept a login and then fail
+//! the first request with error 40613 ("database not currently available").
+//! With `Retry First Query=true` the client reconnects once and re-runs that
+//! first request; later requests and clients without the option surface the
+//! error unchanged. The mock server fails the first matching batch, so each
+//! test can count reconnects via `total_connection_count`. The reconnect
+//! closes the replaced connection without the drop-without-close warning.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use mssql_client::{Client, Config, Error};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, client_config};
+
+/// Fails the first `failures` executions of the batch with error 40613,
+/// then answers with `SELECT 1`'s scalar.
+fn flaky(failures: usize) -> MockResponse {
+    let calls = Arc::new(AtomicUsize::new(0));
+    MockResponse::Custom(Arc::new(move |_sql| {
+        if calls.fetch_add(1, Ordering::SeqCst) < failures {
+            MockResponse::error(40613, "Database is not currently available.")
+        } else {
+            MockResponse::scalar_int(1)
+        }
+    }))
+}
+
+fn mock_config(port: u16, retry_first_query: bool) -> Config {
+    client_config(port).with_first_query_retry(retry_first_query)
+}
+
+#[tokio::test]
+async fn first_query_transient_error_reconnects_and_retries() {
+    let server = MockTdsServer::builder()
+        .with_response("SELECT 1", flaky(1))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = Client::connect(mock_config(server.port(), true))
+        .await
+        .expect("connect");
+
+    let rows = client
+        .query("SELECT 1", &[])
+        .await
+        .expect("first query is retried")
+        .collect_all()
+        .await
+        .expect("rows");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(
+        server.total_connection_count().await,
+        2,
+        "exactly one reconnect"
+    );
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn only_the_first_query_is_retried() {
+    let server = MockTdsServer::builder()
+        .with_response("UPDATE t SET x = 1", flaky(1))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = Client::connect(mock_config(server.port(), true))
+        .await
+        .expect("connect");
+
+    client
+        .execute("SELECT 2", &[])
+        .await
+        .expect("first request");
+    let err = client
+        .execute("UPDATE t SET x = 1", &[])
+        .await
+        .expect_err("a later transient error must surface");
+    assert!(
+        matches!(err, Error::Server { number: 40613, .. }),
+        "got {err:?}"
+    );
+    assert_eq!(server.total_connection_count().await, 1, "no reconnect");
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn first_query_is_not_retried_when_disabled() {
+    let server = MockTdsServer::builder()
+        .with_response("SELECT 1", flaky(1))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = Client::connect(mock_config(server.port(), false))
+        .await
+        .expect("connect");
+
+    let err = client
+        .execute("SELECT 1", &[])
+        .await
+        .expect_err("the error must surface");
+    assert!(
+        matches!(err, Error::Server { number: 40613, .. }),
+        "got {err:?}"
+    );
+    assert_eq!(server.total_connection_count().await, 1, "no reconnect");
+
+    let _ = client.close().await;
+}
+
+/// The retry happens once: if the request fails again on the fresh
+/// connection, that error is returned.
+#[tokio::test]
+async fn first_query_retry_happens_once() {
+    let server = MockTdsServer::builder()
+        .with_response("SELECT 1", flaky(2))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = Client::connect(mock_config(server.port(), true))
+        .await
+        .expect("connect");
+
+    let err = client
+        .execute("SELECT 1", &[])
+        .await
+        .expect_err("the second failure must surface");
+    assert!(
+        matches!(err, Error::Server { number: 40613, .. }),
+        "got {err:?}"
+    );
+    assert_eq!(server.total_connection_count().await, 2);
+
+    let _ = client.close().await;
+}
+
+/// The `SET XACT_ABORT ON` batch sent after login is the driver's own, so
+/// the caller's first query is still retried.
+#[tokio::test]
+async fn first_query_is_retried_with_xact_abort() {
+    let server = MockTdsServer::builder()
+        .with_response("SELECT 1", flaky(1))
+        .build()
+        .await
+        .expect("server starts");
+    let config = mock_config(server.port(), true).xact_abort(true);
+    let mut client = Client::connect(config).await.expect("connect");
+
+    client
+        .execute("SELECT 1", &[])
+        .await
+        .expect("first query is retried");
+    assert_eq!(
+        server.total_connection_count().await,
+        2,
+        "exactly one reconnect"
+    );
+    let batches = server.sql_batches().await;
+    assert_eq!(
+        batches
+            .iter()
+            .filter(|sql| sql.as_str() == "SET XACT_ABORT ON")
+            .count(),
+        2,
+        "the option is applied on both connections"
+    );
+
+    let _ = client.close().await;
+}
+
+/// Log output captured from a `tracing` subscriber.
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The reconnect replaces the client in place. The discarded connection must
+/// not warn about being dropped without `close()`, and a pool-owned client
+/// stays pool-owned, so the pool discarding it later does not warn either.
+#[tokio::test]
+async fn first_query_reconnect_does_not_warn_about_drop() {
+    let captured = Captured::default();
+    let writer = captured.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let server = MockTdsServer::builder()
+        .with_response("SELECT 1", flaky(1))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = Client::connect(mock_config(server.port(), true))
+        .await
+        .expect("connect");
+    client.set_pooled(true);
+
+    client
+        .execute("SELECT 1", &[])
+        .await
+        .expect("first query is retried");
+    assert_eq!(server.total_connection_count().await, 2);
+    drop(client);
+
+    let logs = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    assert!(logs.contains("reconnecting once to retry"), "{logs}");
+    assert!(!logs.contains("dropped without close()"), "{logs}");
+}
@@ -0,0 +1,42 @@
+//! Behavior tests for `Client::is_encrypted`.
+//!
+//! A connection upgraded to TLS reports `true`; an `Encrypt=no_tls`
+//! connection reports `false`.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Client, Config};
+use mssql_testing::mock_server::{MockTdsServer, connect, connection_string};
+
+#[tokio::test]
+async fn plain_connection_is_not_encrypted() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let client = connect(&server).await;
+
+    assert!(!client.is_encrypted());
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn tls_connection_is_encrypted() {
+    mssql_testing::tls::ensure_crypto_provider_for_test();
+    let server = MockTdsServer::builder()
+        .with_tls()
+        .build()
+        .await
+        .expect("server starts");
+    let config = Config::from_connection_string(&format!(
+        "{};Encrypt=true;TrustServerCertificate=true",
+        connection_string(server.port())
+    ))
+    .expect("config parses");
+    let client = Client::connect(config).await.expect("connect");
+
+    assert!(client.is_encrypted());
+    let _ = client.close().await;
+}
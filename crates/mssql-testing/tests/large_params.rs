@@ -0,0 +1,82 @@
+//! Behavior tests for parameters too large for NVARCHAR(n)/VARBINARY(n).
+//!
+//! A string longer than 4000 UTF-16 code units or a binary value longer than
+//! 8000 bytes is declared as `nvarchar(max)`/`varbinary(max)` in the
+//! `sp_executesql` parameter list and sent as a single-chunk PLP value, so it
+//! binds whole instead of being truncated or rejected.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::Buf;
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+const INSERT_DOC: &str = "INSERT INTO dbo.docs (body) VALUES (@p1)";
+const SIZE: usize = 100 * 1024;
+
+fn utf16(s: &str) -> Vec<u8> {
+    s.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// The value of the PLP parameter whose TYPE_INFO starts at `at`: checks the
+/// single-chunk framing (total length, one chunk, terminator) and returns the
+/// chunk.
+fn plp_value(rpc: &[u8], at: usize) -> &[u8] {
+    let mut buf = &rpc[at..];
+    let total = buf.get_u64_le() as usize;
+    assert_eq!(buf.get_u32_le() as usize, total, "sent as one chunk");
+    let (value, mut rest) = buf.split_at(total);
+    assert_eq!(rest.get_u32_le(), 0, "PLP terminator");
+    value
+}
+
+async fn start() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_default_response(MockResponse::affected(1))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+#[tokio::test]
+async fn large_string_binds_as_nvarchar_max() {
+    let server = start().await;
+    let mut client = connect(&server).await;
+
+    let doc = format!("{{\"data\":\"{}\"}}", "x".repeat(SIZE));
+    let affected = client.execute(INSERT_DOC, &[&doc]).await.expect("execute");
+    assert_eq!(affected, 1);
+
+    let rpcs = server.rpc_requests().await;
+    let rpc = &rpcs[0];
+    assert!(find(rpc, &utf16("@p1 nvarchar(max)")).is_some());
+
+    // NVARCHAR, MAX length, then the 5-byte collation before the value.
+    let type_info = find(rpc, &[0xE7, 0xFF, 0xFF]).expect("NVARCHAR(MAX) TYPE_INFO");
+    assert_eq!(plp_value(rpc, type_info + 3 + 5), &utf16(&doc)[..]);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn large_binary_binds_as_varbinary_max() {
+    let server = start().await;
+    let mut client = connect(&server).await;
+
+    let blob: Vec<u8> = (0..SIZE).map(|i| (i % 251) as u8).collect();
+    client.execute(INSERT_DOC, &[&blob]).await.expect("execute");
+
+    let rpcs = server.rpc_requests().await;
+    let rpc = &rpcs[0];
+    assert!(find(rpc, &utf16("@p1 varbinary(max)")).is_some());
+
+    let type_info = find(rpc, &[0xA5, 0xFF, 0xFF]).expect("VARBINARY(MAX) TYPE_INFO");
+    assert_eq!(plp_value(rpc, type_info + 3), &blob[..]);
+    let _ = client.close().await;
+}
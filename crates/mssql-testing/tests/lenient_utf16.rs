@@ -0,0 +1,113 @@
+//! Behavior tests for `Config::lenient_utf16`.
+//!
+//! SQL Server stores NVARCHAR values containing unpaired surrogates. By
+//! default such a value fails the row with an error naming the column and the
+//! byte offset; with lenient decoding it becomes U+FFFD. Both the buffered and
+//! the streaming read paths honor the setting.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::Client;
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, client_config};
+
+/// `SELECT comment` with one NVARCHAR(50) row holding `"a"`, a lone high
+/// surrogate, then `"b"`.
+fn response() -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(1);
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0xE7); // NVARCHAR
+    buf.put_u16_le(100); // max length in bytes
+    buf.put_slice(&[0x09, 0x04, 0xD0, 0x00, 0x34]); // collation
+    buf.put_u8(7);
+    for unit in "comment".encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+
+    buf.put_u8(0xD1); // ROW
+    buf.put_u16_le(6);
+    buf.put_u16_le(u16::from(b'a'));
+    buf.put_u16_le(0xD83D);
+    buf.put_u16_le(u16::from(b'b'));
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0x0010); // count
+    buf.put_u16_le(0xC1); // SELECT
+    buf.put_u64_le(1);
+    buf.freeze()
+}
+
+async fn connect(server: &MockTdsServer, lenient: bool) -> Client<mssql_client::Ready> {
+    let config = client_config(server.port()).lenient_utf16(lenient);
+    Client::connect(config).await.expect("connect")
+}
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_default_response(MockResponse::Raw(response()))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+#[tokio::test]
+async fn strict_decoding_names_column_and_offset() {
+    let server = start_server().await;
+    let mut client = connect(&server, false).await;
+
+    let err = client
+        .query("SELECT comment FROM t", &[])
+        .await
+        .expect("rows decode lazily")
+        .next()
+        .expect("one row")
+        .expect_err("lone surrogate must fail in strict mode");
+    let msg = err.to_string();
+    assert!(msg.contains("'comment'"), "got {msg}");
+    assert!(msg.contains("byte offset 2"), "got {msg}");
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn lenient_decoding_replaces_lone_surrogate_in_buffered_query() {
+    let server = start_server().await;
+    let mut client = connect(&server, true).await;
+
+    let rows: Vec<_> = client
+        .query("SELECT comment FROM t", &[])
+        .await
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<String>(0).unwrap(), "a\u{FFFD}b");
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn lenient_decoding_replaces_lone_surrogate_in_streamed_query() {
+    let server = start_server().await;
+    let mut client = connect(&server, true).await;
+
+    let mut rows = client
+        .query_stream("SELECT comment FROM t", &[])
+        .await
+        .expect("query");
+    let row = rows
+        .try_next()
+        .await
+        .expect("row decodes")
+        .expect("one row");
+    assert_eq!(row.get::<String>(0).unwrap(), "a\u{FFFD}b");
+    assert!(rows.try_next().await.expect("drains").is_none());
+    drop(rows);
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,91 @@
+//! Behavior tests for how login-phase server errors are classified.
+//!
+//! A rejected password (18456) surfaces as `Error::Authentication` and is not
+//! retried; a database that cannot be opened (4060) surfaces as
+//! `Error::DatabaseUnavailable` and goes through the connect-retry loop like
+//! any other transient failure. Other login errors stay `Error::Server`.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Client, Config, Error};
+use mssql_testing::mock_server::{MockTdsServer, connection_string};
+use tds_protocol::ServerError;
+
+fn login_error(number: i32, class: u8, message: &str) -> ServerError {
+    ServerError {
+        number,
+        state: 1,
+        class,
+        message: message.to_string(),
+        server: "MockSQLServer".to_string(),
+        procedure: String::new(),
+        line: 1,
+    }
+}
+
+/// Connect with up to two retries and return the error along with the
+/// number of connections the client opened.
+async fn connect_err(error: ServerError) -> (Error, usize) {
+    let server = MockTdsServer::builder()
+        .with_login_error(error)
+        .build()
+        .await
+        .expect("server starts");
+    let config = Config::from_connection_string(&format!(
+        "{};ConnectRetryCount=2;ConnectRetryInterval=0",
+        connection_string(server.port())
+    ))
+    .expect("config parses");
+    let err = Client::connect(config).await.expect_err("login must fail");
+    (err, server.total_connection_count().await)
+}
+
+#[tokio::test]
+async fn login_failed_is_authentication_and_not_retried() {
+    let (err, connections) =
+        connect_err(login_error(18456, 14, "Login failed for user 'sa'.")).await;
+
+    assert!(
+        matches!(err, Error::Authentication(_)),
+        "expected Authentication, got: {err:?}"
+    );
+    assert!(err.to_string().contains("Login failed for user 'sa'."));
+    assert_eq!(connections, 1, "a bad password is not worth retrying");
+}
+
+#[tokio::test]
+async fn cannot_open_database_is_database_unavailable_and_retried() {
+    let (err, connections) = connect_err(login_error(
+        4060,
+        11,
+        "Cannot open database \"sales\" requested by the login. The login failed.",
+    ))
+    .await;
+
+    match &err {
+        Error::DatabaseUnavailable {
+            number, message, ..
+        } => {
+            assert_eq!(*number, 4060);
+            assert!(message.contains("\"sales\""));
+        }
+        other => panic!("expected DatabaseUnavailable, got: {other:?}"),
+    }
+    assert!(err.is_server_error(4060));
+    assert_eq!(err.class(), Some(11));
+    assert_eq!(connections, 3, "initial attempt plus two retries");
+}
+
+#[tokio::test]
+async fn other_login_errors_stay_server_errors() {
+    let (err, _) = connect_err(login_error(
+        18401,
+        14,
+        "Login failed for user 'sa'. Reason: Server is in script upgrade mode.",
+    ))
+    .await;
+
+    assert!(err.is_server_error(18401), "got: {err:?}");
+}
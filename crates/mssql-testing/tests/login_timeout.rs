@@ -0,0 +1,46 @@
+//! Behavior tests for `TimeoutConfig::login_timeout`.
+//!
+//! A server that accepts the TCP connection but never answers the PreLogin
+//! must not hang `Client::connect`: the whole attempt is bounded by
+//! `login_timeout` and fails with `Error::LoginTimeout`.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::time::{Duration, Instant};
+
+use mssql_client::{Client, Error, TimeoutConfig};
+use mssql_testing::mock_server::client_config;
+use tokio::net::TcpListener;
+
+#[tokio::test]
+async fn silent_server_fails_with_login_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    // Accept and hold the socket without ever writing to it.
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        drop(stream);
+    });
+
+    let config = client_config(port)
+        .timeouts(TimeoutConfig::new().login_timeout(Duration::from_millis(300)));
+
+    let started = Instant::now();
+    let err = Client::connect(config)
+        .await
+        .expect_err("a silent server must not produce a connection");
+    assert!(
+        matches!(err, Error::LoginTimeout { port: p, .. } if p == port),
+        "got {err:?}"
+    );
+    assert!(
+        started.elapsed() < Duration::from_secs(5),
+        "connect took {:?}",
+        started.elapsed()
+    );
+
+    server.abort();
+}
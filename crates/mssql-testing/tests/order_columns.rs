@@ -0,0 +1,90 @@
+//! Behavior tests for `QueryStream::order_columns`.
+//!
+//! The ORDER token that precedes the rows of an ordered result names its
+//! sort-key columns by 1-based number. The stream reports them as zero-based
+//! column indexes, and reports `None` when the server sent no ORDER token.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+
+const ORDERED: &str = "SELECT id, region FROM dbo.sales ORDER BY region, id";
+const UNORDERED: &str = "SELECT id, region FROM dbo.sales";
+
+fn put_int_column(buf: &mut BytesMut, name: &str) {
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(name.len() as u8);
+    for unit in name.encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+}
+
+/// Two INT columns ordered by `region` then `id`, and one row.
+fn ordered_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(2);
+    put_int_column(&mut buf, "id");
+    put_int_column(&mut buf, "region");
+
+    buf.put_u8(0xA9); // ORDER
+    buf.put_u16_le(4);
+    buf.put_u16_le(2); // region
+    buf.put_u16_le(1); // id
+
+    buf.put_u8(0xD1); // ROW
+    buf.put_u8(4);
+    buf.put_i32_le(10);
+    buf.put_u8(4);
+    buf.put_i32_le(3);
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0x0010); // count
+    buf.put_u16_le(0xC1); // cur_cmd: SELECT
+    buf.put_u64_le(1);
+    buf.freeze()
+}
+
+#[tokio::test]
+async fn order_token_is_reported_as_zero_based_columns() {
+    let server = MockTdsServer::builder()
+        .with_response(ORDERED, MockResponse::Raw(ordered_response()))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let stream = client.query(ORDERED, &[]).await.expect("query");
+    assert_eq!(stream.order_columns(), Some(&[1, 0][..]));
+
+    let rows = stream.collect_all().await.expect("rows decode");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<i32>(1).unwrap(), 3);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn result_without_order_token_reports_none() {
+    let server = MockTdsServer::builder()
+        .with_response(
+            UNORDERED,
+            MockResponse::rows(
+                vec![MockColumn::int("id"), MockColumn::int("region")],
+                vec![vec![ScalarValue::Int(10), ScalarValue::Int(3)]],
+            ),
+        )
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let stream = client.query(UNORDERED, &[]).await.expect("query");
+    assert_eq!(stream.order_columns(), None);
+    let _ = client.close().await;
+}
@@ -10,8 +10,8 @@
 
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
-use mssql_client::{Client, Config, metric_names};
-use mssql_testing::mock_server::{MockResponse, MockTdsServer};
+use mssql_client::metric_names;
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
 use opentelemetry::global;
 use opentelemetry_sdk::metrics::data::AggregatedMetrics;
 use opentelemetry_sdk::metrics::{InMemoryMetricExporter, PeriodicReader, SdkMeterProvider};
@@ -32,13 +32,7 @@ async fn test_query_metrics_emit_real_values() {
         .await
         .expect("mock starts");
 
-    let config = Config::from_connection_string(&format!(
-        "Server=127.0.0.1,{};User Id=sa;Password=t;Encrypt=no_tls;ConnectRetryCount=0",
-        server.port()
-    ))
-    .expect("config parses");
-
-    let mut client = Client::connect(config).await.expect("connect");
+    let mut client = connect(&server).await;
     for _ in 0..3 {
         let rows = client.query("SELECT 7", &[]).await.expect("query");
         assert_eq!(rows.into_iter().count(), 1);
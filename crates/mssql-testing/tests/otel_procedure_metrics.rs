@@ -15,8 +15,8 @@
 
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
-use mssql_client::{Client, Config, metric_names};
-use mssql_testing::mock_server::MockTdsServer;
+use mssql_client::metric_names;
+use mssql_testing::mock_server::{MockTdsServer, connect};
 use opentelemetry::global;
 use opentelemetry_sdk::metrics::data::AggregatedMetrics;
 use opentelemetry_sdk::metrics::{InMemoryMetricExporter, PeriodicReader, SdkMeterProvider};
@@ -32,13 +32,7 @@ async fn test_procedure_and_named_paths_emit_metrics() {
 
     let server = MockTdsServer::builder().build().await.expect("mock starts");
 
-    let config = Config::from_connection_string(&format!(
-        "Server=127.0.0.1,{};User Id=sa;Password=t;Encrypt=no_tls;ConnectRetryCount=0",
-        server.port()
-    ))
-    .expect("config parses");
-
-    let mut client = Client::connect(config).await.expect("connect");
+    let mut client = connect(&server).await;
 
     // Each call exercises a distinct newly-instrumented path. The mock returns
     // its default (empty) response to all of them.
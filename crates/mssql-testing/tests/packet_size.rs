@@ -0,0 +1,65 @@
+//! Behavior tests for packet size negotiation.
+//!
+//! The client requests `Config::packet_size` in LOGIN7, and then splits every
+//! request at the size the server granted in its PacketSize ENVCHANGE, which
+//! may be larger or smaller than the one requested.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Client, Config};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connection_string};
+
+fn mock_config(port: u16, extra: &str) -> Config {
+    Config::from_connection_string(&format!("{};{extra}", connection_string(port)))
+        .expect("config parses")
+}
+
+/// A batch far larger than any packet size, so it spans several packets.
+fn large_batch() -> String {
+    format!("SELECT '{}'", "x".repeat(20_000))
+}
+
+#[tokio::test]
+async fn requests_use_the_packet_size_granted_by_the_server() {
+    let server = MockTdsServer::builder()
+        .with_packet_size(8192)
+        .with_default_response(MockResponse::affected(0))
+        .build()
+        .await
+        .expect("server starts");
+
+    let mut client = Client::connect(mock_config(server.port(), ""))
+        .await
+        .expect("connect");
+    assert_eq!(client.packet_size(), 8192);
+
+    client.execute(&large_batch(), &[]).await.unwrap();
+    assert_eq!(server.largest_request_packet().await, 8192);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn requested_packet_size_is_sent_in_login7_and_capped_by_the_server() {
+    let server = MockTdsServer::builder()
+        .with_default_response(MockResponse::affected(0))
+        .build()
+        .await
+        .expect("server starts");
+
+    let mut client = Client::connect(mock_config(server.port(), "PacketSize=16384"))
+        .await
+        .expect("connect");
+
+    // LOGIN7 fixed header: Length (4), TDSVersion (4), PacketSize (4).
+    let login = &server.login_requests().await[0];
+    let requested = u32::from_le_bytes(login[8..12].try_into().unwrap());
+    assert_eq!(requested, 16384);
+
+    // The mock grants its default 4096, which wins over the request.
+    assert_eq!(client.packet_size(), 4096);
+    client.execute(&large_batch(), &[]).await.unwrap();
+    assert_eq!(server.largest_request_packet().await, 4096);
+    let _ = client.close().await;
+}
@@ -0,0 +1,136 @@
+//! Behavior tests for MAX (PLP) values that span several TDS packets.
+//!
+//! The mock server splits responses at its 4096-byte packet size, so a large
+//! `VARBINARY(MAX)` cell arrives with its length prefix, chunk headers, and
+//! chunk bodies straddling packet boundaries. The blob stream must pull more
+//! packets mid-value and hand out pieces as they arrive; the row-streaming and
+//! buffered paths must reassemble the same bytes.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::Client;
+use mssql_testing::mock_server::{self, MockResponse, MockTdsServer};
+
+/// PLP chunk sizes for the blob: a chunk larger than a whole packet, then
+/// odd-sized chunks whose headers land at arbitrary offsets.
+const CHUNKS: [usize; 4] = [10_000, 3_001, 1, 6_998];
+
+fn put_name(buf: &mut BytesMut, name: &str) {
+    let utf16: Vec<u16> = name.encode_utf16().collect();
+    buf.put_u8(utf16.len() as u8);
+    for unit in utf16 {
+        buf.put_u16_le(unit);
+    }
+}
+
+/// The expected blob: byte `i` is `i % 251`, so misplaced slices are caught.
+fn blob() -> Vec<u8> {
+    let len: usize = CHUNKS.iter().sum();
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+/// `SELECT id, doc` with one row: `id INT`, `doc VARBINARY(MAX)`.
+fn response() -> Bytes {
+    let mut buf = BytesMut::new();
+
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(2);
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    put_name(&mut buf, "id");
+    buf.put_u32_le(0);
+    buf.put_u16_le(0x0001);
+    buf.put_u8(0xA5); // BIGVARBINARY
+    buf.put_u16_le(0xFFFF); // MAX
+    put_name(&mut buf, "doc");
+
+    let data = blob();
+    buf.put_u8(0xD1); // ROW
+    buf.put_u8(4);
+    buf.put_i32_le(7);
+    buf.put_u64_le(data.len() as u64);
+    let mut offset = 0;
+    for len in CHUNKS {
+        buf.put_u32_le(len as u32);
+        buf.extend_from_slice(&data[offset..offset + len]);
+        offset += len;
+    }
+    buf.put_u32_le(0); // PLP terminator
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0x0010); // count
+    buf.put_u16_le(0xC1); // SELECT
+    buf.put_u64_le(1);
+    buf.freeze()
+}
+
+async fn connect() -> (MockTdsServer, Client<mssql_client::Ready>) {
+    let server = MockTdsServer::builder()
+        .with_default_response(MockResponse::Raw(response()))
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+#[tokio::test]
+async fn blob_stream_pulls_packets_mid_value() {
+    let (_server, mut client) = connect().await;
+
+    let mut stream = client
+        .query_stream_blob("SELECT id, doc FROM files", &[])
+        .await
+        .expect("stream");
+    let row = stream.next().await.expect("next").expect("one row");
+    assert_eq!(row.get::<i32>(0).unwrap(), 7);
+
+    let mut received = Vec::new();
+    let mut largest_piece = 0;
+    while let Some(piece) = stream.read_chunk().await.expect("read_chunk") {
+        largest_piece = largest_piece.max(piece.len());
+        received.extend_from_slice(&piece);
+    }
+    assert_eq!(received, blob());
+    assert_eq!(stream.blob_len(), Some(received.len() as u64));
+    // The 10 000-byte chunk spans packets, so it is handed out as it arrives
+    // rather than after reassembling the whole chunk.
+    assert!(
+        largest_piece < 4096,
+        "largest piece was {largest_piece} bytes"
+    );
+
+    assert!(stream.next().await.expect("next").is_none());
+    drop(stream);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn row_stream_and_buffered_query_reassemble_value() {
+    let (_server, mut client) = connect().await;
+
+    let mut rows = client
+        .query_stream("SELECT id, doc FROM files", &[])
+        .await
+        .expect("query_stream");
+    let row = rows.try_next().await.expect("next").expect("one row");
+    assert_eq!(row.get::<Vec<u8>>(1).unwrap(), blob());
+    assert!(rows.try_next().await.expect("next").is_none());
+    drop(rows);
+
+    let rows: Vec<_> = client
+        .query("SELECT id, doc FROM files", &[])
+        .await
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<Vec<u8>>(1).unwrap(), blob());
+
+    let _ = client.close().await;
+}
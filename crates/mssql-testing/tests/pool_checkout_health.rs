@@ -0,0 +1,137 @@
+//! Behavior tests for the pool's checkout health check.
+//!
+//! `Pool::get()` pings idle connections before handing them out, bounded by
+//! `PoolConfig::health_check_timeout` so a half-open socket can't hang the
+//! caller. A connection that fails is discarded and the next idle one tried,
+//! falling back to a fresh connection, up to
+//! `PoolConfig::max_checkout_health_failures` failures per checkout.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use mssql_driver_pool::{Pool, PoolConfig, PoolError};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, client_config};
+
+const CHECK_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// A server whose health check pings are counted and, once `hang` is set,
+/// never answered, as on a connection whose peer has silently gone away.
+struct Server {
+    server: MockTdsServer,
+    pings: Arc<AtomicUsize>,
+    hang: Arc<AtomicBool>,
+}
+
+async fn start_server() -> Server {
+    let pings = Arc::new(AtomicUsize::new(0));
+    let hang = Arc::new(AtomicBool::new(false));
+    let (counter, hanging) = (Arc::clone(&pings), Arc::clone(&hang));
+    let server = MockTdsServer::builder()
+        .with_default_response(MockResponse::Custom(Arc::new(move |sql| {
+            if sql.trim() != "SELECT 1" {
+                return MockResponse::empty();
+            }
+            counter.fetch_add(1, Ordering::SeqCst);
+            if hanging.load(Ordering::SeqCst) {
+                MockResponse::Unfinished(Bytes::new())
+            } else {
+                MockResponse::scalar_int(1)
+            }
+        })))
+        .build()
+        .await
+        .expect("server starts");
+    Server {
+        server,
+        pings,
+        hang,
+    }
+}
+
+fn pool_config(connections: u32) -> PoolConfig {
+    PoolConfig::new()
+        .min_connections(connections)
+        .max_connections(connections)
+        .health_check_timeout(CHECK_TIMEOUT)
+}
+
+#[tokio::test]
+async fn hung_idle_connection_is_replaced_on_checkout() {
+    let server = start_server().await;
+    let pool = Pool::new(pool_config(1), client_config(server.server.port()))
+        .await
+        .expect("pool builds");
+    assert_eq!(pool.metrics().connections_created, 1);
+
+    server.hang.store(true, Ordering::SeqCst);
+    let started = Instant::now();
+    let conn = pool.get().await.expect("checkout recovers");
+    assert!(
+        started.elapsed() < CHECK_TIMEOUT * 5,
+        "checkout waited {:?}",
+        started.elapsed()
+    );
+
+    let metrics = pool.metrics();
+    assert_eq!(metrics.health_checks_failed, 1);
+    assert_eq!(metrics.connections_closed, 1, "hung connection discarded");
+    assert_eq!(metrics.connections_created, 2, "fresh connection opened");
+    drop(conn);
+
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn checkout_gives_up_after_max_health_failures() {
+    let server = start_server().await;
+    let pool = Pool::new(
+        pool_config(3).max_checkout_health_failures(2),
+        client_config(server.server.port()),
+    )
+    .await
+    .expect("pool builds");
+    assert_eq!(pool.status().available, 3);
+
+    server.hang.store(true, Ordering::SeqCst);
+    let err = pool.get().await.err().expect("too many failed checks");
+    assert!(
+        matches!(err, PoolError::UnhealthyConnection(_)),
+        "got {err:?}"
+    );
+
+    assert_eq!(server.pings.load(Ordering::SeqCst), 2);
+    let metrics = pool.metrics();
+    assert_eq!(metrics.health_checks_failed, 2);
+    assert_eq!(metrics.connections_created, 3, "no connection opened");
+    assert_eq!(pool.status().available, 1, "untested connection kept");
+
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn recently_used_connection_skips_the_checkout_check() {
+    let server = start_server().await;
+    let idle_threshold = Duration::from_millis(100);
+    let pool = Pool::new(
+        pool_config(1).test_on_checkout_after_idle(idle_threshold),
+        client_config(server.server.port()),
+    )
+    .await
+    .expect("pool builds");
+
+    drop(pool.get().await.expect("checkout"));
+    assert_eq!(server.pings.load(Ordering::SeqCst), 0, "fresh connection");
+
+    tokio::time::sleep(idle_threshold * 2).await;
+    drop(pool.get().await.expect("checkout"));
+    assert_eq!(server.pings.load(Ordering::SeqCst), 1, "idle connection");
+    assert_eq!(pool.metrics().health_checks_performed, 1);
+
+    pool.close().await;
+}
@@ -0,0 +1,111 @@
+//! Behavior tests for `Pool::get` under contention.
+//!
+//! Checkouts queue on a FIFO semaphore: when the pool is saturated, waiters
+//! are served in arrival order as connections come back, and a waiter that
+//! is not served within `connection_timeout` gets `PoolError::Timeout`.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use mssql_driver_pool::{Pool, PoolConfig, PoolError};
+use mssql_testing::mock_server::{MockTdsServer, client_config};
+use tokio::sync::Mutex;
+
+async fn saturated_pool(server: &MockTdsServer, connection_timeout: Duration) -> Pool {
+    let config = PoolConfig::new()
+        .min_connections(0)
+        .max_connections(2)
+        .test_on_checkout(false)
+        .sp_reset_connection(false)
+        .connection_timeout(connection_timeout);
+    Pool::new(config, client_config(server.port()))
+        .await
+        .expect("pool builds")
+}
+
+/// Wait until `n` tasks are queued in `Pool::get`.
+async fn wait_for_waiters(pool: &Pool, n: u32) {
+    for _ in 0..200 {
+        if pool.wait_queue_depth() == n {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("expected {n} waiters, found {}", pool.wait_queue_depth());
+}
+
+#[tokio::test]
+async fn waiters_are_served_in_arrival_order() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let pool = Arc::new(saturated_pool(&server, Duration::from_secs(10)).await);
+
+    let first = pool.get().await.expect("checkout 1");
+    let second = pool.get().await.expect("checkout 2");
+
+    let served = Arc::new(Mutex::new(Vec::new()));
+    let mut waiters = Vec::new();
+    for n in 1..=3u32 {
+        let queued_pool = Arc::clone(&pool);
+        let served = Arc::clone(&served);
+        waiters.push(tokio::spawn(async move {
+            let conn = queued_pool.get().await.expect("queued checkout");
+            served.lock().await.push(n);
+            // Hold briefly so the next waiter is served by this return.
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(conn);
+        }));
+        // Enqueue one at a time so arrival order is deterministic.
+        wait_for_waiters(&pool, n).await;
+    }
+
+    drop(first);
+    drop(second);
+    for waiter in waiters {
+        waiter.await.expect("waiter task");
+    }
+
+    assert_eq!(*served.lock().await, vec![1, 2, 3]);
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn waiter_times_out_when_pool_stays_saturated() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let pool = saturated_pool(&server, Duration::from_millis(100)).await;
+
+    let _first = pool.get().await.expect("checkout 1");
+    let _second = pool.get().await.expect("checkout 2");
+
+    let started = std::time::Instant::now();
+    let err = pool
+        .get()
+        .await
+        .err()
+        .expect("saturated pool must time out");
+    match err {
+        PoolError::Timeout {
+            capacity, in_use, ..
+        } => {
+            assert_eq!(capacity, 2);
+            assert_eq!(in_use, 2);
+        }
+        other => panic!("expected PoolError::Timeout, got {other:?}"),
+    }
+    assert!(started.elapsed() >= Duration::from_millis(100));
+    assert_eq!(
+        pool.wait_queue_depth(),
+        0,
+        "timed-out waiter left the queue"
+    );
+    assert_eq!(pool.metrics().checkouts_failed, 1);
+}
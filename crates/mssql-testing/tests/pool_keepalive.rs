@@ -0,0 +1,112 @@
+//! Behavior tests for `PoolConfig::keepalive_interval`.
+//!
+//! The pool's background reaper pings connections that have sat idle longer
+//! than the keepalive interval with the health check query, and discards the
+//! ones whose ping fails. Checked-out connections are never pinged.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use mssql_driver_pool::{Pool, PoolConfig};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, client_config};
+
+const KEEPALIVE: Duration = Duration::from_millis(100);
+
+/// Start a server that counts health check pings and answers them with
+/// `ping_response`.
+async fn start_server(ping_response: MockResponse) -> (MockTdsServer, Arc<AtomicUsize>) {
+    let pings = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&pings);
+    let server = MockTdsServer::builder()
+        .with_default_response(MockResponse::Custom(Arc::new(move |sql| {
+            if sql.trim() == "SELECT 1" {
+                counter.fetch_add(1, Ordering::SeqCst);
+                ping_response.clone()
+            } else {
+                MockResponse::empty()
+            }
+        })))
+        .build()
+        .await
+        .expect("server starts");
+    (server, pings)
+}
+
+fn keepalive_pool_config() -> PoolConfig {
+    PoolConfig::new()
+        .min_connections(1)
+        .max_connections(1)
+        .test_on_checkout(false)
+        .keepalive_interval(KEEPALIVE)
+}
+
+#[tokio::test]
+async fn idle_connection_is_pinged_after_interval() {
+    let (server, pings) = start_server(MockResponse::scalar_int(1)).await;
+    let pool = Pool::new(keepalive_pool_config(), client_config(server.port()))
+        .await
+        .expect("pool builds");
+    assert_eq!(pool.status().available, 1, "warm-up opens one connection");
+
+    tokio::time::sleep(KEEPALIVE * 5).await;
+
+    assert!(
+        pings.load(Ordering::SeqCst) > 0,
+        "idle connection was pinged"
+    );
+    let metrics = pool.metrics();
+    assert!(metrics.idle_health_checks_performed > 0);
+    assert_eq!(metrics.idle_health_checks_failed, 0);
+    // Not `status().available`: a ping may be in flight, with the connection
+    // briefly out of the idle set.
+    assert_eq!(metrics.connections_closed, 0, "healthy connection is kept");
+
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn checked_out_connection_is_not_pinged() {
+    let (server, pings) = start_server(MockResponse::scalar_int(1)).await;
+    let pool = Pool::new(keepalive_pool_config(), client_config(server.port()))
+        .await
+        .expect("pool builds");
+
+    let conn = pool.get().await.expect("checkout");
+    tokio::time::sleep(KEEPALIVE * 5).await;
+    assert_eq!(
+        pings.load(Ordering::SeqCst),
+        0,
+        "checked-out connection must not be pinged"
+    );
+    drop(conn);
+
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn failing_ping_removes_connection() {
+    let (server, pings) = start_server(MockResponse::error(10054, "connection reset")).await;
+    let pool = Pool::new(keepalive_pool_config(), client_config(server.port()))
+        .await
+        .expect("pool builds");
+    assert_eq!(pool.status().total, 1);
+
+    tokio::time::sleep(KEEPALIVE * 5).await;
+
+    assert!(
+        pings.load(Ordering::SeqCst) > 0,
+        "idle connection was pinged"
+    );
+    let metrics = pool.metrics();
+    assert!(metrics.idle_health_checks_failed > 0);
+    let status = pool.status();
+    assert_eq!(status.available, 0, "failed connection left the idle set");
+    assert_eq!(status.total, 0, "failed connection was closed");
+
+    pool.close().await;
+}
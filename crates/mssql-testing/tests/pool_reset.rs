@@ -0,0 +1,98 @@
+//! Behavior tests for session reset on pooled connections.
+//!
+//! With `sp_reset_connection` enabled, a connection returned to the pool
+//! carries the RESETCONNECTION bit on the first request of its next checkout.
+//! `PooledConnection::reset` does the same eagerly and waits for the server's
+//! acknowledgement.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_driver_pool::{Pool, PoolConfig};
+use mssql_testing::mock_server::{MockTdsServer, client_config};
+
+async fn single_connection_pool(server: &MockTdsServer, sp_reset_connection: bool) -> Pool {
+    let config = PoolConfig::new()
+        .min_connections(0)
+        .max_connections(1)
+        .test_on_checkout(false)
+        .sp_reset_connection(sp_reset_connection);
+    Pool::new(config, client_config(server.port()))
+        .await
+        .expect("pool builds")
+}
+
+#[tokio::test]
+async fn returned_connection_is_reset_on_next_use() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let pool = single_connection_pool(&server, true).await;
+
+    let mut conn = pool.get().await.expect("checkout 1");
+    conn.execute("SELECT 1", &[]).await.expect("execute");
+    drop(conn);
+    assert_eq!(server.reset_request_count().await, 0, "reset is deferred");
+
+    let mut conn = pool.get().await.expect("checkout 2");
+    conn.execute("SELECT 1", &[]).await.expect("execute");
+    assert_eq!(server.reset_request_count().await, 1);
+    assert_eq!(
+        server.total_connection_count().await,
+        1,
+        "connection reused"
+    );
+    assert_eq!(pool.metrics().resets_performed, 1);
+
+    drop(conn);
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn returned_connection_is_not_reset_when_disabled() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let pool = single_connection_pool(&server, false).await;
+
+    drop(pool.get().await.expect("checkout 1"));
+    let mut conn = pool.get().await.expect("checkout 2");
+    conn.execute("SELECT 1", &[]).await.expect("execute");
+    assert_eq!(server.reset_request_count().await, 0);
+
+    drop(conn);
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn explicit_reset_is_acknowledged_before_returning() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let pool = single_connection_pool(&server, true).await;
+
+    // Dirty the connection, then take it out again: the pending lazy reset
+    // and the explicit one collapse into a single request.
+    drop(pool.get().await.expect("checkout 1"));
+    let mut conn = pool.get().await.expect("checkout 2");
+    conn.reset().await.expect("reset succeeds");
+    assert_eq!(server.reset_request_count().await, 1);
+    assert!(!conn.client().expect("attached").needs_reset());
+
+    conn.execute("SELECT 1", &[]).await.expect("execute");
+    assert_eq!(server.reset_request_count().await, 1, "no second reset");
+
+    let metrics = pool.metrics();
+    assert_eq!(
+        metrics.resets_performed, 1,
+        "the explicit reset performs the pending one"
+    );
+    assert_eq!(metrics.resets_failed, 0);
+
+    drop(conn);
+    pool.close().await;
+}
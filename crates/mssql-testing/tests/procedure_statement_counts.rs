@@ -0,0 +1,60 @@
+//! Behavior tests for `ProcedureResult::statement_row_counts`.
+//!
+//! Each statement inside a stored procedure ends with a DONEINPROC token. The
+//! ones carrying a row count must be reported individually, in order, while
+//! `rows_affected` stays the overall total. Statements without a count (e.g.
+//! under `SET NOCOUNT ON`) are skipped.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+const DONE_MORE: u16 = 0x0001;
+const DONE_COUNT: u16 = 0x0010;
+
+fn put_done(buf: &mut BytesMut, token: u8, status: u16, row_count: u64) {
+    buf.put_u8(token);
+    buf.put_u16_le(status);
+    buf.put_u16_le(0xC5); // cur_cmd: UPDATE
+    buf.put_u64_le(row_count);
+}
+
+/// A procedure response: one DONEINPROC per `(status, count)` step, then
+/// RETURNSTATUS 0 and the final DONEPROC.
+fn procedure_response(steps: &[(u16, u64)]) -> Bytes {
+    let mut buf = BytesMut::new();
+    for &(status, count) in steps {
+        put_done(&mut buf, 0xFF, status | DONE_MORE, count); // DONEINPROC
+    }
+    buf.put_u8(0x79); // RETURNSTATUS
+    buf.put_i32_le(0);
+    put_done(&mut buf, 0xFE, 0, 0); // DONEPROC
+    buf.freeze()
+}
+
+#[tokio::test]
+async fn procedure_reports_per_statement_row_counts() {
+    let server = MockTdsServer::builder()
+        .with_default_response(MockResponse::Raw(procedure_response(&[
+            (DONE_COUNT, 3),
+            (0, 0), // SET NOCOUNT ON / control flow: no count
+            (DONE_COUNT, 0),
+            (DONE_COUNT, 5),
+        ])))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let result = client
+        .call_procedure("dbo.NightlyEtl", &[])
+        .await
+        .expect("call_procedure");
+    assert_eq!(result.statement_row_counts, vec![3, 0, 5]);
+    assert_eq!(result.rows_affected, 8);
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,61 @@
+//! Behavior tests for `Client::product_version`.
+//!
+//! The version comes from `SERVERPROPERTY('ProductVersion')`, is parsed into
+//! a comparable `ProductVersion`, and stays cached across the transaction
+//! state change. An unparseable value is a protocol error.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Client, Error, ProductVersion};
+use mssql_testing::mock_server::{self, MockResponse, MockTdsServer};
+
+const VERSION_QUERY: &str = "SELECT CAST(SERVERPROPERTY('ProductVersion') AS NVARCHAR(128))";
+
+async fn connect(version: &str) -> (MockTdsServer, Client<mssql_client::Ready>) {
+    let server = MockTdsServer::builder()
+        .with_response(VERSION_QUERY, MockResponse::scalar_string(version))
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+#[tokio::test]
+async fn product_version_is_parsed_and_cached() {
+    let (server, mut client) = connect("16.0.4225.2").await;
+
+    let version = client.product_version().await.expect("product_version");
+    assert_eq!(version, ProductVersion::new(16, 0, 4225, 2));
+    assert!(version.supports_json());
+    assert!(version.supports_tds8());
+
+    let mut tx = client.begin_transaction().await.expect("begin");
+    assert_eq!(tx.product_version().await.expect("cached"), version);
+    let client = tx.rollback().await.expect("rollback");
+
+    let version_queries = server
+        .sql_batches()
+        .await
+        .iter()
+        .filter(|sql| sql.as_str() == VERSION_QUERY)
+        .count();
+    assert_eq!(version_queries, 1, "second call is served from the cache");
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn unparseable_product_version_is_a_protocol_error() {
+    let (_server, mut client) = connect("not a version").await;
+
+    let err = client
+        .product_version()
+        .await
+        .expect_err("garbage must not parse");
+    assert!(matches!(err, Error::Protocol(_)), "got {err:?}");
+
+    let _ = client.close().await;
+}
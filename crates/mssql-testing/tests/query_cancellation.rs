@@ -0,0 +1,136 @@
+//! Behavior tests for abandoning a query mid-flight.
+//!
+//! `query_with_cancellation` / `execute_with_cancellation` stop a running
+//! statement when their `CancellationToken` fires: an Attention is sent, the
+//! acknowledgement drained, and the connection stays usable. A query future
+//! that is simply dropped instead leaves the connection in flight, and the
+//! pool must then discard it rather than hand it out again.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::time::Duration;
+
+use bytes::Bytes;
+use mssql_client::{CancellationToken, Error};
+use mssql_driver_pool::{Pool, PoolConfig};
+use mssql_testing::mock_server::{
+    MockColumn, MockResponse, MockTdsServer, ScalarValue, client_config, connect,
+};
+
+const SLOW_QUERY: &str = "SELECT id FROM big_table";
+const SLOW_UPDATE: &str = "UPDATE big_table SET flag = 1";
+
+/// `SELECT id` with one row and no DONE: the result is still running.
+fn unfinished_response() -> MockResponse {
+    MockResponse::unfinished_rows(vec![MockColumn::int("id")], vec![vec![ScalarValue::Int(1)]])
+}
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(SLOW_QUERY, unfinished_response())
+        .with_response(SLOW_UPDATE, MockResponse::Unfinished(Bytes::new()))
+        .with_default_response(MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+/// Cancel `token` after a short delay, from another task.
+fn cancel_soon(token: &CancellationToken) -> tokio::task::JoinHandle<()> {
+    let token = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        token.cancel();
+    })
+}
+
+#[tokio::test]
+async fn cancelled_token_stops_query_and_keeps_connection_usable() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+    let token = CancellationToken::new();
+    let canceller = cancel_soon(&token);
+
+    let err = client
+        .query_with_cancellation(SLOW_QUERY, &[], &token)
+        .await
+        .err()
+        .expect("stalled query must end on cancel");
+    assert!(matches!(err, Error::Cancelled), "got {err:?}");
+    canceller.await.expect("cancel task");
+
+    assert_eq!(server.attention_count().await, 1);
+    assert!(!client.is_in_flight());
+    assert_eq!(client.execute("SELECT 1", &[]).await.expect("reusable"), 1);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn cancelled_token_stops_execute() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+    let token = CancellationToken::new();
+    let canceller = cancel_soon(&token);
+
+    let err = client
+        .execute_with_cancellation(SLOW_UPDATE, &[], &token)
+        .await
+        .expect_err("stalled statement must end on cancel");
+    assert!(matches!(err, Error::Cancelled), "got {err:?}");
+    canceller.await.expect("cancel task");
+
+    assert_eq!(server.attention_count().await, 1);
+    assert_eq!(client.execute("SELECT 1", &[]).await.expect("reusable"), 1);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn uncancelled_token_does_not_interfere() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+    let token = CancellationToken::new();
+
+    let rows = client
+        .execute_with_cancellation("SELECT 1", &[], &token)
+        .await
+        .expect("statement completes");
+    assert_eq!(rows, 1);
+    assert_eq!(server.attention_count().await, 0);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn dropped_query_future_is_not_returned_to_pool() {
+    let server = start_server().await;
+    let pool = Pool::new(
+        PoolConfig::new().min_connections(0).max_connections(1),
+        client_config(server.port()),
+    )
+    .await
+    .expect("pool builds");
+
+    {
+        let mut conn = pool.get().await.expect("checkout");
+        let abandoned =
+            tokio::time::timeout(Duration::from_millis(50), conn.query(SLOW_QUERY, &[])).await;
+        assert!(abandoned.is_err(), "query future dropped by the timeout");
+        assert!(
+            conn.client().unwrap().is_in_flight(),
+            "response left unread"
+        );
+    }
+
+    let status = pool.status();
+    assert_eq!(status.available, 0, "in-flight connection must not be idle");
+    assert_eq!(status.total, 0);
+
+    let mut conn = pool.get().await.expect("checkout replacement");
+    assert_eq!(pool.metrics().connections_created, 2, "fresh connection");
+    assert!(!conn.client().unwrap().is_in_flight());
+    assert_eq!(conn.execute("SELECT 1", &[]).await.expect("healthy"), 1);
+}
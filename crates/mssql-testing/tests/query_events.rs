@@ -0,0 +1,256 @@
+//! Behavior tests for `Client::query_events`.
+//!
+//! The event stream must report result-set boundaries, rows, per-statement
+//! DONE counts (with the statement's command code) and INFO messages in the order the server sent them, and
+//! leave the connection clean once drained.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::QueryEvent;
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+const BATCH_SQL: &str = "SELECT 1 AS a; PRINT 'between'; SELECT 2 AS b";
+
+fn put_utf16(buf: &mut BytesMut, s: &str) {
+    for unit in s.encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+}
+
+/// One single-INT-row result set followed by its DONE token.
+fn put_result_set(buf: &mut BytesMut, column: &str, value: i32, done_status: u16) {
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(1);
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(column.encode_utf16().count() as u8);
+    put_utf16(buf, column);
+
+    buf.put_u8(0xD1); // ROW
+    buf.put_u8(4);
+    buf.put_i32_le(value);
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(done_status);
+    buf.put_u16_le(0xC1); // cur_cmd: SELECT
+    buf.put_u64_le(1);
+}
+
+/// An INFO token as sent for `PRINT`.
+fn put_info(buf: &mut BytesMut, message: &str) {
+    put_message(buf, 0xAB, 0, 0, message);
+}
+
+/// An ERROR token.
+fn put_error(buf: &mut BytesMut, number: i32, message: &str) {
+    put_message(buf, 0xAA, number, 16, message);
+}
+
+/// An ERROR (0xAA) or INFO (0xAB) token.
+fn put_message(buf: &mut BytesMut, token: u8, number: i32, class: u8, message: &str) {
+    let mut body = BytesMut::new();
+    body.put_i32_le(number);
+    body.put_u8(1); // state
+    body.put_u8(class);
+    body.put_u16_le(message.encode_utf16().count() as u16);
+    put_utf16(&mut body, message);
+    body.put_u8(0); // server name
+    body.put_u8(0); // procedure name
+    body.put_u32_le(1); // line
+
+    buf.put_u8(token);
+    buf.put_u16_le(body.len() as u16);
+    buf.put_slice(&body);
+}
+
+fn batch_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    put_result_set(&mut buf, "a", 1, 0x0011); // MORE | COUNT
+    put_info(&mut buf, "between");
+    put_result_set(&mut buf, "b", 2, 0x0010); // final, COUNT
+    buf.freeze()
+}
+
+#[tokio::test]
+async fn query_events_yield_protocol_order() {
+    let server = MockTdsServer::builder()
+        .with_response(BATCH_SQL, MockResponse::Raw(batch_response()))
+        .with_response("SELECT 'next'", MockResponse::scalar_string("next"))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let events = client
+        .query_events(BATCH_SQL, &[])
+        .await
+        .expect("query_events")
+        .collect_all()
+        .await
+        .expect("events");
+
+    let summary: Vec<String> = events
+        .iter()
+        .map(|event| match event {
+            QueryEvent::NewResultSet(columns) => format!("set:{}", columns[0].name),
+            QueryEvent::Row(row) => format!("row:{}", row.get::<i32>(0).unwrap()),
+            QueryEvent::Done { command, count } => format!("done:{command:#x}:{count:?}"),
+            QueryEvent::Message(msg) => format!("msg:{}", msg.message),
+            other => panic!("unexpected event {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            "set:a",
+            "row:1",
+            "done:0xc1:Some(1)",
+            "msg:between",
+            "set:b",
+            "row:2",
+            "done:0xc1:Some(1)",
+        ]
+    );
+
+    assert!(
+        !client.is_in_flight(),
+        "drained stream leaves connection clean"
+    );
+    let rows = client
+        .query("SELECT 'next'", &[])
+        .await
+        .expect("follow-up query")
+        .collect_all()
+        .await
+        .expect("collect");
+    assert_eq!(rows[0].get::<String>(0).unwrap(), "next");
+}
+
+#[tokio::test]
+async fn query_events_surface_server_error() {
+    let server = MockTdsServer::builder()
+        .with_response(
+            "SELECT * FROM missing",
+            MockResponse::error(208, "Invalid object name"),
+        )
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let mut events = client
+        .query_events("SELECT * FROM missing", &[])
+        .await
+        .expect("request sent");
+    let err = loop {
+        match events.try_next().await {
+            Ok(Some(_)) => continue,
+            Ok(None) => panic!("stream ended without the server error"),
+            Err(e) => break e,
+        }
+    };
+    assert!(matches!(
+        err,
+        mssql_client::Error::Server { number: 208, .. }
+    ));
+    assert!(events.is_finished());
+}
+
+#[tokio::test]
+async fn query_events_error_drains_rest_of_response() {
+    const FAILING_SQL: &str = "SELECT 1 AS a; INSERT INTO child VALUES (1); SELECT 2 AS b";
+    let mut response = BytesMut::new();
+    put_result_set(&mut response, "a", 1, 0x0011); // MORE | COUNT
+    put_error(&mut response, 547, "The INSERT statement conflicted");
+    put_message(
+        &mut response,
+        0xAA,
+        3621,
+        0,
+        "The statement has been terminated.",
+    );
+    response.put_u8(0xFD); // DONE
+    response.put_u16_le(0x0003); // MORE | ERROR
+    response.put_u16_le(0xC3); // cur_cmd: INSERT
+    response.put_u64_le(0);
+    put_result_set(&mut response, "b", 2, 0x0010); // final, COUNT
+
+    let server = MockTdsServer::builder()
+        .with_response(FAILING_SQL, MockResponse::Raw(response.freeze()))
+        .with_response("SELECT 'next'", MockResponse::scalar_string("next"))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let mut events = client
+        .query_events(FAILING_SQL, &[])
+        .await
+        .expect("request sent");
+    let mut seen = 0;
+    let err = loop {
+        match events.try_next().await {
+            Ok(Some(_)) => seen += 1,
+            Ok(None) => panic!("stream ended without the server error"),
+            Err(e) => break e,
+        }
+    };
+    assert_eq!(seen, 3, "first result set, its row and its DONE");
+    assert!(
+        matches!(err, mssql_client::Error::Server { number: 547, .. }),
+        "got {err:?}"
+    );
+    let additional: Vec<i32> = err.additional_messages().iter().map(|m| m.number).collect();
+    assert_eq!(additional, [3621]);
+    assert!(events.is_finished());
+    assert!(events.try_next().await.expect("finished").is_none());
+    drop(events);
+
+    assert!(!client.is_in_flight(), "error drains the response");
+    let rows = client
+        .query("SELECT 'next'", &[])
+        .await
+        .expect("follow-up query")
+        .collect_all()
+        .await
+        .expect("collect");
+    assert_eq!(rows[0].get::<String>(0).unwrap(), "next");
+}
+
+#[tokio::test]
+async fn done_events_attribute_counts_to_their_statement() {
+    const MIXED_SQL: &str = "INSERT INTO t VALUES (1), (2); SELECT 1 AS a";
+    let mut response = BytesMut::new();
+    response.put_u8(0xFD); // DONE
+    response.put_u16_le(0x0011); // MORE | COUNT
+    response.put_u16_le(0xC3); // cur_cmd: INSERT
+    response.put_u64_le(2);
+    put_result_set(&mut response, "a", 1, 0x0010); // final, COUNT
+
+    let server = MockTdsServer::builder()
+        .with_response(MIXED_SQL, MockResponse::Raw(response.freeze()))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let done: Vec<(u16, Option<u64>)> = client
+        .query_events(MIXED_SQL, &[])
+        .await
+        .expect("query_events")
+        .collect_all()
+        .await
+        .expect("events")
+        .into_iter()
+        .filter_map(|event| match event {
+            QueryEvent::Done { command, count } => Some((command, count)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(done, [(0xC3, Some(2)), (0xC1, Some(1))]);
+}
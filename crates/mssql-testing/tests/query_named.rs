@@ -0,0 +1,94 @@
+//! Behavior tests for `Client::query_named`.
+//!
+//! Named parameters are sent under the caller's names, so SQL that refers to
+//! `@name` and `@email` runs unchanged. A name that is not a plain identifier
+//! is rejected before anything is sent.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Error, NamedParam};
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+
+const SELECT: &str = "SELECT id FROM dbo.users WHERE name = @name AND email = @email";
+
+fn utf16(s: &str) -> Vec<u8> {
+    s.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+async fn start() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_default_response(MockResponse::rows(
+            vec![MockColumn::int("id")],
+            vec![vec![ScalarValue::Int(42)]],
+        ))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+#[tokio::test]
+async fn parameters_are_bound_under_their_own_names() {
+    let server = start().await;
+    let mut client = connect(&server).await;
+
+    let params = [
+        NamedParam::from_value("name", &"Alice").unwrap(),
+        NamedParam::from_value("@email", &"alice@example.com").unwrap(),
+    ];
+    let rows = client
+        .query_named(SELECT, &params)
+        .await
+        .expect("query")
+        .collect_all()
+        .await
+        .expect("rows decode");
+    assert_eq!(rows[0].get::<i32>(0).unwrap(), 42);
+
+    let rpcs = server.rpc_requests().await;
+    assert_eq!(rpcs.len(), 1);
+    let rpc = &rpcs[0];
+    assert!(contains(rpc, &utf16(SELECT)), "SQL is sent unchanged");
+    assert!(contains(rpc, &utf16("@name nvarchar")), "@name declared");
+    assert!(contains(rpc, &utf16("@email nvarchar")), "@email declared");
+    assert!(!contains(rpc, &utf16("@p1")), "no positional names");
+
+    // Each value follows its own parameter name.
+    let name_at = rpc
+        .windows(utf16("@name").len() + 1)
+        .position(|w| w[0] == 5 && w[1..] == utf16("@name")[..])
+        .expect("@name parameter");
+    let email_at = rpc
+        .windows(utf16("@email").len() + 1)
+        .position(|w| w[0] == 6 && w[1..] == utf16("@email")[..])
+        .expect("@email parameter");
+    let alice_at = rpc
+        .windows(utf16("Alice").len())
+        .position(|w| w == utf16("Alice"))
+        .unwrap();
+    assert!(name_at < alice_at && alice_at < email_at);
+    assert!(contains(&rpc[email_at..], &utf16("alice@example.com")));
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn invalid_name_is_rejected_before_sending() {
+    let server = start().await;
+    let mut client = connect(&server).await;
+
+    let params = [NamedParam::from_value("name int; DROP TABLE users --", &"x").unwrap()];
+    match client.query_named(SELECT, &params).await {
+        Err(Error::InvalidIdentifier(_)) => {}
+        Err(other) => panic!("unexpected error: {other:?}"),
+        Ok(_) => panic!("name is not an identifier"),
+    }
+    assert!(server.rpc_requests().await.is_empty());
+    let _ = client.close().await;
+}
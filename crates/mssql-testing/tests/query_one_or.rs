@@ -0,0 +1,106 @@
+//! Behavior tests for `Client::query_one_or` / `query_one_or_else`.
+//!
+//! Both map the first row through `FromRow` and fall back to the default only
+//! when the query returns no rows; `query_one_or_else` builds the default
+//! lazily. Query errors are never replaced by the default.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Error, FromRow, Row};
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+
+#[derive(Debug, PartialEq)]
+struct Count {
+    n: i32,
+}
+
+impl FromRow for Count {
+    fn from_row(row: &Row) -> Result<Self, Error> {
+        Ok(Self { n: row.get(0)? })
+    }
+}
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(
+            "SELECT n FROM two_rows",
+            MockResponse::rows(
+                vec![MockColumn::int("n")],
+                vec![vec![ScalarValue::Int(7)], vec![ScalarValue::Int(8)]],
+            ),
+        )
+        .with_response(
+            "SELECT n FROM no_rows",
+            MockResponse::rows(vec![MockColumn::int("n")], vec![]),
+        )
+        .with_response(
+            "SELECT n FROM missing",
+            MockResponse::error(208, "Invalid object name"),
+        )
+        .build()
+        .await
+        .expect("server starts")
+}
+
+#[tokio::test]
+async fn query_one_or_returns_first_row() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let found = client
+        .query_one_or(Count { n: -1 }, "SELECT n FROM two_rows", &[])
+        .await
+        .expect("query");
+    assert_eq!(found, Count { n: 7 });
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn query_one_or_falls_back_on_empty_result() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let found = client
+        .query_one_or(Count { n: -1 }, "SELECT n FROM no_rows", &[])
+        .await
+        .expect("query");
+    assert_eq!(found, Count { n: -1 });
+
+    let found = client
+        .query_one_or_else(|| Count { n: -2 }, "SELECT n FROM no_rows", &[])
+        .await
+        .expect("query");
+    assert_eq!(found, Count { n: -2 });
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn query_one_or_else_is_lazy_and_keeps_errors() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let found = client
+        .query_one_or_else(
+            || -> Count { panic!("default must not be built when a row exists") },
+            "SELECT n FROM two_rows",
+            &[],
+        )
+        .await
+        .expect("query");
+    assert_eq!(found, Count { n: 7 });
+
+    let err = client
+        .query_one_or(Count { n: -1 }, "SELECT n FROM missing", &[])
+        .await
+        .expect_err("a server error must not be replaced by the default");
+    assert!(
+        matches!(err, Error::Server { number: 208, .. }),
+        "got {err:?}"
+    );
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,91 @@
+//! Behavior tests for `QueryStream::rows_affected` on `UPDATE ... OUTPUT`.
+//!
+//! A parameterized statement runs as an RPC, so its count arrives in a
+//! DONEINPROC token followed by the DONEPROC that ends the call. `query`
+//! returns the OUTPUT rows and still reports that count, before and after
+//! the rows are drained.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{Client, Ready};
+use mssql_testing::mock_server::{self, MockResponse, MockTdsServer};
+
+const UPDATE: &str = "UPDATE dbo.Jobs SET state = @p1 OUTPUT inserted.id WHERE state = 0";
+
+/// `OUTPUT inserted.id` with the given ids, then a DONEINPROC whose COUNT
+/// bit is set when `count` is `Some`, then the closing DONEPROC.
+fn output_response(ids: &[i32], count: Option<u64>) -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(1);
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(2);
+    for unit in "id".encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+
+    for id in ids {
+        buf.put_u8(0xD1); // ROW
+        buf.put_u8(4);
+        buf.put_i32_le(*id);
+    }
+
+    buf.put_u8(0xFF); // DONEINPROC
+    buf.put_u16_le(if count.is_some() { 0x0010 } else { 0x0000 });
+    buf.put_u16_le(0xC5); // cur_cmd: UPDATE
+    buf.put_u64_le(count.unwrap_or(0));
+
+    buf.put_u8(0xFE); // DONEPROC
+    buf.put_u16_le(0x0000);
+    buf.put_u16_le(0xE0); // cur_cmd: EXECUTE
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+async fn connect(response: Bytes) -> (MockTdsServer, Client<Ready>) {
+    let server = MockTdsServer::builder()
+        .with_default_response(MockResponse::Raw(response))
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+#[tokio::test]
+async fn output_rows_and_count_are_both_available() {
+    let (server, mut client) = connect(output_response(&[4, 7, 9], Some(3))).await;
+
+    let mut stream = client.query(UPDATE, &[&1i32]).await.expect("query");
+    assert_eq!(stream.rows_affected(), Some(3), "known before draining");
+
+    let ids: Vec<i32> = stream
+        .by_ref()
+        .map(|row| row.expect("row decodes").get(0).expect("id"))
+        .collect();
+    assert_eq!(ids, [4, 7, 9]);
+    assert!(stream.is_finished());
+    assert_eq!(stream.rows_affected(), Some(3), "kept after draining");
+    drop(stream);
+
+    assert_eq!(server.rpc_requests().await.len(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn output_without_count_reports_none() {
+    let (_server, mut client) = connect(output_response(&[4], None)).await;
+
+    let mut stream = client.query(UPDATE, &[&1i32]).await.expect("query");
+    assert_eq!(stream.by_ref().count(), 1);
+    assert_eq!(stream.rows_affected(), None);
+    drop(stream);
+
+    let _ = client.close().await;
+}
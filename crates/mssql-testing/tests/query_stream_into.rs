@@ -0,0 +1,97 @@
+//! Behavior tests for `Client::query_stream_into`.
+//!
+//! Rows are streamed from the network and written, one serialized row at a
+//! time, into an async writer. A failing writer must surface its error and
+//! leave the connection usable for the next request.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use mssql_client::{Error, Row};
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+
+const EXPORT_SQL: &str = "SELECT id, name FROM things";
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(
+            EXPORT_SQL,
+            MockResponse::rows(
+                vec![MockColumn::int("id"), MockColumn::nvarchar("name", 50)],
+                vec![
+                    vec![ScalarValue::Int(1), ScalarValue::String("one".into())],
+                    vec![ScalarValue::Int(2), ScalarValue::String("two".into())],
+                    vec![ScalarValue::Int(3), ScalarValue::String("three".into())],
+                ],
+            ),
+        )
+        .build()
+        .await
+        .expect("server starts")
+}
+
+fn csv_line(row: &Row) -> Bytes {
+    let id: i32 = row.get(0).unwrap();
+    let name: String = row.get(1).unwrap();
+    Bytes::from(format!("{id},{name}\n"))
+}
+
+/// A writer that accepts nothing.
+struct BrokenPipe;
+
+impl tokio::io::AsyncWrite for BrokenPipe {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        _buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Poll::Ready(Err(std::io::ErrorKind::BrokenPipe.into()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn query_stream_into_writes_every_row() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let mut out = Vec::new();
+    let rows = client
+        .query_stream_into(EXPORT_SQL, &[], &mut out, csv_line)
+        .await
+        .expect("export");
+
+    assert_eq!(rows, 3);
+    assert_eq!(String::from_utf8(out).unwrap(), "1,one\n2,two\n3,three\n");
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn query_stream_into_surfaces_write_errors() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let err = client
+        .query_stream_into(EXPORT_SQL, &[], &mut BrokenPipe, csv_line)
+        .await
+        .expect_err("a failing writer must surface its error");
+    assert!(matches!(err, Error::Io(_)), "got {err:?}");
+
+    // The abandoned response was cancelled; the connection is still usable.
+    client.execute("SELECT 1", &[]).await.expect("execute");
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,127 @@
+//! Behavior tests for `Client::query_to_channel`.
+//!
+//! Rows arrive in order through a bounded channel, an error reaches the
+//! consumers as the last item, and dropping every receiver stops the query
+//! with the connection left usable.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Error, Row};
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+use tokio::sync::mpsc;
+
+const ROWS_SQL: &str = "SELECT id FROM items";
+const FAILING_SQL: &str = "SELECT id FROM missing";
+const ROW_COUNT: i32 = 50;
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(
+            ROWS_SQL,
+            MockResponse::rows(
+                vec![MockColumn::int("id")],
+                (1..=ROW_COUNT)
+                    .map(|id| vec![ScalarValue::Int(id)])
+                    .collect(),
+            ),
+        )
+        .with_response(
+            FAILING_SQL,
+            MockResponse::error(208, "Invalid object name 'missing'."),
+        )
+        .with_response("SELECT 1", MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+#[tokio::test]
+async fn rows_arrive_in_order_through_a_bounded_channel() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    // Capacity 1: the producer can only run one row ahead of the consumer.
+    let (tx, mut rx) = mpsc::channel::<Result<Row, Error>>(1);
+    let consumer = tokio::spawn(async move {
+        let mut ids = Vec::new();
+        while let Some(row) = rx.recv().await {
+            ids.push(row.expect("row").get::<i32>(0).unwrap());
+            tokio::task::yield_now().await;
+        }
+        ids
+    });
+
+    let sent = client.query_to_channel(ROWS_SQL, &[], tx).await.unwrap();
+    assert_eq!(sent, ROW_COUNT as u64);
+    // The sender was dropped on return, which ends the consumer's loop.
+    let ids = consumer.await.unwrap();
+    assert_eq!(ids, (1..=ROW_COUNT).collect::<Vec<_>>());
+
+    assert!(!client.is_in_flight());
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn a_query_error_is_sent_to_the_consumers() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let (tx, mut rx) = mpsc::channel(4);
+    let sent = client.query_to_channel(FAILING_SQL, &[], tx).await.unwrap();
+    assert_eq!(sent, 0);
+
+    let err = rx.recv().await.expect("error item").unwrap_err();
+    assert!(
+        matches!(err, Error::Server { number: 208, .. }),
+        "got {err:?}"
+    );
+    assert!(rx.recv().await.is_none());
+
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn an_error_nobody_receives_is_returned() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let (tx, rx) = mpsc::channel(4);
+    drop(rx);
+    let err = client
+        .query_to_channel(FAILING_SQL, &[], tx)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, Error::Server { number: 208, .. }),
+        "got {err:?}"
+    );
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn dropping_the_receiver_stops_the_query() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let (tx, mut rx) = mpsc::channel::<Result<Row, Error>>(1);
+    let consumer = tokio::spawn(async move {
+        // Take a few rows, then lose interest.
+        for _ in 0..3 {
+            rx.recv().await.expect("row").expect("row");
+        }
+    });
+
+    let sent = client.query_to_channel(ROWS_SQL, &[], tx).await.unwrap();
+    consumer.await.unwrap();
+    assert!(sent >= 3 && sent < ROW_COUNT as u64, "sent {sent}");
+
+    // The rest of the result was cancelled, not left on the wire.
+    assert!(!client.is_in_flight());
+    let rows = client.query("SELECT 1", &[]).await.unwrap();
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
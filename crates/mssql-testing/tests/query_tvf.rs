@@ -0,0 +1,86 @@
+//! Behavior tests for `Client::query_tvf`.
+//!
+//! `query_tvf` builds `SELECT * FROM function(...)` from a validated function
+//! name and maps each returned row through `FromRow`. An invalid function name
+//! must be rejected before anything is sent to the server.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Error, FromRow, Row};
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+
+#[derive(Debug, PartialEq)]
+struct Thing {
+    id: i32,
+    name: String,
+}
+
+impl FromRow for Thing {
+    fn from_row(row: &Row) -> Result<Self, Error> {
+        Ok(Self {
+            id: row.get_by_name("id")?,
+            name: row.get_by_name("name")?,
+        })
+    }
+}
+
+#[tokio::test]
+async fn query_tvf_maps_rows() {
+    let server = MockTdsServer::builder()
+        .with_response(
+            "SELECT * FROM dbo.fn_GetThings()",
+            MockResponse::rows(
+                vec![MockColumn::int("id"), MockColumn::nvarchar("name", 50)],
+                vec![
+                    vec![ScalarValue::Int(1), ScalarValue::String("one".into())],
+                    vec![ScalarValue::Int(2), ScalarValue::String("two".into())],
+                ],
+            ),
+        )
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let things: Vec<Thing> = client
+        .query_tvf("dbo.fn_GetThings", &[])
+        .await
+        .expect("query_tvf");
+    assert_eq!(
+        things,
+        vec![
+            Thing {
+                id: 1,
+                name: "one".into()
+            },
+            Thing {
+                id: 2,
+                name: "two".into()
+            },
+        ]
+    );
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn query_tvf_rejects_invalid_function_name() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let err = client
+        .query_tvf::<Thing>("dbo.fn_x(); DROP TABLE users; --", &[&1i32])
+        .await
+        .expect_err("injection attempt must be rejected");
+    assert!(matches!(err, Error::InvalidIdentifier(_)), "got {err:?}");
+
+    // The connection is untouched and still usable.
+    client.execute("SELECT 1", &[]).await.expect("execute");
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,114 @@
+//! Behavior tests for `Client::query_typed` and `Client::query_typed_stream`.
+//!
+//! Rows are mapped through a `#[derive(FromRow)]` struct, including a renamed
+//! field and an `Option` field over a NULL column. The collecting form stops
+//! at the first mapping error; the iterator form reports it per row.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::FromRow;
+use mssql_testing::mock_server::{MockColumn, MockResponse, MockTdsServer, ScalarValue, connect};
+
+const USERS_SQL: &str = "SELECT id, user_name, manager_id FROM users";
+
+#[derive(Debug, PartialEq, FromRow)]
+struct User {
+    id: i32,
+    #[mssql(rename = "user_name")]
+    name: String,
+    manager_id: Option<i32>,
+}
+
+/// Maps `manager_id` as non-nullable, so the NULL row fails to map.
+#[derive(Debug, FromRow)]
+struct StrictUser {
+    manager_id: i32,
+}
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(
+            USERS_SQL,
+            MockResponse::rows(
+                vec![
+                    MockColumn::int("id"),
+                    MockColumn::nvarchar("user_name", 50),
+                    MockColumn::int("manager_id").with_nullable(true),
+                ],
+                vec![
+                    vec![
+                        ScalarValue::Int(1),
+                        ScalarValue::String("ada".into()),
+                        ScalarValue::Null,
+                    ],
+                    vec![
+                        ScalarValue::Int(2),
+                        ScalarValue::String("grace".into()),
+                        ScalarValue::Int(1),
+                    ],
+                ],
+            ),
+        )
+        .build()
+        .await
+        .expect("server starts")
+}
+
+fn expected_users() -> Vec<User> {
+    vec![
+        User {
+            id: 1,
+            name: "ada".into(),
+            manager_id: None,
+        },
+        User {
+            id: 2,
+            name: "grace".into(),
+            manager_id: Some(1),
+        },
+    ]
+}
+
+#[tokio::test]
+async fn query_typed_maps_every_row() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let users: Vec<User> = client.query_typed(USERS_SQL, &[]).await.expect("query");
+    assert_eq!(users, expected_users());
+}
+
+#[tokio::test]
+async fn query_typed_stream_maps_lazily() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let users = client
+        .query_typed_stream::<User>(USERS_SQL, &[])
+        .await
+        .expect("query")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("rows map");
+    assert_eq!(users, expected_users());
+}
+
+#[tokio::test]
+async fn query_typed_returns_first_mapping_error() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    client
+        .query_typed::<StrictUser>(USERS_SQL, &[])
+        .await
+        .expect_err("NULL manager_id must fail to map");
+
+    let mut rows = client
+        .query_typed_stream::<StrictUser>(USERS_SQL, &[])
+        .await
+        .expect("query");
+    assert!(rows.next().unwrap().is_err(), "row 1 has NULL manager_id");
+    assert_eq!(rows.next().unwrap().unwrap().manager_id, 1);
+    assert!(rows.next().is_none());
+}
@@ -0,0 +1,87 @@
+//! Behavior tests for `Config::read_only_guard`.
+//!
+//! With the guard on, statements containing a write keyword fail with
+//! `Error::WriteAttemptOnReadOnly` before anything is sent, and the
+//! connection stays usable. Reads are unaffected.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::sync::{Arc, Mutex};
+
+use mssql_client::{Client, Error};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, client_config};
+
+async fn connect(
+    read_only_guard: bool,
+) -> (
+    MockTdsServer,
+    Client<mssql_client::Ready>,
+    Arc<Mutex<Vec<String>>>,
+) {
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&batches);
+    let server = MockTdsServer::builder()
+        .with_default_response(MockResponse::Custom(Arc::new(move |sql| {
+            recorded.lock().unwrap().push(sql.trim().to_string());
+            MockResponse::scalar_int(1)
+        })))
+        .build()
+        .await
+        .expect("server starts");
+    let config = client_config(server.port()).read_only_guard(read_only_guard);
+    let client = Client::connect(config).await.expect("connect");
+    (server, client, batches)
+}
+
+#[tokio::test]
+async fn write_is_rejected_without_reaching_server() {
+    let (_server, mut client, batches) = connect(true).await;
+
+    let err = client
+        .execute("DELETE FROM orders WHERE id = 1", &[])
+        .await
+        .expect_err("write must be rejected");
+    assert!(
+        matches!(err, Error::WriteAttemptOnReadOnly { keyword: "DELETE" }),
+        "got {err:?}"
+    );
+    let err = client
+        .simple_query("SELECT 1; DROP TABLE orders")
+        .await
+        .expect_err("write must be rejected");
+    assert!(matches!(
+        err,
+        Error::WriteAttemptOnReadOnly { keyword: "DROP" }
+    ));
+    assert!(batches.lock().unwrap().is_empty(), "nothing was sent");
+
+    // The connection is still usable for reads.
+    let rows: Vec<_> = client
+        .query("SELECT [update] FROM orders", &[])
+        .await
+        .expect("read passes")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(
+        *batches.lock().unwrap(),
+        vec!["SELECT [update] FROM orders"]
+    );
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn writes_pass_when_guard_is_off() {
+    let (_server, mut client, batches) = connect(false).await;
+
+    client
+        .execute("DELETE FROM orders WHERE id = 1", &[])
+        .await
+        .expect("write passes");
+    assert_eq!(batches.lock().unwrap().len(), 1);
+
+    let _ = client.close().await;
+}
@@ -0,0 +1,158 @@
+//! Behavior tests for `Client::recover`.
+//!
+//! A client abandoned mid-stream inside a raw-SQL transaction is brought
+//! back without reconnecting: the stalled response is cancelled with an
+//! Attention, the transaction is rolled back, and the session is reset.
+//! A failed or timed-out recovery leaves the client marked for eviction.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{Client, Error, Ready, TimeoutConfig};
+use mssql_testing::mock_server::{
+    MockColumn, MockResponse, MockTdsServer, ScalarValue, client_config, connect,
+};
+
+const SLOW_QUERY: &str = "SELECT id FROM big_table";
+const ROLLBACK: &str = "IF @@TRANCOUNT > 0 ROLLBACK TRANSACTION";
+const DESCRIPTOR: [u8; 8] = [7, 0, 0, 0, 0, 0, 0, 0];
+
+/// A transaction ENVCHANGE (`env_type` 8 = begin, 10 = rollback) and DONE.
+fn transaction_response(env_type: u8, new: &[u8], old: &[u8]) -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0xE3); // ENVCHANGE
+    buf.put_u16_le((1 + 1 + new.len() + 1 + old.len()) as u16);
+    buf.put_u8(env_type);
+    buf.put_u8(new.len() as u8);
+    buf.put_slice(new);
+    buf.put_u8(old.len() as u8);
+    buf.put_slice(old);
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0);
+    buf.put_u16_le(0);
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+/// `SELECT id` with one row and no DONE: the result is still running.
+fn unfinished_response() -> MockResponse {
+    MockResponse::unfinished_rows(vec![MockColumn::int("id")], vec![vec![ScalarValue::Int(1)]])
+}
+
+/// Start a server that counts rollback batches and answers them with
+/// `rollback`.
+async fn start_server(rollback: MockResponse) -> (MockTdsServer, Arc<AtomicUsize>) {
+    let rollbacks = Arc::new(AtomicUsize::new(0));
+    let counter = rollbacks.clone();
+    let server = MockTdsServer::builder()
+        .with_response(
+            "BEGIN TRANSACTION",
+            MockResponse::Raw(transaction_response(8, &DESCRIPTOR, &[])),
+        )
+        .with_response(SLOW_QUERY, unfinished_response())
+        .with_response(
+            ROLLBACK,
+            MockResponse::Custom(Arc::new(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                rollback.clone()
+            })),
+        )
+        .with_default_response(MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts");
+    (server, rollbacks)
+}
+
+async fn connect_with_timeout(server: &MockTdsServer, timeout: Duration) -> Client<Ready> {
+    let config =
+        client_config(server.port()).timeouts(TimeoutConfig::new().command_timeout(timeout));
+    Client::connect(config).await.expect("connect")
+}
+
+fn rolled_back() -> MockResponse {
+    MockResponse::Raw(transaction_response(10, &[], &DESCRIPTOR))
+}
+
+#[tokio::test]
+async fn recover_cancels_rolls_back_and_resets_an_abandoned_client() {
+    let (server, rollbacks) = start_server(rolled_back()).await;
+    let mut client = connect(&server).await;
+
+    client.execute("BEGIN TRANSACTION", &[]).await.unwrap();
+    assert!(client.is_in_transaction());
+    {
+        // Abandoned part-way, as when user code panics mid-stream.
+        let mut rows = client.query_stream(SLOW_QUERY, &[]).await.unwrap();
+        rows.try_next().await.unwrap().expect("first row");
+    }
+    assert!(client.is_in_flight());
+
+    client.recover().await.expect("recover");
+
+    assert_eq!(server.attention_count().await, 1);
+    assert_eq!(rollbacks.load(Ordering::SeqCst), 1);
+    assert!(!client.is_in_flight());
+    assert!(!client.is_in_transaction());
+    // The reset has already been sent and acknowledged.
+    assert!(!client.needs_reset());
+    assert_eq!(server.reset_request_count().await, 1);
+
+    assert_eq!(client.execute("SELECT 1", &[]).await.expect("usable"), 1);
+    assert_eq!(server.reset_request_count().await, 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn recover_on_a_healthy_client_is_harmless() {
+    let (server, rollbacks) = start_server(MockResponse::empty()).await;
+    let mut client = connect(&server).await;
+
+    client.recover().await.expect("recover");
+
+    assert_eq!(server.attention_count().await, 0);
+    assert_eq!(rollbacks.load(Ordering::SeqCst), 1);
+    assert_eq!(server.reset_request_count().await, 1);
+    assert_eq!(client.execute("SELECT 1", &[]).await.expect("usable"), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn a_failed_recovery_marks_the_client_for_eviction() {
+    let (server, _rollbacks) = start_server(MockResponse::error(
+        3998,
+        "Uncommittable transaction is detected at the end of the batch.",
+    ))
+    .await;
+    let mut client = connect(&server).await;
+
+    client.recover().await.expect_err("rollback failed");
+    // A pool discards in-flight connections at check-in.
+    assert!(client.is_in_flight());
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn a_hung_rollback_times_out_under_the_command_timeout() {
+    const TIMEOUT: Duration = Duration::from_millis(200);
+    let (server, rollbacks) = start_server(unfinished_response()).await;
+    let mut client = connect_with_timeout(&server, TIMEOUT).await;
+
+    let started = Instant::now();
+    let err = client.recover().await.expect_err("rollback never finishes");
+    assert!(matches!(err, Error::CommandTimeout), "got {err:?}");
+    assert!(started.elapsed() >= TIMEOUT);
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    assert_eq!(rollbacks.load(Ordering::SeqCst), 1);
+    assert_eq!(server.attention_count().await, 1);
+    assert_eq!(server.reset_request_count().await, 0);
+    assert!(client.is_in_flight());
+    let _ = client.close().await;
+}
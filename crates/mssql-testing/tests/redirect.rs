@@ -11,18 +11,8 @@
 
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
-use mssql_client::{Client, Config, Error};
-use mssql_testing::mock_server::{MockResponse, MockTdsServer};
-
-fn mock_config(port: u16) -> Config {
-    // The mock is plaintext-only: NotSupported encryption keeps the entire
-    // exchange (including login) off TLS. Retries are disabled so connection
-    // counts below are deterministic.
-    Config::from_connection_string(&format!(
-        "Server=127.0.0.1,{port};User Id=sa;Password=test;Encrypt=no_tls;ConnectRetryCount=0"
-    ))
-    .expect("config parses")
-}
+use mssql_client::{Client, Error};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, client_config};
 
 /// A login-time routing token must be followed to the target server, and the
 /// session must end up on the target.
@@ -42,7 +32,7 @@ async fn test_login_redirect_followed_to_target() {
         .await
         .expect("gateway starts");
 
-    let mut client = Client::connect(mock_config(gateway.port()))
+    let mut client = Client::connect(client_config(gateway.port()))
         .await
         .expect("connect must follow the routing redirect to the target");
 
@@ -80,7 +70,7 @@ async fn test_redirect_loop_stops_at_max_redirects() {
         .await
         .expect("looper starts");
 
-    let err = Client::connect(mock_config(looper.port()))
+    let err = Client::connect(client_config(looper.port()))
         .await
         .expect_err("a self-routing server must not be followed forever");
 
@@ -109,7 +99,7 @@ async fn test_redirect_surfaced_when_following_disabled() {
         .await
         .expect("gateway starts");
 
-    let mut config = mock_config(gateway.port());
+    let mut config = client_config(gateway.port());
     config.redirect.follow_redirects = false;
 
     let err = Client::connect(config)
@@ -13,14 +13,14 @@
 #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 
 use mssql_client::{Client, Config};
-use mssql_testing::mock_server::MockTdsServer;
+use mssql_testing::mock_server::{MockTdsServer, connection_string};
 
 fn mock_config(port: u16, retry_count: u32) -> Config {
     // Plaintext-only mock (Encrypt=no_tls). ConnectRetryInterval=1 keeps the
     // backoff to ~1s so the test stays fast.
     Config::from_connection_string(&format!(
-        "Server=127.0.0.1,{port};User Id=sa;Password=test;Encrypt=no_tls;\
-         ConnectRetryCount={retry_count};ConnectRetryInterval=1"
+        "{};ConnectRetryCount={retry_count};ConnectRetryInterval=1",
+        connection_string(port)
     ))
     .expect("config parses")
 }
@@ -0,0 +1,222 @@
+//! Behavior tests driving the client against scripted mock responses.
+//!
+//! `MockResponse::server_error` sends a fully specified ERROR token,
+//! `MockResponse::done` a DONE with explicit status bits, and
+//! `MockResponse::sequence` answers repeated requests from a script, which is
+//! what retry handling needs ("fail twice, then succeed").
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use mssql_client::{Client, Error, Ready, RetryPolicy};
+use mssql_testing::mock_server::{
+    self, MockResponse, MockServerBuilder, MockTdsServer, client_config,
+};
+use tds_protocol::{DoneStatus, ServerError};
+
+const UPDATE: &str = "UPDATE dbo.Accounts SET balance = balance - 10 WHERE id = 1";
+
+fn deadlock() -> ServerError {
+    ServerError {
+        number: 1205,
+        state: 51,
+        class: 13,
+        message: "Transaction (Process ID 57) was deadlocked on lock resources with \
+                  another process and has been chosen as the deadlock victim. \
+                  Rerun the transaction."
+            .to_string(),
+        server: "sql-prod-01".to_string(),
+        procedure: "usp_debit".to_string(),
+        line: 12,
+    }
+}
+
+fn done(count: bool, error: bool) -> DoneStatus {
+    let mut status = DoneStatus::default();
+    status.count = count;
+    status.error = error;
+    status
+}
+
+async fn connect(builder: MockServerBuilder) -> (MockTdsServer, Client<Ready>) {
+    let server = builder.build().await.expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+async fn updates_sent(server: &MockTdsServer) -> usize {
+    server
+        .sql_batches()
+        .await
+        .iter()
+        .filter(|sql| *sql == UPDATE)
+        .count()
+}
+
+#[tokio::test]
+async fn server_error_fields_reach_the_client() {
+    let (_server, mut client) = connect(
+        MockTdsServer::builder().with_response(UPDATE, MockResponse::server_error(&deadlock())),
+    )
+    .await;
+
+    let err = client.execute(UPDATE, &[]).await.expect_err("deadlock");
+    match &err {
+        Error::Server {
+            number,
+            class,
+            state,
+            message,
+            server,
+            procedure,
+            line,
+            ..
+        } => {
+            assert_eq!(*number, 1205);
+            assert_eq!(*class, 13);
+            assert_eq!(*state, 51);
+            assert!(message.starts_with("Transaction (Process ID 57)"));
+            assert_eq!(server.as_deref(), Some("sql-prod-01"));
+            assert_eq!(procedure.as_deref(), Some("usp_debit"));
+            assert_eq!(*line, 12);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert!(err.is_transient());
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn done_status_bits_decide_the_outcome() {
+    let (_server, mut client) = connect(
+        MockTdsServer::builder()
+            .with_response(
+                "UPDATE t SET x = 1",
+                MockResponse::done(done(true, false), 42),
+            )
+            .with_response(
+                "UPDATE t SET x = 2",
+                MockResponse::done(done(true, false), 0),
+            )
+            .with_response(
+                "UPDATE t SET x = 3",
+                MockResponse::done(done(false, true), 0),
+            ),
+    )
+    .await;
+
+    assert_eq!(client.execute("UPDATE t SET x = 1", &[]).await.unwrap(), 42);
+    assert_eq!(client.execute("UPDATE t SET x = 2", &[]).await.unwrap(), 0);
+    let err = client
+        .execute("UPDATE t SET x = 3", &[])
+        .await
+        .expect_err("DONE carries the ERROR bit");
+    assert!(matches!(err, Error::Query(_)), "got {err:?}");
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn transient_failures_are_retried_until_the_script_succeeds() {
+    let (server, mut client) = connect(MockTdsServer::builder().with_response(
+        UPDATE,
+        MockResponse::sequence([
+            MockResponse::server_error(&deadlock()),
+            MockResponse::server_error(&deadlock()),
+            MockResponse::affected(1),
+        ]),
+    ))
+    .await;
+
+    let policy = RetryPolicy::new().max_retries(3);
+    let mut attempt = 0;
+    let affected = loop {
+        match client.execute(UPDATE, &[]).await {
+            Ok(affected) => break affected,
+            Err(e) if e.is_transient() && policy.should_retry(attempt) => attempt += 1,
+            Err(e) => panic!("gave up after {attempt} retries: {e:?}"),
+        }
+    };
+
+    assert_eq!(affected, 1);
+    assert_eq!(attempt, 2);
+    assert_eq!(updates_sent(&server).await, 3);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn retries_stop_when_the_policy_is_exhausted() {
+    let (server, mut client) = connect(MockTdsServer::builder().with_response(
+        UPDATE,
+        MockResponse::sequence([MockResponse::server_error(&deadlock())]),
+    ))
+    .await;
+
+    let policy = RetryPolicy::new().max_retries(2);
+    let mut attempt = 0;
+    let err = loop {
+        match client.execute(UPDATE, &[]).await {
+            Ok(_) => panic!("the script never succeeds"),
+            Err(e) if e.is_transient() && policy.should_retry(attempt) => attempt += 1,
+            Err(e) => break e,
+        }
+    };
+
+    assert!(matches!(err, Error::Server { number: 1205, .. }));
+    // The last scripted response repeats: one attempt plus two retries.
+    assert_eq!(updates_sent(&server).await, 3);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn execute_with_retry_reruns_deadlock_victims() {
+    let server = MockTdsServer::builder()
+        .with_response(
+            UPDATE,
+            MockResponse::sequence([
+                MockResponse::server_error(&deadlock()),
+                MockResponse::server_error(&deadlock()),
+                MockResponse::affected(1),
+            ]),
+        )
+        .build()
+        .await
+        .expect("server starts");
+    let retry = RetryPolicy::new()
+        .max_retries(3)
+        .initial_backoff(std::time::Duration::from_millis(1));
+    let mut client = Client::connect(client_config(server.port()).retry(retry))
+        .await
+        .expect("connect");
+
+    let affected = client.execute_with_retry(UPDATE, &[]).await.unwrap();
+
+    assert_eq!(affected, 1);
+    assert_eq!(updates_sent(&server).await, 3);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn execute_with_retry_returns_terminal_server_errors_at_once() {
+    let mut violation = deadlock();
+    violation.number = 547;
+    violation.message = "The UPDATE statement conflicted with the CHECK constraint".to_string();
+    let server = MockTdsServer::builder()
+        .with_response(UPDATE, MockResponse::server_error(&violation))
+        .build()
+        .await
+        .expect("server starts");
+    let retry = RetryPolicy::new().initial_backoff(std::time::Duration::from_millis(1));
+    let mut client = Client::connect(client_config(server.port()).retry(retry))
+        .await
+        .expect("connect");
+
+    let err = client.execute_with_retry(UPDATE, &[]).await.unwrap_err();
+
+    assert!(
+        matches!(err, Error::Server { number: 547, .. }),
+        "got {err:?}"
+    );
+    assert_eq!(updates_sent(&server).await, 1);
+    let _ = client.close().await;
+}
@@ -0,0 +1,97 @@
+//! Behavior tests for server errors on the buffered read path.
+//!
+//! SQL Server often follows an error with more ERROR/INFO tokens, e.g. a
+//! constraint violation and then "The statement has been terminated." The
+//! first error is reported as [`Error::Server`] and every other message of
+//! the response rides along in its `additional` field, in the order sent.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::Error;
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+const INSERT: &str = "INSERT INTO child VALUES (1)";
+
+/// An ERROR (0xAA) or INFO (0xAB) token.
+fn put_message(buf: &mut BytesMut, token: u8, number: i32, class: u8, message: &str) {
+    let mut body = BytesMut::new();
+    body.put_i32_le(number);
+    body.put_u8(1); // state
+    body.put_u8(class);
+    body.put_u16_le(message.encode_utf16().count() as u16);
+    for unit in message.encode_utf16() {
+        body.put_u16_le(unit);
+    }
+    body.put_u8(0); // server name
+    body.put_u8(0); // procedure name
+    body.put_u32_le(1); // line
+
+    buf.put_u8(token);
+    buf.put_u16_le(body.len() as u16);
+    buf.put_slice(&body);
+}
+
+/// An INFO, two ERRORs, then the final DONE with the error bit.
+fn failing_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    put_message(&mut buf, 0xAB, 5701, 0, "Changed database context");
+    put_message(&mut buf, 0xAA, 547, 16, "The INSERT statement conflicted");
+    put_message(
+        &mut buf,
+        0xAA,
+        3621,
+        0,
+        "The statement has been terminated.",
+    );
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0x0002); // ERROR
+    buf.put_u16_le(0xC3); // cur_cmd: INSERT
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+async fn start() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(INSERT, MockResponse::Raw(failing_response()))
+        .with_response("SELECT 1", MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+fn assert_full_chain(err: &Error) {
+    assert!(
+        matches!(err, Error::Server { number: 547, .. }),
+        "got {err:?}"
+    );
+    let additional: Vec<i32> = err.additional_messages().iter().map(|m| m.number).collect();
+    assert_eq!(additional, [5701, 3621]);
+}
+
+#[tokio::test]
+async fn execute_error_reports_every_message() {
+    let server = start().await;
+    let mut client = connect(&server).await;
+
+    let err = client.execute(INSERT, &[]).await.expect_err("insert fails");
+    assert_full_chain(&err);
+
+    let rows = client.query("SELECT 1", &[]).await.expect("reusable");
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn query_error_reports_every_message() {
+    let server = start().await;
+    let mut client = connect(&server).await;
+
+    match client.query(INSERT, &[]).await {
+        Err(err) => assert_full_chain(&err),
+        Ok(_) => panic!("insert fails"),
+    }
+    let _ = client.close().await;
+}
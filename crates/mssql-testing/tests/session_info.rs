@@ -0,0 +1,93 @@
+//! Behavior tests for the session details read from the login response:
+//! `Client::spid`, `Client::server_version_string` and
+//! `Client::current_database`.
+//!
+//! The SPID comes from the response packet headers and the version string
+//! from LoginAck, so neither costs a round trip. The current database
+//! follows Database ENVCHANGEs after login.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{Client, Ready};
+use mssql_testing::mock_server::{self, MockResponse, MockServerBuilder, MockTdsServer};
+
+/// `USE sales`: a Database ENVCHANGE from `inventory`, then DONE.
+fn use_sales_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    let new: Vec<u16> = "sales".encode_utf16().collect();
+    let old: Vec<u16> = "inventory".encode_utf16().collect();
+    buf.put_u8(0xE3); // ENVCHANGE
+    buf.put_u16_le((1 + 1 + new.len() * 2 + 1 + old.len() * 2) as u16);
+    buf.put_u8(1); // Database
+    for value in [&new, &old] {
+        buf.put_u8(value.len() as u8);
+        for unit in value {
+            buf.put_u16_le(*unit);
+        }
+    }
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0);
+    buf.put_u16_le(0);
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+async fn connect(builder: MockServerBuilder) -> (MockTdsServer, Client<Ready>) {
+    let server = builder.build().await.expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+#[tokio::test]
+async fn spid_and_server_version_come_from_the_login_response() {
+    let (_server, client) = connect(
+        MockTdsServer::builder()
+            .with_spid(57)
+            .with_server_name("Microsoft SQL Server"),
+    )
+    .await;
+
+    assert_eq!(client.spid(), Some(57));
+    assert_eq!(
+        client.server_version_string(),
+        Some("Microsoft SQL Server 16.0.4225")
+    );
+
+    // Both survive the transaction state change.
+    let tx = client.begin_transaction().await.expect("begin");
+    assert_eq!(tx.spid(), Some(57));
+    assert_eq!(
+        tx.server_version_string(),
+        Some("Microsoft SQL Server 16.0.4225")
+    );
+    let client = tx.rollback().await.expect("rollback");
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn spid_is_none_when_the_header_field_is_zero() {
+    let (_server, client) = connect(MockTdsServer::builder()).await;
+    assert_eq!(client.spid(), None);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn current_database_follows_database_changes() {
+    let (_server, mut client) = connect(
+        MockTdsServer::builder()
+            .with_database("inventory")
+            .with_response("USE sales", MockResponse::Raw(use_sales_response())),
+    )
+    .await;
+
+    // The login database, even though the connection string named none.
+    assert_eq!(client.database(), None);
+    assert_eq!(client.current_database(), Some("inventory"));
+
+    client.execute("USE sales", &[]).await.expect("use");
+    assert_eq!(client.current_database(), Some("sales"));
+    let _ = client.close().await;
+}
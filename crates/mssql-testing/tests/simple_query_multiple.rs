@@ -0,0 +1,104 @@
+//! Behavior tests for `Client::simple_query_multiple`.
+//!
+//! A parameterless script is sent verbatim as a SQL batch (no
+//! `sp_executesql`), and every SELECT in it comes back as its own result
+//! set, with PRINT output available alongside.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+const SCRIPT: &str = "PRINT 'starting'; SELECT 1 AS a; SELECT 2 AS b";
+
+fn put_utf16(buf: &mut BytesMut, s: &str) {
+    for unit in s.encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+}
+
+/// One single-INT-row result set followed by its DONE token.
+fn put_result_set(buf: &mut BytesMut, column: &str, value: i32, done_status: u16) {
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(1);
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(column.encode_utf16().count() as u8);
+    put_utf16(buf, column);
+
+    buf.put_u8(0xD1); // ROW
+    buf.put_u8(4);
+    buf.put_i32_le(value);
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(done_status);
+    buf.put_u16_le(0xC1); // cur_cmd: SELECT
+    buf.put_u64_le(1);
+}
+
+/// An INFO token as sent for `PRINT`.
+fn put_info(buf: &mut BytesMut, message: &str) {
+    let mut body = BytesMut::new();
+    body.put_i32_le(0); // number
+    body.put_u8(1); // state
+    body.put_u8(0); // class
+    body.put_u16_le(message.encode_utf16().count() as u16);
+    put_utf16(&mut body, message);
+    body.put_u8(0); // server name
+    body.put_u8(0); // procedure name
+    body.put_u32_le(1); // line
+
+    buf.put_u8(0xAB); // INFO
+    buf.put_u16_le(body.len() as u16);
+    buf.put_slice(&body);
+}
+
+fn script_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    put_info(&mut buf, "starting");
+    put_result_set(&mut buf, "a", 1, 0x0011); // MORE | COUNT
+    put_result_set(&mut buf, "b", 2, 0x0010); // final, COUNT
+    buf.freeze()
+}
+
+#[tokio::test]
+async fn two_selects_in_one_batch_return_two_result_sets() {
+    let server = MockTdsServer::builder()
+        .with_response(SCRIPT, MockResponse::Raw(script_response()))
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let mut results = client
+        .simple_query_multiple(SCRIPT)
+        .await
+        .expect("script runs");
+    assert_eq!(results.result_count(), 2);
+
+    let messages: Vec<&str> = results
+        .messages()
+        .iter()
+        .map(|m| m.message.as_str())
+        .collect();
+    assert_eq!(messages, ["starting"]);
+
+    assert_eq!(results.columns().unwrap()[0].name, "a");
+    let row = results.next_row().await.unwrap().expect("first row");
+    assert_eq!(row.get::<i32>(0).unwrap(), 1);
+    assert!(results.next_row().await.unwrap().is_none());
+
+    assert!(results.next_result().await.unwrap());
+    assert_eq!(results.columns().unwrap()[0].name, "b");
+    let row = results.next_row().await.unwrap().expect("second row");
+    assert_eq!(row.get::<i32>(0).unwrap(), 2);
+    assert!(!results.next_result().await.unwrap());
+
+    assert_eq!(server.sql_batches().await, [SCRIPT]);
+    assert!(server.rpc_requests().await.is_empty(), "no sp_executesql");
+    let _ = client.close().await;
+}
@@ -0,0 +1,103 @@
+//! Behavior tests for beginning an `IsolationLevel::Snapshot` transaction.
+//!
+//! The client checks `ALLOW_SNAPSHOT_ISOLATION` before starting the
+//! transaction, because SQL Server itself only fails the first read (error
+//! 3952). A disallowed database is reported as that same server error.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{Client, Error, IsolationLevel, Ready};
+use mssql_testing::mock_server::{self, MockResponse, MockTdsServer};
+
+const STATE_QUERY: &str = "SELECT CAST(snapshot_isolation_state AS INT) \
+     FROM sys.databases WHERE database_id = DB_ID()";
+const SET_SNAPSHOT: &str = "SET TRANSACTION ISOLATION LEVEL SNAPSHOT";
+
+/// A BeginTransaction ENVCHANGE and DONE.
+fn begin_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0xE3); // ENVCHANGE
+    buf.put_u16_le(1 + 1 + 8 + 1);
+    buf.put_u8(8); // BeginTransaction
+    buf.put_u8(8);
+    buf.put_u64_le(7);
+    buf.put_u8(0);
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0);
+    buf.put_u16_le(0);
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+async fn connect(snapshot_state: i32) -> (MockTdsServer, Client<Ready>) {
+    let server = MockTdsServer::builder()
+        .with_database("sales")
+        .with_response(STATE_QUERY, MockResponse::scalar_int(snapshot_state))
+        .with_response("BEGIN TRANSACTION", MockResponse::Raw(begin_response()))
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+#[tokio::test]
+async fn snapshot_transaction_begins_when_the_database_allows_it() {
+    let (server, client) = connect(1).await;
+
+    let tx = client
+        .begin_transaction_with_isolation(IsolationLevel::Snapshot)
+        .await
+        .expect("begin");
+
+    let batches = server.sql_batches().await;
+    assert_eq!(
+        &batches[batches.len() - 3..],
+        [STATE_QUERY, SET_SNAPSHOT, "BEGIN TRANSACTION"]
+    );
+    let _ = tx.rollback().await;
+}
+
+#[tokio::test]
+async fn snapshot_transaction_fails_clearly_when_the_database_disallows_it() {
+    let (server, client) = connect(0).await;
+
+    let err = client
+        .begin_transaction_with_isolation(IsolationLevel::Snapshot)
+        .await
+        .expect_err("snapshot isolation is off");
+    match err {
+        Error::Server {
+            number: 3952,
+            ref message,
+            ..
+        } => assert!(message.contains("'sales'"), "{message}"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+
+    let batches = server.sql_batches().await;
+    assert!(!batches.iter().any(|sql| sql == SET_SNAPSHOT));
+    assert!(!batches.iter().any(|sql| sql == "BEGIN TRANSACTION"));
+}
+
+#[tokio::test]
+async fn other_isolation_levels_skip_the_check() {
+    let (server, client) = connect(0).await;
+
+    let tx = client
+        .begin_transaction_with_isolation(IsolationLevel::Serializable)
+        .await
+        .expect("begin");
+
+    assert!(
+        !server
+            .sql_batches()
+            .await
+            .iter()
+            .any(|sql| sql == STATE_QUERY)
+    );
+    let _ = tx.rollback().await;
+}
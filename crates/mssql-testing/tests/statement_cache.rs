@@ -0,0 +1,142 @@
+//! Behavior tests for the opt-in prepared-statement cache.
+//!
+//! With `Config::statement_cache` enabled, the first parameterized `query` of
+//! a statement goes out as `sp_prepexec` and later ones `sp_execute` the
+//! handle it returned. A statement pushed out of a full cache is released
+//! with `sp_unprepare`. With the cache off every call is `sp_executesql`.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use mssql_client::Client;
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, client_config, connect};
+
+const HANDLE: i32 = 7;
+const BY_ID: &str = "SELECT name FROM dbo.users WHERE id = @p1";
+const BY_AGE: &str = "SELECT name FROM dbo.users WHERE age > @p1";
+
+const SP_EXECUTESQL: u16 = 0x000A;
+const SP_EXECUTE: u16 = 0x000C;
+const SP_PREPEXEC: u16 = 0x000D;
+const SP_UNPREPARE: u16 = 0x000F;
+
+/// The `@handle` RETURNVALUE of `sp_prepexec`, then DONEPROC.
+fn prepexec_response() -> Bytes {
+    let name: Vec<u16> = "@handle".encode_utf16().collect();
+    let mut buf = BytesMut::new();
+    buf.put_u8(0xAC); // RETURNVALUE
+    buf.put_u16_le(0); // ordinal
+    buf.put_u8(name.len() as u8);
+    for unit in name {
+        buf.put_u16_le(unit);
+    }
+    buf.put_u8(0x01); // status: output parameter
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0); // flags
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(4);
+    buf.put_i32_le(HANDLE);
+    buf.put_u8(0xFE); // DONEPROC
+    buf.put_u16_le(0);
+    buf.put_u16_le(0);
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+/// The procedure id of an RPC request, and its leading INT parameter (the
+/// prepared handle for `sp_execute`/`sp_unprepare`).
+fn rpc_call(payload: &[u8]) -> (u16, Option<i32>) {
+    let mut buf = payload;
+    let headers_len = buf.get_u32_le() as usize;
+    buf = &payload[headers_len..];
+    assert_eq!(buf.get_u16_le(), 0xFFFF, "procedure is sent by id");
+    let proc_id = buf.get_u16_le();
+    buf.advance(2); // option flags
+    let name_len = buf.get_u8() as usize;
+    buf.advance(name_len * 2 + 1); // name, status
+    let handle =
+        (buf.get_u8() == 0x26 && buf.get_u8() == 4 && buf.get_u8() == 4).then(|| buf.get_i32_le());
+    (proc_id, handle)
+}
+
+async fn rpc_calls(server: &MockTdsServer) -> Vec<(u16, Option<i32>)> {
+    server
+        .rpc_requests()
+        .await
+        .iter()
+        .map(|payload| rpc_call(payload))
+        .collect()
+}
+
+async fn start() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_default_response(MockResponse::Raw(prepexec_response()))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+#[tokio::test]
+async fn second_execution_reuses_the_prepared_handle() {
+    let server = start().await;
+    let config = client_config(server.port()).with_statement_cache(true);
+    let mut client = Client::connect(config).await.expect("connect");
+
+    let _ = client.query(BY_ID, &[&1i32]).await.expect("first query");
+    let _ = client.query(BY_ID, &[&2i32]).await.expect("second query");
+
+    let calls = rpc_calls(&server).await;
+    assert_eq!(calls[0].0, SP_PREPEXEC);
+    assert_eq!(calls[1], (SP_EXECUTE, Some(HANDLE)));
+    assert_eq!(calls.len(), 2);
+
+    let stats = client.statement_cache_stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    let entries = client.statement_cache_entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!((entries[0].handle, entries[0].hit_count), (HANDLE, 1));
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn eviction_unprepares_the_dropped_handle() {
+    let server = start().await;
+    let config = client_config(server.port())
+        .with_statement_cache(true)
+        .with_statement_cache_size(1);
+    let mut client = Client::connect(config).await.expect("connect");
+
+    let _ = client
+        .query(BY_ID, &[&1i32])
+        .await
+        .expect("first statement");
+    let _ = client
+        .query(BY_AGE, &[&30i32])
+        .await
+        .expect("second statement");
+
+    let calls = rpc_calls(&server).await;
+    assert_eq!(calls[0].0, SP_PREPEXEC);
+    assert_eq!(calls[1].0, SP_PREPEXEC);
+    assert_eq!(calls[2], (SP_UNPREPARE, Some(HANDLE)));
+    assert_eq!(calls.len(), 3);
+    assert_eq!(client.statement_cache_stats().entries, 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn disabled_cache_always_uses_sp_executesql() {
+    let server = start().await;
+    let mut client = connect(&server).await;
+
+    let _ = client.query(BY_ID, &[&1i32]).await.expect("first query");
+    let _ = client.query(BY_ID, &[&2i32]).await.expect("second query");
+
+    let procs: Vec<u16> = rpc_calls(&server).await.iter().map(|c| c.0).collect();
+    assert_eq!(procs, [SP_EXECUTESQL, SP_EXECUTESQL]);
+    assert_eq!(client.statement_cache_stats().entries, 0);
+    let _ = client.close().await;
+}
@@ -0,0 +1,147 @@
+//! Behavior tests for server errors on the streaming read path.
+//!
+//! A streamed response reports its first ERROR token as [`Error::Server`]
+//! only after reading the rest of the response, so the error carries the
+//! server's later messages — as the buffered path does — and the connection
+//! is clean for the next request.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{Client, Error, Ready};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+const FAILING_SQL: &str = "INSERT INTO child VALUES (1)";
+const MID_STREAM_SQL: &str = "SELECT 1 AS a; INSERT INTO child VALUES (1)";
+
+fn put_utf16(buf: &mut BytesMut, s: &str) {
+    for unit in s.encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+}
+
+/// An ERROR (0xAA) or INFO (0xAB) token.
+fn put_message(buf: &mut BytesMut, token: u8, number: i32, class: u8, message: &str) {
+    let mut body = BytesMut::new();
+    body.put_i32_le(number);
+    body.put_u8(1); // state
+    body.put_u8(class);
+    body.put_u16_le(message.encode_utf16().count() as u16);
+    put_utf16(&mut body, message);
+    body.put_u8(0); // server name
+    body.put_u8(0); // procedure name
+    body.put_u32_le(1); // line
+
+    buf.put_u8(token);
+    buf.put_u16_le(body.len() as u16);
+    buf.put_slice(&body);
+}
+
+/// The tail of a failed INSERT: the constraint error, the termination
+/// notice SQL Server sends after it, and the final DONE with the error bit.
+fn put_failed_insert(buf: &mut BytesMut) {
+    put_message(buf, 0xAA, 547, 16, "The INSERT statement conflicted");
+    put_message(buf, 0xAA, 3621, 0, "The statement has been terminated.");
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0x0002); // ERROR
+    buf.put_u16_le(0xC3); // cur_cmd: INSERT
+    buf.put_u64_le(0);
+}
+
+fn failing_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    put_message(&mut buf, 0xAB, 0, 0, "before");
+    put_failed_insert(&mut buf);
+    buf.freeze()
+}
+
+/// One single-INT-row result set, then the failed INSERT.
+fn mid_stream_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(1);
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(1);
+    put_utf16(&mut buf, "a");
+    buf.put_u8(0xD1); // ROW
+    buf.put_u8(4);
+    buf.put_i32_le(1);
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0x0011); // MORE | COUNT
+    buf.put_u16_le(0xC1); // cur_cmd: SELECT
+    buf.put_u64_le(1);
+    put_failed_insert(&mut buf);
+    buf.freeze()
+}
+
+async fn start() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(FAILING_SQL, MockResponse::Raw(failing_response()))
+        .with_response(MID_STREAM_SQL, MockResponse::Raw(mid_stream_response()))
+        .with_response("SELECT 'next'", MockResponse::scalar_string("next"))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+async fn assert_reusable(client: &mut Client<Ready>) {
+    assert!(!client.is_in_flight(), "error leaves connection clean");
+    let rows = client
+        .query("SELECT 'next'", &[])
+        .await
+        .expect("follow-up query")
+        .collect_all()
+        .await
+        .expect("collect");
+    assert_eq!(rows[0].get::<String>(0).unwrap(), "next");
+}
+
+fn additional_numbers(err: &Error) -> Vec<i32> {
+    err.additional_messages().iter().map(|m| m.number).collect()
+}
+
+#[tokio::test]
+async fn query_stream_error_carries_surrounding_messages() {
+    let server = start().await;
+    let mut client = connect(&server).await;
+
+    let err = match client.query_stream(FAILING_SQL, &[]).await {
+        Ok(_) => panic!("the failed INSERT must be reported"),
+        Err(e) => e,
+    };
+    assert!(
+        matches!(err, Error::Server { number: 547, .. }),
+        "got {err:?}"
+    );
+    assert_eq!(additional_numbers(&err), [0, 3621]);
+
+    assert_reusable(&mut client).await;
+}
+
+#[tokio::test]
+async fn row_stream_error_mid_response_carries_later_messages() {
+    let server = start().await;
+    let mut client = connect(&server).await;
+
+    let mut stream = client
+        .query_stream(MID_STREAM_SQL, &[])
+        .await
+        .expect("first result set streams");
+    let row = stream.try_next().await.expect("row").expect("one row");
+    assert_eq!(row.get::<i32>(0).unwrap(), 1);
+    let err = stream.try_next().await.expect_err("the failed INSERT");
+    assert!(
+        matches!(err, Error::Server { number: 547, .. }),
+        "got {err:?}"
+    );
+    assert_eq!(additional_numbers(&err), [3621]);
+    assert!(stream.try_next().await.expect("finished").is_none());
+    drop(stream);
+
+    assert_reusable(&mut client).await;
+}
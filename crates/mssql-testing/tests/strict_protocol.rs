@@ -0,0 +1,104 @@
+//! Behavior tests for `Config::strict_protocol`.
+//!
+//! A TABNAME token in the middle of a result set is skipped by default; with
+//! strict protocol parsing it must fail the request with a protocol error that
+//! names the token byte, on both the buffered and the streaming read paths.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{Client, Error};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, client_config};
+
+/// `SELECT n` with one row (`n = 5`) and an empty TABNAME token between the
+/// metadata and the row.
+fn response() -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(1);
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(1);
+    buf.put_u16_le(u16::from(b'n'));
+
+    buf.put_u8(0xA4); // TABNAME
+    buf.put_u16_le(0);
+
+    buf.put_u8(0xD1); // ROW
+    buf.put_u8(4);
+    buf.put_i32_le(5);
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0x0010); // count
+    buf.put_u16_le(0xC1); // SELECT
+    buf.put_u64_le(1);
+    buf.freeze()
+}
+
+async fn connect(server: &MockTdsServer, strict: bool) -> Client<mssql_client::Ready> {
+    let config = client_config(server.port()).strict_protocol(strict);
+    Client::connect(config).await.expect("connect")
+}
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_default_response(MockResponse::Raw(response()))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+fn assert_unhandled_tabname(err: &Error) {
+    assert!(err.is_protocol_error(), "got {err:?}");
+    assert!(err.to_string().contains("0xa4"), "got {err}");
+}
+
+#[tokio::test]
+async fn lenient_parsing_skips_tabname() {
+    let server = start_server().await;
+    let mut client = connect(&server, false).await;
+
+    let rows: Vec<_> = client
+        .query("SELECT n FROM t", &[])
+        .await
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<i32>(0).unwrap(), 5);
+
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn strict_parsing_rejects_tabname_in_buffered_query() {
+    let server = start_server().await;
+    let mut client = connect(&server, true).await;
+
+    let err = client
+        .query("SELECT n FROM t", &[])
+        .await
+        .err()
+        .expect("strict parsing must reject TABNAME");
+    assert_unhandled_tabname(&err);
+}
+
+#[tokio::test]
+async fn strict_parsing_rejects_tabname_in_streamed_query() {
+    let server = start_server().await;
+    let mut client = connect(&server, true).await;
+
+    let mut rows = client
+        .query_stream("SELECT n FROM t", &[])
+        .await
+        .expect("metadata precedes the TABNAME token");
+    let err = rows
+        .try_next()
+        .await
+        .expect_err("strict parsing must reject TABNAME");
+    assert_unhandled_tabname(&err);
+}
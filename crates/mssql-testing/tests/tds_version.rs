@@ -0,0 +1,89 @@
+//! Behavior tests for requesting an older TDS version with `TDS Version=`.
+//!
+//! The requested version is sent in the LOGIN7 header. Versions before 7.4
+//! have no FeatureExt block, so such a login carries no feature extensions;
+//! the session then runs at the version the server acknowledges, including
+//! the 7.1/7.2 of SQL Server 2000/2005.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::Bytes;
+use mssql_client::{Client, Config};
+use mssql_testing::mock_server::{
+    MockResponse, MockServerBuilder, MockTdsServer, connection_string,
+};
+use tds_protocol::TdsVersion;
+
+fn mock_config(port: u16, extra: &str) -> Config {
+    Config::from_connection_string(&format!("{};{extra}", connection_string(port)))
+        .expect("config parses")
+}
+
+async fn start(builder: MockServerBuilder) -> MockTdsServer {
+    builder
+        .with_response("SELECT 1", MockResponse::scalar_int(1))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+/// LOGIN7 fixed header: Length (4), then TDSVersion (4).
+fn requested_version(login: &Bytes) -> TdsVersion {
+    TdsVersion::new(u32::from_le_bytes(login[4..8].try_into().unwrap()))
+}
+
+/// OptionFlags3 (offset 27) bit 0x10: a FeatureExt block follows.
+fn has_feature_ext(login: &Bytes) -> bool {
+    login[27] & 0x10 != 0
+}
+
+#[tokio::test]
+async fn requested_version_is_sent_in_login7() {
+    let server = start(MockTdsServer::builder().with_tds_version(TdsVersion::V7_3A)).await;
+
+    let mut client = Client::connect(mock_config(server.port(), "TDS Version=7.3"))
+        .await
+        .expect("connect at 7.3");
+
+    let login = &server.login_requests().await[0];
+    assert_eq!(requested_version(login), TdsVersion::V7_3A);
+    assert!(!has_feature_ext(login), "7.3 has no FeatureExt block");
+
+    let rows = client.query("SELECT 1", &[]).await.expect("query");
+    assert_eq!(rows.count(), 1);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn default_login_requests_7_4_with_feature_extensions() {
+    let server = start(MockTdsServer::builder()).await;
+
+    let client = Client::connect(mock_config(server.port(), ""))
+        .await
+        .expect("connect");
+
+    let login = &server.login_requests().await[0];
+    assert_eq!(requested_version(login), TdsVersion::V7_4);
+    assert!(has_feature_ext(login), "UTF-8 support is requested");
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn legacy_login_ack_is_accepted_and_reported() {
+    for legacy in [TdsVersion::V7_2, TdsVersion::V7_1] {
+        let server = start(MockTdsServer::builder().with_tds_version(legacy)).await;
+
+        let mut client = Client::connect(mock_config(server.port(), ""))
+            .await
+            .expect("connect to a legacy server");
+        let version = client.tds_version().expect("LoginAck version");
+        assert_eq!(version, legacy);
+        assert!(!version.supports_date_time_types());
+
+        let rows = client.query("SELECT 1", &[]).await.expect("query");
+        assert_eq!(rows.count(), 1);
+        let _ = client.close().await;
+    }
+}
@@ -0,0 +1,128 @@
+//! Behavior tests for batches that return result sets after the expected one.
+//!
+//! A batch such as `SELECT ...; SELECT ...` — or an `INSERT` whose trigger
+//! selects — sends extra result sets in the same response. Whatever the
+//! caller reads, every trailing token must be consumed before the call
+//! returns so the next request on the connection sees only its own response.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::Client;
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+const BATCH_SQL: &str = "SELECT 1 AS id; SELECT 2 AS id";
+
+/// One single-INT-row result set followed by its DONE token.
+fn put_result_set(buf: &mut BytesMut, value: i32, done_status: u16) {
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(1);
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(0x26); // INTN
+    buf.put_u8(4);
+    buf.put_u8(2);
+    for unit in "id".encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+
+    buf.put_u8(0xD1); // ROW
+    buf.put_u8(4);
+    buf.put_i32_le(value);
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(done_status);
+    buf.put_u16_le(0xC1); // cur_cmd: SELECT
+    buf.put_u64_le(1);
+}
+
+/// Two result sets in one response: the first DONE carries DONE_MORE.
+fn two_result_sets() -> Bytes {
+    let mut buf = BytesMut::new();
+    put_result_set(&mut buf, 1, 0x0011); // MORE | COUNT
+    put_result_set(&mut buf, 2, 0x0010); // final, COUNT
+    buf.freeze()
+}
+
+async fn start_server() -> MockTdsServer {
+    MockTdsServer::builder()
+        .with_response(BATCH_SQL, MockResponse::Raw(two_result_sets()))
+        .with_response("SELECT 'next'", MockResponse::scalar_string("next"))
+        .build()
+        .await
+        .expect("server starts")
+}
+
+async fn assert_next_query_clean(client: &mut Client<mssql_client::Ready>) {
+    assert!(!client.is_in_flight(), "response must be fully drained");
+    let rows = client
+        .query("SELECT 'next'", &[])
+        .await
+        .expect("follow-up query")
+        .collect_all()
+        .await
+        .expect("collect");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<String>(0).unwrap(), "next");
+}
+
+#[tokio::test]
+async fn query_drains_trailing_result_sets() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let rows = client
+        .query(BATCH_SQL, &[])
+        .await
+        .expect("batch query")
+        .collect_all()
+        .await
+        .expect("collect");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(
+        rows[0].get::<i32>(0).unwrap(),
+        2,
+        "query returns the last result set"
+    );
+
+    assert_next_query_clean(&mut client).await;
+}
+
+#[tokio::test]
+async fn dropped_stream_does_not_leak_trailing_result_sets() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    {
+        let mut stream = client.query_stream(BATCH_SQL, &[]).await.expect("stream");
+        let first = stream.try_next().await.expect("first row").expect("row");
+        assert_eq!(first.get::<i32>(0).unwrap(), 1);
+        // Dropped with the second result set still unread.
+    }
+
+    let rows = client
+        .query("SELECT 'next'", &[])
+        .await
+        .expect("follow-up query")
+        .collect_all()
+        .await
+        .expect("collect");
+    assert_eq!(rows[0].get::<String>(0).unwrap(), "next");
+}
+
+#[tokio::test]
+async fn query_multiple_reads_every_result_set() {
+    let server = start_server().await;
+    let mut client = connect(&server).await;
+
+    let results = client
+        .query_multiple(BATCH_SQL, &[])
+        .await
+        .expect("query_multiple");
+    assert_eq!(results.result_count(), 2);
+    drop(results);
+
+    assert_next_query_clean(&mut client).await;
+}
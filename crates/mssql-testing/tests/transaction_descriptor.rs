@@ -0,0 +1,134 @@
+//! Behavior tests for the transaction descriptor in request headers.
+//!
+//! Inside an explicit transaction every request must carry the descriptor
+//! from the BeginTransaction ENVCHANGE in its ALL_HEADERS; outside one it is
+//! zero. Both SQL batches and parameterized (RPC) requests are checked.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, connect};
+
+const DESCRIPTOR: [u8; 8] = [0x2A, 0, 0, 0, 0, 0, 0x01, 0];
+const UPDATE: &str = "UPDATE t SET x = 1";
+const PARAMETERIZED_UPDATE: &str = "UPDATE t SET x = @p1";
+
+/// A transaction ENVCHANGE (`env_type` 8 = begin, 9 = commit) and DONE.
+fn transaction_response(env_type: u8, new: &[u8], old: &[u8]) -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0xE3); // ENVCHANGE
+    buf.put_u16_le((1 + 1 + new.len() + 1 + old.len()) as u16);
+    buf.put_u8(env_type);
+    buf.put_u8(new.len() as u8);
+    buf.put_slice(new);
+    buf.put_u8(old.len() as u8);
+    buf.put_slice(old);
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0);
+    buf.put_u16_le(0);
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+/// The transaction descriptor from the ALL_HEADERS that open a SQL batch or
+/// RPC request.
+fn request_descriptor(payload: &[u8]) -> u64 {
+    // ALL_HEADERS: total length (4), then the transaction descriptor header:
+    // length (4), type (2) = 0x0002, descriptor (8), outstanding requests (4).
+    assert_eq!(
+        u16::from_le_bytes([payload[8], payload[9]]),
+        0x0002,
+        "first header is not the transaction descriptor"
+    );
+    let mut descriptor = [0u8; 8];
+    descriptor.copy_from_slice(&payload[10..18]);
+    u64::from_le_bytes(descriptor)
+}
+
+#[tokio::test]
+async fn batches_in_a_transaction_carry_its_descriptor() {
+    let server = MockTdsServer::builder()
+        .with_response(
+            "BEGIN TRANSACTION",
+            MockResponse::Raw(transaction_response(8, &DESCRIPTOR, &[])),
+        )
+        .with_response(
+            "COMMIT TRANSACTION",
+            MockResponse::Raw(transaction_response(9, &[], &DESCRIPTOR)),
+        )
+        .with_response(UPDATE, MockResponse::affected(1))
+        .build()
+        .await
+        .expect("server starts");
+    let client = connect(&server).await;
+
+    let mut tx = client.begin_transaction().await.expect("begin");
+    tx.execute(UPDATE, &[])
+        .await
+        .expect("update in transaction");
+    let mut client = tx.commit().await.expect("commit");
+    client
+        .execute(UPDATE, &[])
+        .await
+        .expect("update after commit");
+
+    let batches = server.sql_batches().await;
+    let payloads = server.sql_batch_requests().await;
+    let descriptors: Vec<(String, u64)> = batches
+        .into_iter()
+        .zip(payloads.iter().map(|p| request_descriptor(p)))
+        .collect();
+    assert_eq!(
+        descriptors,
+        [
+            ("BEGIN TRANSACTION".to_string(), 0),
+            (UPDATE.to_string(), u64::from_le_bytes(DESCRIPTOR)),
+            (
+                "COMMIT TRANSACTION".to_string(),
+                u64::from_le_bytes(DESCRIPTOR)
+            ),
+            (UPDATE.to_string(), 0),
+        ]
+    );
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn rpc_requests_in_a_transaction_carry_its_descriptor() {
+    let server = MockTdsServer::builder()
+        .with_response(
+            "BEGIN TRANSACTION",
+            MockResponse::Raw(transaction_response(8, &DESCRIPTOR, &[])),
+        )
+        .with_response(
+            "COMMIT TRANSACTION",
+            MockResponse::Raw(transaction_response(9, &[], &DESCRIPTOR)),
+        )
+        .with_default_response(MockResponse::affected(1))
+        .build()
+        .await
+        .expect("server starts");
+    let client = connect(&server).await;
+
+    let mut tx = client.begin_transaction().await.expect("begin");
+    tx.execute(PARAMETERIZED_UPDATE, &[&1i32])
+        .await
+        .expect("parameterized update in transaction");
+    let mut client = tx.commit().await.expect("commit");
+    client
+        .execute(PARAMETERIZED_UPDATE, &[&2i32])
+        .await
+        .expect("parameterized update after commit");
+
+    // Both updates go out as sp_executesql RPCs, not SQL batches.
+    let descriptors: Vec<u64> = server
+        .rpc_requests()
+        .await
+        .iter()
+        .map(|p| request_descriptor(p))
+        .collect();
+    assert_eq!(descriptors, [u64::from_le_bytes(DESCRIPTOR), 0]);
+    let _ = client.close().await;
+}
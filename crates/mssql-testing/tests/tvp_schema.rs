@@ -0,0 +1,111 @@
+//! Behavior tests for sending table-valued parameters built from a shared
+//! `TvpSchema`.
+//!
+//! One schema is resolved up front and reused for several batches; each RPC
+//! still carries the TVP's type and column metadata exactly once, followed by
+//! that batch's rows. A row with the wrong number of values is rejected
+//! before anything is sent.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{Client, Error, Ready, SqlValue, TvpColumn, TvpRow, TvpSchema, TvpValue};
+use mssql_testing::mock_server::{self, MockTdsServer};
+
+const EXEC: &str = "EXEC dbo.ImportUsers @UserIds = @p1";
+
+async fn connect() -> (MockTdsServer, Client<Ready>) {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+fn schema() -> TvpSchema {
+    TvpSchema::new("dbo.UserIdList", vec![TvpColumn::new("UserId", "INT", 0)])
+        .expect("schema resolves")
+}
+
+/// The start of the TVP metadata: TVP type ID, empty DbName, then the
+/// owning schema and type name as B_VARCHARs, then the column count.
+fn metadata_header() -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0xF3);
+    buf.put_u8(0);
+    for name in ["dbo", "UserIdList"] {
+        buf.put_u8(name.len() as u8);
+        for unit in name.encode_utf16() {
+            buf.put_u16_le(unit);
+        }
+    }
+    buf.put_u16_le(1);
+    buf.freeze()
+}
+
+fn occurrences(haystack: &[u8], needle: &[u8]) -> usize {
+    haystack
+        .windows(needle.len())
+        .filter(|window| *window == needle)
+        .count()
+}
+
+#[tokio::test]
+async fn one_schema_sends_metadata_once_per_rpc() {
+    let (server, mut client) = connect().await;
+    let schema = schema();
+
+    for batch in [[1, 2], [3, 4], [5, 6]] {
+        let rows = batch
+            .iter()
+            .map(|id| TvpRow::new(vec![SqlValue::Int(*id)]))
+            .collect();
+        let tvp = TvpValue::with_schema(&schema, rows);
+        client.execute(EXEC, &[&tvp]).await.expect("execute");
+    }
+
+    let rpcs = server.rpc_requests().await;
+    assert_eq!(rpcs.len(), 3);
+    let header = metadata_header();
+    for (rpc, batch) in rpcs.iter().zip([[1, 2], [3, 4], [5, 6]]) {
+        assert_eq!(occurrences(rpc, &header), 1, "metadata sent once per RPC");
+        for id in batch {
+            // INTN cell: length byte, then the value.
+            let mut cell = vec![4u8];
+            cell.extend_from_slice(&i32::to_le_bytes(id));
+            assert_eq!(occurrences(rpc, &cell), 1, "row {id} sent once");
+        }
+    }
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn row_with_wrong_value_count_is_rejected_before_sending() {
+    let (server, mut client) = connect().await;
+
+    let tvp = TvpValue::with_schema(
+        &schema(),
+        vec![
+            TvpRow::new(vec![SqlValue::Int(1)]),
+            TvpRow::new(vec![SqlValue::Int(2), SqlValue::Int(3)]),
+        ],
+    );
+    let err = client
+        .execute(EXEC, &[&tvp])
+        .await
+        .expect_err("row 1 has two values");
+    match err {
+        Error::Config(message) => {
+            assert!(message.contains("row 1 has 2 values"), "{message}");
+            assert!(message.contains("1 columns"), "{message}");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert!(server.rpc_requests().await.is_empty());
+
+    assert!(!client.is_in_flight());
+    let _ = client.close().await;
+}
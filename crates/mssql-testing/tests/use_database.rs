@@ -0,0 +1,110 @@
+//! Behavior tests for `Client::use_database`.
+//!
+//! The database name is validated and bracket-quoted before `USE` is sent;
+//! `current_database()` then follows the database ENVCHANGE in the
+//! response. A database the server cannot open surfaces the server's error.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use mssql_client::{Client, Error, Ready};
+use mssql_testing::mock_server::{self, MockResponse, MockTdsServer};
+use tds_protocol::ServerError;
+
+fn put_b_varchar(buf: &mut BytesMut, value: &str) {
+    buf.put_u8(value.encode_utf16().count() as u8);
+    for unit in value.encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+}
+
+/// A Database ENVCHANGE from `old` to `new`, then DONE.
+fn database_changed(old: &str, new: &str) -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0xE3); // ENVCHANGE
+    let len = 1 + (1 + 2 * new.len()) + (1 + 2 * old.len());
+    buf.put_u16_le(len as u16);
+    buf.put_u8(1); // Database
+    put_b_varchar(&mut buf, new);
+    put_b_varchar(&mut buf, old);
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0);
+    buf.put_u16_le(0);
+    buf.put_u64_le(0);
+    buf.freeze()
+}
+
+async fn connect() -> (MockTdsServer, Client<Ready>) {
+    let server = MockTdsServer::builder()
+        .with_database("master")
+        .with_response(
+            "USE [sales]",
+            MockResponse::Raw(database_changed("master", "sales")),
+        )
+        .with_response(
+            "USE [missing]",
+            MockResponse::server_error(&ServerError {
+                number: 911,
+                state: 1,
+                class: 16,
+                message: "Database 'missing' does not exist. Make sure that the name is \
+                          entered correctly."
+                    .to_string(),
+                server: String::new(),
+                procedure: String::new(),
+                line: 1,
+            }),
+        )
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+#[tokio::test]
+async fn current_database_follows_the_switch() {
+    let (server, mut client) = connect().await;
+    assert_eq!(client.current_database(), Some("master"));
+
+    client.use_database("sales").await.expect("use sales");
+
+    assert_eq!(client.current_database(), Some("sales"));
+    assert_eq!(server.sql_batches().await.last().unwrap(), "USE [sales]");
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn missing_database_returns_the_server_error() {
+    let (_server, mut client) = connect().await;
+
+    let err = client
+        .use_database("missing")
+        .await
+        .expect_err("no such database");
+
+    assert!(
+        matches!(err, Error::Server { number: 911, .. }),
+        "got {err:?}"
+    );
+    assert_eq!(client.current_database(), Some("master"));
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn invalid_name_is_rejected_before_sending() {
+    let (server, mut client) = connect().await;
+    let sent_before = server.sql_batches().await.len();
+
+    let err = client
+        .use_database("sales]; DROP TABLE users; --")
+        .await
+        .expect_err("not an identifier");
+
+    assert!(matches!(err, Error::InvalidIdentifier(_)), "got {err:?}");
+    assert_eq!(server.sql_batches().await.len(), sent_before);
+    assert_eq!(client.current_database(), Some("master"));
+    let _ = client.close().await;
+}
@@ -0,0 +1,131 @@
+//! Behavior tests for reading and binding `chrono::DateTime<Utc>`.
+//!
+//! A `DateTime<Utc>` parameter is sent as a native DATETIMEOFFSET. Reading
+//! one back works from a DATETIMEOFFSET column (converted from its stored
+//! offset) and from a DATETIME2 column (taken as UTC).
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use bytes::{BufMut, Bytes, BytesMut};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+use mssql_client::{Client, Ready};
+use mssql_testing::mock_server::{self, MockResponse, MockTdsServer};
+
+const SELECT: &str = "SELECT created_at, created_at_utc FROM dbo.Events";
+
+fn instant() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 3, 15, 10, 0, 0)
+        .unwrap()
+        .with_nanosecond(123_456_700)
+        .unwrap()
+}
+
+/// TIME(7) and DATE parts as SQL Server stores them: 100 ns intervals since
+/// midnight in 5 bytes, then days since 0001-01-01 in 3 bytes.
+fn put_datetime2(buf: &mut BytesMut, value: NaiveDateTime) {
+    let intervals = u64::from(value.num_seconds_from_midnight()) * 10_000_000
+        + u64::from(value.nanosecond()) / 100;
+    buf.put_slice(&intervals.to_le_bytes()[..5]);
+    let days = (value.date() - NaiveDate::from_ymd_opt(1, 1, 1).unwrap()).num_days() as u32;
+    buf.put_slice(&days.to_le_bytes()[..3]);
+}
+
+fn put_column(buf: &mut BytesMut, type_id: u8, name: &str) {
+    buf.put_u32_le(0); // user type
+    buf.put_u16_le(0x0001); // nullable
+    buf.put_u8(type_id);
+    buf.put_u8(7); // scale
+    buf.put_u8(name.len() as u8);
+    for unit in name.encode_utf16() {
+        buf.put_u16_le(unit);
+    }
+}
+
+/// One row of `instant()`: as DATETIMEOFFSET(7) stored at +05:30, and as
+/// DATETIME2(7) holding the UTC wall-clock time.
+fn select_response() -> Bytes {
+    let mut buf = BytesMut::new();
+    buf.put_u8(0x81); // COLMETADATA
+    buf.put_u16_le(2);
+    put_column(&mut buf, 0x2B, "created_at"); // DATETIMEOFFSETN
+    put_column(&mut buf, 0x2A, "created_at_utc"); // DATETIME2N
+
+    buf.put_u8(0xD1); // ROW
+    buf.put_u8(10);
+    put_datetime2(&mut buf, instant().naive_utc());
+    buf.put_i16_le(5 * 60 + 30);
+    buf.put_u8(8);
+    put_datetime2(&mut buf, instant().naive_utc());
+
+    buf.put_u8(0xFD); // DONE
+    buf.put_u16_le(0x0010); // count
+    buf.put_u16_le(0xC1); // cur_cmd: SELECT
+    buf.put_u64_le(1);
+    buf.freeze()
+}
+
+async fn connect() -> (MockTdsServer, Client<Ready>) {
+    let server = MockTdsServer::builder()
+        .with_response(SELECT, MockResponse::Raw(select_response()))
+        .build()
+        .await
+        .expect("server starts");
+    let client = mock_server::connect(&server).await;
+    (server, client)
+}
+
+#[tokio::test]
+async fn datetimeoffset_and_datetime2_columns_read_as_utc() {
+    let (_server, mut client) = connect().await;
+
+    let rows: Vec<_> = client
+        .query(SELECT, &[])
+        .await
+        .expect("query")
+        .collect::<Result<_, _>>()
+        .expect("rows decode");
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].get::<DateTime<Utc>>(0).unwrap(), instant());
+    assert_eq!(rows[0].get::<DateTime<Utc>>(1).unwrap(), instant());
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn utc_parameter_is_sent_as_datetimeoffset() {
+    let (server, mut client) = connect().await;
+
+    client
+        .execute(
+            "INSERT INTO dbo.Events (created_at) VALUES (@p1)",
+            &[&instant()],
+        )
+        .await
+        .expect("execute");
+
+    let rpcs = server.rpc_requests().await;
+    assert_eq!(rpcs.len(), 1);
+    let declaration: Vec<u8> = "@p1 datetimeoffset"
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    assert!(
+        rpcs[0]
+            .windows(declaration.len())
+            .any(|window| window == declaration),
+        "parameter declared as datetimeoffset"
+    );
+    // DATETIMEOFFSETN(7) value: length 10, the UTC parts, then a +00:00 offset.
+    let mut value = BytesMut::new();
+    value.put_u8(10);
+    put_datetime2(&mut value, instant().naive_utc());
+    value.put_i16_le(0);
+    assert!(
+        rpcs[0]
+            .windows(value.len())
+            .any(|window| window == &value[..])
+    );
+    let _ = client.close().await;
+}
@@ -0,0 +1,100 @@
+//! Behavior tests for `Client::validate_sql`.
+//!
+//! The batch is sent on its own between `SET NOEXEC ON` and `SET NOEXEC OFF`,
+//! and compile errors are returned as server errors. NOEXEC is turned back
+//! off even when the batch fails to compile or times out.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::time::Duration;
+
+use mssql_client::{Client, Error, TimeoutConfig};
+use mssql_testing::mock_server::{
+    MockColumn, MockResponse, MockTdsServer, ScalarValue, client_config, connect,
+};
+
+const VALID: &str = "DELETE FROM dbo.orders WHERE id = 1";
+const INVALID: &str = "SELECT * FROM dbo.missing";
+
+#[tokio::test]
+async fn validate_sql_wraps_the_batch_in_noexec() {
+    let server = MockTdsServer::builder()
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    client.validate_sql(VALID).await.expect("batch compiles");
+
+    assert_eq!(
+        server.sql_batches().await,
+        ["SET NOEXEC ON", VALID, "SET NOEXEC OFF"]
+    );
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn validate_sql_reports_compile_errors_and_restores_noexec() {
+    let server = MockTdsServer::builder()
+        .with_response(
+            INVALID,
+            MockResponse::error(208, "Invalid object name 'dbo.missing'."),
+        )
+        .build()
+        .await
+        .expect("server starts");
+    let mut client = connect(&server).await;
+
+    let err = client
+        .validate_sql(INVALID)
+        .await
+        .expect_err("compile error is reported");
+    assert!(
+        matches!(err, Error::Server { number: 208, .. }),
+        "unexpected error: {err:?}"
+    );
+
+    assert_eq!(
+        server.sql_batches().await,
+        ["SET NOEXEC ON", INVALID, "SET NOEXEC OFF"]
+    );
+
+    // The connection is still usable afterwards.
+    client.execute(VALID, &[]).await.expect("execute");
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn validate_sql_times_out_and_still_restores_noexec() {
+    const HUNG: &str = "SELECT id FROM locked_table";
+    let server = MockTdsServer::builder()
+        .with_response(
+            HUNG,
+            MockResponse::unfinished_rows(
+                vec![MockColumn::int("id")],
+                vec![vec![ScalarValue::Int(1)]],
+            ),
+        )
+        .build()
+        .await
+        .expect("server starts");
+    let config = client_config(server.port())
+        .timeouts(TimeoutConfig::new().command_timeout(Duration::from_millis(200)));
+    let mut client = Client::connect(config).await.expect("connect");
+
+    let err = client
+        .validate_sql(HUNG)
+        .await
+        .expect_err("hung batch must time out");
+    assert!(matches!(err, Error::CommandTimeout), "got {err:?}");
+
+    assert_eq!(server.attention_count().await, 1);
+    assert_eq!(
+        server.sql_batches().await,
+        ["SET NOEXEC ON", HUNG, "SET NOEXEC OFF"]
+    );
+    client.execute(VALID, &[]).await.expect("execute");
+    let _ = client.close().await;
+}
@@ -0,0 +1,90 @@
+//! Behavior tests for `Config::xact_abort`.
+//!
+//! With the option on, the client sends `SET XACT_ABORT ON` right after
+//! login, and again on every connection reset (RESETCONNECTION restores the
+//! login's SET options). With it off, no extra batch is sent.
+//!
+//! These run in normal CI; no live SQL Server required.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::sync::{Arc, Mutex};
+
+use mssql_client::{Client, Config};
+use mssql_testing::mock_server::{MockResponse, MockTdsServer, client_config};
+
+/// Start a server that records every SQL batch it receives.
+async fn start_server() -> (MockTdsServer, Arc<Mutex<Vec<String>>>) {
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&batches);
+    let server = MockTdsServer::builder()
+        .with_default_response(MockResponse::Custom(Arc::new(move |sql| {
+            recorded.lock().unwrap().push(sql.trim().to_string());
+            MockResponse::affected(1)
+        })))
+        .build()
+        .await
+        .expect("server starts");
+    (server, batches)
+}
+
+fn mock_config(port: u16, xact_abort: bool) -> Config {
+    client_config(port).xact_abort(xact_abort)
+}
+
+#[tokio::test]
+async fn xact_abort_is_applied_after_login() {
+    let (server, batches) = start_server().await;
+    let mut client = Client::connect(mock_config(server.port(), true))
+        .await
+        .expect("connect");
+    client.execute("UPDATE t SET x = 1", &[]).await.unwrap();
+
+    assert_eq!(
+        *batches.lock().unwrap(),
+        vec!["SET XACT_ABORT ON", "UPDATE t SET x = 1"]
+    );
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn xact_abort_is_reapplied_after_reset() {
+    let (server, batches) = start_server().await;
+    let mut client = Client::connect(mock_config(server.port(), true))
+        .await
+        .expect("connect");
+    batches.lock().unwrap().clear();
+
+    client.mark_needs_reset();
+    client.execute("UPDATE t SET x = 1", &[]).await.unwrap();
+
+    assert_eq!(
+        *batches.lock().unwrap(),
+        vec!["SET XACT_ABORT ON", "UPDATE t SET x = 1"]
+    );
+    assert_eq!(
+        server.reset_request_count().await,
+        1,
+        "one reset per request pair"
+    );
+    assert!(!client.needs_reset());
+
+    // Without a pending reset the option is not re-sent.
+    client.execute("UPDATE t SET x = 2", &[]).await.unwrap();
+    assert_eq!(batches.lock().unwrap().len(), 3);
+    let _ = client.close().await;
+}
+
+#[tokio::test]
+async fn xact_abort_off_sends_no_extra_batch() {
+    let (server, batches) = start_server().await;
+    let mut client = Client::connect(mock_config(server.port(), false))
+        .await
+        .expect("connect");
+    client.mark_needs_reset();
+    client.execute("UPDATE t SET x = 1", &[]).await.unwrap();
+
+    assert_eq!(*batches.lock().unwrap(), vec!["UPDATE t SET x = 1"]);
+    assert_eq!(server.reset_request_count().await, 1);
+    let _ = client.close().await;
+}
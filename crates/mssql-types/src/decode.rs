@@ -546,7 +546,6 @@ fn decode_guid(buf: &mut Bytes) -> Result<SqlValue, TypeError> {
 
 #[cfg(not(feature = "uuid"))]
 fn decode_guid(buf: &mut Bytes) -> Result<SqlValue, TypeError> {
-    // Skip the GUID data
     if buf.remaining() < 1 {
         return Err(TypeError::BufferTooSmall {
             needed: 1,
@@ -559,15 +558,28 @@ fn decode_guid(buf: &mut Bytes) -> Result<SqlValue, TypeError> {
         return Ok(SqlValue::Null);
     }
 
-    if buf.remaining() < len {
+    if len != 16 {
+        return Err(TypeError::InvalidBinary(format!(
+            "invalid GUID length: {len}"
+        )));
+    }
+
+    if buf.remaining() < 16 {
         return Err(TypeError::BufferTooSmall {
-            needed: len,
+            needed: 16,
             available: buf.remaining(),
         });
     }
 
-    let data = buf.copy_to_bytes(len);
-    Ok(SqlValue::Binary(data))
+    // Without the `uuid` feature the GUID is returned as its 16 RFC 4122
+    // bytes, un-swapping the mixed-endian first three groups exactly as the
+    // `uuid` path does, so both builds agree on the byte order.
+    let mut bytes = [0u8; 16];
+    buf.copy_to_slice(&mut bytes);
+    bytes[0..4].reverse();
+    bytes[4..6].reverse();
+    bytes[6..8].reverse();
+    Ok(SqlValue::Binary(Bytes::copy_from_slice(&bytes)))
 }
 
 #[cfg(feature = "decimal")]
@@ -1224,6 +1236,25 @@ mod tests {
         assert_eq!(decoded, SqlValue::Uuid(original));
     }
 
+    /// Without the `uuid` feature the GUID decodes to its RFC 4122 bytes, not
+    /// the raw mixed-endian wire bytes.
+    #[cfg(not(feature = "uuid"))]
+    #[test]
+    fn test_guid_decodes_to_rfc_bytes_without_uuid_feature() {
+        let wire = [
+            16u8, 0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xaa, 0xbb, 0xcc,
+            0xdd, 0xee, 0xff,
+        ];
+        let decoded = decode_guid(&mut Bytes::copy_from_slice(&wire)).unwrap();
+        assert_eq!(
+            decoded,
+            SqlValue::Binary(Bytes::from_static(&[
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+                0xee, 0xff
+            ]))
+        );
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn hostile_datetime_days_overflow_is_error_not_panic() {
@@ -1288,6 +1319,118 @@ mod tests {
         }
     }
 
+    /// `DateTime<Utc>` and `DateTime<FixedOffset>` parameters go through
+    /// `ToSql` as native DATETIMEOFFSET values (not strings) and read back
+    /// with the same instant and offset: UTC as +00:00, a fixed offset as
+    /// itself.
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_datetimeoffset_tosql_roundtrip_preserves_offset() {
+        use crate::encode::encode_datetimeoffset;
+        use crate::{FromSql, ToSql};
+        use bytes::BufMut;
+        use chrono::{FixedOffset, TimeZone, Timelike, Utc};
+
+        fn roundtrip(param: &dyn ToSql) -> SqlValue {
+            assert_eq!(param.sql_type(), "DATETIMEOFFSET");
+            let SqlValue::DateTimeOffset(dto) = param.to_sql().unwrap() else {
+                panic!("must bind as a native DATETIMEOFFSET");
+            };
+            let mut encoded = bytes::BytesMut::new();
+            encode_datetimeoffset(dto, &mut encoded).unwrap();
+            let mut framed = bytes::BytesMut::new();
+            framed.put_u8(encoded.len() as u8);
+            framed.put_slice(&encoded);
+            let type_info = TypeInfo {
+                type_id: 0x2B,
+                length: None,
+                scale: Some(7),
+                precision: None,
+                collation: None,
+            };
+            decode_value(&mut framed.freeze(), &type_info).unwrap()
+        }
+
+        let utc = Utc
+            .with_ymd_and_hms(2024, 3, 15, 10, 0, 0)
+            .unwrap()
+            .with_nanosecond(123_456_700)
+            .unwrap();
+        let value = roundtrip(&utc);
+        let read = chrono::DateTime::<FixedOffset>::from_sql(&value).unwrap();
+        assert_eq!(read.offset().local_minus_utc(), 0);
+        assert_eq!(chrono::DateTime::<Utc>::from_sql(&value).unwrap(), utc);
+
+        let ist = FixedOffset::east_opt(5 * 3600 + 30 * 60)
+            .unwrap()
+            .with_ymd_and_hms(2024, 3, 15, 15, 30, 0)
+            .unwrap();
+        let read = chrono::DateTime::<FixedOffset>::from_sql(&roundtrip(&ist)).unwrap();
+        assert_eq!(read, ist);
+        assert_eq!(read.offset(), ist.offset());
+    }
+
+    /// The `time` crate types bind as the same SQL types as their chrono
+    /// counterparts and read back unchanged, including an `OffsetDateTime`'s
+    /// offset through a DATETIMEOFFSET.
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_crate_types_roundtrip() {
+        use crate::encode::encode_datetimeoffset;
+        use crate::{FromSql, ToSql};
+        use bytes::BufMut;
+
+        let date = time::Date::from_calendar_date(2024, time::Month::March, 15).unwrap();
+        let time_of_day = time::Time::from_hms_nano(15, 30, 0, 123_456_700).unwrap();
+        let local = time::PrimitiveDateTime::new(date, time_of_day);
+        let offset = time::UtcOffset::from_hms(5, 30, 0).unwrap();
+        let odt = local.assume_offset(offset);
+
+        assert_eq!(date.sql_type(), "DATE");
+        assert_eq!(time_of_day.sql_type(), "TIME");
+        assert_eq!(local.sql_type(), "DATETIME2");
+        assert_eq!(odt.sql_type(), "DATETIMEOFFSET");
+        assert_eq!(time::Date::from_sql(&date.to_sql().unwrap()).unwrap(), date);
+        assert_eq!(
+            time::Time::from_sql(&time_of_day.to_sql().unwrap()).unwrap(),
+            time_of_day
+        );
+        assert_eq!(
+            time::PrimitiveDateTime::from_sql(&local.to_sql().unwrap()).unwrap(),
+            local
+        );
+
+        let SqlValue::DateTimeOffset(dto) = odt.to_sql().unwrap() else {
+            panic!("must bind as a native DATETIMEOFFSET");
+        };
+        let mut encoded = bytes::BytesMut::new();
+        encode_datetimeoffset(dto, &mut encoded).unwrap();
+        let mut framed = bytes::BytesMut::new();
+        framed.put_u8(encoded.len() as u8);
+        framed.put_slice(&encoded);
+        let type_info = TypeInfo {
+            type_id: 0x2B,
+            length: None,
+            scale: Some(7),
+            precision: None,
+            collation: None,
+        };
+        let value = decode_value(&mut framed.freeze(), &type_info).unwrap();
+        let read = time::OffsetDateTime::from_sql(&value).unwrap();
+        assert_eq!(read, odt);
+        assert_eq!(read.offset(), offset);
+        assert_eq!(
+            chrono::DateTime::<chrono::Utc>::from_sql(&value)
+                .unwrap()
+                .timestamp(),
+            odt.unix_timestamp()
+        );
+
+        // DATETIME2 carries no offset and reads as UTC.
+        let read = time::OffsetDateTime::from_sql(&local.to_sql().unwrap()).unwrap();
+        assert_eq!(read, local.assume_utc());
+    }
+
     #[test]
     fn test_decode_utf16_string() {
         // "AB" in UTF-16LE
@@ -232,6 +232,53 @@ pub(crate) mod sealed {
         buf.put_u128_le(mantissa);
     }
 
+    /// Fit a decimal to a `decimal(precision, scale)` column.
+    ///
+    /// The value is rescaled to `scale`, rounding extra fractional digits, so
+    /// its mantissa is what the server reads at that scale. A value that then
+    /// has more than `precision` digits is rejected with
+    /// [`TypeError::DecimalOverflow`] instead of reaching the server, which
+    /// would fail with an opaque arithmetic overflow or, for bulk copy,
+    /// store a truncated mantissa.
+    #[cfg(feature = "decimal")]
+    pub fn fit_decimal(
+        value: rust_decimal::Decimal,
+        precision: u8,
+        scale: u8,
+    ) -> Result<rust_decimal::Decimal, TypeError> {
+        // rust_decimal holds at most 28 fractional digits; `rescale` would
+        // silently stop there and the server would read the mantissa at the
+        // wrong scale.
+        if scale > 28 {
+            return Err(TypeError::InvalidDecimal(format!(
+                "scale {scale} is out of range (max 28: rust_decimal cannot represent more fractional digits)"
+            )));
+        }
+        let mut fitted = value;
+        fitted.rescale(u32::from(scale));
+        // `rescale` also stops short when the mantissa would overflow 96 bits
+        // (e.g. 1e25 at scale 4); the mantissa is then not at `scale`.
+        if fitted.scale() != u32::from(scale) {
+            return Err(TypeError::DecimalOverflow {
+                value: value.to_string(),
+                max_precision: precision,
+            });
+        }
+        let mantissa = fitted.mantissa().unsigned_abs();
+        let digits = if mantissa == 0 {
+            0
+        } else {
+            mantissa.ilog10() + 1
+        };
+        if digits > u32::from(precision) {
+            return Err(TypeError::DecimalOverflow {
+                value: fitted.to_string(),
+                max_precision: precision,
+            });
+        }
+        Ok(fitted)
+    }
+
     /// Rescale a decimal to MONEY's 4-decimal fixed-point representation.
     ///
     /// Returns the signed 128-bit integer representing the value multiplied by
@@ -412,28 +459,46 @@ pub(crate) mod sealed {
         Ok(())
     }
 
-    /// Encode a TIME value.
+    /// Byte length of a TIME value at the given fractional-seconds scale
+    /// (0-7). DATETIME2 and DATETIMEOFFSET use the same length for their
+    /// time portion.
+    #[cfg(feature = "chrono")]
+    pub fn time_byte_length(scale: u8) -> u8 {
+        match scale {
+            0..=2 => 3,
+            3..=4 => 4,
+            _ => 5,
+        }
+    }
+
+    /// Encode a TIME value at scale 7.
     ///
     /// TDS TIME is encoded as 100-nanosecond intervals since midnight.
     #[cfg(feature = "chrono")]
     pub fn encode_time(time: chrono::NaiveTime, buf: &mut BytesMut) {
+        encode_time_with_scale(time, 7, buf);
+    }
+
+    /// Encode a TIME value at the given fractional-seconds scale (0-7).
+    ///
+    /// The value is the number of `10^-scale` second units since midnight,
+    /// written little-endian in [`time_byte_length`] bytes. Digits beyond the
+    /// scale are truncated; scales above 7 are treated as 7.
+    #[cfg(feature = "chrono")]
+    pub fn encode_time_with_scale(time: chrono::NaiveTime, scale: u8, buf: &mut BytesMut) {
         use chrono::Timelike;
 
-        // Calculate 100-ns intervals since midnight
-        // Scale = 7 (100-nanosecond precision)
         let nanos =
             time.num_seconds_from_midnight() as u64 * 1_000_000_000 + time.nanosecond() as u64;
-        let intervals = nanos / 100;
+        let units = nanos / 10u64.pow(9 - u32::from(scale.min(7)));
+        let len = time_byte_length(scale);
 
-        // TIME with scale 7 uses 5 bytes
-        buf.put_u8((intervals & 0xFF) as u8);
-        buf.put_u8(((intervals >> 8) & 0xFF) as u8);
-        buf.put_u8(((intervals >> 16) & 0xFF) as u8);
-        buf.put_u8(((intervals >> 24) & 0xFF) as u8);
-        buf.put_u8(((intervals >> 32) & 0xFF) as u8);
+        for i in 0..len {
+            buf.put_u8(((units >> (i * 8)) & 0xFF) as u8);
+        }
     }
 
-    /// Encode a DATETIME2 value.
+    /// Encode a DATETIME2 value at scale 7.
     ///
     /// DATETIME2 is encoded as TIME followed by DATE.
     ///
@@ -446,31 +511,75 @@ pub(crate) mod sealed {
         datetime: chrono::NaiveDateTime,
         buf: &mut BytesMut,
     ) -> Result<(), TypeError> {
-        encode_time(datetime.time(), buf);
+        encode_datetime2_with_scale(datetime, 7, buf)
+    }
+
+    /// Encode a DATETIME2 value at the given fractional-seconds scale; see
+    /// [`encode_time_with_scale`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the date portion is outside the DATE range; see
+    /// [`encode_date`].
+    #[cfg(feature = "chrono")]
+    pub fn encode_datetime2_with_scale(
+        datetime: chrono::NaiveDateTime,
+        scale: u8,
+        buf: &mut BytesMut,
+    ) -> Result<(), TypeError> {
+        encode_time_with_scale(datetime.time(), scale, buf);
         encode_date(datetime.date(), buf)
     }
 
-    /// Encode a DATETIMEOFFSET value.
+    /// Encode a DATETIMEOFFSET value at scale 7.
     ///
     /// DATETIMEOFFSET is encoded as TIME + DATE + offset (in minutes). Per
     /// MS-TDS §2.2.5.5.1.9 the date/time portion is the **UTC** instant, not the
     /// local wall-clock; the offset is carried separately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the offset is not a whole number of minutes or lies
+    /// outside DATETIMEOFFSET's ±14:00 range (chrono allows both; truncating
+    /// would silently change the stored zone), or if the date portion is
+    /// outside the DATE range.
     #[cfg(feature = "chrono")]
     pub fn encode_datetimeoffset(
         datetime: chrono::DateTime<chrono::FixedOffset>,
         buf: &mut BytesMut,
+    ) -> Result<(), TypeError> {
+        encode_datetimeoffset_with_scale(datetime, 7, buf)
+    }
+
+    /// Encode a DATETIMEOFFSET value at the given fractional-seconds scale;
+    /// see [`encode_datetimeoffset`] and [`encode_time_with_scale`].
+    ///
+    /// # Errors
+    ///
+    /// As for [`encode_datetimeoffset`].
+    #[cfg(feature = "chrono")]
+    pub fn encode_datetimeoffset_with_scale(
+        datetime: chrono::DateTime<chrono::FixedOffset>,
+        scale: u8,
+        buf: &mut BytesMut,
     ) -> Result<(), TypeError> {
         use chrono::Offset;
 
+        let offset_seconds = datetime.offset().fix().local_minus_utc();
+        if offset_seconds % 60 != 0 || offset_seconds.abs() > 14 * 3600 {
+            return Err(TypeError::InvalidDateTime(format!(
+                "DATETIMEOFFSET offset must be whole minutes within ±14:00, got {}",
+                datetime.offset()
+            )));
+        }
+
         // Encode the UTC date/time components
         let utc = datetime.naive_utc();
-        encode_time(utc.time(), buf);
+        encode_time_with_scale(utc.time(), scale, buf);
         encode_date(utc.date(), buf)?;
 
         // Encode timezone offset in minutes (signed 16-bit)
-        let offset_seconds = datetime.offset().fix().local_minus_utc();
-        let offset_minutes = (offset_seconds / 60) as i16;
-        buf.put_i16_le(offset_minutes);
+        buf.put_i16_le((offset_seconds / 60) as i16);
         Ok(())
     }
 }
@@ -612,6 +721,14 @@ mod tests {
         assert!(encode_datetime2(dt, &mut buf).is_err());
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_time_byte_length() {
+        assert_eq!(time_byte_length(0), 3);
+        assert_eq!(time_byte_length(3), 4);
+        assert_eq!(time_byte_length(7), 5);
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn test_encode_date() {
@@ -682,6 +799,43 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_fit_decimal() {
+        use rust_decimal::Decimal;
+
+        // Rescaled up to the column's scale, and rounded down to it.
+        let fitted = fit_decimal(Decimal::new(125, 1), 5, 2).unwrap();
+        assert_eq!((fitted.mantissa(), fitted.scale()), (1_250, 2));
+        let fitted = fit_decimal(Decimal::new(12_345, 3), 5, 2).unwrap();
+        assert_eq!((fitted.mantissa(), fitted.scale()), (1_235, 2));
+
+        // 999.99 is the largest DECIMAL(5,2); 999.995 rounds past it.
+        assert!(fit_decimal(Decimal::new(99_999, 2), 5, 2).is_ok());
+        assert!(matches!(
+            fit_decimal(Decimal::new(999_995, 3), 5, 2),
+            Err(TypeError::DecimalOverflow { ref value, max_precision: 5 }) if value == "1000.00"
+        ));
+        assert!(fit_decimal(Decimal::new(-100_000, 2), 5, 2).is_err());
+
+        // 1e25 at scale 4 needs more than rust_decimal's 96-bit mantissa, so
+        // `rescale` cannot reach scale 4 even though DECIMAL(38,4) holds it.
+        let big = Decimal::from_i128_with_scale(10_i128.pow(25), 0);
+        assert!(matches!(
+            fit_decimal(big, 38, 4),
+            Err(TypeError::DecimalOverflow {
+                max_precision: 38,
+                ..
+            })
+        ));
+
+        // rust_decimal cannot hold a scale past 28.
+        assert!(matches!(
+            fit_decimal(Decimal::ONE, 38, 30),
+            Err(TypeError::InvalidDecimal(_))
+        ));
+    }
+
     #[cfg(feature = "chrono")]
     #[test]
     fn golden_chrono_wire_bytes() {
@@ -705,6 +859,17 @@ mod tests {
             [0x00, 0x68, 0xC4, 0x61, 0x08]
         );
 
+        // TIME at lower scales counts 10^-scale units in fewer bytes: 01:00:00
+        // is 3_600 seconds (scale 0, 3 bytes) or 3_600_000 ms (scale 3, 4 bytes).
+        assert_eq!(
+            golden(|b| encode_time_with_scale(one_am, 0, b)),
+            [0x10, 0x0E, 0x00]
+        );
+        assert_eq!(
+            golden(|b| encode_time_with_scale(one_am, 3, b)),
+            [0x80, 0xEE, 0x36, 0x00]
+        );
+
         // DATETIME (legacy, 8 bytes): days since 1900 (i32 LE) + 1/300s ticks
         // (u32 LE). epoch1900 + 0x010203 days at midnight → ticks 0.
         let legacy = dt_epoch_1900
@@ -735,4 +900,31 @@ mod tests {
             [0x00, 0x00, 0x00, 0x00, 0x00, 0x56, 0x34, 0x12]
         );
     }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_datetimeoffset_rejects_unrepresentable_offsets() {
+        use chrono::TimeZone;
+
+        for seconds in [5 * 3600 + 30, 15 * 3600, -15 * 3600] {
+            let dto = chrono::FixedOffset::east_opt(seconds)
+                .unwrap()
+                .with_ymd_and_hms(2024, 3, 15, 12, 0, 0)
+                .unwrap();
+            let mut buf = BytesMut::new();
+            assert!(
+                matches!(
+                    encode_datetimeoffset(dto, &mut buf),
+                    Err(TypeError::InvalidDateTime(_))
+                ),
+                "offset {seconds}s must be rejected"
+            );
+        }
+
+        let edge = chrono::FixedOffset::west_opt(14 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2024, 3, 15, 12, 0, 0)
+            .unwrap();
+        encode_datetimeoffset(edge, &mut BytesMut::new()).unwrap();
+    }
 }
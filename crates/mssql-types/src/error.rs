@@ -46,6 +46,15 @@ pub enum TypeError {
     #[error("invalid UUID: {0}")]
     InvalidUuid(String),
 
+    /// Decimal value has more digits than the target `DECIMAL` precision.
+    #[error("decimal {value} does not fit DECIMAL precision {max_precision}")]
+    DecimalOverflow {
+        /// The value, after rounding to the target scale.
+        value: String,
+        /// The target precision.
+        max_precision: u8,
+    },
+
     /// Truncation occurred during conversion.
     #[error("value truncated: {0}")]
     Truncation(String),
@@ -68,6 +77,15 @@ pub enum TypeError {
         reason: String,
     },
 
+    /// Value matches no variant of the enum it is read into.
+    #[error("unknown {target_type} value: {value}")]
+    UnknownEnumValue {
+        /// Enum type name.
+        target_type: &'static str,
+        /// The value that was read.
+        value: String,
+    },
+
     /// Buffer too small for value.
     #[error("buffer too small: need {needed} bytes, have {available}")]
     BufferTooSmall {
@@ -151,6 +151,67 @@ impl FromSql for String {
     }
 }
 
+/// A string that reads SQL NULL as `""` instead of failing.
+///
+/// Some legacy schemas treat NULL and the empty string interchangeably. Read a
+/// column as `NullAsEmpty` to get a plain string either way; reading it as
+/// `String` still reports NULL as [`TypeError::UnexpectedNull`], and
+/// `Option<String>` keeps the distinction. Use this as a migration aid, not a
+/// default: it hides real NULLs.
+///
+/// ```
+/// use mssql_types::{FromSql, NullAsEmpty, SqlValue};
+///
+/// let name = NullAsEmpty::from_sql(&SqlValue::Null).unwrap();
+/// assert_eq!(name.as_str(), "");
+/// assert_eq!(String::from(name), "");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NullAsEmpty(pub String);
+
+impl NullAsEmpty {
+    /// The string value (`""` for NULL).
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Unwrap into the inner `String`.
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl std::ops::Deref for NullAsEmpty {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for NullAsEmpty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<NullAsEmpty> for String {
+    fn from(value: NullAsEmpty) -> Self {
+        value.0
+    }
+}
+
+impl FromSql for NullAsEmpty {
+    fn from_sql(value: &SqlValue) -> Result<Self, TypeError> {
+        match value {
+            SqlValue::Null => Ok(Self(String::new())),
+            other => String::from_sql(other).map(Self),
+        }
+    }
+}
+
 impl FromSql for Vec<u8> {
     fn from_sql(value: &SqlValue) -> Result<Self, TypeError> {
         match value {
@@ -289,6 +350,82 @@ impl FromSql for chrono::DateTime<chrono::Utc> {
     }
 }
 
+/// Convert a chrono date to a `time` date (both cover SQL Server's range).
+#[cfg(feature = "time")]
+fn to_time_date(date: chrono::NaiveDate) -> Result<time::Date, TypeError> {
+    use chrono::Datelike;
+
+    time::Date::from_ordinal_date(date.year(), date.ordinal() as u16)
+        .map_err(|e| TypeError::InvalidDateTime(format!("date {date}: {e}")))
+}
+
+#[cfg(feature = "time")]
+fn to_time_time(value: chrono::NaiveTime) -> Result<time::Time, TypeError> {
+    use chrono::Timelike;
+
+    time::Time::from_hms_nano(
+        value.hour() as u8,
+        value.minute() as u8,
+        value.second() as u8,
+        value.nanosecond(),
+    )
+    .map_err(|e| TypeError::InvalidDateTime(format!("time {value}: {e}")))
+}
+
+#[cfg(feature = "time")]
+fn to_primitive_date_time(
+    value: chrono::NaiveDateTime,
+) -> Result<time::PrimitiveDateTime, TypeError> {
+    Ok(time::PrimitiveDateTime::new(
+        to_time_date(value.date())?,
+        to_time_time(value.time())?,
+    ))
+}
+
+#[cfg(feature = "time")]
+impl FromSql for time::Date {
+    fn from_sql(value: &SqlValue) -> Result<Self, TypeError> {
+        chrono::NaiveDate::from_sql(value).and_then(to_time_date)
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromSql for time::Time {
+    fn from_sql(value: &SqlValue) -> Result<Self, TypeError> {
+        chrono::NaiveTime::from_sql(value).and_then(to_time_time)
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromSql for time::PrimitiveDateTime {
+    fn from_sql(value: &SqlValue) -> Result<Self, TypeError> {
+        chrono::NaiveDateTime::from_sql(value).and_then(to_primitive_date_time)
+    }
+}
+
+/// Reads DATETIMEOFFSET with its stored offset, and DATETIME2/DATETIME/
+/// SMALLDATETIME as UTC, matching `chrono::DateTime<Utc>`.
+#[cfg(feature = "time")]
+impl FromSql for time::OffsetDateTime {
+    fn from_sql(value: &SqlValue) -> Result<Self, TypeError> {
+        match value {
+            SqlValue::DateTimeOffset(v) => {
+                let offset = time::UtcOffset::from_whole_seconds(v.offset().local_minus_utc())
+                    .map_err(|e| TypeError::InvalidDateTime(format!("offset of {v}: {e}")))?;
+                Ok(to_primitive_date_time(v.naive_local())?.assume_offset(offset))
+            }
+            SqlValue::DateTime(v) | SqlValue::SmallDateTime(v) => {
+                Ok(to_primitive_date_time(*v)?.assume_utc())
+            }
+            SqlValue::Null => Err(TypeError::UnexpectedNull),
+            _ => Err(TypeError::TypeMismatch {
+                expected: "OffsetDateTime",
+                actual: value.type_name().to_string(),
+            }),
+        }
+    }
+}
+
 #[cfg(feature = "json")]
 impl FromSql for serde_json::Value {
     fn from_sql(value: &SqlValue) -> Result<Self, TypeError> {
@@ -324,6 +461,27 @@ mod tests {
         assert_eq!(String::from_sql(&value).unwrap(), "hello");
     }
 
+    #[test]
+    fn test_null_as_empty() {
+        assert_eq!(NullAsEmpty::from_sql(&SqlValue::Null).unwrap().as_str(), "");
+        assert_eq!(
+            NullAsEmpty::from_sql(&SqlValue::String("x".into()))
+                .unwrap()
+                .as_str(),
+            "x"
+        );
+        assert!(NullAsEmpty::from_sql(&SqlValue::Int(1)).is_err());
+        // Plain String keeps rejecting NULL; Option keeps the distinction.
+        assert!(matches!(
+            String::from_sql(&SqlValue::Null),
+            Err(TypeError::UnexpectedNull)
+        ));
+        assert_eq!(
+            Option::<NullAsEmpty>::from_sql(&SqlValue::Null).unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn test_from_sql_null() {
         let value = SqlValue::Null;
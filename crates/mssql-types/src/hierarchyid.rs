@@ -0,0 +1,331 @@
+//! SQL Server `hierarchyid` values.
+//!
+//! `hierarchyid` is a CLR UDT; the server sends it as its compact binary
+//! serialization, which the driver surfaces as [`SqlValue::Binary`] like any
+//! other UDT. [`HierarchyId`] wraps those bytes and decodes the variable-length
+//! bit encoding into the canonical path form (`/1/3.2/`).
+//!
+//! Each node label is a run of integers separated by `.`; each integer is
+//! stored as a prefix selecting a value range, the offset within that range
+//! spread over the pattern's `x` bits, and a final bit that is 1 when the
+//! integer ends its label and 0 when a `.` follows (in which case the stored
+//! offset is one higher). The bit stream is zero-padded to a whole byte.
+
+use std::fmt;
+
+use bytes::Bytes;
+
+use crate::error::TypeError;
+use crate::from_sql::FromSql;
+use crate::to_sql::ToSql;
+use crate::value::SqlValue;
+
+/// Value range and bit layout of one integer encoding. In `layout`, `x` is a
+/// value bit and `0`/`1` are fixed bits; the leading fixed bits (up to the
+/// first `x`) are the prefix that identifies the pattern. The trailing
+/// label-terminator bit is not part of `layout`.
+struct Pattern {
+    min: i64,
+    layout: &'static str,
+}
+
+const PATTERNS: &[Pattern] = &[
+    Pattern {
+        min: 0,
+        layout: "01xx",
+    },
+    Pattern {
+        min: 4,
+        layout: "100xx",
+    },
+    Pattern {
+        min: 8,
+        layout: "101xxx",
+    },
+    Pattern {
+        min: 16,
+        layout: "110xx0x1xxx",
+    },
+    Pattern {
+        min: 80,
+        layout: "1110xxx0xxx0x1xxx",
+    },
+    Pattern {
+        min: 1104,
+        layout: "11110xxxxx0xxx0x1xxx",
+    },
+    Pattern {
+        min: 5200,
+        layout: "111110xxxxxxxxxxxxxxxxxxx0xxxxxx0xxx0x1xxx",
+    },
+    Pattern {
+        min: 4_294_972_496,
+        layout: "111111xxxxxxxxxxxxxx0xxxxxxxxxxxxxxxxxxxxx0xxxxxx0xxx0x1xxx",
+    },
+    Pattern {
+        min: -8,
+        layout: "00111xxx",
+    },
+    Pattern {
+        min: -72,
+        layout: "0010xx0x1xxx",
+    },
+    Pattern {
+        min: -4168,
+        layout: "000111xxxxx0xxx0x1xxx",
+    },
+    Pattern {
+        min: -4_294_971_464,
+        layout: "000110xxxxxxxxxxxxxxxxxxx0xxxxxx0xxx0x1xxx",
+    },
+    Pattern {
+        min: -281_479_271_682_120,
+        layout: "000101xxxxxxxxxxxxxx0xxxxxxxxxxxxxxxxxxxxx0xxxxxx0xxx0x1xxx",
+    },
+];
+
+impl Pattern {
+    fn prefix(&self) -> &'static str {
+        let end = self.layout.find('x').unwrap_or(self.layout.len());
+        &self.layout[..end]
+    }
+}
+
+/// MSB-first reader over the serialized bits.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl BitReader<'_> {
+    fn remaining(&self) -> usize {
+        self.bytes.len() * 8 - self.pos
+    }
+
+    fn peek(&self, offset: usize) -> bool {
+        let bit = self.pos + offset;
+        self.bytes[bit / 8] & (0x80 >> (bit % 8)) != 0
+    }
+
+    fn read(&mut self) -> bool {
+        let bit = self.peek(0);
+        self.pos += 1;
+        bit
+    }
+
+    /// Whether every unread bit is zero (the trailing padding).
+    fn rest_is_zero(&self) -> bool {
+        (0..self.remaining()).all(|i| !self.peek(i))
+    }
+
+    /// The pattern whose prefix matches the next bits and which fits in the
+    /// remaining input, if any.
+    fn next_pattern(&self) -> Option<&'static Pattern> {
+        PATTERNS.iter().find(|p| {
+            p.layout.len() < self.remaining()
+                && p.prefix()
+                    .bytes()
+                    .enumerate()
+                    .all(|(i, b)| self.peek(i) == (b == b'1'))
+        })
+    }
+}
+
+/// A SQL Server `hierarchyid` value.
+///
+/// Holds the raw serialized bytes (as sent by the server) together with the
+/// decoded node labels. [`Display`](fmt::Display) renders the canonical path,
+/// e.g. `/1/3.2/`, with `/` for the root.
+///
+/// ```rust
+/// use mssql_types::HierarchyId;
+///
+/// let node = HierarchyId::from_bytes(vec![0x5A, 0xC0]).unwrap();
+/// assert_eq!(node.to_string(), "/1/1/");
+/// assert_eq!(node.depth(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HierarchyId {
+    bytes: Bytes,
+    levels: Vec<Vec<i64>>,
+}
+
+impl HierarchyId {
+    /// Decode a `hierarchyid` from its binary serialization.
+    ///
+    /// Returns an error if the bytes are not a valid encoding (an unknown bit
+    /// pattern, a label cut off mid-way, or non-zero padding).
+    pub fn from_bytes(bytes: impl Into<Bytes>) -> Result<Self, TypeError> {
+        let bytes = bytes.into();
+        let levels = decode(&bytes)?;
+        Ok(Self { bytes, levels })
+    }
+
+    /// The raw serialized bytes.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consume the value, returning the raw serialized bytes.
+    #[must_use]
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+
+    /// The node labels from the root down; each label is one or more
+    /// integers (`3.2` is `[3, 2]`). Empty for the root.
+    #[must_use]
+    pub fn levels(&self) -> &[Vec<i64>] {
+        &self.levels
+    }
+
+    /// Number of levels below the root (`GetLevel()` in T-SQL).
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Whether this is the root node (`/`).
+    #[must_use]
+    pub fn is_root(&self) -> bool {
+        self.levels.is_empty()
+    }
+}
+
+fn decode(bytes: &[u8]) -> Result<Vec<Vec<i64>>, TypeError> {
+    let invalid = |reason: &str| TypeError::InvalidBinary(format!("invalid hierarchyid: {reason}"));
+
+    let mut reader = BitReader { bytes, pos: 0 };
+    let mut levels = Vec::new();
+    let mut label = Vec::new();
+
+    while let Some(pattern) = reader.next_pattern() {
+        reader.pos += pattern.prefix().len();
+        let mut offset: i64 = 0;
+        for b in pattern.layout.bytes().skip(pattern.prefix().len()) {
+            let bit = reader.read();
+            if b == b'x' {
+                offset = (offset << 1) | i64::from(bit);
+            }
+        }
+        let ends_label = reader.read();
+        if !ends_label {
+            offset -= 1;
+        }
+        label.push(pattern.min + offset);
+        if ends_label {
+            levels.push(std::mem::take(&mut label));
+        }
+    }
+
+    if !label.is_empty() {
+        return Err(invalid("unterminated node label"));
+    }
+    if !reader.rest_is_zero() {
+        return Err(invalid("unrecognized bit pattern"));
+    }
+    Ok(levels)
+}
+
+impl fmt::Display for HierarchyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("/")?;
+        for level in &self.levels {
+            for (i, n) in level.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(".")?;
+                }
+                write!(f, "{n}")?;
+            }
+            f.write_str("/")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromSql for HierarchyId {
+    fn from_sql(value: &SqlValue) -> Result<Self, TypeError> {
+        match value {
+            SqlValue::Binary(v) => Self::from_bytes(v.clone()),
+            SqlValue::Null => Err(TypeError::UnexpectedNull),
+            _ => Err(TypeError::TypeMismatch {
+                expected: "HierarchyId",
+                actual: value.type_name().to_string(),
+            }),
+        }
+    }
+}
+
+impl ToSql for HierarchyId {
+    fn to_sql(&self) -> Result<SqlValue, TypeError> {
+        Ok(SqlValue::Binary(self.bytes.clone()))
+    }
+
+    fn sql_type(&self) -> &'static str {
+        "VARBINARY"
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn path(bytes: &[u8]) -> String {
+        HierarchyId::from_bytes(bytes.to_vec()).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_root() {
+        let root = HierarchyId::from_bytes(Vec::new()).unwrap();
+        assert!(root.is_root());
+        assert_eq!(root.to_string(), "/");
+    }
+
+    #[test]
+    fn test_simple_paths() {
+        assert_eq!(path(&[0x58]), "/1/");
+        assert_eq!(path(&[0x68]), "/2/");
+        assert_eq!(path(&[0x78]), "/3/");
+        assert_eq!(path(&[0x84]), "/4/");
+        assert_eq!(path(&[0x5A, 0xC0]), "/1/1/");
+        assert_eq!(path(&[0x5B, 0x40]), "/1/2/");
+    }
+
+    #[test]
+    fn test_multi_byte_ranges() {
+        // 16: prefix 110, offset 0 spread over "xx0x1xxx", then T.
+        assert_eq!(path(&[0xC1, 0x10]), "/16/");
+        // -1: prefix 00111, offset 7, then T.
+        assert_eq!(path(&[0x3F, 0x80]), "/-1/");
+    }
+
+    #[test]
+    fn test_dotted_label() {
+        // "1." is stored as offset 2 with T=0, then "1" with T=1.
+        let node = HierarchyId::from_bytes(vec![0x62, 0xC0]).unwrap();
+        assert_eq!(node.levels(), &[vec![1, 1]]);
+        assert_eq!(node.to_string(), "/1.1/");
+    }
+
+    #[test]
+    fn test_invalid_encodings() {
+        // "01 01 0": a label that never terminates.
+        assert!(HierarchyId::from_bytes(vec![0x50]).is_err());
+        // Trailing bits that match no pattern and are not zero padding.
+        assert!(HierarchyId::from_bytes(vec![0x58, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_sql_conversions() {
+        let value = SqlValue::Binary(Bytes::from_static(&[0x5A, 0xC0]));
+        let node = HierarchyId::from_sql(&value).unwrap();
+        assert_eq!(node.depth(), 2);
+        assert_eq!(node.to_sql().unwrap(), value);
+        assert!(matches!(
+            HierarchyId::from_sql(&SqlValue::Null),
+            Err(TypeError::UnexpectedNull)
+        ));
+    }
+}
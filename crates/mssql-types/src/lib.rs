@@ -8,6 +8,7 @@
 //! ## Features
 //!
 //! - `chrono` (default): Enable date/time type support via chrono
+//! - `time`: Enable date/time type support via the `time` crate (implies `chrono`)
 //! - `uuid` (default): Enable UUID type support
 //! - `decimal` (default): Enable decimal type support via rust_decimal
 //! - `json`: Enable JSON type support via serde_json
@@ -29,7 +30,9 @@
 //! | `DATE` | `chrono::NaiveDate` |
 //! | `TIME` | `chrono::NaiveTime` |
 //! | `DATETIME2` | `chrono::NaiveDateTime` |
+//! | `DATETIMEOFFSET` | `chrono::DateTime<FixedOffset>`, `chrono::DateTime<Utc>` |
 //! | `UNIQUEIDENTIFIER` | `uuid::Uuid` |
+//! | `HIERARCHYID` | [`HierarchyId`] |
 
 #![warn(missing_docs)]
 #![deny(unsafe_code)]
@@ -38,6 +41,7 @@ pub mod decode;
 pub mod encode;
 pub mod error;
 pub mod from_sql;
+pub mod hierarchyid;
 pub mod to_sql;
 pub mod tvp;
 pub mod value;
@@ -45,7 +49,8 @@ pub mod value;
 pub use decode::{Collation, TdsDecode, TypeInfo, decode_utf16_string};
 pub use encode::{TdsEncode, encode_utf16_string};
 pub use error::TypeError;
-pub use from_sql::FromSql;
+pub use from_sql::{FromSql, NullAsEmpty};
+pub use hierarchyid::HierarchyId;
 pub use to_sql::{
     Binary, Char, EncryptedParamType, NChar, SqlTyped, ToSql, TypedNull, binary, char, nchar, null,
 };
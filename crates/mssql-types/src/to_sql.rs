@@ -21,7 +21,7 @@ pub trait ToSql {
     /// when the value alone cannot convey it.
     ///
     /// Returns `None` for every type except the typed-parameter wrappers
-    /// (e.g. [`numeric`], [`datetime2`], [`time`]). An Always Encrypted column
+    /// (e.g. [`numeric`], [`datetime2`], [`time()`]). An Always Encrypted column
     /// requires the declared type — including precision, scale, or length — to
     /// match the column exactly, which a bare value cannot always express (a
     /// `Decimal` carries no precision; a `NaiveDateTime` is ambiguous between
@@ -34,7 +34,7 @@ pub trait ToSql {
 }
 
 /// The explicit SQL type for an Always Encrypted parameter whose value cannot
-/// convey it (see [`numeric`], [`time`], [`datetime2`], [`datetimeoffset`],
+/// convey it (see [`numeric`], [`time()`], [`datetime2`], [`datetimeoffset`],
 /// [`datetime`]). Carries the precision/scale/length the encrypted column
 /// requires the declared parameter type to match exactly.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -357,7 +357,8 @@ pub struct Numeric {
 ///   would emit a magnitude a Microsoft client reads at the wrong scale (e.g.
 ///   `0.5` → `0.005`) with no server backstop. The driver's `decimal` Always
 ///   Encrypted support is therefore bounded to `scale ≤ 28`;
-/// - the value, after rescaling, has more significant digits than `precision`.
+/// - the value, after rescaling, has more significant digits than `precision`
+///   ([`TypeError::DecimalOverflow`]).
 ///
 /// The value is rescaled to `scale`, which **rounds** when the value has more
 /// fractional digits than `scale` (e.g. `numeric(dec!(12.999), 18, 2)` stores
@@ -396,33 +397,15 @@ impl ToSql for Numeric {
                 self.scale, self.precision
             )));
         }
-        if self.scale > 28 {
-            return Err(TypeError::InvalidDecimal(format!(
-                "scale {} is out of range (max 28: rust_decimal cannot represent more fractional digits)",
-                self.scale
-            )));
-        }
 
-        let mut value = self.value;
-        value.rescale(u32::from(self.scale));
         // The server cannot range-check an encrypted value, so a value that
         // exceeds the declared precision must be rejected client-side rather
         // than silently stored out of the column's domain (matches the
         // Always Encrypted behaviour of Microsoft.Data.SqlClient). After
         // rescaling, the magnitude bound `|mantissa| < 10^precision` is exactly
-        // the `decimal(precision, scale)` domain.
-        let mantissa = value.mantissa().unsigned_abs();
-        let digits = if mantissa == 0 {
-            0
-        } else {
-            mantissa.ilog10() + 1
-        };
-        if digits > u32::from(self.precision) {
-            return Err(TypeError::InvalidDecimal(format!(
-                "value has {digits} significant digits, which exceeds the declared precision {}",
-                self.precision
-            )));
-        }
+        // the `decimal(precision, scale)` domain. `fit_decimal` also enforces
+        // the scale ≤ 28 bound.
+        let value = crate::encode::sealed::fit_decimal(self.value, self.precision, self.scale)?;
         Ok(SqlValue::Decimal(value))
     }
 
@@ -449,7 +432,7 @@ fn validate_temporal_scale(scale: u8) -> Result<(), TypeError> {
     Ok(())
 }
 
-/// A `time(scale)` parameter for an Always Encrypted column (see [`time`]).
+/// A `time(scale)` parameter for an Always Encrypted column (see [`time()`]).
 #[cfg(feature = "chrono")]
 #[derive(Debug, Clone, Copy)]
 pub struct Time {
@@ -775,6 +758,85 @@ impl ToSql for chrono::DateTime<chrono::Utc> {
     }
 }
 
+/// Convert a `time` date to a chrono date (both cover SQL Server's range).
+#[cfg(feature = "time")]
+fn to_chrono_date(date: time::Date) -> Result<chrono::NaiveDate, TypeError> {
+    chrono::NaiveDate::from_yo_opt(date.year(), u32::from(date.ordinal()))
+        .ok_or_else(|| TypeError::InvalidDateTime(format!("date {date} is out of range")))
+}
+
+#[cfg(feature = "time")]
+fn to_chrono_time(value: time::Time) -> chrono::NaiveTime {
+    let (hour, minute, second, nanosecond) = value.as_hms_nano();
+    chrono::NaiveTime::from_hms_nano_opt(
+        u32::from(hour),
+        u32::from(minute),
+        u32::from(second),
+        nanosecond,
+    )
+    .expect("time::Time components are always in range")
+}
+
+#[cfg(feature = "time")]
+fn to_naive_date_time(value: time::PrimitiveDateTime) -> Result<chrono::NaiveDateTime, TypeError> {
+    Ok(to_chrono_date(value.date())?.and_time(to_chrono_time(value.time())))
+}
+
+#[cfg(feature = "time")]
+impl ToSql for time::Date {
+    fn to_sql(&self) -> Result<SqlValue, TypeError> {
+        Ok(SqlValue::Date(to_chrono_date(*self)?))
+    }
+
+    fn sql_type(&self) -> &'static str {
+        "DATE"
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToSql for time::Time {
+    fn to_sql(&self) -> Result<SqlValue, TypeError> {
+        Ok(SqlValue::Time(to_chrono_time(*self)))
+    }
+
+    fn sql_type(&self) -> &'static str {
+        "TIME"
+    }
+}
+
+#[cfg(feature = "time")]
+impl ToSql for time::PrimitiveDateTime {
+    fn to_sql(&self) -> Result<SqlValue, TypeError> {
+        Ok(SqlValue::DateTime(to_naive_date_time(*self)?))
+    }
+
+    fn sql_type(&self) -> &'static str {
+        "DATETIME2"
+    }
+}
+
+/// Sent as DATETIMEOFFSET, keeping the value's own offset.
+#[cfg(feature = "time")]
+impl ToSql for time::OffsetDateTime {
+    fn to_sql(&self) -> Result<SqlValue, TypeError> {
+        use chrono::TimeZone;
+
+        let out_of_range = || TypeError::InvalidDateTime(format!("{self} is out of range"));
+        let offset = chrono::FixedOffset::east_opt(self.offset().whole_seconds())
+            .ok_or_else(out_of_range)?;
+        let local = to_naive_date_time(time::PrimitiveDateTime::new(self.date(), self.time()))?;
+        let value = offset
+            .from_local_datetime(&local)
+            .single()
+            .ok_or_else(out_of_range)?;
+        Ok(SqlValue::DateTimeOffset(value))
+    }
+
+    fn sql_type(&self) -> &'static str {
+        "DATETIMEOFFSET"
+    }
+}
+
 #[cfg(feature = "json")]
 impl ToSql for serde_json::Value {
     fn to_sql(&self) -> Result<SqlValue, TypeError> {
@@ -838,7 +900,13 @@ mod tests {
 
         // Exceeds declared precision: 6 significant digits into decimal(4,0).
         assert!(
-            numeric(Decimal::new(123_456, 0), 4, 0).to_sql().is_err(),
+            matches!(
+                numeric(Decimal::new(123_456, 0), 4, 0).to_sql(),
+                Err(TypeError::DecimalOverflow {
+                    max_precision: 4,
+                    ..
+                })
+            ),
             "value exceeding the declared precision must error"
         );
 
@@ -23,6 +23,16 @@ pub enum ProtocolError {
     #[error("invalid token type: {0:#x}")]
     InvalidTokenType(u8),
 
+    /// A known token the parser would otherwise skip, rejected because
+    /// strict parsing is enabled (see `TokenParser::with_strict`).
+    #[error("unhandled token type {token_type:#04x} at offset {offset}")]
+    UnhandledToken {
+        /// Token type byte.
+        token_type: u8,
+        /// Byte offset of the token within the parsed buffer.
+        offset: usize,
+    },
+
     /// Invalid data type value.
     #[error("invalid data type: {0:#x}")]
     InvalidDataType(u8),
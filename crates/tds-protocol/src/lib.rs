@@ -119,7 +119,10 @@ pub use packet::{
     PacketType,
 };
 pub use prelogin::{EncryptionLevel, PreLogin, PreLoginOption};
-pub use rpc::{ParamFlags, ProcId, RpcOptionFlags, RpcParam, RpcRequest, TypeInfo as RpcTypeInfo};
+pub use rpc::{
+    ParamFlags, ProcId, RpcOptionFlags, RpcParam, RpcRequest, TypeInfo as RpcTypeInfo,
+    TypeInfoBuilder as RpcTypeInfoBuilder,
+};
 pub use sql_batch::SqlBatch;
 pub use token::{
     ColMetaData, Collation, ColumnData, Done, DoneInProc, DoneProc, DoneStatus, EnvChange,
@@ -214,6 +214,28 @@ pub enum FeatureId {
     Terminator = 0xFF,
 }
 
+impl FeatureId {
+    /// Create from wire value.
+    ///
+    /// Returns `None` for feature IDs this crate does not know, which servers
+    /// may acknowledge in FEATUREEXTACK.
+    #[must_use]
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0x01 => Some(Self::SessionRecovery),
+            0x02 => Some(Self::FedAuth),
+            0x04 => Some(Self::ColumnEncryption),
+            0x05 => Some(Self::GlobalTransactions),
+            0x08 => Some(Self::AzureSqlSupport),
+            0x09 => Some(Self::DataClassification),
+            0x0A => Some(Self::Utf8Support),
+            0x0B => Some(Self::AzureSqlDnsCaching),
+            0xFF => Some(Self::Terminator),
+            _ => None,
+        }
+    }
+}
+
 /// LOGIN7 packet builder.
 #[derive(Debug, Clone)]
 pub struct Login7 {
@@ -417,11 +439,26 @@ impl Login7 {
     /// Add a feature extension.
     #[must_use]
     pub fn with_feature(mut self, feature: FeatureExtension) -> Self {
-        self.option_flags3.extension = true;
-        self.features.push(feature);
+        self.add_feature_extension(feature.feature_id, feature.data);
         self
     }
 
+    /// Append a raw FeatureExt data block and set `fExtension`.
+    ///
+    /// Blocks are written in insertion order, followed by a single
+    /// terminator; [`FeatureId::Terminator`] itself is not a feature and is
+    /// ignored here.
+    pub fn add_feature_extension(&mut self, feature_id: FeatureId, data: impl Into<Bytes>) {
+        if feature_id == FeatureId::Terminator {
+            return;
+        }
+        self.option_flags3.extension = true;
+        self.features.push(FeatureExtension {
+            feature_id,
+            data: data.into(),
+        });
+    }
+
     /// Encode the LOGIN7 packet to bytes.
     #[must_use]
     pub fn encode(&self) -> Bytes {
@@ -586,7 +623,13 @@ impl Login7 {
 
         // Feature extensions (if any)
         if self.option_flags3.extension {
-            for feature in &self.features {
+            // `features` is a public field, so a stray terminator entry may
+            // have been pushed directly; writing it would end the block early.
+            for feature in self
+                .features
+                .iter()
+                .filter(|f| f.feature_id != FeatureId::Terminator)
+            {
                 var_data.put_u8(feature.feature_id as u8);
                 var_data.put_u32_le(feature.data.len() as u32);
                 var_data.put_slice(&feature.data);
@@ -687,6 +730,24 @@ impl Login7 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_feature_id_from_u8_roundtrip() {
+        for id in [
+            FeatureId::SessionRecovery,
+            FeatureId::FedAuth,
+            FeatureId::ColumnEncryption,
+            FeatureId::GlobalTransactions,
+            FeatureId::AzureSqlSupport,
+            FeatureId::DataClassification,
+            FeatureId::Utf8Support,
+            FeatureId::AzureSqlDnsCaching,
+            FeatureId::Terminator,
+        ] {
+            assert_eq!(FeatureId::from_u8(id as u8), Some(id));
+        }
+        assert_eq!(FeatureId::from_u8(0x03), None);
+    }
+
     #[test]
     fn test_login7_default() {
         let login = Login7::new();
@@ -792,6 +853,32 @@ mod tests {
         assert_eq!(buf[1], 0xA5);
     }
 
+    #[test]
+    fn test_add_feature_extension_writes_blocks_and_single_terminator() {
+        let mut login = Login7::new();
+        login.add_feature_extension(FeatureId::Utf8Support, Vec::new());
+        login.add_feature_extension(FeatureId::Terminator, vec![0xAA]);
+        login.add_feature_extension(FeatureId::AzureSqlDnsCaching, vec![0x01, 0x02]);
+        // A terminator pushed straight into the public field is skipped too.
+        login.features.insert(
+            1,
+            FeatureExtension {
+                feature_id: FeatureId::Terminator,
+                data: Bytes::new(),
+            },
+        );
+        assert!(login.option_flags3.extension);
+
+        let encoded = login.encode();
+        // The FeatureExt block is the last variable-length field.
+        let expected: &[u8] = &[
+            0x0A, 0, 0, 0, 0, // UTF-8 support, empty
+            0x0B, 2, 0, 0, 0, 0x01, 0x02, // DNS caching, 2 bytes
+            0xFF,
+        ];
+        assert!(encoded.ends_with(expected));
+    }
+
     /// Per MS-TDS §2.2.6.4, when `fExtension=1` the slot normally occupied
     /// by `Unused` in the offset/length table becomes `ibExtension`/`cbExtension`:
     ///   * `ibExtension` = absolute offset of a 4-byte u32.
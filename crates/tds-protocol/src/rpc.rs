@@ -506,6 +506,43 @@ impl TypeInfo {
         }
     }
 
+    /// Start building type info for an arbitrary TDS type ID.
+    ///
+    /// Use this when none of the convenience constructors fit — for example a
+    /// DECIMAL with a specific precision and scale, or a character type with a
+    /// non-default collation.
+    ///
+    /// ```rust
+    /// use tds_protocol::RpcTypeInfo;
+    ///
+    /// // NVARCHAR(128): the length is in bytes (two per UTF-16 code unit).
+    /// let ti = RpcTypeInfo::builder(0xE7).max_length(256).build();
+    /// assert_eq!(ti.max_length, Some(256));
+    /// assert!(ti.collation.is_some());
+    ///
+    /// // DECIMAL(18, 4), declared with the 17-byte maximum length that
+    /// // `RpcTypeInfo::decimal` uses.
+    /// let dec = RpcTypeInfo::builder(0x6C)
+    ///     .max_length(17)
+    ///     .precision(18)
+    ///     .scale(4)
+    ///     .build();
+    /// assert_eq!(dec.max_length, RpcTypeInfo::decimal(18, 4).max_length);
+    /// assert_eq!((dec.precision, dec.scale), (Some(18), Some(4)));
+    /// ```
+    pub fn builder(type_id: u8) -> TypeInfoBuilder {
+        TypeInfoBuilder {
+            info: Self {
+                type_id,
+                max_length: None,
+                precision: None,
+                scale: None,
+                collation: None,
+                tvp_type_name: None,
+            },
+        }
+    }
+
     /// Create type info for a Table-Valued Parameter.
     ///
     /// # Arguments
@@ -577,6 +614,56 @@ impl TypeInfo {
     }
 }
 
+/// Builder for [`TypeInfo`], created by [`TypeInfo::builder`].
+///
+/// Only the fields relevant to the type ID are written on the wire; see
+/// [`TypeInfo::encode`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct TypeInfoBuilder {
+    info: TypeInfo,
+}
+
+impl TypeInfoBuilder {
+    /// Set the maximum length in bytes (`0xFFFF` selects the MAX/PLP form
+    /// for variable-length character and binary types).
+    pub fn max_length(mut self, max_length: u16) -> Self {
+        self.info.max_length = Some(max_length);
+        self
+    }
+
+    /// Set the precision for DECIMAL/NUMERIC.
+    pub fn precision(mut self, precision: u8) -> Self {
+        self.info.precision = Some(precision);
+        self
+    }
+
+    /// Set the scale for DECIMAL/NUMERIC and the fractional-second scale for
+    /// TIME, DATETIME2 and DATETIMEOFFSET.
+    pub fn scale(mut self, scale: u8) -> Self {
+        self.info.scale = Some(scale);
+        self
+    }
+
+    /// Set the 5-byte collation for character types.
+    pub fn collation(mut self, collation: [u8; 5]) -> Self {
+        self.info.collation = Some(collation);
+        self
+    }
+
+    /// Finish building.
+    ///
+    /// Character types (NVARCHAR, VARCHAR, NCHAR, CHAR) without an explicit
+    /// collation get the default Latin1_General_CI_AS collation, since the
+    /// server rejects their TYPE_INFO without one.
+    pub fn build(mut self) -> TypeInfo {
+        if matches!(self.info.type_id, 0xE7 | 0xA7 | 0xEF | 0xAF) && self.info.collation.is_none() {
+            self.info.collation = Some(TypeInfo::DEFAULT_COLLATION);
+        }
+        self.info
+    }
+}
+
 /// Always Encrypted cipher metadata, written after an encrypted parameter's
 /// ciphertext value (MS-TDS 2.2.6.6 `CryptoMetadata`).
 ///
@@ -1378,6 +1465,56 @@ mod tests {
         assert_eq!(rpc.params.len(), 3);
     }
 
+    #[test]
+    fn test_rpc_all_headers_carry_transaction_descriptor() {
+        let request = RpcRequest::execute_sql("SELECT @p1", vec![RpcParam::int("@p1", 1)]);
+        let descriptor = 0x0102_0304_0506_0708u64;
+        let payload = request.encode_with_transaction(descriptor);
+
+        // ALL_HEADERS: total length, then one transaction-descriptor header.
+        assert_eq!(&payload[0..4], &22u32.to_le_bytes());
+        assert_eq!(&payload[4..8], &18u32.to_le_bytes());
+        assert_eq!(&payload[8..10], &0x0002u16.to_le_bytes());
+        assert_eq!(&payload[10..18], &descriptor.to_le_bytes());
+        assert_eq!(&payload[18..22], &1u32.to_le_bytes());
+
+        // Auto-commit requests send a zero descriptor in the same slot.
+        let auto = request.encode();
+        assert_eq!(&auto[10..18], &0u64.to_le_bytes());
+        assert_eq!(auto[22..], payload[22..]);
+    }
+
+    #[test]
+    fn test_type_info_builder_matches_constructors() {
+        let built = TypeInfo::builder(0xE7).max_length(256).build();
+        let mut expected = BytesMut::new();
+        TypeInfo::nvarchar(128).encode(&mut expected);
+        let mut actual = BytesMut::new();
+        built.encode(&mut actual);
+        assert_eq!(actual, expected);
+
+        let built = TypeInfo::builder(0x6C)
+            .max_length(17)
+            .precision(18)
+            .scale(4)
+            .build();
+        let mut expected = BytesMut::new();
+        TypeInfo::decimal(18, 4).encode(&mut expected);
+        let mut actual = BytesMut::new();
+        built.encode(&mut actual);
+        assert_eq!(actual, expected);
+
+        let binary = TypeInfo::builder(0xA5).max_length(16).build();
+        assert!(binary.collation.is_none());
+
+        let custom = [0x04, 0x08, 0xD0, 0x00, 0x00];
+        let varchar = TypeInfo::builder(0xA7)
+            .max_length(10)
+            .collation(custom)
+            .build();
+        assert_eq!(varchar.collation, Some(custom));
+    }
+
     #[test]
     fn test_param_declarations() {
         let params = vec![
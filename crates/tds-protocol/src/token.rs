@@ -2414,6 +2414,8 @@ pub struct TokenParser {
     /// parse and drop the matching ALTROW tokens. Cleared on each new
     /// ColMetaData (result-set boundary), where compute Ids become unique again.
     alt_metadata: Vec<(u16, ColMetaData)>,
+    /// Reject tokens the parser only knows how to skip instead of skipping them.
+    strict: bool,
 }
 
 impl TokenParser {
@@ -2425,6 +2427,7 @@ impl TokenParser {
             position: 0,
             encryption_enabled: false,
             alt_metadata: Vec::new(),
+            strict: false,
         }
     }
 
@@ -2438,6 +2441,19 @@ impl TokenParser {
         self
     }
 
+    /// Enable strict parsing.
+    ///
+    /// By default, tokens that carry nothing the driver surfaces (ColInfo,
+    /// TabName, Offset, and the COMPUTE BY AltMetaData/AltRow pair) are
+    /// consumed and dropped. When strict, they instead fail with
+    /// [`ProtocolError::UnhandledToken`], so a misjudged skip cannot silently
+    /// desynchronize the stream.
+    #[must_use]
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
     /// Get remaining bytes in the buffer.
     #[must_use]
     pub fn remaining(&self) -> usize {
@@ -2492,6 +2508,24 @@ impl TokenParser {
             let token_type_byte = buf.get_u8();
             let token_type = TokenType::from_u8(token_type_byte);
 
+            if self.strict
+                && matches!(
+                    token_type,
+                    Some(
+                        TokenType::ColInfo
+                            | TokenType::TabName
+                            | TokenType::Offset
+                            | TokenType::AltMetaData
+                            | TokenType::AltRow
+                    )
+                )
+            {
+                return Err(ProtocolError::UnhandledToken {
+                    token_type: token_type_byte,
+                    offset: start_pos,
+                });
+            }
+
             let token = match token_type {
                 Some(TokenType::Done) => {
                     let done = Done::decode(&mut buf)?;
@@ -4471,6 +4505,53 @@ mod tests {
         assert!(parser.next_token().unwrap().is_none());
     }
 
+    /// In strict mode a token the parser would skip is an error naming the
+    /// token byte and its offset; tokens before it still parse normally.
+    #[test]
+    fn strict_parser_rejects_skipped_tokens() {
+        let done = Done {
+            status: DoneStatus {
+                more: true,
+                error: false,
+                in_xact: false,
+                count: false,
+                attn: false,
+                srverror: false,
+            },
+            cur_cmd: 0xC1,
+            row_count: 0,
+        };
+        let mut buf = BytesMut::new();
+        done.encode(&mut buf);
+        let tabname_offset = buf.len();
+        buf.put_u8(TokenType::TabName as u8);
+        buf.put_u16_le(0);
+        done.encode(&mut buf);
+        let data = buf.freeze();
+
+        let mut lenient = TokenParser::new(data.clone());
+        assert!(matches!(
+            lenient.next_token().unwrap(),
+            Some(Token::Done(_))
+        ));
+        assert!(matches!(
+            lenient.next_token().unwrap(),
+            Some(Token::Done(_))
+        ));
+
+        let mut strict = TokenParser::new(data).with_strict(true);
+        assert!(matches!(strict.next_token().unwrap(), Some(Token::Done(_))));
+        let err = strict.next_token().unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ProtocolError::UnhandledToken { token_type: 0xA4, offset }
+                    if offset == tabname_offset
+            ),
+            "got {err:?}"
+        );
+    }
+
     /// `skip_token` must parse a RETURNVALUE to find its end, not treat the
     /// leading ParamOrdinal as a 2-byte length prefix (issue #281). With the
     /// old length-prefix treatment it skipped `1 + 2 + ParamOrdinal` bytes,